@@ -109,10 +109,10 @@ export default function CasesDashboard() {
                   </span>
                 </div>
 
-                {caseData.account.jurors.length > 0 && (
+                {caseData.account.jurorCount > 0 && (
                   <div className="detail-row">
                     <span className="label">Jurors:</span>
-                    <span className="value">{caseData.account.jurors.length} selected</span>
+                    <span className="value">{caseData.account.jurorCount} selected</span>
                   </div>
                 )}
               </div>
@@ -1,276 +1,301 @@
-// ZK Proof Utilities for Private Voting Frontend
-import { PublicKey } from '@solana/web3.js';
-import { sha256 } from 'crypto-hash';
-
-export interface VoteCommitment {
-  commitment: Uint8Array;
-  nullifier: Uint8Array;
-  salt: Uint8Array;
-  vote: boolean;
-  caseId: number;
-}
-
-export interface ZkProof {
-  proofData: Uint8Array;
-  publicInputs: Uint8Array;
-  proofType: 'VoteCommitment' | 'EvidenceHash' | 'JurorEligibility' | 'TallyVerification';
-}
-
-/**
- * Generate a vote commitment for private voting
- * Uses Pedersen commitment scheme
- */
-export async function generateVoteCommitment(
-  caseId: number,
-  vote: boolean,
-  salt?: Uint8Array
-): Promise<VoteCommitment> {
-  // Generate random salt if not provided
-  const voteSalt = salt || crypto.getRandomValues(new Uint8Array(32));
-  
-  // Create commitment: H(vote || salt)
-  const voteBytes = new Uint8Array([vote ? 1 : 0]);
-  const commitmentInput = new Uint8Array([...voteBytes, ...voteSalt]);
-  const commitmentHash = await sha256(commitmentInput, { outputFormat: 'buffer' });
-  const commitment = new Uint8Array(commitmentHash);
-  
-  // Create nullifier: H(caseId || commitment)
-  const caseIdBytes = new Uint8Array(new BigUint64Array([BigInt(caseId)]).buffer);
-  const nullifierInput = new Uint8Array([...caseIdBytes, ...commitment]);
-  const nullifierHash = await sha256(nullifierInput, { outputFormat: 'buffer' });
-  const nullifier = new Uint8Array(nullifierHash);
-  
-  return {
-    commitment,
-    nullifier,
-    salt: voteSalt,
-    vote,
-    caseId,
-  };
-}
-
-/**
- * Generate a ZK proof for vote commitment
- * In production, this would use a ZK circuit (e.g., circom, snarkjs)
- */
-export async function generateVoteProof(
-  commitment: VoteCommitment
-): Promise<ZkProof> {
-  // Placeholder for actual ZK proof generation
-  // In production, integrate with Light Protocol SDK
-  const proofData = new Uint8Array(128); // Placeholder proof
-  crypto.getRandomValues(proofData);
-  
-  const publicInputs = new Uint8Array([
-    ...commitment.commitment,
-    ...commitment.nullifier,
-  ]);
-  
-  return {
-    proofData,
-    publicInputs,
-    proofType: 'VoteCommitment',
-  };
-}
-
-/**
- * Verify a vote reveal matches the commitment
- */
-export async function verifyVoteReveal(
-  commitment: Uint8Array,
-  vote: boolean,
-  salt: Uint8Array
-): Promise<boolean> {
-  const voteBytes = new Uint8Array([vote ? 1 : 0]);
-  const commitmentInput = new Uint8Array([...voteBytes, ...salt]);
-  const recomputedHash = await sha256(commitmentInput, { outputFormat: 'buffer' });
-  const recomputedCommitment = new Uint8Array(recomputedHash);
-  
-  // Compare commitments
-  if (commitment.length !== recomputedCommitment.length) return false;
-  
-  for (let i = 0; i < commitment.length; i++) {
-    if (commitment[i] !== recomputedCommitment[i]) return false;
-  }
-  
-  return true;
-}
-
-/**
- * Generate encrypted evidence using MPC encryption
- * For Arcium MPC integration
- */
-export async function encryptEvidenceForMPC(
-  evidence: string,
-  jurorPublicKeys: PublicKey[],
-  threshold: number
-): Promise<{
-  encryptedEvidence: Uint8Array;
-  evidenceHash: Uint8Array;
-  shares: Uint8Array[];
-}> {
-  // Hash the evidence
-  const evidenceHash = new Uint8Array(
-    await sha256(evidence, { outputFormat: 'buffer' })
-  );
-  
-  // In production, use Arcium's threshold encryption
-  // This is a placeholder implementation
-  const evidenceBytes = new TextEncoder().encode(evidence);
-  const encryptedEvidence = new Uint8Array(evidenceBytes.length);
-  
-  // Simple XOR "encryption" as placeholder
-  for (let i = 0; i < evidenceBytes.length; i++) {
-    encryptedEvidence[i] = evidenceBytes[i] ^ 0xAA;
-  }
-  
-  // Generate shares (placeholder - use Arcium's actual secret sharing)
-  const shares = jurorPublicKeys.map((_, idx) => {
-    const share = new Uint8Array(32);
-    crypto.getRandomValues(share);
-    return share;
-  });
-  
-  return {
-    encryptedEvidence,
-    evidenceHash,
-    shares,
-  };
-}
-
-/**
- * Generate range proof for confidential transfer (Dust Protocol)
- */
-export async function generateRangeProof(
-  amount: number,
-  maxAmount: number
-): Promise<Uint8Array> {
-  // Placeholder for Bulletproofs-based range proof
-  // In production, integrate with Dust Protocol SDK
-  
-  if (amount < 0 || amount > maxAmount) {
-    throw new Error('Amount out of valid range');
-  }
-  
-  const proof = new Uint8Array(256);
-  crypto.getRandomValues(proof);
-  
-  // Encode amount in proof (for testing)
-  const amountBytes = new Uint8Array(new BigUint64Array([BigInt(amount)]).buffer);
-  proof.set(amountBytes, 0);
-  
-  return proof;
-}
-
-/**
- * Encrypt amount for confidential transfer
- */
-export async function encryptAmount(
-  amount: number,
-  recipientPubkey: PublicKey
-): Promise<Uint8Array> {
-  // Placeholder for ElGamal encryption
-  // In production, use Dust Protocol's encryption
-  
-  const encrypted = new Uint8Array(64);
-  const amountBytes = new Uint8Array(new BigUint64Array([BigInt(amount)]).buffer);
-  
-  // Simple placeholder encryption
-  encrypted.set(amountBytes, 0);
-  crypto.getRandomValues(encrypted.subarray(8));
-  
-  return encrypted;
-}
-
-/**
- * Generate compliance proof for confidential transfer
- */
-export async function generateComplianceProof(
-  amount: number,
-  sender: PublicKey,
-  recipient: PublicKey
-): Promise<Uint8Array> {
-  // Placeholder for compliance proof
-  // In production, integrate with compliance oracle
-  
-  const proof = new Uint8Array(256);
-  const data = new TextEncoder().encode(
-    `${amount}-${sender.toString()}-${recipient.toString()}`
-  );
-  const hash = await sha256(data, { outputFormat: 'buffer' });
-  proof.set(new Uint8Array(hash), 0);
-  
-  return proof;
-}
-
-/**
- * Light Protocol: Add to compressed state tree
- */
-export interface CompressedStateUpdate {
-  merkleRoot: Uint8Array;
-  commitment: Uint8Array;
-  proof: Uint8Array[];
-}
-
-export async function createCompressedStateUpdate(
-  commitment: Uint8Array,
-  currentRoot: Uint8Array
-): Promise<CompressedStateUpdate> {
-  // Placeholder for Light Protocol compression
-  // In production, use Light Protocol SDK
-  
-  const newRoot = await sha256(
-    new Uint8Array([...currentRoot, ...commitment]),
-    { outputFormat: 'buffer' }
-  );
-  
-  return {
-    merkleRoot: new Uint8Array(newRoot),
-    commitment,
-    proof: [], // Merkle proof path
-  };
-}
-
-/**
- * Storage helper for vote commitments (keep salt secret!)
- */
-export class VoteCommitmentStore {
-  private static STORAGE_KEY = 'solsafe_vote_commitments';
-  
-  static save(caseId: number, commitment: VoteCommitment): void {
-    const stored = this.getAll();
-    stored[caseId] = {
-      commitment: Array.from(commitment.commitment),
-      nullifier: Array.from(commitment.nullifier),
-      salt: Array.from(commitment.salt),
-      vote: commitment.vote,
-      caseId: commitment.caseId,
-    };
-    localStorage.setItem(this.STORAGE_KEY, JSON.stringify(stored));
-  }
-  
-  static get(caseId: number): VoteCommitment | null {
-    const stored = this.getAll();
-    const data = stored[caseId];
-    if (!data) return null;
-    
-    return {
-      commitment: new Uint8Array(data.commitment),
-      nullifier: new Uint8Array(data.nullifier),
-      salt: new Uint8Array(data.salt),
-      vote: data.vote,
-      caseId: data.caseId,
-    };
-  }
-  
-  static getAll(): Record<number, any> {
-    const stored = localStorage.getItem(this.STORAGE_KEY);
-    return stored ? JSON.parse(stored) : {};
-  }
-  
-  static remove(caseId: number): void {
-    const stored = this.getAll();
-    delete stored[caseId];
-    localStorage.setItem(this.STORAGE_KEY, JSON.stringify(stored));
-  }
-}
+// ZK Proof Utilities for Private Voting Frontend
+import { PublicKey } from '@solana/web3.js';
+import { sha256 } from 'crypto-hash';
+
+// Must match the on-chain domain separation in programs/solsafe-program/src/domain.rs
+export const SOLSAFE_PROGRAM_ID = new PublicKey('D4mtpATBupFapyGgX3QHaUU1ZpMUTXP7LE12Lpgc77m1');
+const DOMAIN_NAMESPACE = new TextEncoder().encode('solsafe:v1');
+
+/**
+ * Build a domain-separated hash preimage:
+ * program_id || len(namespace) || namespace || len(purpose) || purpose || parts...
+ */
+function domainPreimage(purpose: string, ...parts: Uint8Array[]): Uint8Array {
+  const purposeBytes = new TextEncoder().encode(purpose);
+  return new Uint8Array([
+    ...SOLSAFE_PROGRAM_ID.toBytes(),
+    DOMAIN_NAMESPACE.length,
+    ...DOMAIN_NAMESPACE,
+    purposeBytes.length,
+    ...purposeBytes,
+    ...parts.flatMap((part) => Array.from(part)),
+  ]);
+}
+
+function caseIdToBytes(caseId: number): Uint8Array {
+  return new Uint8Array(new BigUint64Array([BigInt(caseId)]).buffer);
+}
+
+export interface VoteCommitment {
+  commitment: Uint8Array;
+  nullifier: Uint8Array;
+  salt: Uint8Array;
+  vote: boolean;
+  caseId: number;
+}
+
+export interface ZkProof {
+  proofData: Uint8Array;
+  publicInputs: Uint8Array;
+  proofType: 'VoteCommitment' | 'EvidenceHash' | 'JurorEligibility' | 'TallyVerification';
+}
+
+/**
+ * Generate a vote commitment for private voting
+ * Uses Pedersen commitment scheme
+ */
+export async function generateVoteCommitment(
+  caseId: number,
+  vote: boolean,
+  salt?: Uint8Array
+): Promise<VoteCommitment> {
+  // Generate random salt if not provided
+  const voteSalt = salt || crypto.getRandomValues(new Uint8Array(32));
+  
+  // Create commitment: H(domain || caseId || vote || salt)
+  const voteBytes = new Uint8Array([vote ? 1 : 0]);
+  const caseIdBytes = caseIdToBytes(caseId);
+  const commitmentInput = domainPreimage('vote_commitment', caseIdBytes, voteBytes, voteSalt);
+  const commitmentHash = await sha256(commitmentInput, { outputFormat: 'buffer' });
+  const commitment = new Uint8Array(commitmentHash);
+  
+  // Create nullifier: H(domain || caseId || commitment)
+  const nullifierInput = domainPreimage('vote_nullifier', caseIdBytes, commitment);
+  const nullifierHash = await sha256(nullifierInput, { outputFormat: 'buffer' });
+  const nullifier = new Uint8Array(nullifierHash);
+  
+  return {
+    commitment,
+    nullifier,
+    salt: voteSalt,
+    vote,
+    caseId,
+  };
+}
+
+/**
+ * Generate a ZK proof for vote commitment
+ * In production, this would use a ZK circuit (e.g., circom, snarkjs)
+ */
+export async function generateVoteProof(
+  commitment: VoteCommitment
+): Promise<ZkProof> {
+  // Placeholder for actual ZK proof generation
+  // In production, integrate with Light Protocol SDK
+  const proofData = new Uint8Array(128); // Placeholder proof
+  crypto.getRandomValues(proofData);
+  
+  const publicInputs = new Uint8Array([
+    ...commitment.commitment,
+    ...commitment.nullifier,
+  ]);
+  
+  return {
+    proofData,
+    publicInputs,
+    proofType: 'VoteCommitment',
+  };
+}
+
+/**
+ * Verify a vote reveal matches the commitment
+ */
+export async function verifyVoteReveal(
+  caseId: number,
+  commitment: Uint8Array,
+  vote: boolean,
+  salt: Uint8Array
+): Promise<boolean> {
+  const voteBytes = new Uint8Array([vote ? 1 : 0]);
+  const commitmentInput = domainPreimage('vote_commitment', caseIdToBytes(caseId), voteBytes, salt);
+  const recomputedHash = await sha256(commitmentInput, { outputFormat: 'buffer' });
+  const recomputedCommitment = new Uint8Array(recomputedHash);
+  
+  // Compare commitments
+  if (commitment.length !== recomputedCommitment.length) return false;
+  
+  for (let i = 0; i < commitment.length; i++) {
+    if (commitment[i] !== recomputedCommitment[i]) return false;
+  }
+  
+  return true;
+}
+
+/**
+ * Generate encrypted evidence using MPC encryption
+ * For Arcium MPC integration
+ */
+export async function encryptEvidenceForMPC(
+  evidence: string,
+  jurorPublicKeys: PublicKey[],
+  threshold: number
+): Promise<{
+  encryptedEvidence: Uint8Array;
+  evidenceHash: Uint8Array;
+  shares: Uint8Array[];
+}> {
+  // Hash the evidence
+  const evidenceHash = new Uint8Array(
+    await sha256(evidence, { outputFormat: 'buffer' })
+  );
+  
+  // In production, use Arcium's threshold encryption
+  // This is a placeholder implementation
+  const evidenceBytes = new TextEncoder().encode(evidence);
+  const encryptedEvidence = new Uint8Array(evidenceBytes.length);
+  
+  // Simple XOR "encryption" as placeholder
+  for (let i = 0; i < evidenceBytes.length; i++) {
+    encryptedEvidence[i] = evidenceBytes[i] ^ 0xAA;
+  }
+  
+  // Generate shares (placeholder - use Arcium's actual secret sharing)
+  const shares = jurorPublicKeys.map((_, idx) => {
+    const share = new Uint8Array(32);
+    crypto.getRandomValues(share);
+    return share;
+  });
+  
+  return {
+    encryptedEvidence,
+    evidenceHash,
+    shares,
+  };
+}
+
+/**
+ * Generate range proof for confidential transfer (Dust Protocol)
+ */
+export async function generateRangeProof(
+  amount: number,
+  maxAmount: number
+): Promise<Uint8Array> {
+  // Placeholder for Bulletproofs-based range proof
+  // In production, integrate with Dust Protocol SDK
+  
+  if (amount < 0 || amount > maxAmount) {
+    throw new Error('Amount out of valid range');
+  }
+  
+  const proof = new Uint8Array(256);
+  crypto.getRandomValues(proof);
+  
+  // Encode amount in proof (for testing)
+  const amountBytes = new Uint8Array(new BigUint64Array([BigInt(amount)]).buffer);
+  proof.set(amountBytes, 0);
+  
+  return proof;
+}
+
+/**
+ * Encrypt amount for confidential transfer
+ */
+export async function encryptAmount(
+  amount: number,
+  recipientPubkey: PublicKey
+): Promise<Uint8Array> {
+  // Placeholder for ElGamal encryption
+  // In production, use Dust Protocol's encryption
+  
+  const encrypted = new Uint8Array(64);
+  const amountBytes = new Uint8Array(new BigUint64Array([BigInt(amount)]).buffer);
+  
+  // Simple placeholder encryption
+  encrypted.set(amountBytes, 0);
+  crypto.getRandomValues(encrypted.subarray(8));
+  
+  return encrypted;
+}
+
+/**
+ * Generate compliance proof for confidential transfer
+ */
+export async function generateComplianceProof(
+  amount: number,
+  sender: PublicKey,
+  recipient: PublicKey
+): Promise<Uint8Array> {
+  // Placeholder for compliance proof
+  // In production, integrate with compliance oracle
+  
+  const proof = new Uint8Array(256);
+  const data = new TextEncoder().encode(
+    `${amount}-${sender.toString()}-${recipient.toString()}`
+  );
+  const hash = await sha256(data, { outputFormat: 'buffer' });
+  proof.set(new Uint8Array(hash), 0);
+  
+  return proof;
+}
+
+/**
+ * Light Protocol: Add to compressed state tree
+ */
+export interface CompressedStateUpdate {
+  merkleRoot: Uint8Array;
+  commitment: Uint8Array;
+  proof: Uint8Array[];
+}
+
+export async function createCompressedStateUpdate(
+  commitment: Uint8Array,
+  currentRoot: Uint8Array
+): Promise<CompressedStateUpdate> {
+  // Placeholder for Light Protocol compression
+  // In production, use Light Protocol SDK
+  
+  const newRoot = await sha256(
+    new Uint8Array([...currentRoot, ...commitment]),
+    { outputFormat: 'buffer' }
+  );
+  
+  return {
+    merkleRoot: new Uint8Array(newRoot),
+    commitment,
+    proof: [], // Merkle proof path
+  };
+}
+
+/**
+ * Storage helper for vote commitments (keep salt secret!)
+ */
+export class VoteCommitmentStore {
+  private static STORAGE_KEY = 'solsafe_vote_commitments';
+  
+  static save(caseId: number, commitment: VoteCommitment): void {
+    const stored = this.getAll();
+    stored[caseId] = {
+      commitment: Array.from(commitment.commitment),
+      nullifier: Array.from(commitment.nullifier),
+      salt: Array.from(commitment.salt),
+      vote: commitment.vote,
+      caseId: commitment.caseId,
+    };
+    localStorage.setItem(this.STORAGE_KEY, JSON.stringify(stored));
+  }
+  
+  static get(caseId: number): VoteCommitment | null {
+    const stored = this.getAll();
+    const data = stored[caseId];
+    if (!data) return null;
+    
+    return {
+      commitment: new Uint8Array(data.commitment),
+      nullifier: new Uint8Array(data.nullifier),
+      salt: new Uint8Array(data.salt),
+      vote: data.vote,
+      caseId: data.caseId,
+    };
+  }
+  
+  static getAll(): Record<number, any> {
+    const stored = localStorage.getItem(this.STORAGE_KEY);
+    return stored ? JSON.parse(stored) : {};
+  }
+  
+  static remove(caseId: number): void {
+    const stored = this.getAll();
+    delete stored[caseId];
+    localStorage.setItem(this.STORAGE_KEY, JSON.stringify(stored));
+  }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, keccak, program::invoke};
+
+/// Metaplex Bubblegum; derives cNFT asset ids and writes the leaves
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+/// SPL account compression; owns Bubblegum's concurrent Merkle trees
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// Anchor discriminator of account compression's `verify_leaf`
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+/// `Version::V1` tag hashed into every Bubblegum leaf
+const LEAF_SCHEMA_V1: u8 = 1;
+
+/// Asset id of the cNFT minted as leaf `nonce` of `tree`
+pub fn asset_id(tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", tree.as_ref(), nonce.to_le_bytes().as_ref()],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Hash of a `LeafSchema::V1` leaf, as Bubblegum writes it into the tree
+pub fn leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &[LEAF_SCHEMA_V1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        nonce.to_le_bytes().as_ref(),
+        data_hash.as_ref(),
+        creator_hash.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Have account compression check `leaf` sits at `index` under `root`; the proof nodes
+/// are passed as accounts, leaf end first. Fails the transaction if it doesn't.
+pub fn verify_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+    let mut infos = vec![merkle_tree.clone()];
+    infos.extend(proof.iter().cloned());
+    infos.push(compression_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &infos,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separation for every hash the program commits to.
+///
+/// Preimage layout: program_id || len(NAMESPACE) || NAMESPACE || len(purpose) || purpose || parts...
+/// Binding the program id stops replay across deployments, the namespace stops replay
+/// across protocol versions, and the purpose tag stops one kind of hash being passed
+/// off as another. Callers include the case id in `parts` wherever the value is case-scoped.
+pub const NAMESPACE: &[u8] = b"solsafe:v1";
+
+pub const VOTE_COMMITMENT: &[u8] = b"vote_commitment";
+pub const VOTE_NULLIFIER: &[u8] = b"vote_nullifier";
+pub const MPC_COMPUTATION: &[u8] = b"mpc_computation";
+pub const AUDITOR_DATA: &[u8] = b"auditor_data";
+pub const JUROR_DISCLOSURE: &[u8] = b"juror_disclosure";
+pub const EVIDENCE_COMMITMENT: &[u8] = b"evidence_commitment";
+pub const EVIDENCE_KEY_SHARE: &[u8] = b"evidence_key_share";
+pub const EVIDENCE_KEYSTREAM: &[u8] = b"evidence_keystream";
+pub const COMPRESSED_VOTE_LEAF: &[u8] = b"compressed_vote_leaf";
+pub const CASE_ARCHIVE_LEAF: &[u8] = b"case_archive_leaf";
+pub const MERKLE_NODE: &[u8] = b"merkle_node";
+pub const FUND_FLOW_LEAF: &[u8] = b"fund_flow_leaf";
+pub const SCAM_LIST_LEAF: &[u8] = b"scam_list_leaf";
+pub const JUROR_SELECTION: &[u8] = b"juror_selection";
+pub const JUROR_WEIGHTING: &[u8] = b"juror_weighting";
+pub const JUROR_REPLACEMENT: &[u8] = b"juror_replacement";
+pub const SEALED_ADDRESS: &[u8] = b"sealed_address";
+pub const BATCH_PROOF: &[u8] = b"batch_proof";
+pub const PQ_VERDICT: &[u8] = b"pq_verdict";
+pub const DECRYPTION_PROOF: &[u8] = b"decryption_proof";
+pub const BALLOT_PROOF: &[u8] = b"ballot_proof";
+pub const JUROR_ELIGIBILITY: &[u8] = b"juror_eligibility";
+pub const REPORT_SIGNAL: &[u8] = b"report_signal";
+pub const IDENTITY_SIGNAL: &[u8] = b"identity_signal";
+
+pub fn hash(purpose: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let program_id = crate::ID;
+    let namespace_len = [NAMESPACE.len() as u8];
+    let purpose_len = [purpose.len() as u8];
+
+    let mut preimage: Vec<&[u8]> = Vec::with_capacity(5 + parts.len());
+    preimage.push(program_id.as_ref());
+    preimage.push(&namespace_len);
+    preimage.push(NAMESPACE);
+    preimage.push(&purpose_len);
+    preimage.push(purpose);
+    preimage.extend_from_slice(parts);
+
+    hashv(&preimage).to_bytes()
+}
+
+/// Purpose tag as a BN254 field element for Poseidon preimages: the domain hash with its
+/// top byte cleared, so it is canonical big-endian and still bound to program and namespace
+pub fn poseidon_tag(purpose: &[u8]) -> [u8; 32] {
+    let mut tag = hash(purpose, &[]);
+    tag[0] = 0;
+    tag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purpose_tags_separate_domains() {
+        let parts: &[&[u8]] = &[b"same input"];
+        assert_ne!(hash(VOTE_COMMITMENT, parts), hash(VOTE_NULLIFIER, parts));
+    }
+
+    #[test]
+    fn test_poseidon_tags_are_field_elements() {
+        assert_eq!(poseidon_tag(VOTE_COMMITMENT)[0], 0);
+        assert_ne!(poseidon_tag(VOTE_COMMITMENT), poseidon_tag(VOTE_NULLIFIER));
+    }
+
+    #[test]
+    fn test_hash_is_deterministic() {
+        let parts: &[&[u8]] = &[&7u64.to_le_bytes(), b"payload"];
+        assert_eq!(hash(AUDITOR_DATA, parts), hash(AUDITOR_DATA, parts));
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::ErrorCode;
+
+/// `Ed25519SignatureOffsets`: seven u16s after the signature count and a padding byte
+const OFFSETS_START: usize = 2;
+const OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+/// Instruction index meaning "this instruction's own data"
+const THIS_INSTRUCTION: u16 = u16::MAX;
+
+/// Signer of `message` as verified by an Ed25519 program instruction placed right before
+/// the current one. The runtime has already checked the signature by the time this runs;
+/// what's left is making sure it covers `message` and reading off the key. Only a single
+/// signature whose key and message sit in the Ed25519 instruction itself is accepted.
+pub fn verified_signer(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Pubkey> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let previous = current.checked_sub(1).ok_or(ErrorCode::InvalidNotarization)?;
+    let ix = load_instruction_at_checked(previous as usize, instructions_sysvar)?;
+    require!(ix.program_id == ed25519_program::ID, ErrorCode::InvalidNotarization);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= OFFSETS_START + OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidNotarization
+    );
+    let field = |i: usize| {
+        let at = OFFSETS_START + 2 * i;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let (signature_ix, pubkey_offset, pubkey_ix) = (field(1), field(2) as usize, field(3));
+    let (message_offset, message_size, message_ix) = (field(4) as usize, field(5) as usize, field(6));
+    require!(
+        signature_ix == THIS_INSTRUCTION && pubkey_ix == THIS_INSTRUCTION && message_ix == THIS_INSTRUCTION,
+        ErrorCode::InvalidNotarization
+    );
+
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidNotarization)?;
+    require!(signed == message, ErrorCode::InvalidNotarization);
+    let key = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+        .ok_or(ErrorCode::InvalidNotarization)?;
+    Ok(Pubkey::try_from(key).map_err(|_| ErrorCode::InvalidNotarization)?)
+}
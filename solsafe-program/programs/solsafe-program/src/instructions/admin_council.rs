@@ -0,0 +1,496 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    AdminCouncil, CaseAccount, ConfidentialCaseConfig, CouncilAction, CouncilProposal, GlobalConfig,
+    InsuranceFund, IntelProvider, MisconductReport, PassportRegistry, PriceFeedRegistry, ProtocolStats, Treasury, ValidatorPage, ValidatorStake, ValidatorStatus,
+    COUNCIL_PROPOSAL_TTL, MAX_CASE_JURORS, MAX_COUNCIL_MEMBERS,
+};
+use crate::zk_proofs::{apply_verifying_key, VerifyingKeyAccount};
+use crate::{CouncilActionExecuted, ErrorCode};
+use super::case_bond::apply_price_feeds;
+use super::case_categories::apply_category_params;
+use super::expire_case::apply_max_case_age;
+use super::insurance_fund::{apply_insurance_funding, apply_overturn};
+use super::juror_misconduct::apply_misconduct_ruling;
+use super::juror_passport::apply_passport_policy;
+use super::heartbeat::apply_heartbeat_epochs;
+use super::juror_reputation::apply_reputation_weighting;
+use super::quadratic_voting::apply_quadratic_voting;
+use super::severity_thresholds::apply_severity_threshold;
+use super::threat_intel::apply_provider_approval;
+use super::tie_break::apply_tie_break_rule;
+use super::escalation::apply_escalation;
+use super::evidentiary_round::apply_probable_cause_jurors;
+use super::randomness_source::apply_randomness_source;
+use super::reporter_bounty::apply_reporter_bounty;
+use super::confidential_case::apply_confidential_policy;
+use super::treasury::{set_fees, withdraw_lamports, withdraw_tokens};
+use super::validator_staking::apply_min_validator_stake;
+use super::validator_registry::{append_to_page, remove_from_page, replace_page, sync_active_validators};
+
+#[derive(Accounts)]
+pub struct CreateAdminCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminCouncil::LEN,
+        seeds = [b"admin_council"],
+        bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: CouncilAction)]
+pub struct ProposeAction<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = council.bump,
+        constraint = council.is_member(&member.key()) @ ErrorCode::NotCouncilMember
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + CouncilProposal::space(&action),
+        seeds = [b"council_proposal", council.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    pub member: Signer<'info>,
+    #[account(
+        seeds = [b"admin_council"],
+        bump = council.bump,
+        constraint = council.is_member(&member.key()) @ ErrorCode::NotCouncilMember
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    #[account(
+        mut,
+        seeds = [b"council_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"admin_council"],
+        bump = council.bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
+    #[account(
+        mut,
+        seeds = [b"council_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+    /// Only needed for validator actions
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Option<Account<'info, ValidatorPage>>,
+    /// Only needed for validator removals off a page other than the last
+    #[account(
+        mut,
+        seeds = [b"validator_page", last_page.page_index.to_le_bytes().as_ref()],
+        bump = last_page.bump,
+        constraint = last_page.page_index + 1 == config.page_count @ ErrorCode::InvalidValidatorPage
+    )]
+    pub last_page: Option<Account<'info, ValidatorPage>>,
+    /// Only needed for treasury withdrawals
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    /// CHECK: Checked against the recipient named in the proposal
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// Only needed for passport policy changes
+    #[account(
+        mut,
+        seeds = [b"passport_registry"],
+        bump = passport_registry.bump
+    )]
+    pub passport_registry: Option<Account<'info, PassportRegistry>>,
+    /// Only needed for confidential case policy changes
+    #[account(
+        mut,
+        seeds = [b"confidential_case_config"],
+        bump = confidential_config.bump
+    )]
+    pub confidential_config: Option<Account<'info, ConfidentialCaseConfig>>,
+    /// Only needed for verifying key updates; must hold the proposal's proof type
+    #[account(mut)]
+    pub verifying_key: Option<Account<'info, VerifyingKeyAccount>>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    /// Only needed for misconduct rulings, along with the juror's stake accounts, the
+    /// insurance fund, and the reporter as `recipient`
+    #[account(mut)]
+    pub misconduct_report: Option<Account<'info, MisconductReport>>,
+    #[account(mut)]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+    #[account(mut)]
+    pub validator_status: Option<Account<'info, ValidatorStatus>>,
+    /// Only needed for misconduct rulings and treasury transfers into the fund
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+    /// Only needed for overturning a verdict
+    #[account(mut)]
+    pub case_account: Option<AccountLoader<'info, CaseAccount>>,
+    /// Only needed for price feed changes
+    #[account(
+        mut,
+        seeds = [b"price_feeds"],
+        bump = price_feeds.bump
+    )]
+    pub price_feeds: Option<Account<'info, PriceFeedRegistry>>,
+    /// Only needed for approving an intel provider
+    #[account(
+        mut,
+        seeds = [b"intel_provider", intel_provider.authority.as_ref()],
+        bump = intel_provider.bump
+    )]
+    pub intel_provider: Option<Account<'info, IntelProvider>>,
+}
+
+fn validate_council(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+        ErrorCode::InvalidCouncil
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= members.len(),
+        ErrorCode::InvalidCouncil
+    );
+    let has_duplicates = members
+        .iter()
+        .enumerate()
+        .any(|(i, member)| members[..i].contains(member));
+    require!(!has_duplicates, ErrorCode::InvalidCouncil);
+    Ok(())
+}
+
+/// Hand validator and config changes from the admin key to an M-of-N council
+pub fn create_handler(ctx: Context<CreateAdminCouncil>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    validate_council(&members, threshold)?;
+
+    let council = &mut ctx.accounts.council;
+    council.members = members;
+    council.threshold = threshold;
+    council.proposal_count = 0;
+    council.bump = ctx.bumps.council;
+
+    let config = &mut ctx.accounts.config;
+    config.council = council.key();
+
+    msg!("Admin council created: {}-of-{}", council.threshold, council.members.len());
+    Ok(())
+}
+
+pub fn propose_handler(ctx: Context<ProposeAction>, action: CouncilAction) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.id = council.proposal_count;
+    proposal.proposer = ctx.accounts.member.key();
+    proposal.action = action;
+    proposal.approvals = vec![proposal.proposer];
+    proposal.executed = false;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    council.proposal_count = council
+        .proposal_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Council proposal {} opened by {}", proposal.id, proposal.proposer);
+    Ok(())
+}
+
+pub fn approve_handler(ctx: Context<ApproveAction>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !proposal.executed && now < proposal.created_at + COUNCIL_PROPOSAL_TTL,
+        ErrorCode::ActionNotExecutable
+    );
+    require!(!proposal.approvals.contains(&member), ErrorCode::ActionAlreadyApproved);
+    require!(proposal.approvals.len() < MAX_COUNCIL_MEMBERS, ErrorCode::InvalidCouncil);
+    proposal.approvals.push(member);
+
+    msg!("Council proposal {} approvals: {}", proposal.id, proposal.approvals.len());
+    Ok(())
+}
+
+/// Permissionless once the threshold is met; only current members' approvals count
+pub fn execute_handler(ctx: Context<ExecuteAction>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let council = &mut ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !proposal.executed && now < proposal.created_at + COUNCIL_PROPOSAL_TTL,
+        ErrorCode::ActionNotExecutable
+    );
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|member| council.is_member(member))
+        .count();
+    require!(approvals >= council.threshold as usize, ErrorCode::ThresholdNotReached);
+
+    match proposal.action.clone() {
+        CouncilAction::ReplaceValidators { page_index, validators } => {
+            let validator_page = ctx
+                .accounts
+                .validator_page
+                .as_mut()
+                .ok_or(ErrorCode::InvalidValidatorPage)?;
+            require!(validator_page.page_index == page_index, ErrorCode::InvalidValidatorPage);
+            replace_page(config, validator_page, validators)?;
+            sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+        }
+        CouncilAction::AppendValidator { page_index, validator } => {
+            let validator_page = ctx
+                .accounts
+                .validator_page
+                .as_mut()
+                .ok_or(ErrorCode::InvalidValidatorPage)?;
+            require!(validator_page.page_index == page_index, ErrorCode::InvalidValidatorPage);
+            append_to_page(config, validator_page, validator)?;
+            sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+        }
+        CouncilAction::SetJuryParams { quorum, min_jurors } => {
+            require!(
+                min_jurors >= 1 && min_jurors as usize <= MAX_CASE_JURORS,
+                ErrorCode::InvalidThreshold
+            );
+            config.quorum = quorum;
+            config.min_jurors = min_jurors;
+        }
+        CouncilAction::ProposeAdmin { new_admin } => {
+            config.pending_admin = new_admin;
+        }
+        CouncilAction::SetPauser { pauser } => {
+            config.pauser = pauser;
+        }
+        CouncilAction::SetFees { submission_fee, distribution_fee_bps } => {
+            set_fees(config, submission_fee, distribution_fee_bps)?;
+        }
+        CouncilAction::SetCategoryParams { category, params } => {
+            apply_category_params(config, category, params)?;
+        }
+        CouncilAction::SetMaxCaseAge { max_case_age } => {
+            apply_max_case_age(config, max_case_age)?;
+        }
+        CouncilAction::SetPassportPolicy { attesters, min_cases } => {
+            let registry = ctx
+                .accounts
+                .passport_registry
+                .as_mut()
+                .ok_or(ErrorCode::InvalidPassportPolicy)?;
+            apply_passport_policy(config, registry, attesters, min_cases)?;
+        }
+        CouncilAction::SetHeartbeatEpochs { heartbeat_epochs } => {
+            apply_heartbeat_epochs(config, heartbeat_epochs)?;
+        }
+        CouncilAction::SetRandomnessSource { source } => {
+            apply_randomness_source(config, source)?;
+        }
+        CouncilAction::SetReputationWeighting { enabled } => {
+            apply_reputation_weighting(config, enabled)?;
+        }
+        CouncilAction::RemoveValidator { page_index, validator } => {
+            let validator_page = ctx
+                .accounts
+                .validator_page
+                .as_mut()
+                .ok_or(ErrorCode::InvalidValidatorPage)?;
+            require!(validator_page.page_index == page_index, ErrorCode::InvalidValidatorPage);
+            let last_page = ctx.accounts.last_page.as_deref_mut();
+            require!(
+                last_page.as_ref().map_or(true, |last| last.page_index != page_index),
+                ErrorCode::InvalidValidatorPage
+            );
+            remove_from_page(config, validator_page, last_page, &validator)?;
+            sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+        }
+        CouncilAction::ResolveMisconduct { case_id, juror, uphold } => {
+            let (Some(report), Some(validator_stake), Some(validator_status), Some(insurance_fund), Some(reporter)) = (
+                ctx.accounts.misconduct_report.as_mut(),
+                ctx.accounts.validator_stake.as_mut(),
+                ctx.accounts.validator_status.as_mut(),
+                ctx.accounts.insurance_fund.as_mut(),
+                ctx.accounts.recipient.as_ref(),
+            ) else {
+                return err!(ErrorCode::InvalidMisconductAccounts);
+            };
+            require!(
+                report.case_id == case_id && report.juror == juror && report.reporter == reporter.key(),
+                ErrorCode::InvalidMisconductAccounts
+            );
+            apply_misconduct_ruling(report, validator_stake, validator_status, insurance_fund, uphold)?;
+            report.close(reporter.to_account_info())?;
+        }
+        CouncilAction::FundInsurance { amount } => {
+            let (Some(treasury), Some(insurance_fund)) =
+                (ctx.accounts.treasury.as_mut(), ctx.accounts.insurance_fund.as_mut())
+            else {
+                return err!(ErrorCode::InvalidTreasuryAccounts);
+            };
+            apply_insurance_funding(treasury, insurance_fund, amount)?;
+        }
+        CouncilAction::OverturnVerdict { case_id } => {
+            let case_account = ctx.accounts.case_account.as_ref().ok_or(ErrorCode::InvalidCase)?;
+            let mut case = case_account.load_mut()?;
+            require!(case.case_id == case_id, ErrorCode::InvalidCase);
+            apply_overturn(&mut case)?;
+        }
+        CouncilAction::ConfigurePriceFeeds { sol_usd_feed, feeds, case_bond_bps } => {
+            let registry = ctx.accounts.price_feeds.as_mut().ok_or(ErrorCode::InvalidPriceFeed)?;
+            apply_price_feeds(config, registry, sol_usd_feed, feeds, case_bond_bps)?;
+        }
+        CouncilAction::SetQuadraticVoting { enabled } => {
+            apply_quadratic_voting(config, enabled)?;
+        }
+        CouncilAction::SetSeverityThreshold { severity, threshold } => {
+            apply_severity_threshold(config, severity, threshold)?;
+        }
+        CouncilAction::SetTieBreakRule { rule, tie_breaker } => {
+            apply_tie_break_rule(config, rule, tie_breaker)?;
+        }
+        CouncilAction::ConfigureEscalation { bond, threshold } => {
+            apply_escalation(config, bond, threshold)?;
+        }
+        CouncilAction::SetProbableCauseJurors { jurors } => {
+            apply_probable_cause_jurors(config, jurors)?;
+        }
+        CouncilAction::SetReporterBounty { flat, bps } => {
+            apply_reporter_bounty(config, flat, bps)?;
+        }
+        CouncilAction::SetMinValidatorStake { min_stake } => {
+            apply_min_validator_stake(config, min_stake)?;
+        }
+        CouncilAction::ApproveIntelProvider { provider } => {
+            let intel_provider = ctx.accounts.intel_provider.as_mut().ok_or(ErrorCode::Unauthorized)?;
+            require!(intel_provider.authority == provider, ErrorCode::Unauthorized);
+            apply_provider_approval(intel_provider)?;
+        }
+        CouncilAction::SetConfidentialPolicy { enabled, required_components } => {
+            let confidential_config = ctx
+                .accounts
+                .confidential_config
+                .as_mut()
+                .ok_or(ErrorCode::InvalidConfidentialComponents)?;
+            apply_confidential_policy(confidential_config, enabled, required_components)?;
+        }
+        CouncilAction::UpdateVerifyingKey { proof_type, key } => {
+            let verifying_key = ctx.accounts.verifying_key.as_mut().ok_or(ErrorCode::InvalidVerifyingKey)?;
+            require!(verifying_key.proof_type == proof_type, ErrorCode::InvalidVerifyingKey);
+            apply_verifying_key(verifying_key, key)?;
+        }
+        CouncilAction::WithdrawTreasury { recipient, mint, amount } => {
+            let treasury = ctx.accounts.treasury.as_mut().ok_or(ErrorCode::InvalidTreasuryAccounts)?;
+            match mint {
+                None => {
+                    let recipient_info = ctx
+                        .accounts
+                        .recipient
+                        .as_ref()
+                        .ok_or(ErrorCode::InvalidTreasuryAccounts)?;
+                    require!(recipient_info.key() == recipient, ErrorCode::InvalidTreasuryAccounts);
+                    withdraw_lamports(treasury, &recipient_info.to_account_info(), amount)?;
+                }
+                Some(mint) => {
+                    let (Some(treasury_vault), Some(mint_account), Some(recipient_token_account), Some(token_program)) = (
+                        &ctx.accounts.treasury_vault,
+                        &ctx.accounts.mint,
+                        &ctx.accounts.recipient_token_account,
+                        &ctx.accounts.token_program,
+                    ) else {
+                        return err!(ErrorCode::InvalidTreasuryAccounts);
+                    };
+                    require!(
+                        treasury_vault.mint == mint && recipient_token_account.owner == recipient,
+                        ErrorCode::InvalidTreasuryAccounts
+                    );
+                    withdraw_tokens(
+                        treasury,
+                        treasury_vault,
+                        mint_account,
+                        recipient_token_account,
+                        token_program,
+                        amount,
+                    )?;
+                }
+            }
+        }
+        CouncilAction::SetCouncil { members, threshold } => {
+            validate_council(&members, threshold)?;
+            council.members = members;
+            council.threshold = threshold;
+        }
+    }
+    proposal.executed = true;
+
+    emit!(CouncilActionExecuted {
+        proposal_id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+        approvals: approvals as u8,
+        timestamp: now,
+    });
+
+    msg!("Council proposal {} executed", proposal.id);
+    Ok(())
+}
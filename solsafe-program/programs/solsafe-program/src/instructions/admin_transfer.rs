@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pending_admin == new_admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Nominate a successor; proposing the default key cancels a pending transfer
+pub fn propose_handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = new_admin;
+
+    msg!("Admin transfer proposed to {}", new_admin);
+    Ok(())
+}
+
+/// The nominee must sign, so a mistyped key can never take over governance
+pub fn accept_handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    msg!("Admin transferred from {} to {}", previous_admin, config.admin);
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::domain;
+use crate::state::{
+    ActiveCaseMarker, CaseAccount, CaseCategory, CaseCounter, CaseSeverity, CaseState, CaseStatus,
+    CaseValueTier, GlobalConfig, ProtocolStats, ReporterSet, ScamIndex, ShieldedPool, SpentNote, Treasury,
+    CASE_ACCOUNT_VERSION, POOL_DEPTH, POOL_ROOT_HISTORY,
+};
+use crate::zk_proofs::{load_registered_key, poseidon, CommitmentScheme, ZkProof, ZkProofType};
+use crate::{AnonymousCaseSubmitted, CaseCreated, ErrorCode, NoteDeposited};
+use super::case_bond::{price_case_bond, HoldingQuote};
+use super::duplicate_case::claim_marker;
+use super::submit_evidence::apply_case_policy;
+
+#[derive(Accounts)]
+pub struct SetReporterSet<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ReporterSet::LEN,
+        seeds = [b"reporter_set"],
+        bump
+    )]
+    pub reporter_set: Account<'info, ReporterSet>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureShieldedPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ShieldedPool::LEN,
+        seeds = [b"shielded_pool"],
+        bump
+    )]
+    pub shielded_pool: Account<'info, ShieldedPool>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositNote<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"shielded_pool"],
+        bump = shielded_pool.bump
+    )]
+    pub shielded_pool: Account<'info, ShieldedPool>,
+    pub system_program: Program<'info, System>,
+}
+
+/// The signer only pays rent and becomes the case's reporter of record, so it should be a
+/// fresh key (or a relayer's) with no tie to the whistleblower
+#[derive(Accounts)]
+#[instruction(case_id: u64, evidence_uri: String, evidence_hash: [u8; 32], scam_address: Pubkey, pool_root: [u8; 32], nullifier: [u8; 32])]
+pub struct AnonymousSubmitEvidence<'info> {
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + CaseAccount::LEN,
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Assigns case ids in order; `case_id` must be its next id
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = 8 + ScamIndex::LEN,
+        seeds = [b"scam_index", scam_address.as_ref()],
+        bump
+    )]
+    pub scam_index: Account<'info, ScamIndex>,
+    /// Only one open case per address; later reports go through link_duplicate
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = 8 + ActiveCaseMarker::LEN,
+        seeds = [b"active_case", scam_address.as_ref()],
+        bump
+    )]
+    pub active_case: Account<'info, ActiveCaseMarker>,
+    /// CHECK: The case the marker currently points at; required once the address has had a case
+    pub prior_case: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [b"reporter_set"],
+        bump = reporter_set.bump
+    )]
+    pub reporter_set: Account<'info, ReporterSet>,
+    #[account(
+        mut,
+        seeds = [b"shielded_pool"],
+        bump = shielded_pool.bump
+    )]
+    pub shielded_pool: Account<'info, ShieldedPool>,
+    /// Exists once the note is spent, so it can't bond a second case
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + SpentNote::LEN,
+        seeds = [b"spent_note", nullifier.as_ref()],
+        bump
+    )]
+    pub spent_note: Account<'info, SpentNote>,
+    /// Receives the spent note as the case bond
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    /// CHECK: The membership circuit's VerifyingKeyAccount PDA; checked in the handler
+    pub verifying_key: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+/// Governance replaces the reporter set root; proofs against the old root stop verifying
+pub fn set_reporter_set_handler(ctx: Context<SetReporterSet>, root: [u8; 32], members: u32) -> Result<()> {
+    require!(root != [0u8; 32] && members > 0, ErrorCode::InvalidMembershipProof);
+    let reporter_set = &mut ctx.accounts.reporter_set;
+    reporter_set.root = root;
+    reporter_set.members = members;
+    reporter_set.updated_at = Clock::get()?.unix_timestamp;
+    reporter_set.bump = ctx.bumps.reporter_set;
+
+    msg!("Reporter set updated: {} members", members);
+    Ok(())
+}
+
+/// The denomination is fixed once the first note is in, since every note must look alike
+pub fn configure_pool_handler(ctx: Context<ConfigureShieldedPool>, denomination: u64) -> Result<()> {
+    let shielded_pool = &mut ctx.accounts.shielded_pool;
+    require!(shielded_pool.next_index == 0, ErrorCode::PoolDenominationLocked);
+    require!(
+        denomination > 0 && denomination >= ctx.accounts.config.submission_fee,
+        ErrorCode::PoolDenominationTooLow
+    );
+    shielded_pool.denomination = denomination;
+    shielded_pool.bump = ctx.bumps.shielded_pool;
+
+    msg!("Shielded pool denomination set to {} lamports", denomination);
+    Ok(())
+}
+
+/// Deposits one denomination against `commitment` = Poseidon(secret, nullifier secret)
+pub fn deposit_handler(ctx: Context<DepositNote>, commitment: [u8; 32]) -> Result<()> {
+    let denomination = ctx.accounts.shielded_pool.denomination;
+    require!(denomination > 0, ErrorCode::PoolDenominationTooLow);
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.shielded_pool.to_account_info(),
+            },
+        ),
+        denomination,
+    )?;
+
+    let shielded_pool = &mut ctx.accounts.shielded_pool;
+    let leaf_index = insert_note(shielded_pool, commitment)?;
+    let root = shielded_pool.roots[shielded_pool.current_root as usize];
+
+    emit!(NoteDeposited {
+        leaf_index,
+        commitment,
+        root,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Note {} deposited", leaf_index);
+    Ok(())
+}
+
+/// Append a note to the pool's incremental Poseidon tree and record the new root
+pub(crate) fn insert_note(pool: &mut ShieldedPool, leaf: [u8; 32]) -> Result<u32> {
+    let leaf_index = pool.next_index;
+    require!((leaf_index as u64) < 1u64 << POOL_DEPTH, ErrorCode::ShieldedPoolFull);
+
+    let (mut index, mut node, mut zero) = (leaf_index, leaf, [0u8; 32]);
+    for level in 0..POOL_DEPTH {
+        node = if index % 2 == 0 {
+            pool.filled_subtrees[level] = node;
+            poseidon(&[&node, &zero])?
+        } else {
+            poseidon(&[&pool.filled_subtrees[level], &node])?
+        };
+        zero = poseidon(&[&zero, &zero])?;
+        index /= 2;
+    }
+    pool.current_root = ((pool.current_root as usize + 1) % POOL_ROOT_HISTORY) as u8;
+    pool.roots[pool.current_root as usize] = node;
+    pool.next_index += 1;
+    Ok(leaf_index)
+}
+
+/// What a membership proof is bound to, cut below the BN254 scalar field: the case id,
+/// accused, evidence, and submitting key, so a proof seen in flight can't be reused
+pub fn report_signal(case_id: u64, scam_address: &Pubkey, evidence_hash: &[u8; 32], submitter: &Pubkey) -> [u8; 32] {
+    let mut signal = domain::hash(
+        domain::REPORT_SIGNAL,
+        &[&case_id.to_le_bytes(), scam_address.as_ref(), evidence_hash, submitter.as_ref()],
+    );
+    signal[0] = 0;
+    signal
+}
+
+/// Opens a case from an eligible reporter without saying which one. The proof shows
+/// membership in the reporter set and ownership of an unspent pool note; the note's
+/// denomination goes to the treasury as the bond. Set up as submit_evidence would, minus
+/// threat intel links, analyst notarization, and holding-priced bonds, so the path is
+/// closed while config.case_bond_bps is set.
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<AnonymousSubmitEvidence>,
+    case_id: u64,
+    evidence_uri: String,
+    evidence_hash: [u8; 32],
+    scam_address: Pubkey,
+    pool_root: [u8; 32],
+    nullifier: [u8; 32],
+    bump: u8,
+    category: CaseCategory,
+    evidence_tx_root: [u8; 32],
+    proof: ZkProof,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    ctx.accounts.case_counter.assign(case_id)?;
+    let now = Clock::get()?.unix_timestamp;
+    let submitter = ctx.accounts.submitter.key();
+
+    require!(ctx.accounts.shielded_pool.known_root(&pool_root), ErrorCode::UnknownPoolRoot);
+    let signal = report_signal(case_id, &scam_address, &evidence_hash, &submitter);
+    require!(
+        proof.proof_type == ZkProofType::ReporterMembership
+            && proof.public_inputs
+                == ZkProof::membership_inputs(&ctx.accounts.reporter_set.root, &pool_root, &nullifier, &signal),
+        ErrorCode::InvalidMembershipProof
+    );
+    let verifying_key = load_registered_key(&ctx.accounts.verifying_key, ZkProofType::ReporterMembership)?;
+    require!(
+        proof.verify(verifying_key.as_ref(), CommitmentScheme::Poseidon)?,
+        ErrorCode::InvalidMembershipProof
+    );
+
+    // The note is the bond; it has to cover what a signed submission would pay
+    let empty_quote = HoldingQuote {
+        registry: None,
+        holding: None,
+        mint: None,
+        token_price: None,
+        sol_price: None,
+    };
+    let (fee, _) = price_case_bond(config, empty_quote, &scam_address, now)?;
+    let denomination = ctx.accounts.shielded_pool.denomination;
+    require!(denomination >= fee, ErrorCode::PoolDenominationTooLow);
+    **ctx.accounts.shielded_pool.to_account_info().try_borrow_mut_lamports()? -= denomination;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += denomination;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = treasury
+        .total_collected
+        .checked_add(denomination)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let spent_note = &mut ctx.accounts.spent_note;
+    spent_note.nullifier = nullifier;
+    spent_note.case_id = case_id;
+    spent_note.spent_at = now;
+    spent_note.bump = ctx.bumps.spent_note;
+
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_case();
+    }
+    let active_case = &mut ctx.accounts.active_case;
+    claim_marker(
+        active_case,
+        ctx.accounts.prior_case.as_ref().map(|info| info.as_ref()),
+        scam_address,
+        case_id,
+        now,
+    )?;
+    active_case.bump = ctx.bumps.active_case;
+
+    let mut case = ctx.accounts.case_account.load_init()?;
+    case.case_id = case_id;
+    case.scam_address = scam_address;
+    case.reporter = submitter;
+    case.set_evidence(&evidence_uri, evidence_hash)?;
+    case.evidence_tx_root = evidence_tx_root;
+    case.status = CaseStatus::Open as u8;
+    case.state = CaseState::PendingJurors as u8;
+    case.bump = bump;
+    case.version = CASE_ACCOUNT_VERSION;
+    case.severity = CaseSeverity::Medium as u8;
+    case.created_at = now;
+    case.expires_at = now
+        .checked_add(config.max_case_age)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    case.category = category as u8;
+    case.value_tier = CaseValueTier::from_micro_usd(0) as u8;
+    case.quadratic = config.quadratic_voting as u8;
+    apply_case_policy(&mut case, config, ctx.accounts.scam_index.approved_cases, false);
+
+    let scam_index = &mut ctx.accounts.scam_index;
+    scam_index.address = scam_address;
+    scam_index.record_case(case_id);
+    scam_index.updated_at = now;
+    scam_index.bump = ctx.bumps.scam_index;
+
+    emit!(CaseCreated {
+        case_id,
+        reporter: submitter,
+        scam_address,
+        severity: case.severity,
+        category: case.category,
+        threat_id: 0,
+        prior_approved_cases: case.prior_approved_cases,
+        timestamp: now,
+    });
+    emit!(AnonymousCaseSubmitted {
+        case_id,
+        nullifier,
+        reporter_root: ctx.accounts.reporter_set.root,
+        timestamp: now,
+    });
+    msg!("Case {} opened anonymously", case_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_pool() -> ShieldedPool {
+        ShieldedPool {
+            denomination: 1,
+            next_index: 0,
+            filled_subtrees: [[0u8; 32]; POOL_DEPTH],
+            roots: [[0u8; 32]; POOL_ROOT_HISTORY],
+            current_root: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_incremental_root_matches_full_tree() {
+        let (a, b, c) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        let mut pool = empty_pool();
+        for leaf in [a, b, c] {
+            insert_note(&mut pool, leaf).unwrap();
+        }
+
+        // Leaves a, b, c, 0 under the first two levels, then zero subtrees the rest of the way
+        let zero1 = poseidon(&[&[0u8; 32], &[0u8; 32]]).unwrap();
+        let mut node = poseidon(&[
+            &poseidon(&[&a, &b]).unwrap(),
+            &poseidon(&[&c, &[0u8; 32]]).unwrap(),
+        ])
+        .unwrap();
+        let mut zero = poseidon(&[&zero1, &zero1]).unwrap();
+        for _ in 2..POOL_DEPTH {
+            node = poseidon(&[&node, &zero]).unwrap();
+            zero = poseidon(&[&zero, &zero]).unwrap();
+        }
+        assert_eq!(pool.roots[pool.current_root as usize], node);
+        assert_eq!(pool.next_index, 3);
+        // Earlier roots stay provable
+        assert!(pool.known_root(&pool.roots[1]));
+        assert!(!pool.known_root(&[0u8; 32]));
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, CaseState, EvidenceItem, GlobalConfig, MAX_EVIDENCE_ITEMS, MAX_EVIDENCE_URI_LEN,
+};
+use crate::{ErrorCode, EvidenceAppended};
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct AppendEvidence<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + EvidenceItem::LEN,
+        seeds = [
+            b"evidence_item",
+            case_id.to_le_bytes().as_ref(),
+            case_account.load()?.evidence_item_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub evidence_item: Account<'info, EvidenceItem>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone may add evidence until the panel starts voting
+pub fn handler(ctx: Context<AppendEvidence>, case_id: u64, hash: [u8; 32], uri: String) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    require!(uri.len() <= MAX_EVIDENCE_URI_LEN, ErrorCode::EvidenceTooLarge);
+
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(
+        case.state == CaseState::PendingJurors as u8 || case.state == CaseState::SeatAuction as u8,
+        ErrorCode::EvidenceWindowClosed
+    );
+    require!(case.evidence_item_count < MAX_EVIDENCE_ITEMS, ErrorCode::TooManyEvidenceItems);
+
+    let clock = Clock::get()?;
+    let item = &mut ctx.accounts.evidence_item;
+    item.case_id = case_id;
+    item.index = case.evidence_item_count;
+    item.submitter = ctx.accounts.submitter.key();
+    item.hash = hash;
+    item.uri = uri;
+    item.submitted_at = clock.unix_timestamp;
+    item.bump = ctx.bumps.evidence_item;
+    case.evidence_item_count += 1;
+
+    emit!(EvidenceAppended {
+        case_id,
+        index: item.index,
+        submitter: item.submitter,
+        hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Evidence item {} appended to case {}", item.index, case_id);
+    Ok(())
+}
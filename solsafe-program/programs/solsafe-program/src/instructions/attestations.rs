@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AttestationOracle, CaseAccount, CaseState, ExternalAttestation, GlobalConfig,
+    ATTESTATION_FAST_TRACK_CONFIDENCE,
+};
+use crate::{ErrorCode, ExternalAttestationPosted};
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterAttestationOracle<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AttestationOracle::LEN,
+        seeds = [b"attestation_oracle", authority.as_ref()],
+        bump
+    )]
+    pub oracle: Account<'info, AttestationOracle>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostAttestation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"attestation_oracle", authority.key().as_ref()],
+        bump = oracle.bump,
+        constraint = oracle.active @ ErrorCode::Unauthorized
+    )]
+    pub oracle: Account<'info, AttestationOracle>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ExternalAttestation::LEN,
+        seeds = [
+            b"attestation",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, ExternalAttestation>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance approves a threat feed as an attestation oracle (or retires it)
+pub fn register_oracle_handler(
+    ctx: Context<RegisterAttestationOracle>,
+    authority: Pubkey,
+    feed: [u8; 32],
+    active: bool,
+) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle;
+    oracle.authority = authority;
+    oracle.feed = feed;
+    oracle.active = active;
+    oracle.bump = ctx.bumps.oracle;
+
+    msg!("Attestation oracle {} registered. Active: {}", authority, active);
+    Ok(())
+}
+
+/// Registered oracles attest that the accused appears in one of their feeds. A confident
+/// attestation on a case whose panel hasn't voted yet fast-tracks it to the emergency
+/// threshold, like a program-id threat intel match does at submission.
+pub fn post_handler(
+    ctx: Context<PostAttestation>,
+    list_id: [u8; 32],
+    confidence: u8,
+    report_hash: [u8; 32],
+) -> Result<()> {
+    require!(confidence <= 100, ErrorCode::InvalidConfidence);
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let config = &ctx.accounts.config;
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(!case.is_sealed(), ErrorCode::AddressSealed);
+    require!(
+        case.state == CaseState::PendingJurors as u8
+            || case.state == CaseState::Nominated as u8
+            || case.state == CaseState::SeatAuction as u8
+            || case.state == CaseState::Voting as u8,
+        ErrorCode::InvalidCase
+    );
+
+    let fast_tracked = confidence >= ATTESTATION_FAST_TRACK_CONFIDENCE
+        && case.votes_for == 0
+        && case.votes_against == 0
+        && case.quadratic == 0;
+    if fast_tracked {
+        let (numerator, denominator) = config.case_threshold(case.category, case.severity, true);
+        case.threshold_numerator = numerator;
+        case.threshold_denominator = denominator;
+    }
+    case.attestations = case.attestations.saturating_add(1);
+
+    let now = Clock::get()?.unix_timestamp;
+    let oracle = &mut ctx.accounts.oracle;
+    oracle.attestations = oracle.attestations.saturating_add(1);
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.case_id = case.case_id;
+    attestation.oracle = oracle.authority;
+    attestation.subject = case.scam_address;
+    attestation.list_id = list_id;
+    attestation.confidence = confidence;
+    attestation.report_hash = report_hash;
+    attestation.attested_at = now;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(ExternalAttestationPosted {
+        case_id: case.case_id,
+        oracle: oracle.authority,
+        subject: case.scam_address,
+        list_id,
+        confidence,
+        fast_tracked,
+        timestamp: now,
+    });
+    msg!(
+        "Oracle {} attested case {} at confidence {}{}",
+        oracle.authority,
+        case.case_id,
+        confidence,
+        if fast_tracked { " (fast-tracked)" } else { "" }
+    );
+    Ok(())
+}
@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::{
+    ActiveCaseMarker, CaseAccount, CaseCategory, CaseCounter, CaseGroup, CaseSeverity, CaseState,
+    CaseStatus, CaseValueTier, GlobalConfig, ProtocolStats, ScamIndex, Treasury, CASE_ACCOUNT_VERSION,
+    MAX_BATCH_CASES, MAX_GROUP_CASES,
+};
+use crate::{CaseBatchSubmitted, CaseCreated, ErrorCode};
+use super::case_bond::{price_case_bond, HoldingQuote};
+use super::duplicate_case::claim_marker;
+use super::submit_evidence::apply_case_policy;
+use super::treasury::collect_submission_fee;
+
+/// Each address's case account, scam index, and active case marker follow in remaining
+/// accounts, in that order
+#[derive(Accounts)]
+#[instruction(first_case_id: u64, evidence_uri: String, evidence_hash: [u8; 32])]
+pub struct SubmitEvidenceBatch<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Assigns case ids in order; the batch takes `first_case_id` onwards
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + CaseGroup::LEN,
+        seeds = [b"case_group", evidence_hash.as_ref()],
+        bump
+    )]
+    pub case_group: Account<'info, CaseGroup>,
+    /// Receives the submission fees; required while one is configured
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+/// Opens one case per address on a shared evidence bundle and links them in the bundle's
+/// CaseGroup; later batches on the same bundle join the group. Cases are set up as
+/// submit_evidence would, minus threat intel links and holding-priced bonds, so the batch
+/// path is closed while config.case_bond_bps is set. Addresses that have had a case
+/// before are reported individually.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SubmitEvidenceBatch<'info>>,
+    first_case_id: u64,
+    evidence_uri: String,
+    evidence_hash: [u8; 32],
+    scam_addresses: Vec<Pubkey>,
+    category: CaseCategory,
+    evidence_tx_root: [u8; 32],
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(
+        !scam_addresses.is_empty() && scam_addresses.len() <= MAX_BATCH_CASES,
+        ErrorCode::InvalidBatch
+    );
+    for (i, address) in scam_addresses.iter().enumerate() {
+        require!(!scam_addresses[..i].contains(address), ErrorCode::InvalidBatch);
+    }
+    require!(
+        ctx.remaining_accounts.len() == scam_addresses.len() * 3,
+        ErrorCode::InvalidBatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let reporter = ctx.accounts.reporter.key();
+    let case_group = &mut ctx.accounts.case_group;
+    if case_group.reporter == Pubkey::default() {
+        case_group.evidence_hash = evidence_hash;
+        case_group.reporter = reporter;
+        case_group.created_at = now;
+        case_group.bump = ctx.bumps.case_group;
+    }
+    require!(case_group.reporter == reporter, ErrorCode::Unauthorized);
+    require!(
+        case_group.case_ids.len() + scam_addresses.len() <= MAX_GROUP_CASES,
+        ErrorCode::CaseGroupFull
+    );
+
+    let empty_quote = HoldingQuote {
+        registry: None,
+        holding: None,
+        mint: None,
+        token_price: None,
+        sol_price: None,
+    };
+    let (fee, _) = price_case_bond(config, empty_quote, &scam_addresses[0], now)?;
+    collect_submission_fee(
+        fee.checked_mul(scam_addresses.len() as u64).ok_or(ErrorCode::ArithmeticOverflow)?,
+        ctx.accounts.treasury.as_mut(),
+        ctx.accounts.reporter.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let payer = ctx.accounts.reporter.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let expires_at = now
+        .checked_add(config.max_case_age)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let mut case_ids = Vec::with_capacity(scam_addresses.len());
+    for (scam_address, accounts) in scam_addresses.iter().zip(ctx.remaining_accounts.chunks(3)) {
+        let scam_address = *scam_address;
+        let case_id = first_case_id
+            .checked_add(case_ids.len() as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ctx.accounts.case_counter.assign(case_id)?;
+        let (case_info, index_info, marker_info) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        // Marker first: an address with an earlier case stops the batch here
+        let marker_bump = init_pda(
+            marker_info,
+            &[b"active_case", scam_address.as_ref()],
+            8 + ActiveCaseMarker::LEN,
+            &payer,
+            &system_program,
+        )?;
+        let mut marker = load_or_default(marker_info, || ActiveCaseMarker {
+            scam_address: Pubkey::default(),
+            case_id: 0,
+            opened_at: 0,
+            linked_reports: 0,
+            bump: marker_bump,
+        })?;
+        claim_marker(&mut marker, None, scam_address, case_id, now)?;
+        marker.try_serialize(&mut &mut marker_info.try_borrow_mut_data()?[..])?;
+
+        let index_bump = init_pda(
+            index_info,
+            &[b"scam_index", scam_address.as_ref()],
+            8 + ScamIndex::LEN,
+            &payer,
+            &system_program,
+        )?;
+        let mut scam_index = load_or_default(index_info, || ScamIndex {
+            address: scam_address,
+            case_ids: Vec::new(),
+            total_cases: 0,
+            approved_cases: 0,
+            rejected_cases: 0,
+            flagged: false,
+            updated_at: 0,
+            bump: index_bump,
+        })?;
+        scam_index.record_case(case_id);
+        scam_index.updated_at = now;
+        scam_index.try_serialize(&mut &mut index_info.try_borrow_mut_data()?[..])?;
+
+        let case_bump = init_pda(
+            case_info,
+            &[b"case", case_id.to_le_bytes().as_ref()],
+            8 + CaseAccount::LEN,
+            &payer,
+            &system_program,
+        )?;
+        let mut data = case_info.try_borrow_mut_data()?;
+        require!(data[..8] == [0u8; 8], ErrorCode::InvalidBatch);
+        data[..8].copy_from_slice(&CaseAccount::DISCRIMINATOR);
+        let case: &mut CaseAccount = bytemuck::from_bytes_mut(&mut data[8..8 + CaseAccount::LEN]);
+        case.case_id = case_id;
+        case.scam_address = scam_address;
+        case.reporter = reporter;
+        case.set_evidence(&evidence_uri, evidence_hash)?;
+        case.evidence_tx_root = evidence_tx_root;
+        case.status = CaseStatus::Open as u8;
+        case.state = CaseState::PendingJurors as u8;
+        case.bump = case_bump;
+        case.version = CASE_ACCOUNT_VERSION;
+        case.severity = CaseSeverity::Medium as u8;
+        case.created_at = now;
+        case.expires_at = expires_at;
+        case.category = category as u8;
+        case.value_tier = CaseValueTier::from_micro_usd(0) as u8;
+        case.quadratic = config.quadratic_voting as u8;
+        apply_case_policy(case, config, scam_index.approved_cases, false);
+
+        emit!(CaseCreated {
+            case_id,
+            reporter,
+            scam_address,
+            severity: case.severity,
+            category: case.category,
+            threat_id: 0,
+            prior_approved_cases: case.prior_approved_cases,
+            timestamp: now,
+        });
+        case_ids.push(case_id);
+    }
+
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        for _ in &case_ids {
+            stats.record_case();
+        }
+    }
+    let case_group = &mut ctx.accounts.case_group;
+    case_group.case_ids.extend_from_slice(&case_ids);
+    case_group.updated_at = now;
+
+    emit!(CaseBatchSubmitted {
+        evidence_hash,
+        reporter,
+        case_ids: case_ids.clone(),
+        group_size: case_group.case_ids.len() as u32,
+        timestamp: now,
+    });
+    msg!("Batch opened cases {:?} on one evidence bundle", case_ids);
+    Ok(())
+}
+
+/// Create `info` as the program-owned PDA of `seeds` unless it already exists, the way
+/// `init_if_needed` would. Returns the PDA's bump.
+pub(crate) fn init_pda<'info>(
+    info: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require!(info.key() == address, ErrorCode::InvalidBatch);
+    if info.owner == &crate::ID {
+        return Ok(bump);
+    }
+
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+    let signer = &[&signer_seeds[..]];
+    let rent = Rent::get()?.minimum_balance(space);
+    if info.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+        return Ok(bump);
+    }
+
+    // Someone already sent lamports to the address: top up, then allocate and assign
+    let shortfall = rent.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: info.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: info.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )?;
+    Ok(bump)
+}
+
+/// Deserialize a program account, or start from `fresh` if it was only just created
+fn load_or_default<T: AccountDeserialize + Discriminator>(
+    info: &AccountInfo,
+    fresh: impl FnOnce() -> T,
+) -> Result<T> {
+    let data = info.try_borrow_data()?;
+    if data[..8] == [0u8; 8] {
+        return Ok(fresh());
+    }
+    T::try_deserialize(&mut &data[..])
+}
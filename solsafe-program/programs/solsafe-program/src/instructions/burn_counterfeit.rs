@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{permanent_delegate::get_permanent_delegate, StateWithExtensions},
+};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Burn, FreezeAccount, Mint, ThawAccount, TokenAccount};
+use crate::state::{CaseAccount, CaseCategory, CaseState, GlobalConfig, Verdict};
+use crate::{CounterfeitBurned, ErrorCode};
+
+#[derive(Accounts)]
+pub struct BurnFrozenTokens<'info> {
+    pub cranker: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Counterfeit mint; its permanent delegate must be the program authority
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// The accused's holding of the counterfeit
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = scam_token_account.owner == case_account.load()?.scam_address @ ErrorCode::InvalidCase
+    )]
+    pub scam_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Program authority PDA - the mint's permanent delegate, and its freeze authority
+    /// when the holding is frozen
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Permissionless: destroys the accused's counterfeit holding once a fake-token or
+/// impersonation case is approved for seizure. Only Token-2022 mints whose issuer made
+/// the program authority the permanent delegate can be burned; a frozen holding is
+/// thawed for the burn and frozen again, empty.
+pub fn handler(ctx: Context<BurnFrozenTokens>) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    let case_id = {
+        let case = ctx.accounts.case_account.load()?;
+        require!(case.state == CaseState::Approved as u8, ErrorCode::NotApproved);
+        require!(case.overturned == 0, ErrorCode::NotApproved);
+        require!(
+            case.category == CaseCategory::FakeToken as u8 || case.category == CaseCategory::Impersonation as u8,
+            ErrorCode::NotCounterfeitCase
+        );
+        require!(case.verdict() == Verdict::FreezeAndSeize, ErrorCode::VerdictForbidsSeizure);
+        case.case_id
+    };
+
+    let authority = ctx.accounts.program_authority.key();
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        require!(get_permanent_delegate(&mint) == Some(authority), ErrorCode::BurnNotDelegated);
+    }
+
+    let amount = ctx.accounts.scam_token_account.amount;
+    require!(amount > 0, ErrorCode::NothingToClaim);
+    let frozen = ctx.accounts.scam_token_account.is_frozen();
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    if frozen {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            ThawAccount {
+                account: ctx.accounts.scam_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.scam_token_account.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    if frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account: ctx.accounts.scam_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    case.burned_amount = case
+        .burned_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(CounterfeitBurned {
+        case_id,
+        mint: ctx.accounts.mint.key(),
+        token_account: ctx.accounts.scam_token_account.key(),
+        amount,
+        total_burned: case.burned_amount,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Case {} burned {} counterfeit tokens of {}", case_id, amount, ctx.accounts.mint.key());
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, GlobalConfig, JurorPassport, PassportRegistry, TieBreakRule, ValidatorPage, ValidatorStake,
+};
+use crate::ErrorCode;
+use super::close_case::retention_elapsed;
+use super::execute_freeze::check_freeze_executable;
+use super::expire_case::check_expirable;
+use super::finalize_case::check_finalizable;
+use super::seat_auction::{check_auction_closable, check_seat_claim, check_seat_qualification, seat_bond_forfeited};
+use super::vote::check_voter;
+
+/// Actions a frontend can ask about before building the real transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SimulatedAction {
+    SubmitEvidence,
+    Vote,
+    ExecuteFreeze,
+    ClaimJurorSeat,
+    CloseSeatAuction,
+    ReleaseSeatBond,
+    AcceptJuryDuty,
+    Recuse,
+    ExpireCase,
+    FinalizeCase,
+    CloseCase,
+    Pause,
+    Unpause,
+    UpdateValidators,
+    ConfigureFees,
+    SetCategoryParams,
+    SetMaxCaseAge,
+    ConfigurePassportPolicy,
+    SetHeartbeatEpochs,
+    SetRandomnessSource,
+    UpdateVerifyingKey,
+    SetPauser,
+    ProposeAdmin,
+    WithdrawTreasury,
+    SetReputationWeighting,
+    SetMinValidatorStake,
+    ConfigureReporterBounty,
+    ConfigurePriceFeeds,
+    SetQuadraticVoting,
+    SetSeverityThreshold,
+    SetTieBreakRule,
+    ConfigureEscalation,
+    SetProbableCauseJurors,
+    ApproveIntelProvider,
+}
+
+/// Accounts mirror what the real instruction would read; case-scoped actions need the case
+#[derive(Accounts)]
+pub struct CanExecute<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    pub case_account: Option<AccountLoader<'info, CaseAccount>>,
+    /// The case's panel, for actions only a seated juror can take
+    #[account(
+        seeds = [b"case_panel", case_panel.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+    /// Registry page listing the actor, for validator votes
+    #[account(
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Option<Account<'info, ValidatorPage>>,
+    #[account(
+        seeds = [b"juror_passport", passport.juror.as_ref()],
+        bump = passport.bump
+    )]
+    pub passport: Option<Account<'info, JurorPassport>>,
+    #[account(
+        seeds = [b"passport_registry"],
+        bump = passport_registry.bump
+    )]
+    pub passport_registry: Option<Account<'info, PassportRegistry>>,
+    #[account(
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+}
+
+/// Returns 0 if `actor` could run `action` right now, otherwise the error code the
+/// instruction would fail with. Only role, pause, and state checks are evaluated.
+pub fn handler(ctx: Context<CanExecute>, action: SimulatedAction, actor: Pubkey) -> Result<u32> {
+    match evaluate(ctx.accounts, action, &actor) {
+        Ok(()) => Ok(0),
+        Err(Error::AnchorError(error)) => Ok(error.error_code_number),
+        Err(error) => Err(error),
+    }
+}
+
+fn evaluate(accounts: &CanExecute, action: SimulatedAction, actor: &Pubkey) -> Result<()> {
+    let config = &accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    match action {
+        SimulatedAction::SubmitEvidence => {
+            require!(!config.paused, ErrorCode::ProgramPaused);
+            Ok(())
+        }
+        SimulatedAction::Pause | SimulatedAction::Unpause => {
+            require!(config.pauser == *actor, ErrorCode::Unauthorized);
+            Ok(())
+        }
+        SimulatedAction::UpdateValidators
+        | SimulatedAction::ConfigureFees
+        | SimulatedAction::SetCategoryParams
+        | SimulatedAction::SetMaxCaseAge
+        | SimulatedAction::ConfigurePassportPolicy
+        | SimulatedAction::SetHeartbeatEpochs
+        | SimulatedAction::SetRandomnessSource
+        | SimulatedAction::UpdateVerifyingKey
+        | SimulatedAction::SetPauser
+        | SimulatedAction::ProposeAdmin
+        | SimulatedAction::WithdrawTreasury
+        | SimulatedAction::SetReputationWeighting
+        | SimulatedAction::SetMinValidatorStake
+        | SimulatedAction::ConfigureReporterBounty
+        | SimulatedAction::ConfigurePriceFeeds
+        | SimulatedAction::SetQuadraticVoting
+        | SimulatedAction::SetSeverityThreshold
+        | SimulatedAction::SetTieBreakRule
+        | SimulatedAction::ConfigureEscalation
+        | SimulatedAction::SetProbableCauseJurors
+        | SimulatedAction::ApproveIntelProvider => {
+            require!(config.admin == *actor, ErrorCode::Unauthorized);
+            require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+            Ok(())
+        }
+        _ => {
+            let case_account = accounts.case_account.as_ref().ok_or(ErrorCode::CaseNotOpen)?;
+            let case = case_account.load()?;
+            evaluate_case_action(accounts, &case, action, actor, now)
+        }
+    }
+}
+
+fn evaluate_case_action(
+    accounts: &CanExecute,
+    case: &CaseAccount,
+    action: SimulatedAction,
+    actor: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let config = &accounts.config;
+    match action {
+        SimulatedAction::Vote => {
+            require!(!config.paused, ErrorCode::ProgramPaused);
+            require!(case.state == CaseState::Voting as u8, ErrorCode::CaseNotVoting);
+            check_voter(case, case_panel(accounts, case)?, accounts.validator_page.as_deref(), actor)
+        }
+        SimulatedAction::ExecuteFreeze => check_freeze_executable(case, config),
+        SimulatedAction::ClaimJurorSeat => {
+            check_seat_claim(case, case_panel(accounts, case)?, config, actor, now)?;
+            check_seat_qualification(
+                config,
+                actor,
+                accounts.validator_stake.as_ref(),
+                accounts.passport.as_ref(),
+                accounts.passport_registry.as_ref(),
+            )
+        }
+        SimulatedAction::CloseSeatAuction => check_auction_closable(case, now),
+        // Only whether the bond can be settled yet; the ballot decides whether it's returned
+        SimulatedAction::ReleaseSeatBond => seat_bond_forfeited(case, accounts.case_panel.as_deref(), actor, None).map(|_| ()),
+        SimulatedAction::AcceptJuryDuty => {
+            require!(case.state == CaseState::Nominated as u8, ErrorCode::CaseNotNominated);
+            require!(now < case.acceptance_deadline, ErrorCode::AcceptanceWindowClosed);
+            require!(case_panel(accounts, case)?.is_juror(actor), ErrorCode::NotJuror);
+            Ok(())
+        }
+        SimulatedAction::Recuse => {
+            require!(
+                case.state == CaseState::Nominated as u8 || case.state == CaseState::Voting as u8,
+                ErrorCode::CaseNotVoting
+            );
+            require!(case_panel(accounts, case)?.is_juror(actor), ErrorCode::NotJuror);
+            Ok(())
+        }
+        SimulatedAction::ExpireCase => check_expirable(case, now),
+        SimulatedAction::FinalizeCase if case.is_deadlocked(now) => {
+            require!(
+                config.tie_break_rule != TieBreakRule::CastingVote,
+                ErrorCode::AwaitingTieBreak
+            );
+            Ok(())
+        }
+        SimulatedAction::FinalizeCase => check_finalizable(case),
+        SimulatedAction::CloseCase => {
+            require!(retention_elapsed(case, now), ErrorCode::RetentionPeriodActive);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Seat checks need the case's own panel
+fn case_panel<'a>(accounts: &'a CanExecute, case: &CaseAccount) -> Result<&'a CasePanel> {
+    accounts
+        .case_panel
+        .as_deref()
+        .filter(|panel| panel.case_id == case.case_id)
+        .ok_or_else(|| error!(ErrorCode::InvalidCase))
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::pyth::PythPrice;
+use crate::state::{
+    GlobalConfig, PriceFeedRegistry, TokenPriceFeed, MAX_CASE_BOND_USD, MAX_PRICE_FEEDS, MIN_CASE_BOND_USD,
+};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ConfigurePriceFeeds<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PriceFeedRegistry::LEN,
+        seeds = [b"price_feeds"],
+        bump
+    )]
+    pub registry: Account<'info, PriceFeedRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_handler(
+    ctx: Context<ConfigurePriceFeeds>,
+    sol_usd_feed: [u8; 32],
+    feeds: Vec<TokenPriceFeed>,
+    case_bond_bps: u16,
+) -> Result<()> {
+    ctx.accounts.registry.bump = ctx.bumps.registry;
+    apply_price_feeds(&mut ctx.accounts.config, &mut ctx.accounts.registry, sol_usd_feed, feeds, case_bond_bps)
+}
+
+pub(crate) fn apply_price_feeds(
+    config: &mut GlobalConfig,
+    registry: &mut PriceFeedRegistry,
+    sol_usd_feed: [u8; 32],
+    feeds: Vec<TokenPriceFeed>,
+    case_bond_bps: u16,
+) -> Result<()> {
+    require!(feeds.len() <= MAX_PRICE_FEEDS, ErrorCode::InvalidPriceFeed);
+    let has_duplicates = feeds
+        .iter()
+        .enumerate()
+        .any(|(i, feed)| feeds[..i].iter().any(|other| other.mint == feed.mint));
+    require!(!has_duplicates, ErrorCode::InvalidPriceFeed);
+    require!(case_bond_bps <= 10_000, ErrorCode::InvalidFee);
+    // A priced bond needs somewhere to price the holding and a SOL rate to charge it in
+    require!(
+        case_bond_bps == 0 || (sol_usd_feed != [0u8; 32] && !feeds.is_empty()),
+        ErrorCode::InvalidPriceFeed
+    );
+
+    registry.sol_usd_feed = sol_usd_feed;
+    registry.feeds = feeds;
+    config.case_bond_bps = case_bond_bps;
+
+    msg!(
+        "Price feeds: {} tokens priced, bond at {} bps of the reported holding",
+        registry.feeds.len(),
+        case_bond_bps
+    );
+    Ok(())
+}
+
+/// Pricing inputs a submission may carry, all optional
+pub(crate) struct HoldingQuote<'a, 'info> {
+    pub registry: Option<&'a Account<'info, PriceFeedRegistry>>,
+    pub holding: Option<&'a Account<'info, TokenAccount>>,
+    pub mint: Option<&'a Account<'info, Mint>>,
+    pub token_price: Option<&'a AccountInfo<'info>>,
+    pub sol_price: Option<&'a AccountInfo<'info>>,
+}
+
+/// Returns the lamport bond to charge and the micro-USD value of the reported holding.
+/// Without a configured bond rate the flat submission fee applies, and the holding is
+/// priced only if the reporter supplied it. The reporter picks which holding of the
+/// accused is priced.
+pub(crate) fn price_case_bond(
+    config: &GlobalConfig,
+    quote: HoldingQuote,
+    scam_address: &Pubkey,
+    now: i64,
+) -> Result<(u64, u64)> {
+    let (Some(registry), Some(holding), Some(mint), Some(token_price)) =
+        (quote.registry, quote.holding, quote.mint, quote.token_price)
+    else {
+        require!(config.case_bond_bps == 0, ErrorCode::InvalidPriceFeed);
+        return Ok((config.submission_fee, 0));
+    };
+    require!(
+        holding.owner == *scam_address && holding.mint == mint.key(),
+        ErrorCode::InvalidPriceFeed
+    );
+    let feed_id = registry.feed_for(&mint.key()).ok_or(ErrorCode::InvalidPriceFeed)?;
+    let value = PythPrice::load(token_price, feed_id, now)?.usd_value(holding.amount, mint.decimals)?;
+
+    if config.case_bond_bps == 0 {
+        return Ok((config.submission_fee, value));
+    }
+    let bond_usd = (value as u128 * config.case_bond_bps as u128 / 10_000)
+        .clamp(MIN_CASE_BOND_USD as u128, MAX_CASE_BOND_USD as u128) as u64;
+    let sol_price = quote.sol_price.ok_or(ErrorCode::InvalidPriceFeed)?;
+    let bond = PythPrice::load(sol_price, &registry.sol_usd_feed, now)?.lamports_for(bond_usd)?;
+    Ok((bond, value))
+}
@@ -0,0 +1,417 @@
+// Case bundle export/import for rehearsing multi-stage cases on another cluster.
+// Handlers refuse to run unless the program is built with the `rehearsal` feature.
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, CaseBundleExport, CaseBundleImport, CaseCounter, CasePanel, GlobalConfig, ScamIndex,
+    CASE_ACCOUNT_VERSION, MAX_CASE_BUNDLE_LEN, MAX_CASE_JURORS, MAX_EVIDENCE_URI_LEN, MAX_RECUSALS,
+};
+use crate::ErrorCode;
+use super::select_jurors::fit_panel;
+
+pub const CASE_BUNDLE_VERSION: u8 = 10;
+
+/// Portable case state: fixed arrays are trimmed to their used length
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct CaseBundle {
+    pub version: u8,
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub reporter: Pubkey,
+    pub vrf_request: Pubkey,
+    pub evidence_root: [u8; 32],
+    pub evidence_tx_root: [u8; 32],
+    pub flow_source: Pubkey,
+    pub flow_hops: u8,
+    pub randomness: [u8; 32],
+    pub randomness_seed_slot: u64,
+    pub randomness_commit_slot: u64,
+    pub randomness_source: u8,
+    pub reported_value_usd: u64,
+    pub value_tier: u8,
+    pub quadratic: u8,
+    pub enforcement_votes: [u64; 3],
+    pub burned_amount: u64,
+    pub verdict: u8,
+    pub escalated: u8,
+    pub round: u8,
+    pub attestations: u8,
+    pub commitment_scheme: u8,
+    pub sealed_address: [u8; 32],
+    pub confidential: u8,
+    pub evidence_uri: Vec<u8>,
+    pub evidence_hash: [u8; 32],
+    pub evidence_item_count: u16,
+    pub prior_approved_cases: u16,
+    pub jurors: Vec<Pubkey>,
+    pub recused: Vec<Pubkey>,
+    pub replacements: u8,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub threat_id: u64,
+    pub seat_auction_ends: i64,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub acceptance_deadline: i64,
+    pub reveal_deadline: i64,
+    pub private_commits: u8,
+    pub private_reveals: u8,
+    pub accepted_seats: u32,
+    pub fallback_seating: u8,
+    pub auction_seats: u8,
+    pub seat_bonds_released: u8,
+    pub settlement_defaulted: u8,
+    pub category: u8,
+    pub status: u8,
+    pub state: u8,
+    pub severity: u8,
+    pub threshold_numerator: u8,
+    pub threshold_denominator: u8,
+}
+
+impl CaseBundle {
+    /// A case that hasn't drawn its jurors yet has no panel
+    pub fn from_case(case: &CaseAccount, panel: Option<&CasePanel>) -> Self {
+        Self {
+            version: CASE_BUNDLE_VERSION,
+            case_id: case.case_id,
+            scam_address: case.scam_address,
+            reporter: case.reporter,
+            vrf_request: case.vrf_request,
+            evidence_root: case.evidence_root,
+            evidence_tx_root: case.evidence_tx_root,
+            flow_source: case.flow_source,
+            flow_hops: case.flow_hops,
+            randomness: case.randomness,
+            randomness_seed_slot: case.randomness_seed_slot,
+            randomness_commit_slot: case.randomness_commit_slot,
+            randomness_source: case.randomness_source,
+            reported_value_usd: case.reported_value_usd,
+            value_tier: case.value_tier,
+            quadratic: case.quadratic,
+            enforcement_votes: case.enforcement_votes,
+            burned_amount: case.burned_amount,
+            verdict: case.verdict,
+            escalated: case.escalated,
+            round: case.round,
+            attestations: case.attestations,
+            commitment_scheme: case.commitment_scheme,
+            sealed_address: case.sealed_address,
+            confidential: case.confidential,
+            evidence_uri: case.evidence_uri().to_vec(),
+            evidence_hash: case.evidence_hash,
+            evidence_item_count: case.evidence_item_count,
+            prior_approved_cases: case.prior_approved_cases,
+            jurors: panel.map(|panel| panel.jurors.clone()).unwrap_or_default(),
+            recused: panel.map(|panel| panel.recused.clone()).unwrap_or_default(),
+            replacements: case.replacements,
+            votes_for: case.votes_for,
+            votes_against: case.votes_against,
+            threat_id: case.threat_id,
+            seat_auction_ends: case.seat_auction_ends,
+            created_at: case.created_at,
+            expires_at: case.expires_at,
+            acceptance_deadline: case.acceptance_deadline,
+            reveal_deadline: case.reveal_deadline,
+            private_commits: case.private_commits,
+            private_reveals: case.private_reveals,
+            accepted_seats: case.accepted_seats,
+            fallback_seating: case.fallback_seating,
+            auction_seats: case.auction_seats,
+            seat_bonds_released: case.seat_bonds_released,
+            settlement_defaulted: case.settlement_defaulted,
+            category: case.category,
+            status: case.status,
+            state: case.state,
+            severity: case.severity,
+            threshold_numerator: case.threshold_numerator,
+            threshold_denominator: case.threshold_denominator,
+        }
+    }
+
+    /// Write the bundle into a freshly initialized case and its panel under `case_id`
+    pub fn apply_to(&self, case: &mut CaseAccount, panel: &mut CasePanel, case_id: u64, bump: u8) -> Result<()> {
+        require!(self.version == CASE_BUNDLE_VERSION, ErrorCode::InvalidCaseBundle);
+        require!(
+            self.evidence_uri.len() <= MAX_EVIDENCE_URI_LEN
+                && self.jurors.len() <= MAX_CASE_JURORS
+                && self.recused.len() <= MAX_RECUSALS,
+            ErrorCode::InvalidCaseBundle
+        );
+
+        case.case_id = case_id;
+        case.scam_address = self.scam_address;
+        case.reporter = self.reporter;
+        case.vrf_request = self.vrf_request;
+        case.evidence_root = self.evidence_root;
+        case.evidence_tx_root = self.evidence_tx_root;
+        case.flow_source = self.flow_source;
+        case.flow_hops = self.flow_hops;
+        case.randomness = self.randomness;
+        case.randomness_seed_slot = self.randomness_seed_slot;
+        case.randomness_commit_slot = self.randomness_commit_slot;
+        case.randomness_source = self.randomness_source;
+        case.reported_value_usd = self.reported_value_usd;
+        case.value_tier = self.value_tier;
+        case.quadratic = self.quadratic;
+        case.enforcement_votes = self.enforcement_votes;
+        case.burned_amount = self.burned_amount;
+        case.verdict = self.verdict;
+        case.escalated = self.escalated;
+        case.round = self.round;
+        case.attestations = self.attestations;
+        case.commitment_scheme = self.commitment_scheme;
+        case.sealed_address = self.sealed_address;
+        case.confidential = self.confidential;
+        case.evidence_uri[..self.evidence_uri.len()].copy_from_slice(&self.evidence_uri);
+        case.evidence_uri_len = self.evidence_uri.len() as u16;
+        case.evidence_hash = self.evidence_hash;
+        case.evidence_item_count = self.evidence_item_count;
+        case.prior_approved_cases = self.prior_approved_cases;
+        panel.case_id = case_id;
+        panel.jurors = self.jurors.clone();
+        case.juror_count = self.jurors.len() as u8;
+        panel.recused = self.recused.clone();
+        case.replacements = self.replacements;
+        case.votes_for = self.votes_for;
+        case.votes_against = self.votes_against;
+        case.threat_id = self.threat_id;
+        case.seat_auction_ends = self.seat_auction_ends;
+        case.created_at = self.created_at;
+        case.expires_at = self.expires_at;
+        case.acceptance_deadline = self.acceptance_deadline;
+        case.reveal_deadline = self.reveal_deadline;
+        case.private_commits = self.private_commits;
+        case.private_reveals = self.private_reveals;
+        case.accepted_seats = self.accepted_seats;
+        case.fallback_seating = self.fallback_seating;
+        case.auction_seats = self.auction_seats;
+        case.seat_bonds_released = self.seat_bonds_released;
+        case.settlement_defaulted = self.settlement_defaulted;
+        case.category = self.category;
+        case.status = self.status;
+        case.state = self.state;
+        case.severity = self.severity;
+        case.threshold_numerator = self.threshold_numerator;
+        case.threshold_denominator = self.threshold_denominator;
+        // Left unfinalized so the rehearsal can replay finalize_case
+        case.finalized = 0;
+        case.overturned = 0;
+        case.closed_at = 0;
+        case.bump = bump;
+        case.version = CASE_ACCOUNT_VERSION;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct ExportCaseBundle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CaseBundleExport::LEN,
+        seeds = [b"case_bundle", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bundle: Account<'info, CaseBundleExport>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_case_id: u64)]
+pub struct StageCaseImport<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CaseBundleImport::LEN,
+        seeds = [b"case_import", new_case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub staging: Account<'info, CaseBundleImport>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_case_id: u64, scam_address: Pubkey)]
+pub struct FinishCaseImport<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"case_import", new_case_id.to_le_bytes().as_ref()],
+        bump = staging.bump,
+        constraint = staging.authority == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub staging: Account<'info, CaseBundleImport>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CaseAccount::LEN,
+        seeds = [b"case", new_case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Grown to the imported panel's size in the handler
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CasePanel::len_for(0, 0),
+        seeds = [b"case_panel", new_case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    /// Imports take their id in sequence like any new case
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ScamIndex::LEN,
+        seeds = [b"scam_index", scam_address.as_ref()],
+        bump
+    )]
+    pub scam_index: Account<'info, ScamIndex>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn export_handler(ctx: Context<ExportCaseBundle>, case_id: u64) -> Result<()> {
+    require!(cfg!(feature = "rehearsal"), ErrorCode::RehearsalDisabled);
+
+    let bundle = CaseBundle::from_case(
+        &*ctx.accounts.case_account.load()?,
+        ctx.accounts.case_panel.as_deref(),
+    );
+    let data = bundle.try_to_vec()?;
+    require!(data.len() <= MAX_CASE_BUNDLE_LEN, ErrorCode::InvalidCaseBundle);
+
+    let export = &mut ctx.accounts.bundle;
+    export.case_id = case_id;
+    export.exported_at = Clock::get()?.unix_timestamp;
+    export.data = data;
+    export.bump = ctx.bumps.bundle;
+
+    msg!("Case {} exported ({} bytes)", case_id, export.data.len());
+    Ok(())
+}
+
+/// Append the next chunk of a bundle; `offset` must match the bytes already staged
+pub fn stage_handler(
+    ctx: Context<StageCaseImport>,
+    new_case_id: u64,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    require!(cfg!(feature = "rehearsal"), ErrorCode::RehearsalDisabled);
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, ErrorCode::Unauthorized);
+
+    let staging = &mut ctx.accounts.staging;
+    if staging.data.is_empty() {
+        staging.new_case_id = new_case_id;
+        staging.authority = ctx.accounts.admin.key();
+        staging.bump = ctx.bumps.staging;
+    }
+    require!(staging.authority == ctx.accounts.admin.key(), ErrorCode::Unauthorized);
+    require!(offset as usize == staging.data.len(), ErrorCode::InvalidCaseBundle);
+    require!(
+        staging.data.len() + chunk.len() <= MAX_CASE_BUNDLE_LEN,
+        ErrorCode::InvalidCaseBundle
+    );
+    staging.data.extend_from_slice(&chunk);
+
+    msg!("Staged {} bytes for case import {}", staging.data.len(), new_case_id);
+    Ok(())
+}
+
+/// Open the staged bundle as case `new_case_id`, which must be the case counter's next id
+pub fn finish_handler(
+    ctx: Context<FinishCaseImport>,
+    new_case_id: u64,
+    scam_address: Pubkey,
+) -> Result<()> {
+    require!(cfg!(feature = "rehearsal"), ErrorCode::RehearsalDisabled);
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, ErrorCode::Unauthorized);
+
+    let bundle = CaseBundle::try_from_slice(&ctx.accounts.staging.data)
+        .map_err(|_| error!(ErrorCode::InvalidCaseBundle))?;
+    require!(bundle.scam_address == scam_address, ErrorCode::InvalidCaseBundle);
+    ctx.accounts.case_counter.assign(new_case_id)?;
+
+    let mut case = ctx.accounts.case_account.load_init()?;
+    let panel = &mut ctx.accounts.case_panel;
+    bundle.apply_to(&mut case, panel, new_case_id, ctx.bumps.case_account)?;
+    panel.bump = ctx.bumps.case_panel;
+    fit_panel(panel, &ctx.accounts.admin, &ctx.accounts.system_program)?;
+
+    let scam_index = &mut ctx.accounts.scam_index;
+    scam_index.address = scam_address;
+    scam_index.record_case(new_case_id);
+    scam_index.updated_at = Clock::get()?.unix_timestamp;
+    scam_index.bump = ctx.bumps.scam_index;
+
+    msg!("Case {} imported as case {}", bundle.case_id, new_case_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let mut case = CaseAccount::zeroed();
+        case.case_id = 7;
+        case.scam_address = Pubkey::new_unique();
+        case.set_evidence("ipfs://bundle", [7u8; 32]).unwrap();
+        case.juror_count = 1;
+        let panel = CasePanel { case_id: 7, jurors: vec![Pubkey::new_unique()], recused: vec![], bump: 0 };
+        case.votes_for = 1;
+        case.threshold_numerator = 2;
+        case.threshold_denominator = 3;
+
+        let bundle = CaseBundle::from_case(&case, Some(&panel));
+        let decoded = CaseBundle::try_from_slice(&bundle.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded, bundle);
+
+        let mut imported = CaseAccount::zeroed();
+        let mut imported_panel = CasePanel { case_id: 0, jurors: vec![], recused: vec![], bump: 0 };
+        decoded.apply_to(&mut imported, &mut imported_panel, 42, 255).unwrap();
+        assert_eq!(imported.case_id, 42);
+        assert_eq!(imported.evidence_uri(), case.evidence_uri());
+        assert_eq!(imported.evidence_hash, case.evidence_hash);
+        assert_eq!(imported_panel.jurors, panel.jurors);
+        assert_eq!(imported_panel.case_id, 42);
+        assert_eq!(imported.votes_for, 1);
+    }
+}
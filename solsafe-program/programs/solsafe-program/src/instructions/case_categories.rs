@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseCategory, CategoryParams, GlobalConfig, MAX_CASE_JURORS};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetCategoryParams<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetCategoryParams>, category: CaseCategory, params: CategoryParams) -> Result<()> {
+    apply_category_params(&mut ctx.accounts.config, category, params)
+}
+
+/// Zeroed params clear the override; a threshold must be a proper fraction
+pub(crate) fn apply_category_params(
+    config: &mut GlobalConfig,
+    category: CaseCategory,
+    params: CategoryParams,
+) -> Result<()> {
+    require!(params.min_jurors as usize <= MAX_CASE_JURORS, ErrorCode::TooManyJurors);
+    let no_threshold = params.threshold_numerator == 0 && params.threshold_denominator == 0;
+    require!(
+        no_threshold || params.threshold_numerator < params.threshold_denominator,
+        ErrorCode::InvalidThreshold
+    );
+    config.category_params[category as usize] = params;
+
+    msg!(
+        "Category {} set to {} jurors, threshold {}/{}",
+        category as u8,
+        params.min_jurors,
+        params.threshold_numerator,
+        params.threshold_denominator
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseCounter, GlobalConfig};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetCaseCounter<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CaseCounter::LEN,
+        seeds = [b"case_counter"],
+        bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the counter on deployments initialized before it existed, moving it past ids
+/// handed out before ids were assigned so submissions don't run into cases that already
+/// exist. The counter never goes back.
+pub fn set_handler(ctx: Context<SetCaseCounter>, next_case_id: u64) -> Result<()> {
+    let case_counter = &mut ctx.accounts.case_counter;
+    require!(
+        next_case_id > 0 && next_case_id >= case_counter.next_case_id,
+        ErrorCode::CaseIdOutOfSequence
+    );
+    case_counter.next_case_id = next_case_id;
+    case_counter.bump = ctx.bumps.case_counter;
+
+    msg!("Next case id set to {}", next_case_id);
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{CaseAccount, CasePanel, InsuranceFund, SeatBond, Treasury, VoteRecord, CASE_RETENTION_PERIOD};
+use crate::zk_proofs::arcium_mpc::{MpcConfig, MpcKeyShare, MpcVoteAggregation};
+use crate::zk_proofs::light_compression::{append_leaf, leaf_data_hash, LightCompressionConfig};
+use crate::{CaseArchived, ErrorCode};
+use super::seat_auction::settle_seat_bond;
+
+/// Seat bonds still outstanding are passed as (seat_bond, juror, vote_record) triples in
+/// remaining accounts
+#[derive(Accounts)]
+pub struct CloseCase<'info> {
+    pub cranker: Signer<'info>,
+    /// CHECK: Checked against the case's reporter, who paid its rent
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = reporter,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Closes with the case; required once jurors have been drawn
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+    /// Receives any outstanding bond that's forfeited
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+}
+
+/// Light accounts for the archive tree follow in remaining accounts
+#[derive(Accounts)]
+pub struct ArchiveCase<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    /// CHECK: Checked against the case's reporter, who paid its rent
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = reporter,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Closes with the case; required once jurors have been drawn
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+    #[account(
+        seeds = [b"light_config"],
+        bump = light_config.bump,
+        constraint = light_config.compression_enabled @ ErrorCode::CompressionDisabled
+    )]
+    pub light_config: Account<'info, LightCompressionConfig>,
+}
+
+/// What survives of a case once it's archived, as a compressed account in the state tree
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CaseArchiveLeaf {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub scam_address: Pubkey,           // Default for a confidential case never revealed
+    pub category: u8,
+    pub state: u8,                      // CaseState the case closed in
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub evidence_uri: Vec<u8>,
+    pub evidence_hash: [u8; 32],        // SHA3 of the bundle at evidence_uri
+    pub evidence_root: [u8; 32],
+    pub jurors: Vec<Pubkey>,
+    pub created_at: i64,
+    pub closed_at: i64,
+}
+
+impl CaseArchiveLeaf {
+    pub fn from_case(case: &CaseAccount, panel: Option<&CasePanel>) -> Self {
+        CaseArchiveLeaf {
+            case_id: case.case_id,
+            reporter: case.reporter,
+            scam_address: case.scam_address,
+            category: case.category,
+            state: case.state,
+            votes_for: case.votes_for,
+            votes_against: case.votes_against,
+            evidence_uri: case.evidence_uri().to_vec(),
+            evidence_hash: case.evidence_hash,
+            evidence_root: case.evidence_root,
+            jurors: panel.map(|panel| panel.jurors.clone()).unwrap_or_default(),
+            created_at: case.created_at,
+            closed_at: case.closed_at,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    pub cranker: Signer<'info>,
+    /// CHECK: Checked against the juror who paid for the record
+    #[account(mut)]
+    pub juror: UncheckedAccount<'info>,
+    /// CHECK: May already be closed; `case_retired` handles both cases
+    #[account(
+        seeds = [b"case", vote_record.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = juror,
+        seeds = [b"vote_record", vote_record.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    /// CHECK: Must not exist; an outstanding seat bond is settled against this record
+    #[account(
+        seeds = [b"seat_bond", vote_record.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub seat_bond: UncheckedAccount<'info>,
+}
+
+/// MPC config and aggregation don't record who paid for them, so their rent goes to the
+/// treasury; key shares are passed as (share, juror) pairs in remaining accounts
+#[derive(Accounts)]
+pub struct CloseMpcAccounts<'info> {
+    pub cranker: Signer<'info>,
+    /// CHECK: May already be closed; `case_retired` handles both cases
+    #[account(
+        seeds = [b"case", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = treasury,
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+    #[account(
+        mut,
+        seeds = [b"mpc_aggregation", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = vote_aggregation.bump
+    )]
+    pub vote_aggregation: Option<Account<'info, MpcVoteAggregation>>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+/// Permissionless once the retention period has passed; rent goes back to the reporter.
+/// Unreleased seat bonds are settled first, as `release_seat_bond` would, so the case
+/// can't close over a juror's bond.
+/// Vote records and MPC accounts can still be closed after the case is gone.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCase<'info>>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(retention_elapsed(&case, now), ErrorCode::RetentionPeriodActive);
+    require!(
+        ctx.accounts.case_panel.is_some() || case.juror_count == 0,
+        ErrorCode::InvalidCloseAccounts
+    );
+
+    require!(
+        ctx.remaining_accounts.len() % 3 == 0,
+        ErrorCode::InvalidCloseAccounts
+    );
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let (bond_info, juror, vote_record) = (&accounts[0], &accounts[1], &accounts[2]);
+        require!(bond_info.is_writable && juror.is_writable, ErrorCode::InvalidCloseAccounts);
+        let seat_bond = Account::<SeatBond>::try_from(bond_info)?;
+        let (bond_address, _) = Pubkey::find_program_address(
+            &[b"seat_bond", case.case_id.to_le_bytes().as_ref(), seat_bond.juror.as_ref()],
+            &crate::ID,
+        );
+        let (record_address, _) = Pubkey::find_program_address(
+            &[b"vote_record", case.case_id.to_le_bytes().as_ref(), seat_bond.juror.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            bond_info.key() == bond_address
+                && juror.key() == seat_bond.juror
+                && vote_record.key() == record_address,
+            ErrorCode::InvalidCloseAccounts
+        );
+        settle_seat_bond(
+            &case,
+            ctx.accounts.case_panel.as_deref(),
+            &seat_bond,
+            vote_record,
+            ctx.accounts.insurance_fund.as_mut(),
+        )?;
+        seat_bond.close(juror.clone())?;
+        case.seat_bonds_released = case.seat_bonds_released.saturating_add(1);
+    }
+    require!(
+        case.seat_bonds_released >= case.auction_seats,
+        ErrorCode::SeatBondsOutstanding
+    );
+
+    msg!("Case {} closed; rent returned to {}", case.case_id, case.reporter);
+    Ok(())
+}
+
+/// `close_case` for deployments running Light: same retention rule, but the case's
+/// summary is appended to the configured state tree before the account goes away.
+/// Auction seat bonds must already have been released.
+pub fn archive_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ArchiveCase<'info>>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(retention_elapsed(&case, now), ErrorCode::RetentionPeriodActive);
+    require!(
+        case.seat_bonds_released >= case.auction_seats,
+        ErrorCode::SeatBondsOutstanding
+    );
+    require!(
+        ctx.accounts.case_panel.is_some() || case.juror_count == 0,
+        ErrorCode::InvalidCloseAccounts
+    );
+
+    let leaf = CaseArchiveLeaf::from_case(&case, ctx.accounts.case_panel.as_deref());
+    let light_config = &ctx.accounts.light_config;
+    append_leaf(
+        crate::domain::CASE_ARCHIVE_LEAF,
+        &leaf,
+        (light_config.state_tree, light_config.nullifier_queue),
+        &ctx.accounts.cranker.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(CaseArchived {
+        case_id: leaf.case_id,
+        merkle_tree: light_config.state_tree,
+        data_hash: leaf_data_hash(crate::domain::CASE_ARCHIVE_LEAF, &leaf.try_to_vec()?),
+        timestamp: now,
+    });
+    msg!("Case {} archived to {}; rent returned to {}", leaf.case_id, light_config.state_tree, leaf.reporter);
+    Ok(())
+}
+
+pub fn close_vote_record_handler(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    require!(
+        case_retired(&ctx.accounts.case_account)?,
+        ErrorCode::RetentionPeriodActive
+    );
+    require!(ctx.accounts.seat_bond.data_is_empty(), ErrorCode::SeatBondsOutstanding);
+
+    msg!("Vote record for case {} closed", ctx.accounts.vote_record.case_id);
+    Ok(())
+}
+
+pub fn close_mpc_accounts_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseMpcAccounts<'info>>,
+) -> Result<()> {
+    require!(
+        case_retired(&ctx.accounts.case_account)?,
+        ErrorCode::RetentionPeriodActive
+    );
+    let case_id = ctx.accounts.mpc_config.case_id;
+    let treasury = ctx.accounts.treasury.to_account_info();
+
+    if let Some(vote_aggregation) = &ctx.accounts.vote_aggregation {
+        vote_aggregation.close(treasury)?;
+    }
+
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        ErrorCode::InvalidCloseAccounts
+    );
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (share_info, juror) = (&pair[0], &pair[1]);
+        require!(share_info.is_writable && juror.is_writable, ErrorCode::InvalidCloseAccounts);
+        let share = Account::<MpcKeyShare>::try_from(share_info)?;
+        let (share_address, _) = Pubkey::find_program_address(
+            &[b"mpc_share", case_id.to_le_bytes().as_ref(), share.juror.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            share_info.key() == share_address && juror.key() == share.juror,
+            ErrorCode::InvalidCloseAccounts
+        );
+        share.close(juror.clone())?;
+    }
+
+    msg!(
+        "MPC accounts for case {} closed ({} key shares)",
+        case_id,
+        ctx.remaining_accounts.len() / 2
+    );
+    Ok(())
+}
+
+pub(crate) fn retention_elapsed(case: &CaseAccount, now: i64) -> bool {
+    case.closed_at != 0 && now >= case.closed_at.saturating_add(CASE_RETENTION_PERIOD)
+}
+
+/// A case PDA with no data has already been closed, which implies its retention passed
+fn case_retired(case_info: &AccountInfo) -> Result<bool> {
+    if case_info.data_is_empty() {
+        return Ok(true);
+    }
+    require!(case_info.owner == &crate::ID, ErrorCode::InvalidCloseAccounts);
+    let data = case_info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + CaseAccount::LEN && data[..8] == CaseAccount::DISCRIMINATOR,
+        ErrorCode::InvalidCloseAccounts
+    );
+    let case: &CaseAccount = bytemuck::from_bytes(&data[8..8 + CaseAccount::LEN]);
+    Ok(retention_elapsed(case, Clock::get()?.unix_timestamp))
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::bubblegum::{self, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID};
+use crate::state::{CaseAccount, CaseState, CnftLeaf, FlaggedAsset, FlaggedAssets, GlobalConfig, MAX_FLAGGED_ASSETS};
+use crate::{CnftAssetsFlagged, ErrorCode};
+
+/// Each leaf's Merkle tree and then its `proof_len` proof nodes follow in remaining accounts
+#[derive(Accounts)]
+pub struct FlagCnftAssets<'info> {
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = flagger,
+        space = 8 + FlaggedAssets::LEN,
+        seeds = [b"flagged_assets", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub flagged_assets: Account<'info, FlaggedAssets>,
+    /// CHECK: SPL account compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: lists compressed NFTs owned by the accused of an approved case. Each
+/// leaf is rebuilt with the scam address as owner and verified against its tree, so only
+/// assets the accused actually holds can be flagged.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FlagCnftAssets<'info>>,
+    leaves: Vec<CnftLeaf>,
+) -> Result<()> {
+    let (case_id, owner) = {
+        let case = ctx.accounts.case_account.load()?;
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(case.state == CaseState::Approved as u8, ErrorCode::NotApproved);
+        require!(case.overturned == 0, ErrorCode::NotApproved);
+        (case.case_id, case.scam_address)
+    };
+
+    let flagged_assets = &mut ctx.accounts.flagged_assets;
+    let compression_program = ctx.accounts.compression_program.to_account_info();
+    let mut remaining = ctx.remaining_accounts;
+    let mut added = Vec::with_capacity(leaves.len());
+    for leaf in &leaves {
+        let proof_len = leaf.proof_len as usize;
+        require!(remaining.len() > proof_len, ErrorCode::InvalidCnftProof);
+        let (tree, rest) = remaining.split_first().ok_or(ErrorCode::InvalidCnftProof)?;
+        let (proof, rest) = rest.split_at(proof_len);
+        remaining = rest;
+        require!(tree.owner == &SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, ErrorCode::InvalidCnftProof);
+
+        let asset_id = bubblegum::asset_id(tree.key, leaf.nonce);
+        let repeated = added.iter().any(|asset: &FlaggedAsset| asset.asset_id == asset_id);
+        if repeated || flagged_assets.is_flagged(&asset_id) {
+            continue;
+        }
+        let leaf_hash = bubblegum::leaf_hash(
+            &asset_id,
+            &owner,
+            &leaf.delegate,
+            leaf.nonce,
+            &leaf.data_hash,
+            &leaf.creator_hash,
+        );
+        let index = u32::try_from(leaf.nonce).map_err(|_| ErrorCode::InvalidCnftProof)?;
+        bubblegum::verify_leaf(&compression_program, tree, proof, leaf.root, leaf_hash, index)?;
+
+        added.push(FlaggedAsset {
+            asset_id,
+            tree: tree.key(),
+            leaf_hash,
+        });
+    }
+    require!(remaining.is_empty(), ErrorCode::InvalidCnftProof);
+    require!(
+        flagged_assets.assets.len() + added.len() <= MAX_FLAGGED_ASSETS,
+        ErrorCode::FlaggedAssetsFull
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    flagged_assets.case_id = case_id;
+    flagged_assets.assets.extend_from_slice(&added);
+    flagged_assets.updated_at = now;
+    flagged_assets.bump = ctx.bumps.flagged_assets;
+
+    emit!(CnftAssetsFlagged {
+        case_id,
+        asset_ids: added.iter().map(|asset| asset.asset_id).collect(),
+        total_flagged: flagged_assets.assets.len() as u32,
+        timestamp: now,
+    });
+    msg!("Case {} flagged {} cNFTs ({} total)", case_id, added.len(), flagged_assets.assets.len());
+    Ok(())
+}
@@ -0,0 +1,611 @@
+// Confidential case mode: one case carries a sealed accused, encrypted evidence whose key is
+// escrowed to the panel's MPC shares, ZK private votes tallied by MPC, an atomic
+// reveal-and-freeze, and confidential juror payouts. The ConfidentialCaseConfig decides which
+// of these components every confidential case must use.
+use anchor_lang::prelude::*;
+use anchor_spl::token::{FreezeAccount, Mint, Token, TokenAccount};
+use crate::state::{
+    CaseAccount, CaseCategory, CaseCounter, CasePanel, CaseSeverity, CaseState, CaseStatus, ConfidentialCaseConfig,
+    ConfidentialComponents, FreezeReceipt, GlobalConfig, JurorPayout, ProtocolStats, ScamIndex, Treasury,
+    CASE_ACCOUNT_VERSION,
+};
+use crate::zk_proofs::{
+    CompressedVoteState, ConfidentialTransferRecord, EvidenceCommitment, JurorEvidenceShare,
+    LightCompressionConfig,
+    MpcConfig, MpcKeyShare, MpcState, MpcVoteAggregation, TransferStatus, MPC_DECRYPTION_PERIOD,
+};
+use crate::{
+    AccountFrozen, ConfidentialCaseCreated, ConfidentialPayoutRecorded, ErrorCode, MpcTallyApplied,
+    SealedAddressRevealed,
+};
+use super::execute_freeze::record_delegate;
+use super::treasury::collect_submission_fee;
+use super::vote::emit_verdict;
+
+#[derive(Accounts)]
+pub struct ConfigureConfidentialCases<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ConfidentialCaseConfig::LEN,
+        seeds = [b"confidential_case_config"],
+        bump
+    )]
+    pub confidential_config: Account<'info, ConfidentialCaseConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct SubmitConfidentialCase<'info> {
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + CaseAccount::LEN,
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"confidential_case_config"],
+        bump = confidential_config.bump
+    )]
+    pub confidential_config: Account<'info, ConfidentialCaseConfig>,
+    /// Assigns case ids in order; `case_id` must be its next id
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    /// Posted by initialize_private_evidence earlier in the same transaction; required
+    /// with encrypted evidence
+    #[account(
+        seeds = [b"evidence_commitment", case_id.to_le_bytes().as_ref()],
+        bump = evidence_commitment.bump
+    )]
+    pub evidence_commitment: Option<Account<'info, EvidenceCommitment>>,
+    /// Receives the submission fee; required while one is configured
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+/// Creates the panel's MPC group, vote aggregation, and compressed vote tree once jurors are drawn
+#[derive(Accounts)]
+pub struct OpenConfidentialTally<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MpcConfig::LEN,
+        seeds = [b"mpc_config", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MpcVoteAggregation::MAX_SIZE,
+        seeds = [b"mpc_aggregation", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vote_aggregation: Account<'info, MpcVoteAggregation>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CompressedVoteState::LEN,
+        seeds = [b"compressed_votes", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub compressed_state: Account<'info, CompressedVoteState>,
+    /// Votes are compressed into its state tree when present and enabled
+    #[account(seeds = [b"light_config"], bump = light_config.bump)]
+    pub light_config: Option<Account<'info, LightCompressionConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfirmKeyEscrow<'info> {
+    pub juror: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        mut,
+        seeds = [b"mpc_share", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = mpc_share.bump
+    )]
+    pub mpc_share: Account<'info, MpcKeyShare>,
+    /// The juror's share of the evidence key; required with encrypted evidence
+    #[account(
+        seeds = [b"evidence_share", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = evidence_share.bump
+    )]
+    pub evidence_share: Option<Account<'info, JurorEvidenceShare>>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCaseFromMpc<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"mpc_config", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+    #[account(
+        seeds = [b"mpc_aggregation", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = vote_aggregation.bump
+    )]
+    pub vote_aggregation: Account<'info, MpcVoteAggregation>,
+    #[account(
+        seeds = [b"compressed_votes", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = compressed_state.bump
+    )]
+    pub compressed_state: Account<'info, CompressedVoteState>,
+}
+
+#[derive(Accounts)]
+#[instruction(scam_address: Pubkey)]
+pub struct RevealAndFreeze<'info> {
+    #[account(mut)]
+    pub revealer: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init_if_needed,
+        payer = revealer,
+        space = 8 + ScamIndex::LEN,
+        seeds = [b"scam_index", scam_address.as_ref()],
+        bump
+    )]
+    pub scam_index: Account<'info, ScamIndex>,
+    #[account(
+        init,
+        payer = revealer,
+        space = 8 + FreezeReceipt::LEN,
+        seeds = [
+            b"freeze_receipt",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            scam_token_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    /// Token account of the revealed address to freeze
+    #[account(
+        mut,
+        constraint = scam_token_account.owner == scam_address @ ErrorCode::InvalidSealedAddress,
+        constraint = scam_token_account.mint == mint.key() @ ErrorCode::InvalidSealedAddress
+    )]
+    pub scam_token_account: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    /// CHECK: Program authority PDA that freezes the account
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+#[derive(Accounts)]
+pub struct RecordConfidentialPayout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"confidential_transfer", transfer.transfer_id.to_le_bytes().as_ref()],
+        bump = transfer.bump
+    )]
+    pub transfer: Account<'info, ConfidentialTransferRecord>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + JurorPayout::LEN,
+        seeds = [b"juror_payout", case_account.load()?.case_id.to_le_bytes().as_ref(), transfer.recipient.as_ref()],
+        bump
+    )]
+    pub juror_payout: Account<'info, JurorPayout>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_handler(
+    ctx: Context<ConfigureConfidentialCases>,
+    enabled: bool,
+    required_components: u8,
+) -> Result<()> {
+    let confidential_config = &mut ctx.accounts.confidential_config;
+    confidential_config.bump = ctx.bumps.confidential_config;
+    apply_confidential_policy(confidential_config, enabled, required_components)
+}
+
+/// The accused is always sealed in confidential mode, so that bit is implied
+pub(crate) fn apply_confidential_policy(
+    confidential_config: &mut ConfidentialCaseConfig,
+    enabled: bool,
+    required_components: u8,
+) -> Result<()> {
+    require!(
+        required_components & !ConfidentialComponents::ALL == 0,
+        ErrorCode::InvalidConfidentialComponents
+    );
+    confidential_config.enabled = enabled;
+    confidential_config.required_components = required_components | ConfidentialComponents::SEALED_ADDRESS;
+
+    msg!(
+        "Confidential cases {}; required components {:#06b}",
+        if enabled { "enabled" } else { "disabled" },
+        confidential_config.required_components
+    );
+    Ok(())
+}
+
+/// Opens a case against a sealed accused. The address stays out of the ScamIndex and the
+/// duplicate guard until an approved verdict reveals it, so threat-intel linking and
+/// repeat-offender escalation don't apply.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_handler(
+    ctx: Context<SubmitConfidentialCase>,
+    case_id: u64,
+    sealed_address: [u8; 32],
+    evidence_uri: String,
+    evidence_hash: [u8; 32],
+    components: u8,
+    bump: u8,
+    category: CaseCategory,
+    evidence_tx_root: [u8; 32],
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    ctx.accounts.case_counter.assign(case_id)?;
+    let policy = &ctx.accounts.confidential_config;
+    require!(policy.enabled, ErrorCode::ConfidentialModeDisabled);
+
+    let components = components | ConfidentialComponents::SEALED_ADDRESS;
+    require!(
+        components & !ConfidentialComponents::ALL == 0
+            && components & policy.required_components == policy.required_components,
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(sealed_address != [0u8; 32], ErrorCode::InvalidSealedAddress);
+    if components & ConfidentialComponents::ENCRYPTED_EVIDENCE != 0 {
+        // Plaintext evidence would defeat the escrow
+        require!(
+            evidence_uri.is_empty() && ctx.accounts.evidence_commitment.is_some(),
+            ErrorCode::InvalidConfidentialComponents
+        );
+    }
+    collect_submission_fee(
+        ctx.accounts.config.submission_fee,
+        ctx.accounts.treasury.as_mut(),
+        ctx.accounts.reporter.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_case();
+    }
+
+    let clock = Clock::get()?;
+    let mut case = ctx.accounts.case_account.load_init()?;
+    case.case_id = case_id;
+    case.sealed_address = sealed_address;
+    case.confidential = components;
+    case.reporter = ctx.accounts.reporter.key();
+    case.set_evidence(&evidence_uri, evidence_hash)?;
+    case.evidence_tx_root = evidence_tx_root;
+    case.status = CaseStatus::Open as u8;
+    case.state = CaseState::PendingJurors as u8;
+    case.bump = bump;
+    case.version = CASE_ACCOUNT_VERSION;
+    case.severity = CaseSeverity::Medium as u8;
+    case.created_at = clock.unix_timestamp;
+    case.expires_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.config.max_case_age)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    case.category = category as u8;
+    let (numerator, denominator) = ctx.accounts.config.case_threshold(case.category, case.severity, false);
+    case.threshold_numerator = numerator;
+    case.threshold_denominator = denominator;
+
+    emit!(ConfidentialCaseCreated {
+        case_id,
+        reporter: case.reporter,
+        sealed_address,
+        components,
+        category: case.category,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Confidential case {} opened with components {:#06b}", case_id, components);
+    Ok(())
+}
+
+/// Permissionless once the panel is drawn; the MPC threshold is a majority of the panel
+pub fn open_tally_handler(ctx: Context<OpenConfidentialTally>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    require!(
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(
+        case.state == CaseState::Nominated as u8 || case.state == CaseState::Voting as u8,
+        ErrorCode::CaseNotVoting
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let threshold = case.juror_count / 2 + 1;
+    let mut mpc_config = MpcConfig::new(case.case_id, threshold, case.juror_count, now, ctx.bumps.mpc_config);
+    // Decryption can't start before voting closes
+    mpc_config.decryption_deadline = mpc_config
+        .decryption_deadline
+        .max(case.expires_at.saturating_add(MPC_DECRYPTION_PERIOD));
+    ctx.accounts.mpc_config.set_inner(mpc_config);
+
+    let vote_aggregation = &mut ctx.accounts.vote_aggregation;
+    vote_aggregation.case_id = case.case_id;
+    vote_aggregation.bump = ctx.bumps.vote_aggregation;
+
+    ctx.accounts
+        .compressed_state
+        .set_inner(CompressedVoteState::initialize(
+            case.case_id,
+            ctx.accounts.light_config.as_deref(),
+            ctx.bumps.compressed_state,
+        ));
+
+    msg!("Confidential tally opened for case {}: {}/{} shares", case.case_id, threshold, case.juror_count);
+    Ok(())
+}
+
+/// A seated juror's MPC share counts toward decryption only once it matches the share
+/// of the evidence key they hold, so the same escrow opens the evidence and the tally
+pub fn confirm_key_escrow_handler(ctx: Context<ConfirmKeyEscrow>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let juror = ctx.accounts.juror.key();
+    require!(
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(ctx.accounts.case_panel.is_juror(&juror), ErrorCode::NotJuror);
+
+    let mpc_share = &mut ctx.accounts.mpc_share;
+    if case.has_component(ConfidentialComponents::ENCRYPTED_EVIDENCE) {
+        let evidence_share = ctx
+            .accounts
+            .evidence_share
+            .as_ref()
+            .ok_or(ErrorCode::KeyEscrowMismatch)?;
+        require!(
+            evidence_share.has_verified && evidence_share.share_commitment == mpc_share.share_commitment,
+            ErrorCode::KeyEscrowMismatch
+        );
+    }
+    mpc_share.verified = true;
+
+    msg!("Key share of {} confirmed for case {}", juror, case.case_id);
+    Ok(())
+}
+
+/// Permissionless: publishes the MPC tally as the verdict. The tally is final, so the
+/// approval bar is measured against the seated panel.
+pub fn finalize_from_mpc_handler(ctx: Context<FinalizeCaseFromMpc>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(case.state == CaseState::Voting as u8, ErrorCode::CaseNotVoting);
+
+    // The MPC group must be this case's panel, decrypting at a panel majority; a group
+    // initialized for the case with a lower bar doesn't get to decide it
+    let mpc_config = &mut ctx.accounts.mpc_config;
+    let vote_aggregation = &ctx.accounts.vote_aggregation;
+    require!(
+        mpc_config.case_id == case.case_id && vote_aggregation.case_id == case.case_id,
+        ErrorCode::InvalidCase
+    );
+    require!(
+        mpc_config.total_jurors == case.juror_count
+            && mpc_config.threshold > case.juror_count / 2
+            && vote_aggregation.partial_decryptions.len() >= mpc_config.threshold as usize,
+        ErrorCode::InvalidThreshold
+    );
+
+    let result = vote_aggregation
+        .final_result
+        .as_ref()
+        .filter(|result| vote_aggregation.computation_complete && result.verified)
+        .ok_or(ErrorCode::TallyIncomplete)?;
+    // Every committed vote is counted exactly once
+    require!(
+        result.total_votes == ctx.accounts.compressed_state.total_commitments
+            && result.votes_for.checked_add(result.votes_against) == Some(result.total_votes),
+        ErrorCode::TallyIncomplete
+    );
+
+    case.votes_for = result.votes_for;
+    case.votes_against = result.votes_against;
+    let approved = case.votes_for >= case.required_votes(case.juror_count as u64);
+    case.state = if approved { CaseState::Approved } else { CaseState::Rejected } as u8;
+    case.status = CaseStatus::Closed as u8;
+    mpc_config.state = MpcState::ComputationComplete;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit_verdict(&case, now);
+    emit!(MpcTallyApplied {
+        case_id: case.case_id,
+        votes_for: case.votes_for,
+        votes_against: case.votes_against,
+        approved,
+        timestamp: now,
+    });
+
+    msg!("MPC tally applied to case {}: {} for, {} against", case.case_id, case.votes_for, case.votes_against);
+    Ok(())
+}
+
+/// Opening the commitment and freezing happen in one instruction, so the accused learns
+/// of the verdict no earlier than their account is frozen. A rejected case is never revealed.
+pub fn reveal_and_freeze_handler(
+    ctx: Context<RevealAndFreeze>,
+    scam_address: Pubkey,
+    salt: [u8; 32],
+) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(case.is_sealed(), ErrorCode::InvalidSealedAddress);
+    require!(case.state == CaseState::Approved as u8, ErrorCode::NotApproved);
+    require!(
+        seal_address(case.case_id, &scam_address, &salt) == case.sealed_address,
+        ErrorCode::InvalidSealedAddress
+    );
+
+    let clock = Clock::get()?;
+    case.scam_address = scam_address;
+    case.status = CaseStatus::Frozen as u8;
+
+    let scam_index = &mut ctx.accounts.scam_index;
+    scam_index.address = scam_address;
+    scam_index.record_case(case.case_id);
+    scam_index.updated_at = clock.unix_timestamp;
+    scam_index.bump = ctx.bumps.scam_index;
+
+    let freeze_receipt = &mut ctx.accounts.freeze_receipt;
+    freeze_receipt.case_id = case.case_id;
+    freeze_receipt.token_account = ctx.accounts.scam_token_account.key();
+    freeze_receipt.mint = ctx.accounts.mint.key();
+    freeze_receipt.frozen_at = clock.unix_timestamp;
+    freeze_receipt.last_verified_at = clock.unix_timestamp;
+    freeze_receipt.intact = true;
+    freeze_receipt.bump = ctx.bumps.freeze_receipt;
+    record_delegate(freeze_receipt, &ctx.accounts.scam_token_account);
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_freeze();
+    }
+
+    let case_id = case.case_id;
+    drop(case);
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    anchor_spl::token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.scam_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    emit!(SealedAddressRevealed {
+        case_id,
+        scam_address,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(AccountFrozen {
+        case_id,
+        token_account: ctx.accounts.scam_token_account.key(),
+        mint: ctx.accounts.mint.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Case {} revealed {} and froze its token account", case_id, scam_address);
+    Ok(())
+}
+
+/// Ties a funder's confidential transfer to a juror who sat on the case; one per juror
+pub fn record_payout_handler(ctx: Context<RecordConfidentialPayout>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let transfer = &ctx.accounts.transfer;
+    require!(
+        case.has_component(ConfidentialComponents::CONFIDENTIAL_PAYOUTS),
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(
+        case.state == CaseState::Approved as u8
+            || case.state == CaseState::Executed as u8
+            || case.state == CaseState::Rejected as u8,
+        ErrorCode::CaseNotDecided
+    );
+    require!(ctx.accounts.case_panel.is_juror(&transfer.recipient), ErrorCode::NotJuror);
+    require!(
+        transfer.sender == ctx.accounts.payer.key()
+            && (transfer.status == TransferStatus::Pending || transfer.status == TransferStatus::Completed),
+        ErrorCode::InvalidPayoutTransfer
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let payout = &mut ctx.accounts.juror_payout;
+    payout.case_id = case.case_id;
+    payout.juror = transfer.recipient;
+    payout.transfer_id = transfer.transfer_id;
+    payout.recorded_at = now;
+    payout.bump = ctx.bumps.juror_payout;
+
+    emit!(ConfidentialPayoutRecorded {
+        case_id: case.case_id,
+        juror: payout.juror,
+        transfer_id: payout.transfer_id,
+        timestamp: now,
+    });
+
+    msg!("Confidential payout {} recorded for {} on case {}", payout.transfer_id, payout.juror, case.case_id);
+    Ok(())
+}
+
+/// Commitment a reporter posts instead of the accused address
+pub fn seal_address(case_id: u64, scam_address: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+    crate::domain::hash(
+        crate::domain::SEALED_ADDRESS,
+        &[&case_id.to_le_bytes(), scam_address.as_ref(), salt],
+    )
+}
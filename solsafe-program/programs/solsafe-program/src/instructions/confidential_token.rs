@@ -0,0 +1,477 @@
+// Token-2022 confidential-transfer payouts. A per-mint vault owned by the program authority is
+// configured for the extension; funders deposit into it, and the admin, who holds the vault's
+// ElGamal key and pre-verifies each proof into a context state account, pays juror rewards and
+// restitution as confidential transfers that wallets supporting the extension can read.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        confidential_transfer::{
+            instruction::{self as confidential, ConfidentialTransferInstruction, ConfigureAccountInstructionData},
+        },
+        ExtensionType,
+    },
+    instruction::TokenInstruction,
+    proof::ProofLocation,
+    solana_zk_token_sdk::zk_token_elgamal::pod::AeCiphertext,
+};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TransferChecked};
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, ConfidentialComponents, GlobalConfig, JurorPayout, RestitutionEscrow, VictimClaim,
+};
+use crate::{ConfidentialTokenPayout, ErrorCode};
+use super::restitution::pro_rata_share;
+
+#[derive(Accounts)]
+pub struct OpenConfidentialVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"confidential_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = program_authority,
+        token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Context state holding the pre-verified PubkeyValidity proof; Token-2022 checks it
+    pub proof_context: UncheckedAccount<'info>,
+    /// CHECK: Program authority PDA - owner of the vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundConfidentialVault<'info> {
+    pub funder: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"confidential_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Program authority PDA - owner of the vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyConfidentialVaultBalance<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"confidential_vault", vault.mint.as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Program authority PDA - owner of the vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct PayConfidentialJurorReward<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"confidential_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub juror_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Shared with record_confidential_payout, so a juror is paid once either way
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + JurorPayout::LEN,
+        seeds = [b"juror_payout", case_account.load()?.case_id.to_le_bytes().as_ref(), juror_token_account.owner.as_ref()],
+        bump
+    )]
+    pub juror_payout: Account<'info, JurorPayout>,
+    /// CHECK: Context state holding the pre-verified Transfer proof; Token-2022 checks it
+    pub proof_context: UncheckedAccount<'info>,
+    /// CHECK: Program authority PDA - owner of the vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct PayConfidentialRestitution<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"escrow", case_id.to_le_bytes().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, RestitutionEscrow>,
+    #[account(
+        mut,
+        seeds = [b"victim_claim", case_id.to_le_bytes().as_ref(), victim_token_account.owner.as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, VictimClaim>,
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"confidential_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub victim_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Context state holding the pre-verified Transfer proof; Token-2022 checks it
+    pub proof_context: UncheckedAccount<'info>,
+    /// CHECK: Program authority PDA - owner of the vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Creates the mint's vault, makes room for the extension, and configures it with the
+/// ElGamal key proven in `proof_context`
+pub fn open_vault_handler(
+    ctx: Context<OpenConfidentialVault>,
+    decryptable_zero_balance: [u8; 36],
+    maximum_pending_balance_credit_counter: u64,
+) -> Result<()> {
+    let token_program = ctx.accounts.token_program.key();
+    let vault = ctx.accounts.vault.key();
+    let authority = ctx.accounts.program_authority.key();
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+
+    let reallocate = spl_token_2022::instruction::reallocate(
+        &token_program,
+        &vault,
+        &ctx.accounts.admin.key(),
+        &authority,
+        &[],
+        &[ExtensionType::ConfidentialTransferAccount],
+    )?;
+    invoke_signed(
+        &reallocate,
+        &[
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.program_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    // Token-2022 only builds ConfigureAccount off-chain, so the data is encoded here
+    let mut data = TokenInstruction::ConfidentialTransferExtension.pack();
+    data.push(ConfidentialTransferInstruction::ConfigureAccount.into());
+    data.extend_from_slice(bytemuck::bytes_of(&ConfigureAccountInstructionData {
+        decryptable_zero_balance: AeCiphertext(decryptable_zero_balance),
+        maximum_pending_balance_credit_counter: maximum_pending_balance_credit_counter.into(),
+        proof_instruction_offset: 0,
+    }));
+    let configure = anchor_lang::solana_program::instruction::Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(ctx.accounts.mint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.proof_context.key(), false),
+            AccountMeta::new_readonly(authority, true),
+        ],
+        data,
+    };
+    invoke_signed(
+        &configure,
+        &[
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.proof_context.to_account_info(),
+            ctx.accounts.program_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    msg!("Confidential vault {} opened for mint {}", vault, ctx.accounts.mint.key());
+    Ok(())
+}
+
+/// Permissionless: moves the funder's tokens into the vault's pending confidential balance
+pub fn fund_vault_handler(ctx: Context<FundConfidentialVault>, amount: u64) -> Result<()> {
+    let decimals = ctx.accounts.mint.decimals;
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )?;
+
+    let deposit = confidential::deposit(
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.vault.key(),
+        &ctx.accounts.mint.key(),
+        amount,
+        decimals,
+        &ctx.accounts.program_authority.key(),
+        &[],
+    )?;
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    invoke_signed(
+        &deposit,
+        &[
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.program_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    msg!("Confidential vault for {} funded with {}", ctx.accounts.mint.key(), amount);
+    Ok(())
+}
+
+/// Folds deposits into the spendable balance; the admin supplies the re-encrypted total
+pub fn apply_vault_balance_handler(
+    ctx: Context<ApplyConfidentialVaultBalance>,
+    expected_pending_balance_credit_counter: u64,
+    new_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let apply = confidential::inner_apply_pending_balance(
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.vault.key(),
+        expected_pending_balance_credit_counter,
+        AeCiphertext(new_decryptable_available_balance),
+        &ctx.accounts.program_authority.key(),
+        &[],
+    )?;
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    invoke_signed(
+        &apply,
+        &[
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.program_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    msg!("Confidential vault {} applied {} pending credits", ctx.accounts.vault.key(), expected_pending_balance_credit_counter);
+    Ok(())
+}
+
+/// Pays a juror of a decided case that uses confidential payouts; one payout per juror
+pub fn pay_juror_reward_handler(
+    ctx: Context<PayConfidentialJurorReward>,
+    new_source_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let juror = ctx.accounts.juror_token_account.owner;
+    require!(
+        case.has_component(ConfidentialComponents::CONFIDENTIAL_PAYOUTS),
+        ErrorCode::InvalidConfidentialComponents
+    );
+    require!(
+        case.state == CaseState::Approved as u8
+            || case.state == CaseState::Executed as u8
+            || case.state == CaseState::Rejected as u8,
+        ErrorCode::CaseNotDecided
+    );
+    require!(ctx.accounts.case_panel.is_juror(&juror), ErrorCode::NotJuror);
+
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.juror_token_account,
+        &ctx.accounts.proof_context,
+        &ctx.accounts.program_authority,
+        ctx.bumps.program_authority,
+        new_source_decryptable_available_balance,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let payout = &mut ctx.accounts.juror_payout;
+    payout.case_id = case.case_id;
+    payout.juror = juror;
+    payout.transfer_id = 0; // Paid through Token-2022, not a ConfidentialTransferRecord
+    payout.recorded_at = now;
+    payout.bump = ctx.bumps.juror_payout;
+
+    emit!(ConfidentialTokenPayout {
+        case_id: case.case_id,
+        recipient: juror,
+        token_account: ctx.accounts.juror_token_account.key(),
+        restitution: false,
+        timestamp: now,
+    });
+
+    msg!("Confidential reward paid to juror {} on case {}", juror, case.case_id);
+    Ok(())
+}
+
+/// Pays a victim's claim from the vault instead of the escrow. The pro-rata share is still
+/// computed and recorded on the claim, which is then closed to `claim_restitution`.
+pub fn pay_restitution_handler(
+    ctx: Context<PayConfidentialRestitution>,
+    case_id: u64,
+    new_source_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let claim = &mut ctx.accounts.claim;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= escrow.claims_close_at, ErrorCode::ClaimWindowOpen);
+    require!(!claim.paid, ErrorCode::AlreadyClaimed);
+    let payout = pro_rata_share(escrow.total_seized, claim.amount_lost, escrow.total_claimed)?;
+    require!(payout > 0, ErrorCode::NothingToClaim);
+
+    transfer_from_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.victim_token_account,
+        &ctx.accounts.proof_context,
+        &ctx.accounts.program_authority,
+        ctx.bumps.program_authority,
+        new_source_decryptable_available_balance,
+    )?;
+
+    claim.payout = payout;
+    claim.paid = true;
+
+    emit!(ConfidentialTokenPayout {
+        case_id,
+        recipient: claim.victim,
+        token_account: ctx.accounts.victim_token_account.key(),
+        restitution: true,
+        timestamp: now,
+    });
+
+    msg!("Confidential restitution paid to {} on case {}", claim.victim, case_id);
+    Ok(())
+}
+
+/// Confidential transfer out of the vault, signed by the program authority. The amount is
+/// hidden in the proof the admin verified into `proof_context`.
+#[allow(clippy::too_many_arguments)]
+fn transfer_from_vault<'info>(
+    token_program: &Program<'info, Token2022>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    proof_context: &UncheckedAccount<'info>,
+    program_authority: &UncheckedAccount<'info>,
+    bump: u8,
+    new_source_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    let transfer = confidential::inner_transfer(
+        &token_program.key(),
+        &vault.key(),
+        &mint.key(),
+        &destination.key(),
+        AeCiphertext(new_source_decryptable_available_balance),
+        &program_authority.key(),
+        &[],
+        ProofLocation::ContextStateAccount(&proof_context.key()),
+    )?;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    invoke_signed(
+        &transfer,
+        &[
+            vault.to_account_info(),
+            mint.to_account_info(),
+            destination.to_account_info(),
+            proof_context.to_account_info(),
+            program_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::{CaseAccount, CaseState, GlobalConfig};
+use crate::wormhole::{
+    self, PostMessageAccounts, BRIDGE_SEED, CONSISTENCY_FINALIZED, FEE_COLLECTOR_SEED, SEQUENCE_SEED,
+    WORMHOLE_CHAIN_ID_SOLANA, WORMHOLE_PROGRAM_ID,
+};
+use crate::{ErrorCode, VerdictPublished};
+
+/// Leading byte of an exported verdict, so receivers can tell payload versions apart
+pub const VERDICT_PAYLOAD_ID: u8 = 1;
+
+#[derive(Accounts)]
+pub struct PublishVerdictCrosschain<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// CHECK: Core bridge config; the bridge checks it
+    #[account(
+        mut,
+        seeds = [BRIDGE_SEED],
+        bump,
+        seeds::program = WORMHOLE_PROGRAM_ID
+    )]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+    /// CHECK: Message account the bridge creates; one per case, so a verdict is exported once
+    #[account(
+        mut,
+        seeds = [b"wormhole_message", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub wormhole_message: UncheckedAccount<'info>,
+    /// CHECK: Program PDA that every SolSafe message is emitted from
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    pub wormhole_emitter: UncheckedAccount<'info>,
+    /// CHECK: Emitter's sequence tracker, created by the bridge on the first post
+    #[account(
+        mut,
+        seeds = [SEQUENCE_SEED, wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = WORMHOLE_PROGRAM_ID
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+    /// CHECK: Receives the message fee
+    #[account(
+        mut,
+        seeds = [FEE_COLLECTOR_SEED],
+        bump,
+        seeds::program = WORMHOLE_PROGRAM_ID
+    )]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+    /// CHECK: Wormhole core bridge
+    #[account(address = WORMHOLE_PROGRAM_ID)]
+    pub wormhole_program: UncheckedAccount<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Big-endian, fixed-width layout for EVM receivers: payload id, case id, chain of the
+/// accused, the accused, category, severity, verdict, evidence hash
+pub fn verdict_payload(case: &CaseAccount) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(1 + 8 + 2 + 32 + 3 + 32);
+    payload.push(VERDICT_PAYLOAD_ID);
+    payload.extend_from_slice(&case.case_id.to_be_bytes());
+    payload.extend_from_slice(&WORMHOLE_CHAIN_ID_SOLANA.to_be_bytes());
+    payload.extend_from_slice(case.scam_address.as_ref());
+    payload.push(case.category);
+    payload.push(case.severity);
+    payload.push(case.verdict);
+    payload.extend_from_slice(&case.evidence_hash);
+    payload
+}
+
+/// Permissionless: posts an approved verdict as a Wormhole message so registries on other
+/// chains can mirror it. The payer covers the bridge fee and the message account's rent.
+pub fn handler(ctx: Context<PublishVerdictCrosschain>) -> Result<()> {
+    let (case_id, scam_address, payload) = {
+        let case = ctx.accounts.case_account.load()?;
+        require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+        require!(
+            case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8,
+            ErrorCode::NotApproved
+        );
+        require!(case.overturned == 0, ErrorCode::NotApproved);
+        require!(!case.is_sealed(), ErrorCode::AddressSealed);
+        (case.case_id, case.scam_address, verdict_payload(&case))
+    };
+
+    let fee = wormhole::message_fee(&ctx.accounts.wormhole_bridge)?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+    let sequence = wormhole::next_sequence(&ctx.accounts.wormhole_sequence)?;
+
+    let post = wormhole::post_message(
+        PostMessageAccounts {
+            bridge: ctx.accounts.wormhole_bridge.key(),
+            message: ctx.accounts.wormhole_message.key(),
+            emitter: ctx.accounts.wormhole_emitter.key(),
+            sequence: ctx.accounts.wormhole_sequence.key(),
+            payer: ctx.accounts.payer.key(),
+            fee_collector: ctx.accounts.wormhole_fee_collector.key(),
+        },
+        0,
+        payload,
+        CONSISTENCY_FINALIZED,
+    );
+    let case_id_bytes = case_id.to_le_bytes();
+    let message_seeds: &[&[u8]] = &[b"wormhole_message", &case_id_bytes, &[ctx.bumps.wormhole_message]];
+    let emitter_seeds: &[&[u8]] = &[b"emitter", &[ctx.bumps.wormhole_emitter]];
+    invoke_signed(
+        &post,
+        &[
+            ctx.accounts.wormhole_bridge.to_account_info(),
+            ctx.accounts.wormhole_message.to_account_info(),
+            ctx.accounts.wormhole_emitter.to_account_info(),
+            ctx.accounts.wormhole_sequence.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.wormhole_fee_collector.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.wormhole_program.to_account_info(),
+        ],
+        &[message_seeds, emitter_seeds],
+    )?;
+
+    emit!(VerdictPublished {
+        case_id,
+        scam_address,
+        emitter: ctx.accounts.wormhole_emitter.key(),
+        sequence,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Case {} verdict published cross-chain as sequence {}", case_id, sequence);
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{ActiveCaseMarker, CaseAccount, DuplicateReport, GlobalConfig, MAX_EVIDENCE_URI_LEN};
+use crate::{DuplicateLinked, ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(scam_address: Pubkey)]
+pub struct LinkDuplicate<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"active_case", scam_address.as_ref()],
+        bump = active_case.bump
+    )]
+    pub active_case: Account<'info, ActiveCaseMarker>,
+    #[account(
+        seeds = [b"case", active_case.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + DuplicateReport::LEN,
+        seeds = [b"duplicate_report", active_case.case_id.to_le_bytes().as_ref(), reporter.key().as_ref()],
+        bump
+    )]
+    pub duplicate_report: Account<'info, DuplicateReport>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Attach a second report to the address's open case instead of opening a parallel one
+pub fn link_handler(
+    ctx: Context<LinkDuplicate>,
+    scam_address: Pubkey,
+    hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    require!(uri.len() <= MAX_EVIDENCE_URI_LEN, ErrorCode::EvidenceTooLarge);
+    let case = ctx.accounts.case_account.load()?;
+    require!(case.closed_at == 0, ErrorCode::CaseNotOpen);
+    require!(case.scam_address == scam_address, ErrorCode::InvalidActiveCase);
+
+    let clock = Clock::get()?;
+    let report = &mut ctx.accounts.duplicate_report;
+    report.case_id = case.case_id;
+    report.reporter = ctx.accounts.reporter.key();
+    report.hash = hash;
+    report.uri = uri;
+    report.linked_at = clock.unix_timestamp;
+    report.bump = ctx.bumps.duplicate_report;
+
+    let marker = &mut ctx.accounts.active_case;
+    marker.linked_reports = marker.linked_reports.saturating_add(1);
+
+    emit!(DuplicateLinked {
+        case_id: case.case_id,
+        scam_address,
+        reporter: report.reporter,
+        hash,
+        linked_reports: marker.linked_reports,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Report linked to case {} ({} duplicates)", case.case_id, marker.linked_reports);
+    Ok(())
+}
+
+/// Point `marker` at a new case. A marker already in use is only released once its case
+/// has reached a verdict or expired (or been closed), which `prior_case` must show.
+pub(crate) fn claim_marker(
+    marker: &mut ActiveCaseMarker,
+    prior_case: Option<&AccountInfo>,
+    scam_address: Pubkey,
+    case_id: u64,
+    now: i64,
+) -> Result<()> {
+    if marker.scam_address != Pubkey::default() {
+        let prior_case = prior_case.ok_or(ErrorCode::DuplicateCase)?;
+        let (address, _) = Pubkey::find_program_address(
+            &[b"case", marker.case_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require!(prior_case.key() == address, ErrorCode::InvalidActiveCase);
+        require!(!case_open(prior_case)?, ErrorCode::DuplicateCase);
+    }
+
+    marker.scam_address = scam_address;
+    marker.case_id = case_id;
+    marker.opened_at = now;
+    marker.linked_reports = 0;
+    Ok(())
+}
+
+/// Cases stay open until finalize, expiry, or settlement stamps `closed_at`
+fn case_open(case_info: &AccountInfo) -> Result<bool> {
+    if case_info.data_is_empty() {
+        return Ok(false);
+    }
+    require!(case_info.owner == &crate::ID, ErrorCode::InvalidActiveCase);
+    let data = case_info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + CaseAccount::LEN && data[..8] == CaseAccount::DISCRIMINATOR,
+        ErrorCode::InvalidActiveCase
+    );
+    let case: &CaseAccount = bytemuck::from_bytes(&data[8..8 + CaseAccount::LEN]);
+    Ok(case.closed_at == 0)
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    ApprovalThreshold, CaseAccount, CaseState, CaseStatus, ConfidentialComponents, EscalationBond, GlobalConfig,
+};
+use crate::{CaseEscalated, ErrorCode};
+use super::seat_auction::check_bond_releasable;
+use super::vote::escalated_pool;
+
+#[derive(Accounts)]
+pub struct ConfigureEscalation<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct EscalateCase<'info> {
+    #[account(mut)]
+    pub escalator: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Holds the bond, if one is posted; its existence marks the case as escalated
+    #[account(
+        init,
+        payer = escalator,
+        space = 8 + EscalationBond::LEN,
+        seeds = [b"escalation_bond", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escalation_bond: Account<'info, EscalationBond>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseEscalationBond<'info> {
+    #[account(mut)]
+    pub escalator: Signer<'info>,
+    #[account(
+        seeds = [b"case", escalation_bond.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        close = escalator,
+        seeds = [b"escalation_bond", escalation_bond.case_id.to_le_bytes().as_ref()],
+        bump = escalation_bond.bump,
+        constraint = escalation_bond.escalator == escalator.key() @ ErrorCode::Unauthorized
+    )]
+    pub escalation_bond: Account<'info, EscalationBond>,
+}
+
+pub fn configure_handler(ctx: Context<ConfigureEscalation>, bond: u64, threshold: ApprovalThreshold) -> Result<()> {
+    apply_escalation(&mut ctx.accounts.config, bond, threshold)
+}
+
+pub(crate) fn apply_escalation(config: &mut GlobalConfig, bond: u64, threshold: ApprovalThreshold) -> Result<()> {
+    let unset = threshold.numerator == 0 && threshold.denominator == 0;
+    require!(
+        unset || threshold.numerator < threshold.denominator,
+        ErrorCode::InvalidThreshold
+    );
+    config.escalation_bond = bond;
+    config.escalation_threshold = threshold;
+
+    msg!(
+        "Escalation bond set to {} lamports, threshold floor {}/{}",
+        bond,
+        threshold.numerator,
+        threshold.denominator
+    );
+    Ok(())
+}
+
+/// Reopens a case to every registered validator. A deadlocked case escalates for free;
+/// one still in voting needs the configured bond.
+pub fn escalate_handler(ctx: Context<EscalateCase>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(
+        case.escalated == 0 && !case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        ErrorCode::CaseNotEscalatable
+    );
+
+    let amount = if case.is_deadlocked(now) {
+        0
+    } else {
+        require!(case.state == CaseState::Voting as u8, ErrorCode::CaseNotEscalatable);
+        require!(config.escalation_bond > 0, ErrorCode::EscalationUnavailable);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.escalator.to_account_info(),
+                    to: ctx.accounts.escalation_bond.to_account_info(),
+                },
+            ),
+            config.escalation_bond,
+        )?;
+        config.escalation_bond
+    };
+
+    let escalation_bond = &mut ctx.accounts.escalation_bond;
+    escalation_bond.case_id = case.case_id;
+    escalation_bond.escalator = ctx.accounts.escalator.key();
+    escalation_bond.amount = amount;
+    escalation_bond.posted_at = now;
+    escalation_bond.bump = ctx.bumps.escalation_bond;
+
+    open_to_full_set(&mut case, config, now)
+}
+
+pub fn release_handler(ctx: Context<ReleaseEscalationBond>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    check_bond_releasable(&case)?;
+
+    msg!(
+        "Escalation bond of {} released to {}",
+        ctx.accounts.escalation_bond.amount,
+        ctx.accounts.escalator.key()
+    );
+    Ok(())
+}
+
+/// Reopens voting to every registered validator with a fresh deadline, raising the case's
+/// threshold to the escalation floor if it sits below it. Votes already cast carry over;
+/// each VoteRecord still blocks its juror from voting twice.
+pub(crate) fn open_to_full_set(case: &mut CaseAccount, config: &GlobalConfig, now: i64) -> Result<()> {
+    let (numerator, denominator) = match config.escalation_threshold {
+        ApprovalThreshold { denominator: 0, .. } => (2, 3),
+        threshold => (threshold.numerator, threshold.denominator),
+    };
+    if (case.threshold_numerator as u16) * (denominator as u16)
+        < (numerator as u16) * (case.threshold_denominator as u16)
+    {
+        case.threshold_numerator = numerator;
+        case.threshold_denominator = denominator;
+    }
+    case.escalated = 1;
+    case.state = CaseState::Voting as u8;
+    case.status = CaseStatus::Open as u8;
+    case.expires_at = now.checked_add(config.max_case_age).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(CaseEscalated {
+        case_id: case.case_id,
+        eligible_voters: escalated_pool(case, config),
+        expires_at: case.expires_at,
+        timestamp: now,
+    });
+    msg!(
+        "Case {} escalated to the full validator set at {}/{}",
+        case.case_id,
+        case.threshold_numerator,
+        case.threshold_denominator
+    );
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::quantum_resistant::verify_merkle_proof;
+use crate::state::{CaseAccount, EvidenceTxVerification};
+use crate::{ErrorCode, EvidenceTxVerified};
+
+/// Deepest proof accepted; 2^32 signatures is beyond any real evidence bundle
+pub const MAX_EVIDENCE_TX_PROOF_LEN: usize = 32;
+
+#[derive(Accounts)]
+#[instruction(signature: [u8; 64])]
+pub struct VerifyEvidenceTx<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + EvidenceTxVerification::LEN,
+        seeds = [
+            b"evidence_tx",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            &signature[..32],
+            &signature[32..],
+        ],
+        bump
+    )]
+    pub verification: Account<'info, EvidenceTxVerification>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: anyone holding a signature and its proof can show it was part of the
+/// bundle the reporter committed to at submission. Leaves are the raw 64-byte signatures.
+pub fn handler(
+    ctx: Context<VerifyEvidenceTx>,
+    signature: [u8; 64],
+    leaf_index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    require!(case.evidence_tx_root != [0u8; 32], ErrorCode::EvidenceTxRootNotSet);
+    require!(
+        proof.len() <= MAX_EVIDENCE_TX_PROOF_LEN
+            && verify_merkle_proof(&signature, &case.evidence_tx_root, &proof, leaf_index as usize),
+        ErrorCode::InvalidMerkleProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let verification = &mut ctx.accounts.verification;
+    verification.case_id = case.case_id;
+    verification.signature = signature;
+    verification.leaf_index = leaf_index;
+    verification.verifier = ctx.accounts.verifier.key();
+    verification.verified_at = now;
+    verification.bump = ctx.bumps.verification;
+
+    emit!(EvidenceTxVerified {
+        case_id: case.case_id,
+        signature,
+        leaf_index,
+        verifier: verification.verifier,
+        timestamp: now,
+    });
+    msg!("Evidence tx {} verified for case {}", leaf_index, case.case_id);
+    Ok(())
+}
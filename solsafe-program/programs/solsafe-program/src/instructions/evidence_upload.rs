@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::{CaseAccount, EvidenceBuffer, MAX_EVIDENCE_BUFFER_LEN};
+use crate::{ErrorCode, EvidenceUploaded};
+use super::migrate::grow;
+
+#[derive(Accounts)]
+pub struct BeginEvidenceUpload<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Starts as just the header; writes grow it
+    #[account(
+        init,
+        payer = reporter,
+        space = EvidenceBuffer::DATA_START,
+        seeds = [b"evidence_buffer", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub evidence_buffer: Account<'info, EvidenceBuffer>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WriteEvidenceChunk<'info> {
+    /// Pays rent as the buffer grows
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"evidence_buffer", evidence_buffer.case_id.to_le_bytes().as_ref()],
+        bump = evidence_buffer.bump,
+        constraint = evidence_buffer.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub evidence_buffer: Account<'info, EvidenceBuffer>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeEvidenceUpload<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"evidence_buffer", evidence_buffer.case_id.to_le_bytes().as_ref()],
+        bump = evidence_buffer.bump,
+        constraint = evidence_buffer.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub evidence_buffer: Account<'info, EvidenceBuffer>,
+}
+
+/// The case's reporter declares how large the bundle is before writing any of it
+pub fn begin_handler(ctx: Context<BeginEvidenceUpload>, size: u32) -> Result<()> {
+    require!(size > 0 && size <= MAX_EVIDENCE_BUFFER_LEN, ErrorCode::EvidenceTooLarge);
+    let case_id = ctx.accounts.case_account.load()?.case_id;
+
+    let evidence_buffer = &mut ctx.accounts.evidence_buffer;
+    evidence_buffer.case_id = case_id;
+    evidence_buffer.authority = ctx.accounts.reporter.key();
+    evidence_buffer.size = size;
+    evidence_buffer.hash = [0u8; 32];
+    evidence_buffer.finalized = false;
+    evidence_buffer.created_at = Clock::get()?.unix_timestamp;
+    evidence_buffer.bump = ctx.bumps.evidence_buffer;
+
+    msg!("Evidence upload of {} bytes started for case {}", size, case_id);
+    Ok(())
+}
+
+/// Copies `bytes` to `offset` in the bundle, growing the account to cover them. The runtime
+/// lets an account grow by only 10 KiB per instruction, so chunks past the written end go
+/// front to back; rewriting a range is allowed until finalize, whose hash check is what
+/// vouches for the content.
+pub fn write_handler(ctx: Context<WriteEvidenceChunk>, offset: u32, bytes: Vec<u8>) -> Result<()> {
+    let evidence_buffer = &ctx.accounts.evidence_buffer;
+    require!(!evidence_buffer.finalized, ErrorCode::EvidenceBufferFinalized);
+    let end = offset
+        .checked_add(bytes.len() as u32)
+        .ok_or(ErrorCode::EvidenceChunkOutOfBounds)?;
+    require!(!bytes.is_empty() && end <= evidence_buffer.size, ErrorCode::EvidenceChunkOutOfBounds);
+
+    let info = evidence_buffer.to_account_info();
+    grow(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        EvidenceBuffer::DATA_START + end as usize,
+    )?;
+    let start = EvidenceBuffer::DATA_START + offset as usize;
+    info.try_borrow_mut_data()?[start..start + bytes.len()].copy_from_slice(&bytes);
+
+    msg!("Evidence chunk {}..{} written for case {}", offset, end, evidence_buffer.case_id);
+    Ok(())
+}
+
+/// Seals the bundle once every byte is in and its SHA-256 matches `hash`. SHA-256 because
+/// the runtime computes it natively; a SHA3 pass over a bundle this size wouldn't fit in one
+/// transaction's compute.
+pub fn finalize_handler(ctx: Context<FinalizeEvidenceUpload>, hash: [u8; 32]) -> Result<()> {
+    let evidence_buffer = &ctx.accounts.evidence_buffer;
+    require!(!evidence_buffer.finalized, ErrorCode::EvidenceBufferFinalized);
+    require!(hash != [0u8; 32], ErrorCode::InvalidEvidenceHash);
+
+    let info = evidence_buffer.to_account_info();
+    {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == EvidenceBuffer::DATA_START + evidence_buffer.size as usize,
+            ErrorCode::EvidenceIncomplete
+        );
+        require!(
+            hashv(&[&data[EvidenceBuffer::DATA_START..]]).to_bytes() == hash,
+            ErrorCode::InvalidEvidenceHash
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let evidence_buffer = &mut ctx.accounts.evidence_buffer;
+    evidence_buffer.hash = hash;
+    evidence_buffer.finalized = true;
+
+    emit!(EvidenceUploaded {
+        case_id: evidence_buffer.case_id,
+        authority: evidence_buffer.authority,
+        size: evidence_buffer.size,
+        hash,
+        timestamp: now,
+    });
+    msg!("Evidence upload for case {} finalized", evidence_buffer.case_id);
+    Ok(())
+}
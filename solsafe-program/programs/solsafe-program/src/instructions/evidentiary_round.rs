@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, ThawAccount, Token, TokenAccount};
+use crate::state::{
+    CaseAccount, CasePanel, CaseRound, CaseState, CaseStatus, FreezeReceipt, GlobalConfig, VoteRecord,
+    MAX_CASE_JURORS,
+};
+use crate::{ErrorCode, EvidentiaryRoundOpened, RestrictionLifted};
+
+#[derive(Accounts)]
+pub struct SetProbableCauseJurors<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Preliminary vote records are passed as (vote_record, juror) pairs in remaining accounts
+#[derive(Accounts)]
+pub struct OpenEvidentiaryRound<'info> {
+    pub cranker: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Emptied for the fresh draw
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+}
+
+#[derive(Accounts)]
+pub struct LiftRestriction<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(
+        seeds = [b"case", freeze_receipt.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Closed once the account is thawed, so the integrity crank stops watching it
+    #[account(
+        mut,
+        close = cranker,
+        seeds = [
+            b"freeze_receipt",
+            freeze_receipt.case_id.to_le_bytes().as_ref(),
+            freeze_receipt.token_account.as_ref()
+        ],
+        bump = freeze_receipt.bump,
+        constraint = freeze_receipt.frozen_at != 0 @ ErrorCode::FreezeNotExecuted
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    #[account(mut, address = freeze_receipt.token_account @ ErrorCode::InvalidCase)]
+    pub scam_token_account: Account<'info, TokenAccount>,
+    #[account(address = freeze_receipt.mint @ ErrorCode::InvalidCase)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: PDA holding the mint's freeze authority
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn set_jurors_handler(ctx: Context<SetProbableCauseJurors>, jurors: u8) -> Result<()> {
+    apply_probable_cause_jurors(&mut ctx.accounts.config, jurors)
+}
+
+/// Only cases submitted afterwards pick up the setting; zero hears every case in one round
+pub(crate) fn apply_probable_cause_jurors(config: &mut GlobalConfig, jurors: u8) -> Result<()> {
+    require!(jurors as usize <= MAX_CASE_JURORS, ErrorCode::TooManyJurors);
+    config.probable_cause_jurors = jurors;
+
+    msg!("Probable-cause panel size set to {}", jurors);
+    Ok(())
+}
+
+/// Permissionless: after probable cause is found, clears the preliminary panel and its
+/// ballots so a fresh, full-size jury can be requested. Every preliminary vote record is
+/// closed back to its juror, which lets a preliminary juror sit on the second panel too.
+/// Any restriction applied in the first round stays in place.
+pub fn open_round_handler<'info>(ctx: Context<'_, '_, 'info, 'info, OpenEvidentiaryRound<'info>>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(case.state == CaseState::ProbableCause as u8, ErrorCode::NoProbableCause);
+
+    let ballots = case.votes_for + case.votes_against;
+    require!(
+        ctx.remaining_accounts.len() as u64 == ballots * 2,
+        ErrorCode::InvalidCloseAccounts
+    );
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (record_info, juror) = (&pair[0], &pair[1]);
+        require!(record_info.is_writable && juror.is_writable, ErrorCode::InvalidCloseAccounts);
+        let vote_record = Account::<VoteRecord>::try_from(record_info)?;
+        let (record_address, _) = Pubkey::find_program_address(
+            &[b"vote_record", case.case_id.to_le_bytes().as_ref(), vote_record.juror.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            record_info.key() == record_address && juror.key() == vote_record.juror,
+            ErrorCode::InvalidCloseAccounts
+        );
+        vote_record.close(juror.clone())?;
+    }
+
+    let (preliminary_for, preliminary_against) = (case.votes_for, case.votes_against);
+    let panel = &mut ctx.accounts.case_panel;
+    panel.jurors.clear();
+    panel.recused.clear();
+    case.juror_count = 0;
+    case.replacements = 0;
+    case.accepted_seats = 0;
+    case.acceptance_deadline = 0;
+    case.fallback_seating = 0;
+    case.seat_auction_ends = 0;
+    case.votes_for = 0;
+    case.votes_against = 0;
+    case.enforcement_votes = [0; 3];
+    case.escalated = 0;
+    case.vrf_request = Pubkey::default();
+    case.randomness = [0u8; 32];
+    case.randomness_seed_slot = 0;
+    case.randomness_commit_slot = 0;
+
+    case.round = CaseRound::Evidentiary as u8;
+    case.state = CaseState::PendingJurors as u8;
+    case.status = CaseStatus::Open as u8;
+    case.expires_at = now.checked_add(config.max_case_age).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(EvidentiaryRoundOpened {
+        case_id: case.case_id,
+        preliminary_for,
+        preliminary_against,
+        expires_at: case.expires_at,
+        timestamp: now,
+    });
+    msg!(
+        "Case {} opened its evidentiary round after probable cause at {} / {}",
+        case.case_id,
+        preliminary_for,
+        preliminary_against
+    );
+    Ok(())
+}
+
+/// Permissionless: thaws an account restricted on probable cause once the case ends
+/// without a freezing verdict. Restrictions from single-round cases are never lifted here.
+pub fn lift_handler(ctx: Context<LiftRestriction>) -> Result<()> {
+    {
+        let case = ctx.accounts.case_account.load()?;
+        require!(case.round != CaseRound::Single as u8, ErrorCode::RestrictionStands);
+        let upheld = (case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8)
+            && case.verdict().freezes();
+        require!(
+            !upheld
+                && (case.state == CaseState::Approved as u8
+                    || case.state == CaseState::Executed as u8
+                    || case.state == CaseState::Rejected as u8
+                    || case.state == CaseState::Dismissed as u8),
+            ErrorCode::RestrictionStands
+        );
+    }
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    token::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.scam_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(RestrictionLifted {
+        case_id: ctx.accounts.freeze_receipt.case_id,
+        token_account: ctx.accounts.scam_token_account.key(),
+        delegate: ctx.accounts.freeze_receipt.delegate,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: now,
+    });
+    msg!(
+        "Restriction on {} lifted for case {}",
+        ctx.accounts.scam_token_account.key(),
+        ctx.accounts.freeze_receipt.case_id
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::secp256k1;
+use crate::state::{CaseAccount, CaseState, EvmAttestation, GlobalConfig};
+use crate::{ErrorCode, EvmStatementVerified};
+
+#[derive(Accounts)]
+#[instruction(case_id: u64, eth_address: [u8; 20])]
+pub struct AttestEvmStatement<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + EvmAttestation::LEN,
+        seeds = [b"evm_attestation", case_id.to_le_bytes().as_ref(), eth_address.as_ref()],
+        bump
+    )]
+    pub evm_attestation: Account<'info, EvmAttestation>,
+    /// CHECK: Instructions sysvar, read for the secp256k1 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// What the EVM wallet signs, with `personal_sign`, to back a case: the case and the hash
+/// of the statement it makes, e.g. a victim confirming the drain from the drained wallet
+pub fn evm_statement(case_id: u64, chain_id: u64, statement_hash: &[u8; 32]) -> Vec<u8> {
+    let hex: String = statement_hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("SolSafe case {} on chain {}: 0x{}", case_id, chain_id, hex).into_bytes()
+}
+
+/// Anyone may add a statement signed from an EVM address while the case is still taking
+/// evidence; the signature is checked by the secp256k1 instruction preceding this one
+pub fn handler(
+    ctx: Context<AttestEvmStatement>,
+    case_id: u64,
+    eth_address: [u8; 20],
+    chain_id: u64,
+    statement_hash: [u8; 32],
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    let case = ctx.accounts.case_account.load()?;
+    require!(
+        case.state == CaseState::PendingJurors as u8 || case.state == CaseState::SeatAuction as u8,
+        ErrorCode::EvidenceWindowClosed
+    );
+
+    let message = secp256k1::eip191_message(&evm_statement(case_id, chain_id, &statement_hash));
+    let signer = secp256k1::verified_eth_signer(&ctx.accounts.instructions_sysvar, &message)?;
+    require!(signer == eth_address, ErrorCode::InvalidEvmSignature);
+
+    let now = Clock::get()?.unix_timestamp;
+    let evm_attestation = &mut ctx.accounts.evm_attestation;
+    evm_attestation.case_id = case_id;
+    evm_attestation.eth_address = eth_address;
+    evm_attestation.chain_id = chain_id;
+    evm_attestation.statement_hash = statement_hash;
+    evm_attestation.submitter = ctx.accounts.submitter.key();
+    evm_attestation.attested_at = now;
+    evm_attestation.bump = ctx.bumps.evm_attestation;
+
+    emit!(EvmStatementVerified {
+        case_id,
+        eth_address,
+        chain_id,
+        statement_hash,
+        submitter: evm_attestation.submitter,
+        timestamp: now,
+    });
+    msg!("Case {} statement verified from EVM chain {}", case_id, chain_id);
+    Ok(())
+}
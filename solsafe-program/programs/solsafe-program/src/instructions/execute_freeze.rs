@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, Token, TokenAccount};
+use crate::state::{
+    CaseAccount, CaseState, CaseStatus, FreezeReceipt, GlobalConfig, HookRegistry, ProtocolStats, Treasury,
+    FREEZE_CRANK_TIP,
+};
+use crate::{AccountFrozen, ErrorCode};
+use super::hooks::{notify_hooks, VerdictNotice};
+use super::treasury::withdraw_lamports;
+
+#[derive(Accounts)]
+pub struct ExecuteFreeze<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// One per frozen token account; a second execution against the same account fails on init
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + FreezeReceipt::LEN,
+        seeds = [
+            b"freeze_receipt",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            scam_token_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    /// Token account of the accused to freeze
+    #[account(
+        mut,
+        constraint = scam_token_account.owner == case_account.load()?.scam_address @ ErrorCode::InvalidCase
+    )]
+    pub scam_token_account: Account<'info, TokenAccount>,
+    #[account(address = scam_token_account.mint @ ErrorCode::InvalidCase)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: PDA holding the mint's freeze authority
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    /// Pays the crank tip
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    /// When passed, every registered hook is called back and its program must follow in
+    /// remaining accounts, in registry order
+    #[account(
+        seeds = [b"hook_registry"],
+        bump = hook_registry.bump
+    )]
+    pub hook_registry: Option<Account<'info, HookRegistry>>,
+    /// CHECK: PDA partner hooks see as the signer of their callback
+    #[account(
+        seeds = [b"hook_signer"],
+        bump
+    )]
+    pub hook_signer: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank: freezes one of the accused's token accounts once voting has
+/// approved the case under a freezing verdict, or found probable cause for a temporary
+/// restriction. Voting never touches token accounts, so a failed freeze can simply be
+/// retried, and each further account of the accused is frozen by another call. The
+/// first freeze of a case earns the cranker FREEZE_CRANK_TIP from the treasury.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteFreeze<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (case_id, scam_address, verdict, first_freeze) = {
+        let mut case = ctx.accounts.case_account.load_mut()?;
+        check_freeze_executable(&case, &ctx.accounts.config)?;
+        let first_freeze = case.status != CaseStatus::Frozen as u8;
+        case.status = CaseStatus::Frozen as u8;
+        (case.case_id, case.scam_address, case.verdict, first_freeze)
+    };
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.scam_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.program_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let freeze_receipt = &mut ctx.accounts.freeze_receipt;
+    freeze_receipt.case_id = case_id;
+    freeze_receipt.token_account = ctx.accounts.scam_token_account.key();
+    freeze_receipt.mint = ctx.accounts.mint.key();
+    freeze_receipt.frozen_at = clock.unix_timestamp;
+    freeze_receipt.last_verified_at = clock.unix_timestamp;
+    freeze_receipt.intact = true;
+    freeze_receipt.bump = ctx.bumps.freeze_receipt;
+    record_delegate(freeze_receipt, &ctx.accounts.scam_token_account);
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_freeze();
+    }
+
+    // An underfunded treasury skips the tip rather than holding up enforcement
+    if first_freeze {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(treasury_info.data_len());
+        if treasury_info.lamports().saturating_sub(rent_floor) >= FREEZE_CRANK_TIP {
+            withdraw_lamports(
+                &mut ctx.accounts.treasury,
+                &ctx.accounts.cranker.to_account_info(),
+                FREEZE_CRANK_TIP,
+            )?;
+        } else {
+            msg!("Treasury can't cover the crank tip; freezing without it");
+        }
+    }
+
+    if let Some(hook_registry) = &ctx.accounts.hook_registry {
+        notify_hooks(
+            hook_registry,
+            ctx.remaining_accounts,
+            &ctx.accounts.hook_signer,
+            ctx.bumps.hook_signer,
+            &VerdictNotice {
+                case_id,
+                scam_address,
+                verdict,
+                token_account: ctx.accounts.scam_token_account.key(),
+                mint: ctx.accounts.mint.key(),
+                timestamp: clock.unix_timestamp,
+            },
+        )?;
+    }
+
+    emit!(AccountFrozen {
+        case_id,
+        token_account: ctx.accounts.scam_token_account.key(),
+        mint: ctx.accounts.mint.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    msg!("Case {} froze token account {}", case_id, ctx.accounts.scam_token_account.key());
+    Ok(())
+}
+
+/// What `execute_freeze` would check about the case before touching any token account
+pub(crate) fn check_freeze_executable(case: &CaseAccount, config: &GlobalConfig) -> Result<()> {
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(
+        case.state == CaseState::Approved as u8 || case.state == CaseState::ProbableCause as u8,
+        ErrorCode::NotApproved
+    );
+    require!(case.overturned == 0, ErrorCode::NotApproved);
+    require!(case.verdict().freezes(), ErrorCode::VerdictForbidsFreeze);
+    Ok(())
+}
+
+/// Snapshot the approval the freeze leaves in place (only the owner can revoke it); a
+/// re-freeze takes a fresh snapshot, which verify_freeze_integrity compares against
+pub(crate) fn record_delegate(receipt: &mut FreezeReceipt, token_account: &TokenAccount) {
+    let delegate: Option<Pubkey> = token_account.delegate.into();
+    receipt.delegate = delegate.unwrap_or_default();
+    receipt.delegated_amount = 0;
+    if let Some(delegate) = delegate {
+        receipt.delegated_amount = token_account.delegated_amount;
+        msg!(
+            "Token account {} still approves {} for {}; the owner must revoke it",
+            receipt.token_account,
+            delegate,
+            token_account.delegated_amount
+        );
+    }
+}
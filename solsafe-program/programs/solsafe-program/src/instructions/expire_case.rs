@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CaseState, CaseStatus, GlobalConfig};
+use crate::{CaseExpired, ErrorCode};
+
+#[derive(Accounts)]
+pub struct ExpireCase<'info> {
+    pub cranker: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxCaseAge<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Anyone may dismiss a case that never reached a verdict; seat bonds are then
+/// released through `release_seat_bond`
+pub fn handler(ctx: Context<ExpireCase>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    check_expirable(&case, now)?;
+
+    let previous_state = case.state;
+    case.state = CaseState::Dismissed as u8;
+    case.status = CaseStatus::Closed as u8;
+    case.closed_at = now;
+
+    emit!(CaseExpired {
+        case_id: case.case_id,
+        previous_state,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: now,
+    });
+
+    msg!("Case {} dismissed after expiring at {}", case.case_id, case.expires_at);
+    Ok(())
+}
+
+pub(crate) fn check_expirable(case: &CaseAccount, now: i64) -> Result<()> {
+    require!(
+        case.state == CaseState::PendingJurors as u8
+            || case.state == CaseState::Nominated as u8
+            || case.state == CaseState::Voting as u8
+            || case.state == CaseState::ProbableCause as u8,
+        ErrorCode::CaseNotOpen
+    );
+    require!(now >= case.expires_at, ErrorCode::CaseNotExpired);
+    require!(!case.is_deadlocked(now), ErrorCode::CaseDeadlocked);
+    Ok(())
+}
+
+pub fn set_max_age_handler(ctx: Context<SetMaxCaseAge>, max_case_age: i64) -> Result<()> {
+    apply_max_case_age(&mut ctx.accounts.config, max_case_age)
+}
+
+/// Only affects cases opened afterwards; existing cases keep their `expires_at`
+pub(crate) fn apply_max_case_age(config: &mut GlobalConfig, max_case_age: i64) -> Result<()> {
+    require!(max_case_age > 0, ErrorCode::InvalidMaxCaseAge);
+    config.max_case_age = max_case_age;
+
+    msg!("Max case age set to {} seconds", max_case_age);
+    Ok(())
+}
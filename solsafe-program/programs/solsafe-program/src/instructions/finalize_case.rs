@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CasePanel, CaseState, GlobalConfig, ProtocolStats, ScamIndex};
+use crate::ErrorCode;
+use super::juror_reputation::record_service;
+use super::tie_break::settle_tie;
+
+#[derive(Accounts)]
+pub struct FinalizeCase<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Needed only when JurorProfiles are passed
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+    /// Omitted only for a rejected confidential case, whose accused is never revealed
+    #[account(
+        mut,
+        seeds = [b"scam_index", case_account.load()?.scam_address.as_ref()],
+        bump = scam_index.bump
+    )]
+    pub scam_index: Option<Account<'info, ScamIndex>>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    /// Needed only to settle a deadlocked case by the tie-break rule
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Option<Account<'info, GlobalConfig>>,
+    // Remaining accounts: optionally, the panel's JurorProfiles and ballots in the layout
+    // record_service expects
+}
+
+/// Permissionless: folds a decided case into its address's ScamIndex, and its panel's
+/// record into their JurorProfiles, exactly once. A tied case is first settled by the
+/// tie-break rule, which may instead send it back to voting.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeCase<'info>>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    if case.is_deadlocked(now) {
+        let config = ctx.accounts.config.as_ref().ok_or(ErrorCode::CaseDeadlocked)?;
+        if !settle_tie(&mut case, config, now)? {
+            return Ok(());
+        }
+    }
+    check_finalizable(&case)?;
+    let approved = case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8;
+    record_service(&case, ctx.accounts.case_panel.as_deref(), approved, ctx.remaining_accounts)?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_verdict(approved);
+    }
+
+    if case.is_sealed() {
+        case.finalized = 1;
+        case.closed_at = Clock::get()?.unix_timestamp;
+        msg!("Sealed case {} finalized without revealing the accused", case.case_id);
+        return Ok(());
+    }
+
+    let scam_index = ctx.accounts.scam_index.as_mut().ok_or(ErrorCode::InvalidCase)?;
+    if approved {
+        scam_index.approved_cases = scam_index.approved_cases.saturating_add(1);
+        scam_index.flagged = true;
+    } else {
+        scam_index.rejected_cases = scam_index.rejected_cases.saturating_add(1);
+    }
+    scam_index.updated_at = Clock::get()?.unix_timestamp;
+    case.finalized = 1;
+    case.closed_at = scam_index.updated_at;
+
+    msg!("Case {} finalized. Address {} flagged: {}", case.case_id, scam_index.address, scam_index.flagged);
+    Ok(())
+}
+
+pub(crate) fn check_finalizable(case: &CaseAccount) -> Result<()> {
+    require!(case.finalized == 0, ErrorCode::CaseAlreadyFinalized);
+    // An approved sealed case is finalized once reveal_and_freeze has opened it
+    require!(
+        !case.is_sealed() || case.state == CaseState::Rejected as u8,
+        ErrorCode::AddressSealed
+    );
+    require!(
+        case.state == CaseState::Approved as u8
+            || case.state == CaseState::Executed as u8
+            || case.state == CaseState::Rejected as u8,
+        ErrorCode::CaseNotDecided
+    );
+    Ok(())
+}
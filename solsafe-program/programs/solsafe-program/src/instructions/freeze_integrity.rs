@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::spl_token::state::AccountState;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::{FreezeReceipt, GlobalConfig, IntegrityIncident, IntegrityIssue};
+use crate::{ErrorCode, FreezeIntegrityAlert};
+
+#[derive(Accounts)]
+pub struct VerifyFreezeIntegrity<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"freeze_receipt",
+            freeze_receipt.case_id.to_le_bytes().as_ref(),
+            freeze_receipt.token_account.as_ref()
+        ],
+        bump = freeze_receipt.bump,
+        constraint = freeze_receipt.frozen_at != 0 @ ErrorCode::FreezeNotExecuted
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    #[account(address = freeze_receipt.token_account @ ErrorCode::InvalidCase)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(address = freeze_receipt.mint @ ErrorCode::InvalidCase)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: PDA expected to hold the mint's freeze authority
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    /// Passed only when the freeze has been undone, so an intact check costs no rent
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + IntegrityIncident::LEN,
+        seeds = [
+            b"integrity_incident",
+            freeze_receipt.case_id.to_le_bytes().as_ref(),
+            freeze_receipt.token_account.as_ref()
+        ],
+        bump
+    )]
+    pub incident: Option<Account<'info, IntegrityIncident>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveIntegrityIncident<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [
+            b"integrity_incident",
+            incident.case_id.to_le_bytes().as_ref(),
+            incident.token_account.as_ref()
+        ],
+        bump = incident.bump
+    )]
+    pub incident: Account<'info, IntegrityIncident>,
+}
+
+/// Permissionless crank: re-reads a frozen account and flags it if the freeze was undone.
+/// A breach is recorded in the incident account, which the cranker passes only then.
+pub fn verify_handler(ctx: Context<VerifyFreezeIntegrity>) -> Result<()> {
+    let clock = Clock::get()?;
+    let receipt = &mut ctx.accounts.freeze_receipt;
+    receipt.last_verified_at = clock.unix_timestamp;
+
+    let issue = if ctx.accounts.token_account.state != AccountState::Frozen {
+        Some(IntegrityIssue::Thawed)
+    } else if ctx.accounts.mint.freeze_authority != COption::Some(ctx.accounts.program_authority.key()) {
+        Some(IntegrityIssue::FreezeAuthorityChanged)
+    } else if delegate_changed(receipt, &ctx.accounts.token_account) {
+        Some(IntegrityIssue::DelegateChanged)
+    } else {
+        None
+    };
+
+    let Some(issue) = issue else {
+        require!(ctx.accounts.incident.is_none(), ErrorCode::IncidentAccountUnexpected);
+        // Also clears the flag once a guardian has re-frozen the account
+        receipt.intact = true;
+        msg!("Freeze intact for case {} account {}", receipt.case_id, receipt.token_account);
+        return Ok(());
+    };
+    receipt.intact = false;
+
+    let incident = ctx
+        .accounts
+        .incident
+        .as_mut()
+        .ok_or(ErrorCode::IncidentAccountRequired)?;
+    if incident.is_open() {
+        incident.occurrences = incident
+            .occurrences
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    } else {
+        incident.case_id = receipt.case_id;
+        incident.token_account = receipt.token_account;
+        incident.reporter = ctx.accounts.cranker.key();
+        incident.opened_at = clock.unix_timestamp;
+        incident.resolved_at = 0;
+        incident.occurrences = 1;
+        incident.bump = ctx.bumps.incident.ok_or(ErrorCode::IncidentAccountRequired)?;
+    }
+    incident.issue = issue;
+
+    emit!(FreezeIntegrityAlert {
+        case_id: receipt.case_id,
+        token_account: receipt.token_account,
+        issue,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Freeze integrity breach on case {} account {}", receipt.case_id, receipt.token_account);
+    Ok(())
+}
+
+pub fn resolve_handler(ctx: Context<ResolveIntegrityIncident>) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, ErrorCode::Unauthorized);
+
+    let incident = &mut ctx.accounts.incident;
+    require!(incident.is_open(), ErrorCode::IncidentNotOpen);
+    incident.resolved_at = Clock::get()?.unix_timestamp;
+
+    msg!("Integrity incident for case {} resolved", incident.case_id);
+    Ok(())
+}
+
+/// Whether the account's approval moved since the freeze recorded it. Approving, revoking
+/// and spending an allowance all need the account thawed, so a change means it was
+/// thawed and refrozen in between, possibly after the delegate drained it.
+fn delegate_changed(receipt: &FreezeReceipt, token_account: &TokenAccount) -> bool {
+    let delegate: Option<Pubkey> = token_account.delegate.into();
+    let (delegate, delegated_amount) = match delegate {
+        Some(delegate) => (delegate, token_account.delegated_amount),
+        None => (Pubkey::default(), 0),
+    };
+    delegate != receipt.delegate || delegated_amount != receipt.delegated_amount
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CaseState, MAX_FLOW_HOPS};
+use crate::{ErrorCode, FundFlowVerified};
+
+/// One transfer the reporter claims happened, as committed under the evidence root
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct TransferClaim {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub signature: [u8; 64],       // Transaction signature the claim was read from
+}
+
+impl TransferClaim {
+    pub fn leaf(&self, case_id: u64) -> [u8; 32] {
+        crate::domain::hash(
+            crate::domain::FUND_FLOW_LEAF,
+            &[
+                &case_id.to_le_bytes(),
+                self.from.as_ref(),
+                self.to.as_ref(),
+                self.mint.as_ref(),
+                &self.amount.to_le_bytes(),
+                &self.signature,
+            ],
+        )
+    }
+}
+
+/// A transfer claim plus its inclusion proof; `index` is the leaf position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct FlowHop {
+    pub claim: TransferClaim,
+    pub index: u32,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+pub struct CommitEvidenceRoot<'info> {
+    pub reporter: Signer<'info>,
+    #[account(
+        mut,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyFundFlow<'info> {
+    pub reporter: Signer<'info>,
+    #[account(
+        mut,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+/// The root is fixed once, before the panel forms, so claims can't be rewritten mid-case
+pub fn commit_root_handler(ctx: Context<CommitEvidenceRoot>, evidence_root: [u8; 32]) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(
+        case.state == CaseState::PendingJurors as u8 || case.state == CaseState::SeatAuction as u8,
+        ErrorCode::CaseNotOpen
+    );
+    require!(case.evidence_root == [0u8; 32], ErrorCode::EvidenceRootAlreadySet);
+    require!(evidence_root != [0u8; 32], ErrorCode::InvalidFundFlow);
+    case.evidence_root = evidence_root;
+
+    msg!("Evidence root committed for case {}", case.case_id);
+    Ok(())
+}
+
+/// Check a victim -> ... -> accused path hop by hop and record it on the case
+pub fn verify_handler(ctx: Context<VerifyFundFlow>, victim: Pubkey, hops: Vec<FlowHop>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(case.evidence_root != [0u8; 32], ErrorCode::EvidenceRootNotSet);
+    require!(
+        case.state == CaseState::PendingJurors as u8
+            || case.state == CaseState::SeatAuction as u8
+            || case.state == CaseState::Nominated as u8
+            || case.state == CaseState::Voting as u8,
+        ErrorCode::CaseNotOpen
+    );
+
+    verify_path(case.case_id, &case.evidence_root, &victim, &case.scam_address, &hops)?;
+    case.flow_source = victim;
+    case.flow_hops = hops.len() as u8;
+
+    emit!(FundFlowVerified {
+        case_id: case.case_id,
+        source: victim,
+        destination: case.scam_address,
+        hops: case.flow_hops,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Fund flow verified for case {}: {} hops", case.case_id, case.flow_hops);
+    Ok(())
+}
+
+pub fn verify_path(
+    case_id: u64,
+    root: &[u8; 32],
+    source: &Pubkey,
+    destination: &Pubkey,
+    hops: &[FlowHop],
+) -> Result<()> {
+    require!(
+        !hops.is_empty() && hops.len() <= MAX_FLOW_HOPS,
+        ErrorCode::InvalidFundFlow
+    );
+    require!(hops[0].claim.from == *source, ErrorCode::InvalidFundFlow);
+    require!(
+        hops[hops.len() - 1].claim.to == *destination,
+        ErrorCode::InvalidFundFlow
+    );
+    for pair in hops.windows(2) {
+        require!(pair[0].claim.to == pair[1].claim.from, ErrorCode::InvalidFundFlow);
+    }
+    for hop in hops {
+        require!(
+            merkle_root(case_id, hop.claim.leaf(case_id), hop.index, &hop.proof) == *root,
+            ErrorCode::InvalidFundFlow
+        );
+    }
+    Ok(())
+}
+
+/// Fold `proof` into `leaf`; `scope` is the case or list id the tree belongs to
+pub(crate) fn merkle_root(scope: u64, leaf: [u8; 32], index: u32, proof: &[[u8; 32]]) -> [u8; 32] {
+    let mut node = leaf;
+    let mut index = index;
+    for sibling in proof {
+        let (left, right) = if index % 2 == 0 { (&node, sibling) } else { (sibling, &node) };
+        node = crate::domain::hash(
+            crate::domain::MERKLE_NODE,
+            &[&scope.to_le_bytes(), left, right],
+        );
+        index /= 2;
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim(from: Pubkey, to: Pubkey) -> TransferClaim {
+        TransferClaim { from, to, mint: Pubkey::default(), amount: 5, signature: [1u8; 64] }
+    }
+
+    fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        crate::domain::hash(crate::domain::MERKLE_NODE, &[&1u64.to_le_bytes(), left, right])
+    }
+
+    #[test]
+    fn test_two_hop_path_verifies() {
+        let (victim, mule, accused) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let first = claim(victim, mule);
+        let second = claim(mule, accused);
+        let root = node(&first.leaf(1), &second.leaf(1));
+
+        let hops = vec![
+            FlowHop { claim: first, index: 0, proof: vec![claim(mule, accused).leaf(1)] },
+            FlowHop { claim: second, index: 1, proof: vec![claim(victim, mule).leaf(1)] },
+        ];
+        assert!(verify_path(1, &root, &victim, &accused, &hops).is_ok());
+        assert!(verify_path(1, &root, &mule, &accused, &hops).is_err());
+        assert!(verify_path(2, &root, &victim, &accused, &hops).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, ValidatorHeartbeat};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = 8 + ValidatorHeartbeat::LEN,
+        seeds = [b"heartbeat", validator.key().as_ref()],
+        bump
+    )]
+    pub heartbeat: Account<'info, ValidatorHeartbeat>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetHeartbeatEpochs<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Cheap liveness ping; one per epoch is enough to stay eligible
+pub fn handler(ctx: Context<Heartbeat>) -> Result<()> {
+    let clock = Clock::get()?;
+    let heartbeat = &mut ctx.accounts.heartbeat;
+    heartbeat.validator = ctx.accounts.validator.key();
+    heartbeat.last_epoch = clock.epoch;
+    heartbeat.last_beat_at = clock.unix_timestamp;
+    heartbeat.beats = heartbeat.beats.saturating_add(1);
+    heartbeat.bump = ctx.bumps.heartbeat;
+
+    msg!("Heartbeat from {} in epoch {}", heartbeat.validator, clock.epoch);
+    Ok(())
+}
+
+pub fn set_epochs_handler(ctx: Context<SetHeartbeatEpochs>, heartbeat_epochs: u64) -> Result<()> {
+    apply_heartbeat_epochs(&mut ctx.accounts.config, heartbeat_epochs)
+}
+
+/// Zero turns the requirement off
+pub(crate) fn apply_heartbeat_epochs(config: &mut GlobalConfig, heartbeat_epochs: u64) -> Result<()> {
+    config.heartbeat_epochs = heartbeat_epochs;
+
+    msg!("Heartbeat requirement set to {} epochs", heartbeat_epochs);
+    Ok(())
+}
+
+/// Whether `validator` may be drawn: always while the requirement is off, otherwise only
+/// if its heartbeat PDA is among `accounts` and recent enough
+pub fn is_live<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    config: &GlobalConfig,
+    validator: &Pubkey,
+    epoch: u64,
+) -> bool {
+    if config.heartbeat_epochs == 0 {
+        return true;
+    }
+    let address = heartbeat_address(validator);
+    accounts
+        .iter()
+        .find(|info| info.key() == address)
+        .and_then(|info| Account::<ValidatorHeartbeat>::try_from(info).ok())
+        .is_some_and(|heartbeat| {
+            epoch.saturating_sub(heartbeat.last_epoch) <= config.heartbeat_epochs
+        })
+}
+
+pub(crate) fn heartbeat_address(validator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"heartbeat", validator.as_ref()], &crate::ID).0
+}
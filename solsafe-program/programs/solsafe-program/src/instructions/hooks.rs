@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use crate::state::{GlobalConfig, HookRegistry, Integrator, NotificationHook, MAX_NOTIFICATION_HOOKS};
+use crate::{ErrorCode, NotificationHookRegistered, NotificationHookRemoved};
+
+/// Anchor discriminator of `solsafe_verdict`, the instruction every hook program exposes
+pub const HOOK_DISCRIMINATOR: [u8; 8] = [205, 252, 2, 17, 46, 156, 196, 116];
+
+/// Payload of a `solsafe_verdict` callback, Borsh-encoded after the discriminator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct VerdictNotice {
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub verdict: u8,               // Verdict
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct RegisterHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"integrator", authority.key().as_ref()],
+        bump = integrator.bump,
+        constraint = integrator.active @ ErrorCode::Unauthorized
+    )]
+    pub integrator: Account<'info, Integrator>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HookRegistry::LEN,
+        seeds = [b"hook_registry"],
+        bump
+    )]
+    pub hook_registry: Account<'info, HookRegistry>,
+    /// CHECK: Only has to be a deployed program
+    #[account(constraint = callback_program.executable @ ErrorCode::InvalidHookProgram)]
+    pub callback_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveHook<'info> {
+    /// The integrator itself, or the admin retiring a misbehaving hook
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"hook_registry"],
+        bump = hook_registry.bump
+    )]
+    pub hook_registry: Account<'info, HookRegistry>,
+}
+
+/// Whitelisted integrators point enforcement at their callback program; registering
+/// again replaces the integrator's previous hook
+pub fn register_handler(ctx: Context<RegisterHook>) -> Result<()> {
+    let integrator = ctx.accounts.authority.key();
+    let program_id = ctx.accounts.callback_program.key();
+    require!(program_id != crate::ID, ErrorCode::InvalidHookProgram);
+
+    let hook_registry = &mut ctx.accounts.hook_registry;
+    match hook_registry.hooks.iter_mut().find(|hook| hook.integrator == integrator) {
+        Some(hook) => hook.program_id = program_id,
+        None => {
+            require!(
+                hook_registry.hooks.len() < MAX_NOTIFICATION_HOOKS,
+                ErrorCode::HookRegistryFull
+            );
+            hook_registry.hooks.push(NotificationHook { integrator, program_id });
+        }
+    }
+    hook_registry.bump = ctx.bumps.hook_registry;
+
+    emit!(NotificationHookRegistered {
+        integrator,
+        program_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Integrator {} registered hook {}", integrator, program_id);
+    Ok(())
+}
+
+pub fn remove_handler(ctx: Context<RemoveHook>, integrator: Pubkey) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == integrator || authority == ctx.accounts.config.admin,
+        ErrorCode::Unauthorized
+    );
+
+    let hook_registry = &mut ctx.accounts.hook_registry;
+    let position = hook_registry
+        .hooks
+        .iter()
+        .position(|hook| hook.integrator == integrator)
+        .ok_or(ErrorCode::HookNotRegistered)?;
+    let hook = hook_registry.hooks.remove(position);
+
+    emit!(NotificationHookRemoved {
+        integrator,
+        program_id: hook.program_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Hook {} of integrator {} removed", hook.program_id, integrator);
+    Ok(())
+}
+
+/// Call every registered hook with `notice`. `hook_programs` lists the registry's
+/// programs in order; each callback sees the `hook_signer` PDA as its only, signing,
+/// account so it can tell SolSafe apart from anyone replaying the payload.
+pub(crate) fn notify_hooks<'info>(
+    hook_registry: &HookRegistry,
+    hook_programs: &[AccountInfo<'info>],
+    hook_signer: &AccountInfo<'info>,
+    hook_signer_bump: u8,
+    notice: &VerdictNotice,
+) -> Result<()> {
+    require!(
+        hook_programs.len() == hook_registry.hooks.len()
+            && hook_programs
+                .iter()
+                .zip(&hook_registry.hooks)
+                .all(|(program, hook)| program.key() == hook.program_id),
+        ErrorCode::HookAccountsMismatch
+    );
+
+    let mut data = HOOK_DISCRIMINATOR.to_vec();
+    notice.serialize(&mut data)?;
+    let seeds: &[&[u8]] = &[&b"hook_signer"[..], &[hook_signer_bump]];
+    for program in hook_programs {
+        invoke_signed(
+            &Instruction {
+                program_id: program.key(),
+                accounts: vec![AccountMeta::new_readonly(hook_signer.key(), true)],
+                data: data.clone(),
+            },
+            &[hook_signer.clone(), program.clone()],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
@@ -1,27 +1,77 @@
 use anchor_lang::prelude::*;
-use crate::state::GlobalConfig;
+use crate::state::{
+    ApprovalThreshold, CaseCounter, CategoryParams, GlobalConfig, RandomnessSource, TieBreakRule, CASE_CATEGORY_COUNT,
+    CASE_SEVERITY_COUNT, CONFIG_VERSION, DEFAULT_MAX_CASE_AGE,
+};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + 32 + 4 + (32 * 100) + 1 + 1 + 1, // discriminator + admin + vec len + 100 validators + bump + quorum + min_jurors
+        space = 8 + GlobalConfig::LEN,
         seeds = [b"config"],
         bump
     )]
     pub config: Account<'info, GlobalConfig>,
+    /// Created here so case ids start at 1; deployments older than the counter create it
+    /// with set_case_counter
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CaseCounter::LEN,
+        seeds = [b"case_counter"],
+        bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
     #[account(mut)]
     pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<Initialize>, quorum: u8, min_jurors: u8) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    config.admin = *ctx.accounts.admin.key;
-    config.validator_list = vec![];
-    config.bump = ctx.bumps.config;
-    config.quorum = quorum;
-    config.min_jurors = min_jurors;
+    let config = new_config(*ctx.accounts.admin.key, ctx.bumps.config, quorum, min_jurors);
+    ctx.accounts.config.set_inner(config);
+
+    let case_counter = &mut ctx.accounts.case_counter;
+    case_counter.next_case_id = 1;
+    case_counter.bump = ctx.bumps.case_counter;
     Ok(())
 }
+
+/// A fresh config with every optional policy off; also what a baseline config is
+/// converted to by migrate_config
+pub(crate) fn new_config(admin: Pubkey, bump: u8, quorum: u8, min_jurors: u8) -> GlobalConfig {
+    GlobalConfig {
+        admin,
+        pending_admin: Pubkey::default(),
+        validator_count: 0,
+        page_count: 0,
+        bump,
+        quorum,
+        min_jurors,
+        council: Pubkey::default(),
+        pauser: admin,
+        paused: false,
+        submission_fee: 0,
+        distribution_fee_bps: 0,
+        category_params: [CategoryParams::default(); CASE_CATEGORY_COUNT],
+        max_case_age: DEFAULT_MAX_CASE_AGE,
+        passport_min_cases: 0,
+        heartbeat_epochs: 0,
+        randomness_source: RandomnessSource::Switchboard,
+        reputation_weighting: false,
+        min_validator_stake: 0,
+        reporter_bounty: 0,
+        reporter_bounty_bps: 0,
+        case_bond_bps: 0,
+        quadratic_voting: false,
+        severity_thresholds: [ApprovalThreshold::default(); CASE_SEVERITY_COUNT],
+        tie_break_rule: TieBreakRule::RejectOnTie,
+        tie_breaker: Pubkey::default(),
+        escalation_bond: 0,
+        escalation_threshold: ApprovalThreshold::default(),
+        probable_cause_jurors: 0,
+        version: CONFIG_VERSION,
+    }
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{
+    CaseAccount, CaseState, FreezeCompensation, FreezeReceipt, GlobalConfig, InsuranceFund, Treasury,
+    WRONGFUL_FREEZE_COMPENSATION,
+};
+use crate::{ErrorCode, VerdictOverturned, WrongfulFreezeCompensated};
+use super::treasury::withdraw_lamports;
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + InsuranceFund::LEN,
+        seeds = [b"insurance_fund"],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundInsurance<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+}
+
+#[derive(Accounts)]
+pub struct OverturnVerdict<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimWrongfulFreezeCompensation<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [
+            b"freeze_receipt",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            token_account.key().as_ref()
+        ],
+        bump = freeze_receipt.bump
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    #[account(constraint = token_account.owner == owner.key() @ ErrorCode::Unauthorized)]
+    pub token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + FreezeCompensation::LEN,
+        seeds = [
+            b"freeze_compensation",
+            case_account.load()?.case_id.to_le_bytes().as_ref(),
+            token_account.key().as_ref()
+        ],
+        bump
+    )]
+    pub compensation: Account<'info, FreezeCompensation>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_handler(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.total_deposited = 0;
+    insurance_fund.total_paid = 0;
+    insurance_fund.claims_paid = 0;
+    insurance_fund.bump = ctx.bumps.insurance_fund;
+
+    msg!("Insurance fund opened");
+    Ok(())
+}
+
+pub fn fund_handler(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+    apply_insurance_funding(&mut ctx.accounts.treasury, &mut ctx.accounts.insurance_fund, amount)
+}
+
+/// Moves collected protocol fees from the treasury into the insurance fund
+pub(crate) fn apply_insurance_funding(
+    treasury: &mut Account<Treasury>,
+    insurance_fund: &mut Account<InsuranceFund>,
+    amount: u64,
+) -> Result<()> {
+    withdraw_lamports(treasury, &insurance_fund.to_account_info(), amount)?;
+    record_deposit(insurance_fund, amount)?;
+
+    msg!("{} lamports moved from the treasury into the insurance fund", amount);
+    Ok(())
+}
+
+/// Book lamports already credited to the fund's account
+pub(crate) fn record_deposit(insurance_fund: &mut InsuranceFund, amount: u64) -> Result<()> {
+    insurance_fund.total_deposited = insurance_fund
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+pub fn overturn_handler(ctx: Context<OverturnVerdict>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    apply_overturn(&mut case)
+}
+
+/// Governance reversal of a finalized approval. The verdict itself stays on record; the
+/// flag only opens compensation for the accounts it froze.
+pub(crate) fn apply_overturn(case: &mut CaseAccount) -> Result<()> {
+    require!(
+        case.finalized != 0
+            && (case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8),
+        ErrorCode::VerdictNotOverturnable
+    );
+    require!(case.overturned == 0, ErrorCode::VerdictNotOverturnable);
+    case.overturned = 1;
+
+    emit!(VerdictOverturned {
+        case_id: case.case_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Verdict on case {} overturned", case.case_id);
+    Ok(())
+}
+
+/// Pays WRONGFUL_FREEZE_COMPENSATION to the owner of a token account frozen by an
+/// overturned verdict, once per account
+pub fn claim_handler(ctx: Context<ClaimWrongfulFreezeCompensation>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    require!(case.overturned != 0, ErrorCode::VerdictNotOverturned);
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    let fund_info = insurance_fund.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(fund_info.data_len());
+    let amount = WRONGFUL_FREEZE_COMPENSATION;
+    require!(
+        fund_info.lamports().saturating_sub(rent_floor) >= amount,
+        ErrorCode::InsuranceFundInsufficient
+    );
+    **fund_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    insurance_fund.total_paid = insurance_fund
+        .total_paid
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    insurance_fund.claims_paid = insurance_fund.claims_paid.saturating_add(1);
+
+    let now = Clock::get()?.unix_timestamp;
+    let compensation = &mut ctx.accounts.compensation;
+    compensation.case_id = case.case_id;
+    compensation.token_account = ctx.accounts.token_account.key();
+    compensation.owner = ctx.accounts.owner.key();
+    compensation.amount = amount;
+    compensation.paid_at = now;
+    compensation.bump = ctx.bumps.compensation;
+
+    emit!(WrongfulFreezeCompensated {
+        case_id: case.case_id,
+        token_account: compensation.token_account,
+        owner: compensation.owner,
+        amount,
+        timestamp: now,
+    });
+    msg!("Compensated {} with {} lamports for case {}", compensation.owner, amount, case.case_id);
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    FreezeReceipt, GlobalConfig, GrantProgram, Integrator, IntegratorKind, VerdictAcknowledgment,
+};
+use crate::{ErrorCode, IntegrationGrantPaid};
+
+#[derive(Accounts)]
+pub struct ConfigureGrants<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + GrantProgram::LEN,
+        seeds = [b"grant_program"],
+        bump
+    )]
+    pub grant_program: Account<'info, GrantProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundGrants<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"grant_program"],
+        bump = grant_program.bump
+    )]
+    pub grant_program: Account<'info, GrantProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterIntegrator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Integrator::LEN,
+        seeds = [b"integrator", authority.as_ref()],
+        bump
+    )]
+    pub integrator: Account<'info, Integrator>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcknowledgeVerdict<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"integrator", authority.key().as_ref()],
+        bump = integrator.bump,
+        constraint = integrator.active @ ErrorCode::Unauthorized
+    )]
+    pub integrator: Account<'info, Integrator>,
+    #[account(
+        mut,
+        seeds = [b"grant_program"],
+        bump = grant_program.bump
+    )]
+    pub grant_program: Account<'info, GrantProgram>,
+    /// Only enforced verdicts with an intact freeze earn a grant
+    #[account(
+        seeds = [
+            b"freeze_receipt",
+            freeze_receipt.case_id.to_le_bytes().as_ref(),
+            freeze_receipt.token_account.as_ref()
+        ],
+        bump = freeze_receipt.bump,
+        constraint = freeze_receipt.frozen_at != 0 @ ErrorCode::FreezeNotExecuted,
+        constraint = freeze_receipt.intact @ ErrorCode::FreezeNotExecuted
+    )]
+    pub freeze_receipt: Account<'info, FreezeReceipt>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VerdictAcknowledgment::LEN,
+        seeds = [
+            b"verdict_ack",
+            freeze_receipt.case_id.to_le_bytes().as_ref(),
+            authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub acknowledgment: Account<'info, VerdictAcknowledgment>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_handler(
+    ctx: Context<ConfigureGrants>,
+    rate_per_verdict: u64,
+    enabled: bool,
+) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, ErrorCode::Unauthorized);
+
+    let grant_program = &mut ctx.accounts.grant_program;
+    grant_program.rate_per_verdict = rate_per_verdict;
+    grant_program.enabled = enabled;
+    grant_program.bump = ctx.bumps.grant_program;
+
+    msg!("Integration grants set to {} lamports per verdict. Enabled: {}", rate_per_verdict, enabled);
+    Ok(())
+}
+
+pub fn fund_handler(ctx: Context<FundGrants>, amount: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.grant_program.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    msg!("Grant treasury funded with {} lamports", amount);
+    Ok(())
+}
+
+/// Governance approves an integrator (or updates its cap / deactivates it)
+pub fn register_integrator_handler(
+    ctx: Context<RegisterIntegrator>,
+    authority: Pubkey,
+    kind: IntegratorKind,
+    cap: u64,
+    active: bool,
+) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, ErrorCode::Unauthorized);
+
+    let integrator = &mut ctx.accounts.integrator;
+    integrator.authority = authority;
+    integrator.kind = kind;
+    integrator.cap = cap;
+    integrator.active = active;
+    integrator.bump = ctx.bumps.integrator;
+
+    msg!("Integrator {} registered with cap {}. Active: {}", authority, cap, active);
+    Ok(())
+}
+
+pub fn acknowledge_handler(ctx: Context<AcknowledgeVerdict>) -> Result<()> {
+    let grant_program = &mut ctx.accounts.grant_program;
+    let integrator = &mut ctx.accounts.integrator;
+    let receipt = &ctx.accounts.freeze_receipt;
+    let clock = Clock::get()?;
+
+    require!(grant_program.enabled, ErrorCode::GrantsDisabled);
+
+    // Pay the governance rate, trimmed to the integrator's remaining cap
+    let remaining_cap = integrator.cap.saturating_sub(integrator.earned);
+    let amount = grant_program.rate_per_verdict.min(remaining_cap);
+    require!(amount > 0, ErrorCode::GrantCapReached);
+
+    let grant_info = grant_program.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(grant_info.data_len());
+    require!(
+        grant_info.lamports().saturating_sub(rent_floor) >= amount,
+        ErrorCode::GrantTreasuryInsufficient
+    );
+    **grant_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    grant_program.total_paid = grant_program
+        .total_paid
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    integrator.earned = integrator.earned.checked_add(amount).ok_or(ErrorCode::ArithmeticOverflow)?;
+    integrator.acknowledgments = integrator
+        .acknowledgments
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let acknowledgment = &mut ctx.accounts.acknowledgment;
+    acknowledgment.case_id = receipt.case_id;
+    acknowledgment.integrator = integrator.authority;
+    acknowledgment.receipt = receipt.key();
+    acknowledgment.amount_paid = amount;
+    acknowledgment.timestamp = clock.unix_timestamp;
+    acknowledgment.bump = ctx.bumps.acknowledgment;
+
+    emit!(IntegrationGrantPaid {
+        integrator: integrator.authority,
+        case_id: receipt.case_id,
+        amount,
+        total_earned: integrator.earned,
+    });
+
+    msg!("Integrator {} acknowledged case {} for {} lamports", integrator.authority, receipt.case_id, amount);
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    DisputeStatus, GlobalConfig, IntelDispute, IntelDisputeVote, IntelProvider, ThreatIntel, ValidatorPage,
+    INTEL_DISPUTE_PERIOD, INTEL_SLASH_BPS, MIN_INTEL_DISPUTE_BOND, MIN_INTEL_PROVIDER_BOND,
+};
+use crate::{ErrorCode, IntelDisputeResolved};
+
+#[derive(Accounts)]
+pub struct DisputeThreatIntel<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"threat_intel", threat_intel.threat_id.to_le_bytes().as_ref()],
+        bump = threat_intel.bump,
+        constraint = !threat_intel.disputed @ ErrorCode::IntelUnderDispute,
+        constraint = !threat_intel.slashed @ ErrorCode::IntelSlashed
+    )]
+    pub threat_intel: Account<'info, ThreatIntel>,
+    /// One per dispute: an entry a dispute failed to knock out can be challenged again
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + IntelDispute::LEN,
+        seeds = [
+            b"intel_dispute",
+            threat_intel.threat_id.to_le_bytes().as_ref(),
+            threat_intel.disputes.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub dispute: Account<'info, IntelDispute>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoteIntelDispute<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Registry page listing the juror
+    #[account(
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    #[account(
+        mut,
+        seeds = [b"intel_dispute", dispute.threat_id.to_le_bytes().as_ref(), dispute.nonce.to_le_bytes().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, IntelDispute>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + IntelDisputeVote::LEN,
+        seeds = [
+            b"intel_dispute_vote",
+            dispute.threat_id.to_le_bytes().as_ref(),
+            dispute.nonce.to_le_bytes().as_ref(),
+            juror.key().as_ref()
+        ],
+        bump
+    )]
+    pub dispute_vote: Account<'info, IntelDisputeVote>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveIntelDispute<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"intel_dispute", dispute.threat_id.to_le_bytes().as_ref(), dispute.nonce.to_le_bytes().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, IntelDispute>,
+    #[account(
+        mut,
+        seeds = [b"threat_intel", dispute.threat_id.to_le_bytes().as_ref()],
+        bump = threat_intel.bump
+    )]
+    pub threat_intel: Account<'info, ThreatIntel>,
+    #[account(
+        mut,
+        seeds = [b"intel_provider", dispute.provider.as_ref()],
+        bump = intel_provider.bump
+    )]
+    pub intel_provider: Account<'info, IntelProvider>,
+    /// CHECK: Receives the slashed bond and refund; checked against the dispute record
+    #[account(mut, address = dispute.challenger)]
+    pub challenger: UncheckedAccount<'info>,
+}
+
+pub fn dispute_handler(
+    ctx: Context<DisputeThreatIntel>,
+    bond: u64,
+    reason_hash: [u8; 32],
+) -> Result<()> {
+    require!(bond >= MIN_INTEL_DISPUTE_BOND, ErrorCode::InsufficientBond);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.challenger.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+
+    let clock = Clock::get()?;
+    let threat_intel = &mut ctx.accounts.threat_intel;
+    threat_intel.disputed = true;
+    let nonce = threat_intel.disputes;
+    threat_intel.disputes = nonce.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.threat_id = threat_intel.threat_id;
+    dispute.nonce = nonce;
+    dispute.provider = threat_intel.provider;
+    dispute.challenger = ctx.accounts.challenger.key();
+    dispute.challenger_bond = bond;
+    dispute.reason_hash = reason_hash;
+    dispute.votes_false = 0;
+    dispute.votes_valid = 0;
+    dispute.deadline = clock
+        .unix_timestamp
+        .checked_add(INTEL_DISPUTE_PERIOD)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    dispute.status = DisputeStatus::Open;
+    dispute.bump = ctx.bumps.dispute;
+
+    msg!("Threat intel {} disputed by {}", dispute.threat_id, dispute.challenger);
+    Ok(())
+}
+
+pub fn vote_handler(ctx: Context<VoteIntelDispute>, entry_false: bool) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+    require!(clock.unix_timestamp < dispute.deadline, ErrorCode::VotingPeriodExpired);
+    require!(
+        ctx.accounts.validator_page.slot_of(&ctx.accounts.juror.key()).is_some(),
+        ErrorCode::NotJuror
+    );
+
+    if entry_false {
+        dispute.votes_false = dispute.votes_false.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+    } else {
+        dispute.votes_valid = dispute.votes_valid.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    let dispute_vote = &mut ctx.accounts.dispute_vote;
+    dispute_vote.threat_id = dispute.threat_id;
+    dispute_vote.nonce = dispute.nonce;
+    dispute_vote.juror = ctx.accounts.juror.key();
+    dispute_vote.entry_false = entry_false;
+    dispute_vote.bump = ctx.bumps.dispute_vote;
+
+    msg!("Dispute vote recorded. False: {}, Valid: {}", dispute.votes_false, dispute.votes_valid);
+    Ok(())
+}
+
+pub fn resolve_handler(ctx: Context<ResolveIntelDispute>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+
+    // Same 2/3 supermajority the validator set uses for cases
+    let total_validators = config.validator_count as u64;
+    let required_votes = (total_validators * 2) / 3 + 1;
+    let upheld = dispute.votes_false >= required_votes;
+    let cannot_pass = dispute.votes_valid > total_validators.saturating_sub(required_votes);
+    require!(
+        upheld || cannot_pass || clock.unix_timestamp >= dispute.deadline,
+        ErrorCode::DisputeStillOpen
+    );
+
+    let threat_intel = &mut ctx.accounts.threat_intel;
+    let intel_provider = &mut ctx.accounts.intel_provider;
+    threat_intel.disputed = false;
+
+    let dispute_info = dispute.to_account_info();
+    let challenger_info = ctx.accounts.challenger.to_account_info();
+    let slashed = if upheld {
+        // False intel: retire the entry for good and pay the challenger from the provider bond
+        threat_intel.active = false;
+        threat_intel.slashed = true;
+        let slashed = (intel_provider.bond as u128 * INTEL_SLASH_BPS as u128 / 10_000) as u64;
+        intel_provider.bond -= slashed;
+        intel_provider.entries_slashed = intel_provider
+            .entries_slashed
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if intel_provider.bond < MIN_INTEL_PROVIDER_BOND {
+            intel_provider.active = false;
+        }
+
+        let provider_info = intel_provider.to_account_info();
+        **provider_info.try_borrow_mut_lamports()? -= slashed;
+        **challenger_info.try_borrow_mut_lamports()? += slashed;
+        **dispute_info.try_borrow_mut_lamports()? -= dispute.challenger_bond;
+        **challenger_info.try_borrow_mut_lamports()? += dispute.challenger_bond;
+
+        dispute.status = DisputeStatus::Upheld;
+        slashed
+    } else {
+        // Entry stands: the challenger's bond is added to the provider's collateral
+        let provider_info = intel_provider.to_account_info();
+        **dispute_info.try_borrow_mut_lamports()? -= dispute.challenger_bond;
+        **provider_info.try_borrow_mut_lamports()? += dispute.challenger_bond;
+        intel_provider.bond = intel_provider
+            .bond
+            .checked_add(dispute.challenger_bond)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        dispute.status = DisputeStatus::Rejected;
+        0
+    };
+    dispute.challenger_bond = 0;
+
+    emit!(IntelDisputeResolved {
+        threat_id: dispute.threat_id,
+        provider: dispute.provider,
+        challenger: dispute.challenger,
+        upheld,
+        slashed,
+    });
+
+    msg!("Dispute on threat intel {} resolved. Upheld: {}", dispute.threat_id, upheld);
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CasePanel, CaseState, GlobalConfig, JURY_ACCEPTANCE_WINDOW};
+use crate::{ErrorCode, JuryDutyAccepted};
+use super::recusal::fill_seat;
+
+#[derive(Accounts)]
+pub struct AcceptJuryDuty<'info> {
+    pub juror: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+}
+
+#[derive(Accounts)]
+pub struct ReplaceNonAcceptors<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    // Remaining accounts: the ValidatorPage PDAs holding each drawn index, plus each
+    // candidate's ValidatorHeartbeat when config.heartbeat_epochs is set
+}
+
+/// A nominee confirms they'll serve; the last acceptance opens voting
+pub fn accept_handler(ctx: Context<AcceptJuryDuty>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let juror = ctx.accounts.juror.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(case.state == CaseState::Nominated as u8, ErrorCode::CaseNotNominated);
+    require!(now < case.acceptance_deadline, ErrorCode::AcceptanceWindowClosed);
+    let seat = ctx
+        .accounts
+        .case_panel
+        .jurors
+        .iter()
+        .position(|seated| *seated == juror)
+        .ok_or(ErrorCode::NotJuror)?;
+    require!(case.accepted_seats & (1u32 << seat) == 0, ErrorCode::AlreadyAccepted);
+    case.accepted_seats |= 1u32 << seat;
+
+    emit!(JuryDutyAccepted {
+        case_id: case.case_id,
+        juror,
+        seat: seat as u8,
+        timestamp: now,
+    });
+
+    if all_seats_accepted(&case) {
+        case.state = CaseState::Voting as u8;
+        msg!("Panel for case {} accepted; voting open", case.case_id);
+    }
+    Ok(())
+}
+
+/// Permissionless after the deadline: every seat without an acceptance is redrawn and
+/// the new nominees get a fresh window
+pub fn replace_non_acceptors_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReplaceNonAcceptors<'info>>,
+) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(case.state == CaseState::Nominated as u8, ErrorCode::CaseNotNominated);
+    require!(now >= case.acceptance_deadline, ErrorCode::AcceptanceWindowOpen);
+
+    redraw_unaccepted(&mut case, &mut ctx.accounts.case_panel, &ctx.accounts.config, ctx.remaining_accounts, now)?;
+    case.acceptance_deadline = now
+        .checked_add(JURY_ACCEPTANCE_WINDOW)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Case {} nominees replaced; accept by {}", case.case_id, case.acceptance_deadline);
+    Ok(())
+}
+
+/// Redraw every seat without an acceptance. A silent nominee isn't a recusal: the seat is
+/// overwritten in place, so it costs none of the case's MAX_RECUSALS and the nominee,
+/// still seated while the draw runs, can't be drawn straight back.
+fn redraw_unaccepted<'info>(
+    case: &mut CaseAccount,
+    panel: &mut CasePanel,
+    config: &GlobalConfig,
+    accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    for seat in 0..case.juror_count as usize {
+        if case.accepted_seats & (1u32 << seat) == 0 {
+            fill_seat(case, panel, seat, config, accounts, now)?;
+        }
+    }
+    Ok(())
+}
+
+fn all_seats_accepted(case: &CaseAccount) -> bool {
+    let full = (1u32 << case.juror_count) - 1;
+    case.accepted_seats & full == full
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use crate::state::ValidatorPage;
+
+    /// Off-chain `Clock::get` fails without a runtime; the draw only needs an epoch
+    struct ClockStub;
+    impl SyscallStubs for ClockStub {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Clock) = Clock::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    /// Page 0 of the registry as the program would hold it
+    fn page_account(validators: Vec<Pubkey>) -> &'static [AccountInfo<'static>] {
+        let (address, bump) = Pubkey::find_program_address(&[b"validator_page", &0u16.to_le_bytes()], &crate::ID);
+        let mut data = Vec::new();
+        ValidatorPage { page_index: 0, validators, bump }.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(
+            Box::leak(Box::new(address)),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            data.leak(),
+            &crate::ID,
+            false,
+            0,
+        );
+        Box::leak(vec![info].into_boxed_slice())
+    }
+
+    #[test]
+    fn test_non_acceptors_dont_count_as_recusals() {
+        set_syscall_stubs(Box::new(ClockStub));
+        let validators: Vec<Pubkey> = (0..24).map(|_| Pubkey::new_unique()).collect();
+        let mut config = GlobalConfig::deserialize(&mut &[0u8; GlobalConfig::LEN][..]).unwrap();
+        config.validator_count = validators.len() as u32;
+        config.page_count = 1;
+        let mut case = CaseAccount::zeroed();
+        case.randomness = [7u8; 32];
+        case.juror_count = 7;
+        case.accepted_seats = 1 << 3;
+        let mut panel = CasePanel { case_id: 0, jurors: validators[..7].to_vec(), recused: vec![], bump: 0 };
+        let accounts = page_account(validators.clone());
+
+        // Six silent nominees, more than the four recusals a case allows, are all redrawn
+        redraw_unaccepted(&mut case, &mut panel, &config, accounts, 0).unwrap();
+        assert!(panel.recused.is_empty());
+        assert_eq!(case.replacements, 6);
+        assert_eq!(panel.jurors[3], validators[3]);
+        for seat in (0..7).filter(|seat| *seat != 3) {
+            assert_ne!(panel.jurors[seat], validators[seat]);
+            assert!(validators.contains(&panel.jurors[seat]));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use crate::domain;
+use crate::state::{GlobalConfig, IdentityNullifier, JurorIdentity, PersonhoodRegistry};
+use crate::zk_proofs::{load_registered_key, CommitmentScheme, ZkProof, ZkProofType};
+use crate::{ErrorCode, JurorIdentityAttested};
+
+#[derive(Accounts)]
+pub struct SetPersonhoodRegistry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PersonhoodRegistry::LEN,
+        seeds = [b"personhood_registry"],
+        bump
+    )]
+    pub personhood_registry: Account<'info, PersonhoodRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32])]
+pub struct AttestJurorIdentity<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"personhood_registry"],
+        bump = personhood_registry.bump
+    )]
+    pub personhood_registry: Account<'info, PersonhoodRegistry>,
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = 8 + JurorIdentity::LEN,
+        seeds = [b"juror_identity", validator.key().as_ref()],
+        bump
+    )]
+    pub juror_identity: Account<'info, JurorIdentity>,
+    /// Exists once the credential is bound, so it can't back a second key
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = 8 + IdentityNullifier::LEN,
+        seeds = [b"identity_nullifier", nullifier.as_ref()],
+        bump
+    )]
+    pub identity_nullifier: Account<'info, IdentityNullifier>,
+    /// CHECK: The personhood circuit's VerifyingKeyAccount PDA; checked in the handler
+    pub verifying_key: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance publishes the credential root and decides whether registration insists on
+/// it. Keys attested under an earlier root stay attested.
+pub fn set_registry_handler(ctx: Context<SetPersonhoodRegistry>, root: [u8; 32], required: bool) -> Result<()> {
+    require!(root != [0u8; 32], ErrorCode::InvalidPersonhoodProof);
+    let personhood_registry = &mut ctx.accounts.personhood_registry;
+    personhood_registry.root = root;
+    personhood_registry.required = required;
+    personhood_registry.updated_at = Clock::get()?.unix_timestamp;
+    personhood_registry.bump = ctx.bumps.personhood_registry;
+
+    msg!("Personhood registry updated. Required: {}", required);
+    Ok(())
+}
+
+/// What a personhood proof is bound to, cut below the BN254 scalar field: the validator
+/// key, so a proof seen in flight can't attest someone else's key
+pub fn identity_signal(validator: &Pubkey) -> [u8; 32] {
+    let mut signal = domain::hash(domain::IDENTITY_SIGNAL, &[validator.as_ref()]);
+    signal[0] = 0;
+    signal
+}
+
+/// Binds the signing validator key to one personhood credential. The credential's
+/// nullifier is recorded, so the same entity can't attest a second juror key.
+pub fn attest_handler(ctx: Context<AttestJurorIdentity>, nullifier: [u8; 32], proof: ZkProof) -> Result<()> {
+    let validator = ctx.accounts.validator.key();
+    require!(
+        ctx.accounts.juror_identity.validator == Pubkey::default(),
+        ErrorCode::IdentityAlreadyAttested
+    );
+    require!(
+        ctx.accounts.identity_nullifier.validator == Pubkey::default(),
+        ErrorCode::CredentialAlreadyUsed
+    );
+
+    let credential_root = ctx.accounts.personhood_registry.root;
+    require!(
+        proof.proof_type == ZkProofType::UniqueIdentity
+            && proof.public_inputs == ZkProof::identity_inputs(&credential_root, &nullifier, &identity_signal(&validator)),
+        ErrorCode::InvalidPersonhoodProof
+    );
+    let verifying_key = load_registered_key(&ctx.accounts.verifying_key, ZkProofType::UniqueIdentity)?;
+    require!(
+        proof.verify(verifying_key.as_ref(), CommitmentScheme::Poseidon)?,
+        ErrorCode::InvalidPersonhoodProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let identity_nullifier = &mut ctx.accounts.identity_nullifier;
+    identity_nullifier.nullifier = nullifier;
+    identity_nullifier.validator = validator;
+    identity_nullifier.bump = ctx.bumps.identity_nullifier;
+
+    let juror_identity = &mut ctx.accounts.juror_identity;
+    juror_identity.validator = validator;
+    juror_identity.nullifier = nullifier;
+    juror_identity.credential_root = credential_root;
+    juror_identity.attested_at = now;
+    juror_identity.bump = ctx.bumps.juror_identity;
+
+    let personhood_registry = &mut ctx.accounts.personhood_registry;
+    personhood_registry.attested = personhood_registry.attested.saturating_add(1);
+
+    emit!(JurorIdentityAttested {
+        validator,
+        nullifier,
+        credential_root,
+        timestamp: now,
+    });
+    msg!("Validator {} attested a personhood credential", validator);
+    Ok(())
+}
+
+/// Refuse `validator` while the personhood registry requires a credential and `identity`
+/// isn't its attestation. `registry` is the registry PDA, which may not exist yet.
+pub(crate) fn require_personhood(
+    registry: &AccountInfo,
+    identity: Option<&JurorIdentity>,
+    validator: &Pubkey,
+) -> Result<()> {
+    if registry.owner != &crate::ID || registry.data_is_empty() {
+        return Ok(());
+    }
+    let registry = PersonhoodRegistry::try_deserialize(&mut &registry.try_borrow_data()?[..])?;
+    if registry.required {
+        require!(
+            identity.is_some_and(|identity| identity.validator == *validator),
+            ErrorCode::PersonhoodRequired
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_signal_binds_key_below_field() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(identity_signal(&a)[0], 0);
+        assert_eq!(identity_signal(&a), identity_signal(&a));
+        assert_ne!(identity_signal(&a), identity_signal(&b));
+    }
+}
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, CasePanel, ConfidentialComponents, GlobalConfig, InsuranceFund, MisconductKind, MisconductReport,
+    MisconductStatus, ValidatorStake, ValidatorStatus, MISCONDUCT_SLASH_BPS,
+};
+use crate::zk_proofs::VoteCommitmentAccount;
+use crate::{ErrorCode, JurorMisconductReported, JurorMisconductResolved};
+use super::insurance_fund::record_deposit;
+
+#[derive(Accounts)]
+#[instruction(juror: Pubkey)]
+pub struct ReportJurorMisconduct<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    /// Only staked validators can be slashed
+    #[account(
+        seeds = [b"validator_stake", juror.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+    #[account(
+        mut,
+        seeds = [b"validator_status", juror.as_ref()],
+        bump = validator_status.bump
+    )]
+    pub validator_status: Account<'info, ValidatorStatus>,
+    /// CHECK: The juror's VoteCommitmentAccount if the case ran private votes, otherwise
+    /// their VoteRecord; only read for non-participation reports
+    pub ballot: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + MisconductReport::LEN,
+        seeds = [b"misconduct", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.as_ref()],
+        bump
+    )]
+    pub report: Account<'info, MisconductReport>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveMisconduct<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [b"misconduct", report.case_id.to_le_bytes().as_ref(), report.juror.as_ref()],
+        bump = report.bump
+    )]
+    pub report: Account<'info, MisconductReport>,
+    /// CHECK: Gets the report's rent back; checked against the report
+    #[account(mut, address = report.reporter @ ErrorCode::Unauthorized)]
+    pub reporter: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"validator_stake", report.juror.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+    #[account(
+        mut,
+        seeds = [b"validator_status", report.juror.as_ref()],
+        bump = validator_status.bump
+    )]
+    pub validator_status: Account<'info, ValidatorStatus>,
+    /// Slashed stake is paid in here
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+}
+
+/// Files a report against a juror of a finished case. The claim is checked against
+/// on-chain state here; governance still has to uphold it before anything is slashed.
+pub fn report_handler(ctx: Context<ReportJurorMisconduct>, juror: Pubkey, kind: MisconductKind) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let now = Clock::get()?;
+
+    require!(ctx.accounts.case_panel.is_juror(&juror), ErrorCode::NotJuror);
+    require!(
+        ctx.accounts.validator_status.is_bonded(now.epoch),
+        ErrorCode::StakeNotSlashable
+    );
+    match kind {
+        MisconductKind::NonParticipation => {
+            require!(case.finalized != 0, ErrorCode::CaseNotFinalized);
+            require!(
+                !voted(&case, &juror, &ctx.accounts.ballot)?,
+                ErrorCode::MisconductNotProven
+            );
+        }
+        MisconductKind::ConflictOfInterest => {
+            require!(
+                juror == case.scam_address || juror == case.reporter || juror == case.flow_source,
+                ErrorCode::MisconductNotProven
+            );
+        }
+    }
+
+    let report = &mut ctx.accounts.report;
+    report.case_id = case.case_id;
+    report.juror = juror;
+    report.reporter = ctx.accounts.reporter.key();
+    report.kind = kind;
+    report.status = MisconductStatus::Pending;
+    report.reported_at = now.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    // Keeps the stake from being withdrawn until the report is resolved
+    let validator_status = &mut ctx.accounts.validator_status;
+    validator_status.pending_reports = validator_status
+        .pending_reports
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(JurorMisconductReported {
+        case_id: report.case_id,
+        juror,
+        reporter: report.reporter,
+        kind: kind as u8,
+        timestamp: now.unix_timestamp,
+    });
+    msg!("Misconduct reported against juror {} on case {}", juror, report.case_id);
+    Ok(())
+}
+
+/// Whether `juror` cast a counted ballot. MPC-tallied votes are never opened individually,
+/// and anonymous ones aren't filed under the juror, so non-participation can't be shown
+/// for either.
+fn voted(case: &CaseAccount, juror: &Pubkey, ballot: &AccountInfo) -> Result<bool> {
+    require!(
+        !case.has_component(ConfidentialComponents::PRIVATE_VOTES | ConfidentialComponents::ANONYMOUS_JURORS),
+        ErrorCode::MisconductNotProven
+    );
+    let case_id = case.case_id.to_le_bytes();
+    if case.private_commits > 0 {
+        let address = Pubkey::find_program_address(&[b"vote_commitment", &case_id, juror.as_ref()], &crate::ID).0;
+        require!(ballot.key() == address, ErrorCode::MisconductNotProven);
+        if ballot.data_is_empty() {
+            return Ok(false);
+        }
+        let commitment = VoteCommitmentAccount::try_deserialize(&mut &ballot.try_borrow_data()?[..])?;
+        Ok(commitment.revealed)
+    } else {
+        let address = Pubkey::find_program_address(&[b"vote_record", &case_id, juror.as_ref()], &crate::ID).0;
+        require!(ballot.key() == address, ErrorCode::MisconductNotProven);
+        Ok(!ballot.data_is_empty())
+    }
+}
+
+pub fn resolve_handler(ctx: Context<ResolveMisconduct>, uphold: bool) -> Result<()> {
+    apply_misconduct_ruling(
+        &mut ctx.accounts.report,
+        &mut ctx.accounts.validator_stake,
+        &mut ctx.accounts.validator_status,
+        &mut ctx.accounts.insurance_fund,
+        uphold,
+    )
+}
+
+/// Upholding moves MISCONDUCT_SLASH_BPS of the juror's stake into the insurance fund
+pub(crate) fn apply_misconduct_ruling(
+    report: &mut Account<MisconductReport>,
+    validator_stake: &mut Account<ValidatorStake>,
+    validator_status: &mut Account<ValidatorStatus>,
+    insurance_fund: &mut Account<InsuranceFund>,
+    uphold: bool,
+) -> Result<()> {
+    require!(report.status == MisconductStatus::Pending, ErrorCode::MisconductAlreadyResolved);
+    require!(
+        validator_stake.validator == report.juror && validator_status.validator == report.juror,
+        ErrorCode::Unauthorized
+    );
+
+    let slashed = if uphold {
+        let slashed = (validator_stake.amount as u128 * MISCONDUCT_SLASH_BPS as u128 / 10_000) as u64;
+        validator_stake.amount -= slashed;
+        **validator_stake.to_account_info().try_borrow_mut_lamports()? -= slashed;
+        **insurance_fund.to_account_info().try_borrow_mut_lamports()? += slashed;
+        record_deposit(insurance_fund, slashed)?;
+        report.status = MisconductStatus::Upheld;
+        slashed
+    } else {
+        report.status = MisconductStatus::Dismissed;
+        0
+    };
+    validator_status.pending_reports = validator_status.pending_reports.saturating_sub(1);
+
+    emit!(JurorMisconductResolved {
+        case_id: report.case_id,
+        juror: report.juror,
+        upheld: uphold,
+        slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Misconduct report on case {} resolved; {} lamports slashed", report.case_id, slashed);
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    GlobalConfig, JurorPassport, PassportEntry, PassportRegistry, MAX_PASSPORT_ATTESTERS,
+    MAX_PASSPORT_ENTRIES,
+};
+use crate::{ErrorCode, PassportAttested};
+
+#[derive(Accounts)]
+pub struct ConfigurePassportPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PassportRegistry::LEN,
+        seeds = [b"passport_registry"],
+        bump
+    )]
+    pub registry: Account<'info, PassportRegistry>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenPassport<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + JurorPassport::LEN,
+        seeds = [b"juror_passport", juror.key().as_ref()],
+        bump
+    )]
+    pub passport: Account<'info, JurorPassport>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AttestPassport<'info> {
+    /// Signing key of the namespace vouching for these stats
+    pub attester: Signer<'info>,
+    #[account(
+        seeds = [b"passport_registry"],
+        bump = registry.bump,
+        constraint = registry.attesters.contains(&attester.key()) @ ErrorCode::UntrustedAttester
+    )]
+    pub registry: Account<'info, PassportRegistry>,
+    #[account(
+        mut,
+        seeds = [b"juror_passport", passport.juror.as_ref()],
+        bump = passport.bump
+    )]
+    pub passport: Account<'info, JurorPassport>,
+}
+
+pub fn configure_handler(
+    ctx: Context<ConfigurePassportPolicy>,
+    attesters: Vec<Pubkey>,
+    min_cases: u32,
+) -> Result<()> {
+    ctx.accounts.registry.bump = ctx.bumps.registry;
+    apply_passport_policy(&mut ctx.accounts.config, &mut ctx.accounts.registry, attesters, min_cases)
+}
+
+pub(crate) fn apply_passport_policy(
+    config: &mut GlobalConfig,
+    registry: &mut PassportRegistry,
+    attesters: Vec<Pubkey>,
+    min_cases: u32,
+) -> Result<()> {
+    require!(attesters.len() <= MAX_PASSPORT_ATTESTERS, ErrorCode::InvalidPassportPolicy);
+    let has_duplicates = attesters
+        .iter()
+        .enumerate()
+        .any(|(i, attester)| attesters[..i].contains(attester));
+    require!(!has_duplicates, ErrorCode::InvalidPassportPolicy);
+    // A requirement nobody can attest to would lock every claimant out
+    require!(min_cases == 0 || !attesters.is_empty(), ErrorCode::InvalidPassportPolicy);
+
+    registry.attesters = attesters;
+    config.passport_min_cases = min_cases;
+
+    msg!(
+        "Passport policy: {} trusted namespaces, {} cases required",
+        registry.attesters.len(),
+        min_cases
+    );
+    Ok(())
+}
+
+pub fn open_handler(ctx: Context<OpenPassport>) -> Result<()> {
+    let passport = &mut ctx.accounts.passport;
+    passport.juror = ctx.accounts.juror.key();
+    passport.entries = Vec::new();
+    passport.bump = ctx.bumps.passport;
+
+    msg!("Juror passport opened for {}", passport.juror);
+    Ok(())
+}
+
+/// Attesters post running totals; a namespace can't shrink the record it already vouched for
+pub fn attest_handler(ctx: Context<AttestPassport>, cases_served: u32, majority_votes: u32) -> Result<()> {
+    require!(majority_votes <= cases_served, ErrorCode::InvalidAttestation);
+
+    let namespace = ctx.accounts.attester.key();
+    let now = Clock::get()?.unix_timestamp;
+    let passport = &mut ctx.accounts.passport;
+
+    match passport.entries.iter_mut().find(|entry| entry.namespace == namespace) {
+        Some(entry) => {
+            require!(
+                cases_served >= entry.cases_served && majority_votes >= entry.majority_votes,
+                ErrorCode::InvalidAttestation
+            );
+            entry.cases_served = cases_served;
+            entry.majority_votes = majority_votes;
+            entry.attested_at = now;
+        }
+        None => {
+            require!(passport.entries.len() < MAX_PASSPORT_ENTRIES, ErrorCode::PassportFull);
+            passport.entries.push(PassportEntry {
+                namespace,
+                cases_served,
+                majority_votes,
+                attested_at: now,
+            });
+        }
+    }
+
+    emit!(PassportAttested {
+        juror: passport.juror,
+        namespace,
+        cases_served,
+        majority_votes,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Enforce `passport_min_cases`, counting only namespaces the registry still trusts
+pub(crate) fn check_passport_requirement(
+    config: &GlobalConfig,
+    juror: &Pubkey,
+    passport: Option<&Account<JurorPassport>>,
+    registry: Option<&Account<PassportRegistry>>,
+) -> Result<()> {
+    if config.passport_min_cases == 0 {
+        return Ok(());
+    }
+    let (Some(passport), Some(registry)) = (passport, registry) else {
+        return err!(ErrorCode::PassportRequired);
+    };
+    require!(passport.juror == *juror, ErrorCode::PassportRequired);
+    require!(
+        passport.trusted_cases(&registry.attesters) >= config.passport_min_cases,
+        ErrorCode::PassportRequired
+    );
+    Ok(())
+}
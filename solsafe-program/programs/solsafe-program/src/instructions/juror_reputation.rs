@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, CasePanel, ConfidentialComponents, GlobalConfig, JurorProfile, VoteRecord, MIN_REPUTATION_WEIGHT_BPS,
+};
+use crate::zk_proofs::VoteCommitmentAccount;
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct OpenJurorProfile<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + JurorProfile::LEN,
+        seeds = [b"juror_profile", juror.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, JurorProfile>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetReputationWeighting<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn open_handler(ctx: Context<OpenJurorProfile>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.juror = ctx.accounts.juror.key();
+    profile.bump = ctx.bumps.profile;
+
+    msg!("Juror profile opened for {}", profile.juror);
+    Ok(())
+}
+
+pub fn set_weighting_handler(ctx: Context<SetReputationWeighting>, enabled: bool) -> Result<()> {
+    apply_reputation_weighting(&mut ctx.accounts.config, enabled)
+}
+
+pub(crate) fn apply_reputation_weighting(config: &mut GlobalConfig, enabled: bool) -> Result<()> {
+    config.reputation_weighting = enabled;
+
+    msg!("Reputation-weighted juror selection: {}", enabled);
+    Ok(())
+}
+
+fn profile_address(juror: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"juror_profile", juror.as_ref()], &crate::ID).0
+}
+
+/// Selection weight of `validator`: its profile's score if the profile is among `accounts`,
+/// otherwise the floor
+pub fn reputation_weight<'info>(accounts: &'info [AccountInfo<'info>], validator: &Pubkey) -> u16 {
+    let address = profile_address(validator);
+    accounts
+        .iter()
+        .find(|info| info.key() == address)
+        .and_then(|info| Account::<JurorProfile>::try_from(info).ok())
+        .map_or(MIN_REPUTATION_WEIGHT_BPS, |profile| profile.weight_bps())
+}
+
+/// Folds a finalized case into its panel's profiles. `accounts` is either empty, or holds
+/// one (profile, ballot) pair per seat in seat order followed by one profile per recused
+/// juror, so a cranker can't choose whose record moves. A juror without a profile is
+/// passed as the empty PDA and skipped. The ballot is the juror's VoteCommitmentAccount
+/// when the case ran private votes, otherwise their VoteRecord; an empty one is an abstention.
+pub(crate) fn record_service<'info>(
+    case: &CaseAccount,
+    panel: Option<&CasePanel>,
+    approved: bool,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    if accounts.is_empty() {
+        return Ok(());
+    }
+    let panel = panel.ok_or(ErrorCode::InvalidJurorProfile)?;
+    let seats: Vec<&Pubkey> = panel.jurors.iter().filter(|juror| **juror != Pubkey::default()).collect();
+    require!(
+        accounts.len() == seats.len() * 2 + panel.recused.len(),
+        ErrorCode::InvalidJurorProfile
+    );
+
+    // MPC-tallied votes are never opened individually and anonymous ones can't be traced
+    // to a juror, so only the seat itself counts
+    let mpc_tallied =
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES | ConfidentialComponents::ANONYMOUS_JURORS);
+    let (pairs, recused) = accounts.split_at(seats.len() * 2);
+
+    for (juror, pair) in seats.into_iter().zip(pairs.chunks(2)) {
+        let Some(mut profile) = load_profile(&pair[0], juror)? else {
+            continue;
+        };
+        profile.cases_served = profile.cases_served.saturating_add(1);
+
+        if !mpc_tallied {
+            record_ballot(&mut profile, case, juror, &pair[1], approved)?;
+        }
+        profile.last_case_id = case.case_id;
+        profile.exit(&crate::ID)?;
+    }
+
+    for (juror, info) in panel.recused.iter().zip(recused) {
+        if let Some(mut profile) = load_profile(info, juror)? {
+            profile.recusals = profile.recusals.saturating_add(1);
+            profile.last_case_id = case.case_id;
+            profile.exit(&crate::ID)?;
+        }
+    }
+    Ok(())
+}
+
+fn record_ballot<'info>(
+    profile: &mut JurorProfile,
+    case: &CaseAccount,
+    juror: &Pubkey,
+    ballot: &'info AccountInfo<'info>,
+    approved: bool,
+) -> Result<()> {
+    let case_id = case.case_id.to_le_bytes();
+    if case.private_commits > 0 {
+        let address = Pubkey::find_program_address(&[b"vote_commitment", &case_id, juror.as_ref()], &crate::ID).0;
+        require!(ballot.key() == address, ErrorCode::InvalidJurorProfile);
+        if let Ok(commitment) = Account::<VoteCommitmentAccount>::try_from(ballot) {
+            profile.commitments = profile.commitments.saturating_add(1);
+            if commitment.revealed {
+                profile.reveals = profile.reveals.saturating_add(1);
+                record_vote(profile, commitment.approved == approved);
+            }
+        }
+    } else {
+        let address = Pubkey::find_program_address(&[b"vote_record", &case_id, juror.as_ref()], &crate::ID).0;
+        require!(ballot.key() == address, ErrorCode::InvalidJurorProfile);
+        if let Ok(vote) = Account::<VoteRecord>::try_from(ballot) {
+            record_vote(profile, vote.approved == approved);
+        }
+    }
+    Ok(())
+}
+
+fn record_vote(profile: &mut JurorProfile, with_majority: bool) {
+    profile.decided_votes = profile.decided_votes.saturating_add(1);
+    if with_majority {
+        profile.majority_votes = profile.majority_votes.saturating_add(1);
+    }
+}
+
+/// `None` for the juror's profile PDA before it has been opened
+fn load_profile<'info>(
+    info: &'info AccountInfo<'info>,
+    juror: &Pubkey,
+) -> Result<Option<Account<'info, JurorProfile>>> {
+    require!(info.key() == profile_address(juror), ErrorCode::InvalidJurorProfile);
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(info.is_writable, ErrorCode::InvalidJurorProfile);
+    Account::<JurorProfile>::try_from(info).map(Some)
+}
+
+/// Rejection step layered on a uniform draw: a candidate is kept with probability
+/// weight / 10 000, using a word independent of the index draw
+pub(crate) fn accept_weighted(randomness: &[u8; 32], case_id: u64, attempt: u32, weight_bps: u16) -> bool {
+    let digest = crate::domain::hash(
+        crate::domain::JUROR_WEIGHTING,
+        &[&case_id.to_le_bytes(), randomness, &attempt.to_le_bytes()],
+    );
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest[..8]);
+    // Scale the word onto [0, 10 000) without a modulo
+    let roll = (u64::from_le_bytes(word) as u128 * 10_000) >> 64;
+    roll < weight_bps as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(served: u32, decided: u32, majority: u32, commitments: u32, reveals: u32, recusals: u32) -> JurorProfile {
+        JurorProfile {
+            juror: Pubkey::new_unique(),
+            cases_served: served,
+            decided_votes: decided,
+            majority_votes: majority,
+            commitments,
+            reveals,
+            recusals,
+            last_case_id: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_weight_tracks_record() {
+        let fresh = profile(0, 0, 0, 0, 0, 0).weight_bps();
+        assert_eq!(fresh, 5_000);
+        assert!(profile(20, 20, 20, 0, 0, 0).weight_bps() > fresh);
+        assert!(profile(20, 20, 5, 0, 0, 0).weight_bps() < fresh);
+        // Unopened commitments and recusals both cost weight
+        assert!(profile(10, 10, 10, 10, 2, 0).weight_bps() < profile(10, 10, 10, 10, 10, 0).weight_bps());
+        assert!(profile(10, 10, 10, 0, 0, 10).weight_bps() < profile(10, 10, 10, 0, 0, 0).weight_bps());
+        assert_eq!(profile(20, 20, 0, 20, 0, 20).weight_bps(), MIN_REPUTATION_WEIGHT_BPS);
+    }
+
+    #[test]
+    fn test_accept_weighted_follows_weight() {
+        let randomness = [11u8; 32];
+        let accepted = |weight| (0..2_000).filter(|attempt| accept_weighted(&randomness, 4, *attempt, weight)).count();
+        assert_eq!(accepted(0), 0);
+        assert_eq!(accepted(10_000), 2_000);
+        let half = accepted(5_000);
+        assert!(half > 850 && half < 1_150);
+    }
+}
@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::{
+    CaseAccount, GlobalConfig, ValidatorPage, CASE_ACCOUNT_VERSION, CONFIG_VERSION,
+    VALIDATORS_PER_PAGE,
+};
+use crate::{AccountMigrated, ErrorCode};
+use super::initialize::new_config;
+
+/// Size the baseline config was allocated at: admin, a validator_list of up to 100,
+/// bump, quorum, min_jurors. Nothing versioned is ever this long.
+const LEGACY_CONFIG_SPACE: usize = 8 + 32 + 4 + (32 * 100) + 1 + 1 + 1;
+const _: () = assert!(LEGACY_CONFIG_SPACE > 8 + GlobalConfig::LEN);
+
+/// Discriminator of the Borsh-era CaseAccount, `sha256("account:CaseAccount")[..8]`
+const LEGACY_CASE_DISCRIMINATOR: [u8; 8] = [212, 167, 142, 81, 78, 129, 160, 126];
+
+/// GlobalConfig as the baseline program wrote it
+#[derive(AnchorDeserialize)]
+struct LegacyGlobalConfig {
+    admin: Pubkey,
+    validator_list: Vec<Pubkey>,
+    bump: u8,
+    quorum: u8,
+    min_jurors: u8,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: Possibly an older layout that no longer deserializes; checked by hand
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+    /// First validator page, created for a baseline config's validator_list; omitted when
+    /// the list is empty or the config is already paged
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ValidatorPage::LEN,
+        seeds = [b"validator_page", 0u16.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub validator_page: Option<Account<'info, ValidatorPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateCase<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Possibly an older layout that no longer loads; checked by hand
+    #[account(mut)]
+    pub case_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Owner and discriminator checks for an account whose layout may be out of date
+fn check_program_account(info: &AccountInfo, discriminator: &[u8]) -> Result<()> {
+    require!(info.owner == &crate::ID, ErrorCode::InvalidMigration);
+    let data = info.try_borrow_data()?;
+    require!(data.len() >= 8 && data[..8] == *discriminator, ErrorCode::InvalidMigration);
+    Ok(())
+}
+
+/// Grow `info` to `len` bytes, zero-filling the new tail; the payer tops up rent
+pub(crate) fn grow<'info>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    len: usize,
+) -> Result<()> {
+    if info.data_len() >= len {
+        return Ok(());
+    }
+    let shortfall = Rent::get()?.minimum_balance(len).saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.realloc(len, true)?;
+    Ok(())
+}
+
+/// Brings the config up to CONFIG_VERSION. Only the admin key can do this, and only while
+/// no council governs; the admin field is first in every layout, so it's readable before
+/// the account is upgraded.
+pub fn config_handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+    check_program_account(&info, &GlobalConfig::DISCRIMINATOR)?;
+    {
+        let data = info.try_borrow_data()?;
+        let admin = data.get(8..40).ok_or(ErrorCode::InvalidMigration)?;
+        require!(admin == ctx.accounts.admin.key().as_ref(), ErrorCode::Unauthorized);
+    }
+
+    let from_len = info.data_len();
+    if from_len == LEGACY_CONFIG_SPACE {
+        return convert_legacy_config(ctx);
+    }
+    require!(from_len <= 8 + GlobalConfig::LEN, ErrorCode::InvalidMigration);
+    require!(ctx.accounts.validator_page.is_none(), ErrorCode::InvalidMigration);
+
+    grow(
+        &info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + GlobalConfig::LEN,
+    )?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    let mut config = GlobalConfig::try_deserialize(&mut &data[..])?;
+    require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+    let from_version = config.version;
+    require!(from_version < CONFIG_VERSION, ErrorCode::AlreadyMigrated);
+    config.version = CONFIG_VERSION;
+    config.try_serialize(&mut &mut data[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: CONFIG_VERSION,
+        from_len: from_len as u32,
+        to_len: (8 + GlobalConfig::LEN) as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Config migrated from version {} to {}", from_version, CONFIG_VERSION);
+    Ok(())
+}
+
+/// Rewrites a baseline config in the current layout. Its validator_list moves to validator
+/// page 0 and every setting the baseline didn't have starts off, as after initialize. The
+/// account shrinks to the new length and the freed rent goes to the admin.
+fn convert_legacy_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        LegacyGlobalConfig::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::InvalidMigration)?
+    };
+    require!(legacy.validator_list.len() <= VALIDATORS_PER_PAGE, ErrorCode::InvalidMigration);
+
+    let mut config = new_config(legacy.admin, legacy.bump, legacy.quorum, legacy.min_jurors);
+    match ctx.accounts.validator_page.as_mut() {
+        Some(page) => {
+            require!(!legacy.validator_list.is_empty(), ErrorCode::InvalidMigration);
+            page.page_index = 0;
+            page.validators = legacy.validator_list.clone();
+            page.bump = ctx.bumps.validator_page.ok_or(ErrorCode::InvalidMigration)?;
+            config.validator_count = legacy.validator_list.len() as u32;
+            config.page_count = 1;
+        }
+        None => require!(legacy.validator_list.is_empty(), ErrorCode::InvalidMigration),
+    }
+
+    let to_len = 8 + GlobalConfig::LEN;
+    let refund = info.lamports().saturating_sub(Rent::get()?.minimum_balance(to_len));
+    info.realloc(to_len, false)?;
+    **info.try_borrow_mut_lamports()? -= refund;
+    **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? += refund;
+    config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version: 0,
+        to_version: CONFIG_VERSION,
+        from_len: LEGACY_CONFIG_SPACE as u32,
+        to_len: to_len as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!(
+        "Baseline config converted to version {} with {} validators",
+        CONFIG_VERSION,
+        config.validator_count
+    );
+    Ok(())
+}
+
+/// Permissionless: brings a case up to CASE_ACCOUNT_VERSION. The payer covers any extra rent.
+/// Borsh-era cases are refused: their votes were a voted_jurors list, not VoteRecords, so a
+/// converted case would let those jurors vote again.
+pub fn case_handler(ctx: Context<MigrateCase>) -> Result<()> {
+    let info = ctx.accounts.case_account.to_account_info();
+    {
+        let data = info.try_borrow_data()?;
+        if data.len() >= 8 && data[..8] == LEGACY_CASE_DISCRIMINATOR {
+            msg!("Borsh-era case accounts can't be converted");
+            return err!(ErrorCode::InvalidMigration);
+        }
+    }
+    check_program_account(&info, &CaseAccount::DISCRIMINATOR)?;
+
+    let from_len = info.data_len();
+    require!(from_len <= 8 + CaseAccount::LEN, ErrorCode::InvalidMigration);
+    grow(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + CaseAccount::LEN,
+    )?;
+
+    let mut data = info.try_borrow_mut_data()?;
+    let case: &mut CaseAccount = bytemuck::from_bytes_mut(&mut data[8..8 + CaseAccount::LEN]);
+    let from_version = case.version;
+    require!(from_version < CASE_ACCOUNT_VERSION, ErrorCode::AlreadyMigrated);
+    case.version = CASE_ACCOUNT_VERSION;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: CASE_ACCOUNT_VERSION,
+        from_len: from_len as u32,
+        to_len: (8 + CaseAccount::LEN) as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Case {} migrated from version {} to {}", case.case_id, from_version, CASE_ACCOUNT_VERSION);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    #[test]
+    fn test_legacy_layouts_are_recognisable() {
+        assert_eq!(LEGACY_CASE_DISCRIMINATOR, hash(b"account:CaseAccount").to_bytes()[..8]);
+        assert_ne!(LEGACY_CASE_DISCRIMINATOR, CaseAccount::DISCRIMINATOR);
+    }
+
+    #[test]
+    fn test_legacy_config_deserializes() {
+        let admin = Pubkey::new_unique();
+        let validators = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut data = vec![0u8; LEGACY_CONFIG_SPACE];
+        data[..8].copy_from_slice(&GlobalConfig::DISCRIMINATOR);
+        let mut body = admin.to_bytes().to_vec();
+        body.extend((validators.len() as u32).to_le_bytes());
+        validators.iter().for_each(|v| body.extend(v.to_bytes()));
+        body.extend([254, 3, 5]);
+        data[8..8 + body.len()].copy_from_slice(&body);
+
+        let legacy = LegacyGlobalConfig::deserialize(&mut &data[8..]).unwrap();
+        assert_eq!(legacy.admin, admin);
+        assert_eq!(legacy.validator_list, validators);
+        assert_eq!((legacy.bump, legacy.quorum, legacy.min_jurors), (254, 3, 5));
+    }
+}
@@ -1,15 +1,140 @@
+// Every module exports its own `handler`; lib.rs always calls them by path
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
+pub mod admin_transfer;
+pub mod admin_council;
+pub mod pause;
+pub mod treasury;
+pub mod watchtower;
 pub mod update_validators;
 pub mod submit_evidence;
+pub mod append_evidence;
+pub mod duplicate_case;
+pub mod confidential_case;
+pub mod confidential_token;
+pub mod case_categories;
+pub mod expire_case;
+pub mod close_case;
+pub mod can_execute;
+pub mod juror_passport;
+pub mod fund_flow;
 pub mod request_jurors;
 pub mod select_jurors;
 pub mod vote;
+pub mod finalize_case;
+pub mod seat_auction;
+pub mod recusal;
+pub mod juror_acceptance;
+pub mod heartbeat;
+pub mod randomness_source;
 pub mod sync_validators;
+pub mod restitution;
+pub mod threat_intel;
+pub mod intel_dispute;
+pub mod freeze_integrity;
+pub mod validator_registry;
+pub mod integration_grants;
+pub mod case_bundle;
+pub mod settlement;
+pub mod evidence_tx;
+pub mod juror_reputation;
+pub mod protocol_stats;
+pub mod validator_staking;
+pub mod juror_misconduct;
+pub mod insurance_fund;
+pub mod reporter_bounty;
+pub mod case_bond;
+pub mod quadratic_voting;
+pub mod severity_thresholds;
+pub mod tie_break;
+pub mod escalation;
+pub mod evidentiary_round;
+pub mod execute_freeze;
+pub mod burn_counterfeit;
+pub mod nft_freeze;
+pub mod cnft_flags;
+pub mod wallet_flag;
+pub mod views;
+pub mod crosschain;
+pub mod attestations;
+pub mod scam_list;
+pub mod hooks;
+pub mod migrate;
+pub mod case_counter;
+pub mod batch_evidence;
+pub mod notarization;
+pub mod evm_attestation;
+pub mod pq_attestation;
+pub mod anonymous_report;
+pub mod juror_identity;
+pub mod evidence_upload;
 
 pub use initialize::*;
+pub use admin_transfer::*;
+pub use admin_council::*;
+pub use pause::*;
+pub use treasury::*;
+pub use watchtower::*;
 pub use update_validators::*;
 pub use submit_evidence::*;
+pub use append_evidence::*;
+pub use duplicate_case::*;
+pub use confidential_case::*;
+pub use confidential_token::*;
+pub use case_categories::*;
+pub use expire_case::*;
+pub use close_case::*;
+pub use can_execute::*;
+pub use juror_passport::*;
+pub use fund_flow::*;
 pub use request_jurors::*;
 pub use select_jurors::*;
 pub use vote::*;
+pub use finalize_case::*;
+pub use seat_auction::*;
+pub use recusal::*;
+pub use juror_acceptance::*;
+pub use heartbeat::*;
+pub use randomness_source::*;
 pub use sync_validators::*;
+pub use restitution::*;
+pub use threat_intel::*;
+pub use intel_dispute::*;
+pub use freeze_integrity::*;
+pub use validator_registry::*;
+pub use integration_grants::*;
+pub use case_bundle::*;
+pub use settlement::*;
+pub use evidence_tx::*;
+pub use juror_reputation::*;
+pub use protocol_stats::*;
+pub use validator_staking::*;
+pub use juror_misconduct::*;
+pub use insurance_fund::*;
+pub use reporter_bounty::*;
+pub use case_bond::*;
+pub use quadratic_voting::*;
+pub use severity_thresholds::*;
+pub use tie_break::*;
+pub use escalation::*;
+pub use evidentiary_round::*;
+pub use execute_freeze::*;
+pub use burn_counterfeit::*;
+pub use nft_freeze::*;
+pub use cnft_flags::*;
+pub use wallet_flag::*;
+pub use views::*;
+pub use crosschain::*;
+pub use attestations::*;
+pub use scam_list::*;
+pub use hooks::*;
+pub use migrate::*;
+pub use case_counter::*;
+pub use batch_evidence::*;
+pub use notarization::*;
+pub use evm_attestation::*;
+pub use pq_attestation::*;
+pub use anonymous_report::*;
+pub use juror_identity::*;
+pub use evidence_upload::*;
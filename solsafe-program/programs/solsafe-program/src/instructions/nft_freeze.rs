@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::metaplex::{self, LockAccounts, TOKEN_METADATA_PROGRAM_ID};
+use crate::state::{CaseAccount, GlobalConfig, NftFreezeList, MAX_FROZEN_NFTS};
+use crate::{ErrorCode, NftFrozen};
+use super::execute_freeze::check_freeze_executable;
+
+#[derive(Accounts)]
+pub struct FreezeNft<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + NftFreezeList::LEN,
+        seeds = [b"nft_freezes", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub nft_freezes: Account<'info, NftFreezeList>,
+    /// The accused's holding, delegated to the program authority
+    #[account(
+        mut,
+        constraint = nft_token_account.owner == case_account.load()?.scam_address @ ErrorCode::InvalidCase,
+        constraint = nft_token_account.amount == 1 @ ErrorCode::InvalidCase,
+        constraint = nft_token_account.delegate == Some(program_authority.key()).into() @ ErrorCode::NftNotDelegated
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+    #[account(address = nft_token_account.mint @ ErrorCode::InvalidCase)]
+    pub nft_mint: Account<'info, Mint>,
+    /// CHECK: Token Metadata account of the mint; Token Metadata checks it
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: Master edition of the mint; Token Metadata checks it
+    pub edition: UncheckedAccount<'info>,
+    /// CHECK: Token record of a programmable NFT; omitted for plain NFTs
+    #[account(mut)]
+    pub token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Program authority PDA - the holding's delegate
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    /// CHECK: Token Metadata program
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar, read by Token Metadata for pNFT locks
+    #[account(address = sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless crank: freezes an NFT, or locks a pNFT, held by the accused of an
+/// approved case. The mint's freeze authority sits with Token Metadata, which only acts
+/// for the holding's delegate, so this reaches holdings delegated to the program
+/// authority (escrowed listings, staking, or a marketplace integration).
+pub fn handler(ctx: Context<FreezeNft>) -> Result<()> {
+    let case_id = {
+        let case = ctx.accounts.case_account.load()?;
+        check_freeze_executable(&case, &ctx.accounts.config)?;
+        case.case_id
+    };
+
+    let mint = ctx.accounts.nft_mint.key();
+    let nft_freezes = &mut ctx.accounts.nft_freezes;
+    require!(!nft_freezes.mints.contains(&mint), ErrorCode::NftAlreadyFrozen);
+    require!(nft_freezes.mints.len() < MAX_FROZEN_NFTS, ErrorCode::NftFreezeListFull);
+
+    let authority = ctx.accounts.program_authority.key();
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let programmable = ctx.accounts.token_record.is_some();
+
+    if let Some(token_record) = &ctx.accounts.token_record {
+        let lock = metaplex::lock(LockAccounts {
+            authority,
+            token_owner: ctx.accounts.nft_token_account.owner,
+            token: ctx.accounts.nft_token_account.key(),
+            mint,
+            metadata: ctx.accounts.metadata.key(),
+            edition: ctx.accounts.edition.key(),
+            token_record: token_record.key(),
+            payer: ctx.accounts.cranker.key(),
+            token_program: ctx.accounts.token_program.key(),
+        });
+        invoke_signed(
+            &lock,
+            &[
+                ctx.accounts.program_authority.to_account_info(),
+                ctx.accounts.nft_token_account.to_account_info(),
+                ctx.accounts.nft_mint.to_account_info(),
+                ctx.accounts.metadata.to_account_info(),
+                ctx.accounts.edition.to_account_info(),
+                token_record.to_account_info(),
+                ctx.accounts.cranker.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.sysvar_instructions.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+    } else {
+        let freeze = metaplex::freeze_delegated_account(
+            authority,
+            ctx.accounts.nft_token_account.key(),
+            ctx.accounts.edition.key(),
+            mint,
+            ctx.accounts.token_program.key(),
+        );
+        invoke_signed(
+            &freeze,
+            &[
+                ctx.accounts.program_authority.to_account_info(),
+                ctx.accounts.nft_token_account.to_account_info(),
+                ctx.accounts.edition.to_account_info(),
+                ctx.accounts.nft_mint.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+    }
+
+    nft_freezes.case_id = case_id;
+    nft_freezes.mints.push(mint);
+    nft_freezes.bump = ctx.bumps.nft_freezes;
+
+    emit!(NftFrozen {
+        case_id,
+        mint,
+        token_account: ctx.accounts.nft_token_account.key(),
+        programmable,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Case {} froze NFT {} ({} frozen)", case_id, mint, nft_freezes.mints.len());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::ed25519;
+use crate::state::{Analyst, EvidenceNotarization, GlobalConfig};
+use crate::{ErrorCode, EvidenceNotarized};
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterAnalyst<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Analyst::LEN,
+        seeds = [b"analyst", authority.as_ref()],
+        bump
+    )]
+    pub analyst: Account<'info, Analyst>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Governance trusts an analyst's signing key to co-sign evidence (or retires it)
+pub fn register_analyst_handler(
+    ctx: Context<RegisterAnalyst>,
+    authority: Pubkey,
+    organization: [u8; 32],
+    active: bool,
+) -> Result<()> {
+    let analyst = &mut ctx.accounts.analyst;
+    analyst.authority = authority;
+    analyst.organization = organization;
+    analyst.active = active;
+    analyst.bump = ctx.bumps.analyst;
+
+    msg!("Analyst {} registered. Active: {}", authority, active);
+    Ok(())
+}
+
+/// Record that `analyst` signed `evidence_hash`, checked against the Ed25519 instruction
+/// preceding the submission
+pub(crate) fn notarize_evidence(
+    analyst: &mut Account<Analyst>,
+    notarization: &mut Account<EvidenceNotarization>,
+    notarization_bump: u8,
+    instructions_sysvar: &AccountInfo,
+    case_id: u64,
+    evidence_hash: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    require!(evidence_hash != [0u8; 32], ErrorCode::InvalidNotarization);
+    let signer = ed25519::verified_signer(instructions_sysvar, &evidence_hash)?;
+    require!(signer == analyst.authority, ErrorCode::InvalidNotarization);
+    analyst.notarized = analyst.notarized.saturating_add(1);
+
+    notarization.case_id = case_id;
+    notarization.analyst = signer;
+    notarization.evidence_hash = evidence_hash;
+    notarization.notarized_at = now;
+    notarization.bump = notarization_bump;
+
+    emit!(EvidenceNotarized {
+        case_id,
+        analyst: signer,
+        evidence_hash,
+        timestamp: now,
+    });
+    msg!("Case {} evidence notarized by analyst {}", case_id, signer);
+    Ok(())
+}
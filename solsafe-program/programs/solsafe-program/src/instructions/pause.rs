@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::{ErrorCode, PauseToggled};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.pauser == pauser.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauser<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Circuit breaker: halts new cases, votes and freezes until unpaused
+pub fn pause_handler(ctx: Context<SetPaused>) -> Result<()> {
+    set_paused(&mut ctx.accounts.config, ctx.accounts.pauser.key(), true)
+}
+
+pub fn unpause_handler(ctx: Context<SetPaused>) -> Result<()> {
+    set_paused(&mut ctx.accounts.config, ctx.accounts.pauser.key(), false)
+}
+
+pub fn set_pauser_handler(ctx: Context<SetPauser>, pauser: Pubkey) -> Result<()> {
+    ctx.accounts.config.pauser = pauser;
+
+    msg!("Pauser set to {}", pauser);
+    Ok(())
+}
+
+fn set_paused(config: &mut GlobalConfig, pauser: Pubkey, paused: bool) -> Result<()> {
+    config.paused = paused;
+
+    emit!(PauseToggled {
+        pauser,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Program {} by {}", if paused { "paused" } else { "unpaused" }, pauser);
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::domain;
+use crate::quantum_resistant::{self, PqNode, MAX_PQ_TREE_HEIGHT, PQ_HASH_LEN};
+use crate::state::{CaseAccount, CaseState, PqAttestation, PqKey, VoteRecord, PQ_COSIGN_MIN_TIER};
+use crate::{ErrorCode, PqVerdictCosigned};
+
+#[derive(Accounts)]
+pub struct RegisterPqKey<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + PqKey::LEN,
+        seeds = [b"pq_key", juror.key().as_ref()],
+        bump
+    )]
+    pub pq_key: Account<'info, PqKey>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CosignVerdictPq<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"pq_key", juror.key().as_ref()],
+        bump = pq_key.bump
+    )]
+    pub pq_key: Account<'info, PqKey>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Only jurors who voted on the case co-sign its verdict
+    #[account(
+        seeds = [b"vote_record", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + PqAttestation::LEN,
+        seeds = [b"pq_attestation", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub pq_attestation: Account<'info, PqAttestation>,
+    pub system_program: Program<'info, System>,
+}
+
+/// What a juror's one-time key signs for a verdict
+pub fn pq_verdict_digest(case: &CaseAccount, juror: &Pubkey) -> PqNode {
+    domain::hash(
+        domain::PQ_VERDICT,
+        &[
+            &case.case_id.to_le_bytes(),
+            &[case.state, case.verdict],
+            case.scam_address.as_ref(),
+            juror.as_ref(),
+        ],
+    )[..PQ_HASH_LEN]
+        .try_into()
+        .unwrap()
+}
+
+/// Register (or replace) the juror's post-quantum key. A new tree starts with every
+/// one-time key unspent.
+pub fn register_key_handler(ctx: Context<RegisterPqKey>, root: PqNode, height: u8) -> Result<()> {
+    require!(height <= MAX_PQ_TREE_HEIGHT, ErrorCode::InvalidPqSignature);
+
+    let pq_key = &mut ctx.accounts.pq_key;
+    pq_key.juror = ctx.accounts.juror.key();
+    pq_key.root = root;
+    pq_key.height = height;
+    pq_key.next_leaf = 0;
+    pq_key.registered_at = Clock::get()?.unix_timestamp;
+    pq_key.bump = ctx.bumps.pq_key;
+
+    msg!("Juror {} registered a post-quantum key of height {}", pq_key.juror, height);
+    Ok(())
+}
+
+/// Optional: a juror who voted co-signs a high-value case's verdict with one of their
+/// one-time keys. Keys are spent in order, so `leaf_index` must not be below the next
+/// unspent leaf. Verification runs ~3,300 hashes; request extra compute for it.
+pub fn cosign_handler(
+    ctx: Context<CosignVerdictPq>,
+    leaf_index: u32,
+    signature: Vec<PqNode>,
+    auth_path: Vec<PqNode>,
+) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    require!(
+        case.state == CaseState::Approved as u8 || case.state == CaseState::Rejected as u8,
+        ErrorCode::CaseNotDecided
+    );
+    require!(case.value_tier >= PQ_COSIGN_MIN_TIER as u8, ErrorCode::CaseNotHighValue);
+
+    let juror = ctx.accounts.juror.key();
+    let pq_key = &mut ctx.accounts.pq_key;
+    require!(
+        leaf_index >= pq_key.next_leaf && auth_path.len() == pq_key.height as usize,
+        ErrorCode::PqKeySpent
+    );
+    let digest = pq_verdict_digest(&case, &juror);
+    require!(
+        quantum_resistant::verify_pq_signature(&juror, &pq_key.root, leaf_index, &digest, &signature, &auth_path),
+        ErrorCode::InvalidPqSignature
+    );
+    pq_key.next_leaf = leaf_index + 1;
+
+    let now = Clock::get()?.unix_timestamp;
+    let pq_attestation = &mut ctx.accounts.pq_attestation;
+    pq_attestation.case_id = case.case_id;
+    pq_attestation.juror = juror;
+    pq_attestation.state = case.state;
+    pq_attestation.verdict = case.verdict;
+    pq_attestation.digest = digest;
+    pq_attestation.leaf_index = leaf_index;
+    pq_attestation.attested_at = now;
+    pq_attestation.bump = ctx.bumps.pq_attestation;
+
+    emit!(PqVerdictCosigned {
+        case_id: case.case_id,
+        juror,
+        leaf_index,
+        timestamp: now,
+    });
+    msg!("Juror {} co-signed case {} with post-quantum key leaf {}", juror, case.case_id, leaf_index);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, ProtocolStats};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolStats<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolStats::LEN,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Starts the counters at zero; only the validator count can be read off existing state
+pub fn initialize_handler(ctx: Context<InitializeProtocolStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.active_validators = ctx.accounts.config.validator_count;
+    stats.tracking_since = Clock::get()?.unix_timestamp;
+    stats.bump = ctx.bumps.protocol_stats;
+
+    msg!("Protocol stats tracking from {}", stats.tracking_since);
+    Ok(())
+}
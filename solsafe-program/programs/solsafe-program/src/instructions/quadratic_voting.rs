@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, CaseStatus, GlobalConfig, QuadraticTally, QuadraticVote, ValidatorPage, ValidatorStake,
+    Verdict, VoiceCredits, VoteRecord,
+};
+use crate::{ErrorCode, QuadraticVoteCast};
+use super::vote::{check_eligible, emit_verdict, voting_pool};
+
+#[derive(Accounts)]
+pub struct SetQuadraticVoting<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CastQuadraticVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Registry page listing the juror; omitted by jurors seated through the fallback auction
+    #[account(
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Option<Account<'info, ValidatorPage>>,
+    /// Backs credits beyond BASE_VOICE_CREDITS; jurors without a stake pass nothing
+    #[account(
+        seeds = [b"validator_stake", juror.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + VoiceCredits::LEN,
+        seeds = [b"voice_credits", juror.key().as_ref()],
+        bump
+    )]
+    pub voice_credits: Account<'info, VoiceCredits>,
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + QuadraticTally::LEN,
+        seeds = [b"quadratic_tally", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tally: Account<'info, QuadraticTally>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + QuadraticVote::LEN,
+        seeds = [b"quadratic_vote", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub quadratic_vote: Account<'info, QuadraticVote>,
+    /// Created on first vote; a second vote by the same juror fails on init
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + VoteRecord::LEN,
+        seeds = [b"vote_record", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct ReleaseVoiceCredits<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    /// CHECK: Read only if it still exists; a case closed after retention has left voting
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"voice_credits", juror.key().as_ref()],
+        bump = voice_credits.bump
+    )]
+    pub voice_credits: Account<'info, VoiceCredits>,
+    #[account(
+        mut,
+        close = juror,
+        seeds = [b"quadratic_vote", case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = quadratic_vote.bump
+    )]
+    pub quadratic_vote: Account<'info, QuadraticVote>,
+}
+
+pub fn set_mode_handler(ctx: Context<SetQuadraticVoting>, enabled: bool) -> Result<()> {
+    apply_quadratic_voting(&mut ctx.accounts.config, enabled)
+}
+
+/// Only cases submitted after the switch pick up the new mode
+pub(crate) fn apply_quadratic_voting(config: &mut GlobalConfig, enabled: bool) -> Result<()> {
+    config.quadratic_voting = enabled;
+
+    msg!("Quadratic voting for new cases: {}", enabled);
+    Ok(())
+}
+
+/// Casts `votes` weighted votes for `approve`, locking votes² of the juror's voice credits
+/// until the case leaves voting. Each juror still votes once per case, so a large staker
+/// buys weight on one case only at a quadratic price.
+pub fn cast_handler(ctx: Context<CastQuadraticVote>, approve: bool, votes: u32) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+    let juror = ctx.accounts.juror.key();
+
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    require!(case.state == CaseState::Voting as u8, ErrorCode::CaseNotVoting);
+    require!(case.quadratic != 0, ErrorCode::NotQuadraticCase);
+    require!(votes > 0, ErrorCode::InvalidVoteCount);
+
+    check_eligible(&case, &ctx.accounts.case_panel, ctx.accounts.validator_page.as_deref(), &juror)?;
+
+    let credits = votes as u64 * votes as u64;
+    let stake = ctx.accounts.validator_stake.as_ref().map_or(0, |stake| stake.amount);
+    let voice_credits = &mut ctx.accounts.voice_credits;
+    let locked = voice_credits
+        .locked
+        .checked_add(credits)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(locked <= VoiceCredits::budget(stake), ErrorCode::InsufficientVoiceCredits);
+    voice_credits.juror = juror;
+    voice_credits.locked = locked;
+    voice_credits.bump = ctx.bumps.voice_credits;
+
+    let tally = &mut ctx.accounts.tally;
+    tally.case_id = case.case_id;
+    if approve {
+        tally.votes_for += votes as u64;
+    } else {
+        tally.votes_against += votes as u64;
+    }
+    tally.credits_spent = tally
+        .credits_spent
+        .checked_add(credits)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    tally.voters += 1;
+    tally.bump = ctx.bumps.tally;
+
+    let quadratic_vote = &mut ctx.accounts.quadratic_vote;
+    quadratic_vote.case_id = case.case_id;
+    quadratic_vote.juror = juror;
+    quadratic_vote.approve = approve;
+    quadratic_vote.votes = votes;
+    quadratic_vote.credits = credits;
+    quadratic_vote.bump = ctx.bumps.quadratic_vote;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.juror = juror;
+    vote_record.case_id = case.case_id;
+    vote_record.approved = approve;
+    vote_record.verdict = if approve { Verdict::FreezeAndSeize } else { Verdict::Dismiss };
+    vote_record.timestamp = clock.unix_timestamp;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    // The case keeps counting heads, so turnout is judged exactly as for one-vote cases
+    if approve {
+        case.votes_for += 1;
+    } else {
+        case.votes_against += 1;
+    }
+
+    emit!(QuadraticVoteCast {
+        case_id: case.case_id,
+        juror,
+        approve,
+        votes,
+        credits,
+        weighted_for: tally.votes_for,
+        weighted_against: tally.votes_against,
+        timestamp: clock.unix_timestamp,
+    });
+    msg!("Quadratic vote: {} votes for {} credits. Weighted {} / {}", votes, credits, tally.votes_for, tally.votes_against);
+
+    // Once as many jurors have voted as would carry a one-vote case, the weighted tally decides
+    let total_jurors = voting_pool(&case, &ctx.accounts.case_panel, config);
+    let turnout = case.votes_for + case.votes_against;
+    if turnout >= case.required_votes(total_jurors) || turnout >= total_jurors {
+        if tally.approves(case.threshold_numerator, case.threshold_denominator) {
+            case.state = CaseState::Approved as u8;
+        } else {
+            case.state = CaseState::Rejected as u8;
+        }
+        case.status = CaseStatus::Closed as u8;
+        emit_verdict(&case, clock.unix_timestamp);
+        msg!("Quadratic quorum reached. Final state set.");
+    }
+
+    Ok(())
+}
+
+/// Returns a juror's credits from a case that has left voting
+pub fn release_handler(ctx: Context<ReleaseVoiceCredits>, case_id: u64) -> Result<()> {
+    require!(
+        !case_voting(&ctx.accounts.case_account)?,
+        ErrorCode::VoiceCreditsLocked
+    );
+
+    let credits = ctx.accounts.quadratic_vote.credits;
+    let voice_credits = &mut ctx.accounts.voice_credits;
+    voice_credits.locked = voice_credits.locked.saturating_sub(credits);
+
+    msg!("Released {} voice credits from case {}", credits, case_id);
+    Ok(())
+}
+
+/// The case PDA is checked by seeds; once closed after retention it has no data left
+fn case_voting(case_info: &AccountInfo) -> Result<bool> {
+    if case_info.data_is_empty() {
+        return Ok(false);
+    }
+    let data = case_info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 + CaseAccount::LEN && data[..8] == CaseAccount::DISCRIMINATOR,
+        ErrorCode::CaseNotOpen
+    );
+    let case: &CaseAccount = bytemuck::from_bytes(&data[8..8 + CaseAccount::LEN]);
+    Ok(case.state == CaseState::Voting as u8)
+}
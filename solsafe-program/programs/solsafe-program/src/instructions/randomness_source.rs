@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, RandomnessSource};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetRandomnessSource<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetRandomnessSource>, source: RandomnessSource) -> Result<()> {
+    apply_randomness_source(&mut ctx.accounts.config, source)
+}
+
+/// Only new requests follow the switch; cases already committed keep their own source
+pub(crate) fn apply_randomness_source(config: &mut GlobalConfig, source: RandomnessSource) -> Result<()> {
+    config.randomness_source = source;
+
+    msg!(
+        "Randomness source set to {}",
+        match source {
+            RandomnessSource::Switchboard => "Switchboard",
+            RandomnessSource::Orao => "ORAO",
+        }
+    );
+    Ok(())
+}
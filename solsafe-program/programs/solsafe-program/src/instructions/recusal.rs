@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CasePanel, CaseState, GlobalConfig, MAX_RECUSALS, VALIDATORS_PER_PAGE};
+use crate::{ErrorCode, JurorRecused, JurorReplaced};
+use super::heartbeat::is_live;
+use super::select_jurors::{fit_panel, unbiased_index};
+use super::validator_registry::find_page;
+
+/// Draws per replacement before giving up; mirrors the cap in select_jurors
+const MAX_REPLACEMENT_ATTEMPTS: u32 = 1000;
+
+#[derive(Accounts)]
+pub struct Recuse<'info> {
+    /// Pays for the panel's recusal list to grow
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    /// CHECK: Must not exist yet; a juror who already voted can't recuse
+    #[account(
+        seeds = [b"vote_record", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub vote_record: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReplaceJuror<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    // Remaining accounts: the ValidatorPage PDAs holding each drawn index, plus each
+    // candidate's ValidatorHeartbeat when config.heartbeat_epochs is set
+}
+
+/// Recusal and replacement work while nominees are accepting and during voting
+fn is_panel_open(case: &CaseAccount) -> bool {
+    case.state == CaseState::Nominated as u8 || case.state == CaseState::Voting as u8
+}
+
+/// A seated juror steps down before voting; the seat stays vacant until `replace_juror`
+pub fn recuse_handler(ctx: Context<Recuse>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let panel = &mut ctx.accounts.case_panel;
+    let juror = ctx.accounts.juror.key();
+
+    require!(is_panel_open(&case), ErrorCode::CaseNotVoting);
+    require!(ctx.accounts.vote_record.data_is_empty(), ErrorCode::AlreadyVoted);
+    let seat = panel
+        .jurors
+        .iter()
+        .position(|seated| *seated == juror)
+        .ok_or(ErrorCode::NotJuror)?;
+    vacate_seat(&mut case, panel, seat)?;
+    fit_panel(panel, &ctx.accounts.juror, &ctx.accounts.system_program)?;
+
+    emit!(JurorRecused {
+        case_id: case.case_id,
+        juror,
+        seat: seat as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Juror {} recused from case {}", juror, case.case_id);
+    Ok(())
+}
+
+/// Permissionless: fills the first vacant seat with a validator drawn from the case's
+/// stored randomness, so anyone can recompute who the substitute must be
+pub fn replace_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ReplaceJuror<'info>>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let panel = &mut ctx.accounts.case_panel;
+
+    require!(is_panel_open(&case), ErrorCode::CaseNotVoting);
+    let seat = panel
+        .jurors
+        .iter()
+        .position(|seated| *seated == Pubkey::default())
+        .ok_or(ErrorCode::NoVacantSeat)?;
+    fill_seat(
+        &mut case,
+        panel,
+        seat,
+        &ctx.accounts.config,
+        ctx.remaining_accounts,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Empty `seat` and bar its holder from being drawn again for this case
+fn vacate_seat(case: &mut CaseAccount, panel: &mut CasePanel, seat: usize) -> Result<()> {
+    require!(panel.recused.len() < MAX_RECUSALS, ErrorCode::TooManyRecusals);
+    let juror = panel.jurors[seat];
+    panel.jurors[seat] = Pubkey::default();
+    case.accepted_seats &= !(1u32 << seat);
+    panel.recused.push(juror);
+    Ok(())
+}
+
+/// Draw a live validator who isn't seated or recused into `seat`. One who already voted on
+/// an escalated case can still be drawn; their VoteRecord keeps them to that one ballot.
+pub(crate) fn fill_seat<'info>(
+    case: &mut CaseAccount,
+    panel: &mut CasePanel,
+    seat: usize,
+    config: &GlobalConfig,
+    accounts: &'info [AccountInfo<'info>],
+    timestamp: i64,
+) -> Result<()> {
+    let validator_count = config.validator_count as usize;
+    let epoch = Clock::get()?.epoch;
+    require!(case.randomness != [0u8; 32], ErrorCode::VrfNotReady);
+    require!(validator_count > 0, ErrorCode::JurorSelectionFailed);
+
+    let mut attempt = 0u32;
+    let replacement = loop {
+        require!(attempt < MAX_REPLACEMENT_ATTEMPTS, ErrorCode::JurorSelectionFailed);
+        let draw = unbiased_index(draw_word(case, attempt), validator_count);
+        attempt += 1;
+        let idx = match draw {
+            Some(idx) => idx,
+            None => continue,
+        };
+
+        let page = find_page(accounts, (idx / VALIDATORS_PER_PAGE) as u16)?;
+        let candidate = *page
+            .validators
+            .get(idx % VALIDATORS_PER_PAGE)
+            .ok_or(ErrorCode::InvalidValidatorPage)?;
+        if !panel.is_juror(&candidate)
+            && !panel.is_recused(&candidate)
+            && is_live(accounts, config, &candidate, epoch)
+        {
+            break candidate;
+        }
+    };
+
+    panel.jurors[seat] = replacement;
+    case.replacements = case.replacements.saturating_add(1);
+
+    emit!(JurorReplaced {
+        case_id: case.case_id,
+        seat: seat as u8,
+        replacement,
+        timestamp,
+    });
+
+    msg!("Seat {} on case {} filled by {}", seat, case.case_id, replacement);
+    Ok(())
+}
+
+fn draw_word(case: &CaseAccount, attempt: u32) -> u64 {
+    let digest = crate::domain::hash(
+        crate::domain::JUROR_REPLACEMENT,
+        &[
+            &case.case_id.to_le_bytes(),
+            &case.randomness,
+            &[case.replacements],
+            &attempt.to_le_bytes(),
+        ],
+    );
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(word)
+}
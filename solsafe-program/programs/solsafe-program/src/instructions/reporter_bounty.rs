@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    CaseAccount, CaseState, GlobalConfig, ReporterBountyReceipt, RestitutionEscrow, Treasury,
+    MAX_REPORTER_BOUNTY_BPS,
+};
+use crate::{ErrorCode, ReporterBountyPaid};
+use super::treasury::{withdraw_lamports, withdraw_tokens};
+
+#[derive(Accounts)]
+pub struct ConfigureReporterBounty<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct ClaimReporterBounty<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + ReporterBountyReceipt::LEN,
+        seeds = [b"reporter_bounty", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, ReporterBountyReceipt>,
+    /// Percentage bounties pass the case's escrow, the treasury vault for its mint, the
+    /// mint, and the reporter's token account
+    #[account(
+        seeds = [b"escrow", case_id.to_le_bytes().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Option<Account<'info, RestitutionEscrow>>,
+    #[account(mut)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub reporter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_handler(ctx: Context<ConfigureReporterBounty>, flat: u64, bps: u16) -> Result<()> {
+    apply_reporter_bounty(&mut ctx.accounts.config, flat, bps)
+}
+
+/// A nonzero `bps` switches the bounty from `flat` lamports to a share of seized funds
+pub(crate) fn apply_reporter_bounty(config: &mut GlobalConfig, flat: u64, bps: u16) -> Result<()> {
+    require!(bps <= MAX_REPORTER_BOUNTY_BPS, ErrorCode::InvalidFee);
+    config.reporter_bounty = flat;
+    config.reporter_bounty_bps = bps;
+
+    msg!("Reporter bounty set: {} lamports flat, {} bps of seized funds", flat, bps);
+    Ok(())
+}
+
+/// Pays the reporter of an approved case once. Percentage bounties are taken from
+/// whatever has been seized into escrow at claim time, in the escrow's mint.
+pub fn claim_handler(ctx: Context<ClaimReporterBounty>, case_id: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    {
+        let case = ctx.accounts.case_account.load()?;
+        require!(
+            case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8,
+            ErrorCode::NotApproved
+        );
+        require!(case.overturned == 0, ErrorCode::NotApproved);
+    }
+
+    let (mint, amount) = if config.reporter_bounty_bps > 0 {
+        let (Some(escrow), Some(treasury_vault), Some(mint), Some(reporter_token_account), Some(token_program)) = (
+            &ctx.accounts.escrow,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.reporter_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(ErrorCode::InvalidTreasuryAccounts);
+        };
+        require!(
+            treasury_vault.mint == escrow.mint && reporter_token_account.mint == escrow.mint,
+            ErrorCode::EscrowMintMismatch
+        );
+        require!(
+            reporter_token_account.owner == ctx.accounts.reporter.key(),
+            ErrorCode::Unauthorized
+        );
+        let amount = (escrow.total_seized as u128 * config.reporter_bounty_bps as u128 / 10_000) as u64;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        withdraw_tokens(
+            &ctx.accounts.treasury,
+            treasury_vault,
+            mint,
+            reporter_token_account,
+            token_program,
+            amount,
+        )?;
+        (Some(escrow.mint), amount)
+    } else {
+        let amount = config.reporter_bounty;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+        withdraw_lamports(
+            &mut ctx.accounts.treasury,
+            &ctx.accounts.reporter.to_account_info(),
+            amount,
+        )?;
+        (None, amount)
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.case_id = case_id;
+    receipt.reporter = ctx.accounts.reporter.key();
+    receipt.mint = mint.unwrap_or_default();
+    receipt.amount = amount;
+    receipt.paid_at = now;
+    receipt.bump = ctx.bumps.receipt;
+
+    emit!(ReporterBountyPaid {
+        case_id,
+        reporter: receipt.reporter,
+        mint,
+        amount,
+        timestamp: now,
+    });
+    msg!("Reporter bounty for case {}: {}", case_id, amount);
+    Ok(())
+}
@@ -1,25 +1,51 @@
 use anchor_lang::prelude::*;
-use crate::state::{CaseAccount, CaseState};
-use crate::ErrorCode;
+use crate::state::{CaseAccount, CaseState, CaseStatus, GlobalConfig};
+use crate::switchboard::MAX_REVEAL_DELAY_SLOTS;
+use crate::randomness;
+use crate::{ErrorCode, RandomnessCommitted};
 
 #[derive(Accounts)]
 pub struct RequestJurors<'info> {
     #[account(mut)]
-    pub case_account: Account<'info, CaseAccount>,
-    /// CHECK: Switchboard randomness account - will be validated in handler
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// CHECK: Request account of config.randomness_source; owner, layout, and freshness
+    /// are checked in the handler
     pub randomness_account: AccountInfo<'info>,
     pub authority: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<RequestJurors>, case_id: u64) -> Result<()> {
-    let case = &mut ctx.accounts.case_account;
+    let mut case = ctx.accounts.case_account.load_mut()?;
     
     require!(case.case_id == case_id, ErrorCode::InvalidCase);
-    require!(case.status == crate::state::CaseStatus::Open, ErrorCode::CaseNotOpen);
+    require!(case.status == CaseStatus::Open as u8, ErrorCode::CaseNotOpen);
+    require!(case.state == CaseState::PendingJurors as u8, ErrorCode::InvalidCase);
+
+    // A live commitment can't be swapped out; only one whose reveal window lapsed
+    let slot = Clock::get()?.slot;
+    if case.vrf_request != Pubkey::default() {
+        require!(
+            slot > case.randomness_commit_slot.saturating_add(MAX_REVEAL_DELAY_SLOTS),
+            ErrorCode::RandomnessRecommitted
+        );
+    }
+
+    // Commit to a fresh, still unrevealed request so the requester can't shop for a panel
+    let source = ctx.accounts.config.randomness_source;
+    randomness::commit(&mut case, source, &ctx.accounts.randomness_account, slot)?;
+
+    emit!(RandomnessCommitted {
+        case_id,
+        source,
+        randomness_account: case.vrf_request,
+        seed_slot: case.randomness_seed_slot,
+        commit_slot: slot,
+    });
 
-    // Store the randomness account pubkey for later use in select_jurors
-    case.vrf_request = ctx.accounts.randomness_account.key();
-    case.state = CaseState::PendingJurors;
-    
     Ok(())
 }
@@ -0,0 +1,473 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{permanent_delegate::get_permanent_delegate, StateWithExtensions},
+};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, FreezeAccount, Mint, ThawAccount, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::state::{
+    CaseAccount, CaseState, GlobalConfig, ProtocolStats, RestitutionEscrow, Verdict, VictimClaim,
+    RESTITUTION_CLAIM_WINDOW,
+};
+use super::treasury::distribution_fee;
+use crate::{ErrorCode, RestitutionClaimed, RestitutionSeized, VictimClaimRegistered};
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct SeizeToEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// The accused's holding, moved into escrow whether or not it's frozen
+    #[account(
+        mut,
+        token::token_program = token_program,
+        constraint = scam_token_account.owner == case_account.load()?.scam_address @ ErrorCode::InvalidCase,
+        constraint = scam_token_account.mint == mint.key() @ ErrorCode::EscrowMintMismatch
+    )]
+    pub scam_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Token-2022 mint whose permanent delegate must be the program authority
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RestitutionEscrow::LEN,
+        seeds = [b"escrow", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, RestitutionEscrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"escrow_vault", case_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = program_authority,
+        token::token_program = token_program
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Program authority PDA - the mint's permanent delegate, and its freeze authority
+    /// when the holding is frozen
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64, victim: Pubkey)]
+pub struct RegisterVictimClaim<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"escrow", case_id.to_le_bytes().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, RestitutionEscrow>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VictimClaim::LEN,
+        seeds = [b"victim_claim", case_id.to_le_bytes().as_ref(), victim.as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, VictimClaim>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct ClaimRestitution<'info> {
+    pub victim: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"escrow", case_id.to_le_bytes().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, RestitutionEscrow>,
+    #[account(
+        mut,
+        address = escrow.vault
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(address = escrow.mint @ ErrorCode::EscrowMintMismatch)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"victim_claim", case_id.to_le_bytes().as_ref(), victim.key().as_ref()],
+        bump = claim.bump,
+        has_one = victim
+    )]
+    pub claim: Account<'info, VictimClaim>,
+    #[account(
+        mut,
+        constraint = victim_token_account.owner == victim.key() @ ErrorCode::Unauthorized,
+        constraint = victim_token_account.mint == escrow.mint @ ErrorCode::EscrowMintMismatch
+    )]
+    pub victim_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// Receives the distribution fee; required while one is configured
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", escrow.mint.as_ref()],
+        bump
+    )]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Program authority PDA - owner of the escrow vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    /// The escrow mint's program: Token for settlements, Token-2022 for seizures
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+/// Moves the accused's balance into the case escrow. A freeze authority can't move tokens,
+/// so only Token-2022 mints whose issuer made the program authority the permanent delegate
+/// can be seized; a frozen holding is thawed for the transfer and frozen again, empty.
+pub fn seize_handler(ctx: Context<SeizeToEscrow>, case_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Seizing thaws and re-freezes, so it halts with the rest of the freeze path
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    {
+        let case = ctx.accounts.case_account.load()?;
+        require!(case.state == CaseState::Approved as u8, ErrorCode::NotApproved);
+        require!(case.verdict() == Verdict::FreezeAndSeize, ErrorCode::VerdictForbidsSeizure);
+    }
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        require!(
+            get_permanent_delegate(&mint) == Some(ctx.accounts.program_authority.key()),
+            ErrorCode::SeizureNotDelegated
+        );
+    }
+
+    let escrow = &mut ctx.accounts.escrow;
+    open_escrow(
+        escrow,
+        case_id,
+        ctx.accounts.mint.key(),
+        ctx.accounts.escrow_vault.key(),
+        ctx.bumps.escrow,
+        clock.unix_timestamp,
+    )?;
+
+    let amount = ctx.accounts.scam_token_account.amount;
+    require!(amount > 0, ErrorCode::NothingToClaim);
+    let frozen = ctx.accounts.scam_token_account.is_frozen();
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    if frozen {
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            ThawAccount {
+                account: ctx.accounts.scam_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    // The permanent delegate can move any holder's balance
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: ctx.accounts.scam_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    if frozen {
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account: ctx.accounts.scam_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    escrow.total_seized = escrow
+        .total_seized
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(RestitutionSeized {
+        case_id,
+        source: ctx.accounts.scam_token_account.key(),
+        mint: escrow.mint,
+        amount,
+        total_seized: escrow.total_seized,
+    });
+
+    msg!("Seized {} tokens into escrow for case {}", amount, case_id);
+    Ok(())
+}
+
+/// First deposit for a case opens the escrow and its claim window; later ones must match the mint
+pub(crate) fn open_escrow(
+    escrow: &mut RestitutionEscrow,
+    case_id: u64,
+    mint: Pubkey,
+    vault: Pubkey,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    if escrow.claims_close_at == 0 {
+        escrow.case_id = case_id;
+        escrow.mint = mint;
+        escrow.vault = vault;
+        escrow.claims_close_at = now
+            .checked_add(RESTITUTION_CLAIM_WINDOW)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        escrow.bump = bump;
+    }
+    require!(escrow.mint == mint, ErrorCode::EscrowMintMismatch);
+    require_claims_open(escrow, now)
+}
+
+/// Deposits and victim claims are taken only until the window closes
+pub(crate) fn require_claims_open(escrow: &RestitutionEscrow, now: i64) -> Result<()> {
+    require!(now < escrow.claims_close_at, ErrorCode::ClaimWindowClosed);
+    Ok(())
+}
+
+/// Payouts wait for the window to close, so every share is cut from the final totals
+pub(crate) fn require_claims_closed(escrow: &RestitutionEscrow, now: i64) -> Result<()> {
+    require!(now >= escrow.claims_close_at, ErrorCode::ClaimWindowOpen);
+    Ok(())
+}
+
+pub fn register_claim_handler(
+    ctx: Context<RegisterVictimClaim>,
+    case_id: u64,
+    victim: Pubkey,
+    amount_lost: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+
+    // Claims are verified off-chain against the case evidence by the admin
+    require!(ctx.accounts.admin.key() == config.admin, ErrorCode::Unauthorized);
+    require_claims_open(escrow, clock.unix_timestamp)?;
+    require!(amount_lost > 0, ErrorCode::NothingToClaim);
+
+    let claim = &mut ctx.accounts.claim;
+    claim.case_id = case_id;
+    claim.victim = victim;
+    claim.amount_lost = amount_lost;
+    claim.payout = 0;
+    claim.paid = false;
+    claim.bump = ctx.bumps.claim;
+
+    escrow.total_claimed = escrow
+        .total_claimed
+        .checked_add(amount_lost)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    escrow.claim_count = escrow
+        .claim_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(VictimClaimRegistered {
+        case_id,
+        victim,
+        amount_lost,
+    });
+
+    msg!("Victim claim registered for case {}: {} lost", case_id, amount_lost);
+    Ok(())
+}
+
+pub fn claim_handler(ctx: Context<ClaimRestitution>, case_id: u64) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let claim = &mut ctx.accounts.claim;
+    let clock = Clock::get()?;
+
+    require_claims_closed(escrow, clock.unix_timestamp)?;
+    require!(!claim.paid, ErrorCode::AlreadyClaimed);
+
+    // Pro-rata share of everything seized, capped at the verified loss
+    let payout = pro_rata_share(escrow.total_seized, claim.amount_lost, escrow.total_claimed)?;
+    require!(payout > 0, ErrorCode::NothingToClaim);
+    let fee = distribution_fee(&ctx.accounts.config, payout)?;
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    if fee > 0 {
+        let treasury_vault = ctx
+            .accounts
+            .treasury_vault
+            .as_ref()
+            .ok_or(ErrorCode::InvalidTreasuryAccounts)?;
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: treasury_vault.to_account_info(),
+                    authority: ctx.accounts.program_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            fee,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.victim_token_account.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        payout - fee,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    claim.payout = payout;
+    claim.paid = true;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_restitution(payout - fee);
+    }
+    escrow.total_paid = escrow
+        .total_paid
+        .checked_add(payout)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(RestitutionClaimed {
+        case_id,
+        victim: claim.victim,
+        payout,
+        fee,
+    });
+
+    msg!("Restitution paid for case {}: {}", case_id, payout);
+    Ok(())
+}
+
+/// `amount_lost` as a share of `total_claimed`, applied to `total_seized` and capped at the loss
+pub(crate) fn pro_rata_share(total_seized: u64, amount_lost: u64, total_claimed: u64) -> Result<u64> {
+    if total_claimed == 0 {
+        return Ok(0);
+    }
+    let share = (total_seized as u128)
+        .checked_mul(amount_lost as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / total_claimed as u128;
+    Ok((share as u64).min(amount_lost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_escrow() -> RestitutionEscrow {
+        RestitutionEscrow {
+            case_id: 0,
+            mint: Pubkey::default(),
+            vault: Pubkey::default(),
+            total_seized: 0,
+            total_claimed: 0,
+            total_paid: 0,
+            claim_count: 0,
+            claims_close_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_pro_rata_share() {
+        // Nothing claimed, nothing owed
+        assert_eq!(pro_rata_share(1_000, 0, 0).unwrap(), 0);
+        // Losses split a short escrow in proportion
+        assert_eq!(pro_rata_share(600, 300, 900).unwrap(), 200);
+        assert_eq!(pro_rata_share(600, 600, 900).unwrap(), 400);
+        // An escrow larger than the losses pays each victim no more than they lost
+        assert_eq!(pro_rata_share(5_000, 300, 900).unwrap(), 300);
+        // Rounds down, so the shares never overdraw the vault
+        assert_eq!(pro_rata_share(100, 100, 300).unwrap(), 33);
+        assert_eq!(pro_rata_share(u64::MAX, u64::MAX, u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_claim_window() {
+        let (mint, vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut escrow = empty_escrow();
+        open_escrow(&mut escrow, 7, mint, vault, 255, 1_000).unwrap();
+        let close = 1_000 + RESTITUTION_CLAIM_WINDOW;
+        assert_eq!(escrow.claims_close_at, close);
+        assert_eq!((escrow.case_id, escrow.mint, escrow.vault), (7, mint, vault));
+
+        // A later deposit keeps the first window and must match the mint
+        open_escrow(&mut escrow, 7, mint, vault, 255, close - 1).unwrap();
+        assert_eq!(escrow.claims_close_at, close);
+        assert!(open_escrow(&mut escrow, 7, Pubkey::new_unique(), vault, 255, 2_000).is_err());
+
+        assert!(require_claims_open(&escrow, close - 1).is_ok());
+        assert!(require_claims_closed(&escrow, close - 1).is_err());
+        assert!(require_claims_open(&escrow, close).is_err());
+        assert!(require_claims_closed(&escrow, close).is_ok());
+        assert!(open_escrow(&mut escrow, 7, mint, vault, 255, close).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use crate::domain;
+use crate::state::{
+    CaseAccount, CaseCategory, CaseCounter, CaseSeverity, CaseState, CaseStatus, GlobalConfig,
+    ListedAddressClaim, ProtocolStats, ScamIndex, ScamList, Verdict, CASE_ACCOUNT_VERSION,
+};
+use crate::{ErrorCode, ListedAddressClaimed, ScamListPublished};
+use super::fund_flow::merkle_root;
+
+#[derive(Accounts)]
+#[instruction(list_id: u64)]
+pub struct PublishScamListRoot<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ScamList::LEN,
+        seeds = [b"scam_list", list_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub scam_list: Account<'info, ScamList>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64, address: Pubkey)]
+pub struct ClaimListedAddress<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"scam_list", scam_list.list_id.to_le_bytes().as_ref()],
+        bump = scam_list.bump
+    )]
+    pub scam_list: Account<'info, ScamList>,
+    /// Assigns case ids in order; `case_id` must be its next id
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + CaseAccount::LEN,
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + ScamIndex::LEN,
+        seeds = [b"scam_index", address.as_ref()],
+        bump
+    )]
+    pub scam_index: Account<'info, ScamIndex>,
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ListedAddressClaim::LEN,
+        seeds = [b"listed_claim", scam_list.list_id.to_le_bytes().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub listed_claim: Account<'info, ListedAddressClaim>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Leaf committing to one list entry
+pub fn scam_list_leaf(list_id: u64, address: &Pubkey, category: CaseCategory, severity: CaseSeverity) -> [u8; 32] {
+    domain::hash(
+        domain::SCAM_LIST_LEAF,
+        &[&list_id.to_le_bytes(), address.as_ref(), &[category as u8], &[severity as u8]],
+    )
+}
+
+/// Governance publishes (or replaces) the root of a curated list. Cases already claimed
+/// against an earlier root stand.
+pub fn publish_handler(
+    ctx: Context<PublishScamListRoot>,
+    list_id: u64,
+    root: [u8; 32],
+    entries: u32,
+    verdict: Verdict,
+) -> Result<()> {
+    require!(root != [0u8; 32] && entries > 0, ErrorCode::InvalidScamListProof);
+    require!(verdict != Verdict::Dismiss, ErrorCode::InvalidScamListVerdict);
+
+    let now = Clock::get()?.unix_timestamp;
+    let scam_list = &mut ctx.accounts.scam_list;
+    scam_list.list_id = list_id;
+    scam_list.root = root;
+    scam_list.entries = entries;
+    scam_list.verdict = verdict;
+    scam_list.published_at = now;
+    scam_list.bump = ctx.bumps.scam_list;
+
+    emit!(ScamListPublished {
+        list_id,
+        root,
+        entries,
+        timestamp: now,
+    });
+    msg!("Scam list {} published with {} entries", list_id, entries);
+    Ok(())
+}
+
+/// Permissionless: opens an already-approved, finalized case for a listed address on
+/// proof of membership, skipping the jury. The case carries the list's verdict, so the
+/// usual enforcement cranks apply to it.
+pub fn claim_handler(
+    ctx: Context<ClaimListedAddress>,
+    case_id: u64,
+    address: Pubkey,
+    category: CaseCategory,
+    severity: CaseSeverity,
+    index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    ctx.accounts.case_counter.assign(case_id)?;
+    let scam_list = &mut ctx.accounts.scam_list;
+    require!(index < scam_list.entries, ErrorCode::InvalidScamListProof);
+    let leaf = scam_list_leaf(scam_list.list_id, &address, category, severity);
+    require!(
+        merkle_root(scam_list.list_id, leaf, index, &proof) == scam_list.root,
+        ErrorCode::InvalidScamListProof
+    );
+    scam_list.claims = scam_list.claims.saturating_add(1);
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut case = ctx.accounts.case_account.load_init()?;
+    case.case_id = case_id;
+    case.scam_address = address;
+    case.reporter = ctx.accounts.claimant.key();
+    case.status = CaseStatus::Open as u8;
+    case.state = CaseState::Approved as u8;
+    case.verdict = scam_list.verdict as u8;
+    case.bump = ctx.bumps.case_account;
+    case.version = CASE_ACCOUNT_VERSION;
+    case.category = category as u8;
+    case.severity = severity as u8;
+    case.created_at = now;
+    case.expires_at = now;
+    case.finalized = 1;
+    case.closed_at = now;
+
+    let scam_index = &mut ctx.accounts.scam_index;
+    scam_index.address = address;
+    scam_index.record_case(case_id);
+    scam_index.approved_cases = scam_index.approved_cases.saturating_add(1);
+    scam_index.flagged = true;
+    scam_index.updated_at = now;
+    scam_index.bump = ctx.bumps.scam_index;
+
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_case();
+        stats.record_verdict(true);
+    }
+
+    let listed_claim = &mut ctx.accounts.listed_claim;
+    listed_claim.list_id = scam_list.list_id;
+    listed_claim.address = address;
+    listed_claim.case_id = case_id;
+    listed_claim.bump = ctx.bumps.listed_claim;
+
+    emit!(ListedAddressClaimed {
+        list_id: scam_list.list_id,
+        case_id,
+        address,
+        claimant: ctx.accounts.claimant.key(),
+        timestamp: now,
+    });
+    msg!("Case {} opened for {} from scam list {}", case_id, address, scam_list.list_id);
+    Ok(())
+}
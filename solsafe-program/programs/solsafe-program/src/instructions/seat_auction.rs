@@ -0,0 +1,391 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, ConfidentialComponents, GlobalConfig, InsuranceFund, JurorPassport,
+    PassportRegistry, SeatBond, ValidatorStake, VoteRecord, SEAT_AUCTION_BOND,
+};
+use crate::{ErrorCode, JurorsSelected, SeatBondForfeited};
+use super::insurance_fund::record_deposit;
+use super::juror_passport::check_passport_requirement;
+use super::select_jurors::fit_panel;
+
+#[derive(Accounts)]
+pub struct ClaimJurorSeat<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Grows by the claimed seat at the claimant's expense
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + SeatBond::LEN,
+        seeds = [b"seat_bond", case_account.load()?.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub seat_bond: Account<'info, SeatBond>,
+    /// Only needed while the config sets a passport requirement
+    #[account(
+        seeds = [b"juror_passport", juror.key().as_ref()],
+        bump = passport.bump
+    )]
+    pub passport: Option<Account<'info, JurorPassport>>,
+    #[account(
+        seeds = [b"passport_registry"],
+        bump = passport_registry.bump
+    )]
+    pub passport_registry: Option<Account<'info, PassportRegistry>>,
+    /// Stake of a self-registered validator; qualifies the claimant without a passport
+    #[account(
+        seeds = [b"validator_stake", juror.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSeatAuction<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseSeatBond<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"case", seat_bond.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", seat_bond.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        mut,
+        close = juror,
+        seeds = [b"seat_bond", seat_bond.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = seat_bond.bump
+    )]
+    pub seat_bond: Account<'info, SeatBond>,
+    /// CHECK: The juror's VoteRecord PDA; empty if they never voted
+    #[account(
+        seeds = [b"vote_record", seat_bond.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub vote_record: UncheckedAccount<'info>,
+    /// Receives the bond if it's forfeited
+    #[account(
+        mut,
+        seeds = [b"insurance_fund"],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+}
+
+pub fn claim_handler(ctx: Context<ClaimJurorSeat>, bond: u64) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let clock = Clock::get()?;
+    let juror = ctx.accounts.juror.key();
+
+    require!(bond >= SEAT_AUCTION_BOND, ErrorCode::InsufficientBond);
+    check_seat_claim(&case, &ctx.accounts.case_panel, &ctx.accounts.config, &juror, clock.unix_timestamp)?;
+    check_seat_qualification(
+        &ctx.accounts.config,
+        &juror,
+        ctx.accounts.validator_stake.as_ref(),
+        ctx.accounts.passport.as_ref(),
+        ctx.accounts.passport_registry.as_ref(),
+    )?;
+    let num_jurors = ctx.accounts.config.panel_size_for(&case) as usize;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.juror.to_account_info(),
+                to: ctx.accounts.seat_bond.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+
+    let seat_bond = &mut ctx.accounts.seat_bond;
+    seat_bond.case_id = case.case_id;
+    seat_bond.juror = juror;
+    seat_bond.amount = bond;
+    seat_bond.bump = ctx.bumps.seat_bond;
+
+    let seat = case.juror_count as usize;
+    let panel = &mut ctx.accounts.case_panel;
+    panel.jurors.push(juror);
+    fit_panel(panel, &ctx.accounts.juror, &ctx.accounts.system_program)?;
+    case.juror_count += 1;
+    case.auction_seats += 1;
+    msg!("Juror {} claimed seat {} on case {}", juror, seat, case.case_id);
+
+    // Last open seat starts voting straight away
+    if case.juror_count as usize == num_jurors {
+        start_voting(&mut case, panel, clock.unix_timestamp);
+    }
+    Ok(())
+}
+
+pub(crate) fn check_seat_claim(
+    case: &CaseAccount,
+    panel: &CasePanel,
+    config: &GlobalConfig,
+    juror: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    require!(case.state == CaseState::SeatAuction as u8, ErrorCode::SeatAuctionClosed);
+    require!(now < case.seat_auction_ends, ErrorCode::SeatAuctionClosed);
+    require!(!panel.is_juror(juror), ErrorCode::AlreadySeated);
+    require!(
+        *juror != case.reporter && *juror != case.scam_address,
+        ErrorCode::SeatClaimantConflicted
+    );
+    // A bond is only forfeitable against a ballot the program can read
+    require!(
+        !case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        ErrorCode::SeatBallotPrivate
+    );
+    let num_jurors = config.panel_size_for(case) as usize;
+    require!((case.juror_count as usize) < num_jurors, ErrorCode::SeatAuctionClosed);
+    Ok(())
+}
+
+/// Seats are open to staked validators, and to jurors whose passport carries a case from
+/// an attester the registry trusts; a configured passport minimum applies on top
+pub(crate) fn check_seat_qualification(
+    config: &GlobalConfig,
+    juror: &Pubkey,
+    validator_stake: Option<&Account<ValidatorStake>>,
+    passport: Option<&Account<JurorPassport>>,
+    registry: Option<&Account<PassportRegistry>>,
+) -> Result<()> {
+    let staked = validator_stake
+        .is_some_and(|stake| stake.validator == *juror && stake.amount >= config.min_validator_stake.max(1));
+    let attested = match (passport, registry) {
+        (Some(passport), Some(registry)) => {
+            passport.juror == *juror && passport.trusted_cases(&registry.attesters) > 0
+        }
+        _ => false,
+    };
+    require!(staked || attested, ErrorCode::SeatClaimantUnqualified);
+    check_passport_requirement(config, juror, passport, registry)
+}
+
+/// Seats an auction must fill before its panel may vote: a majority of the panel
+pub(crate) fn min_auction_seats(num_jurors: usize) -> usize {
+    num_jurors / 2 + 1
+}
+
+/// Permissionless: once the window lapses, vote with whoever was seated if they're a
+/// majority of the panel
+pub fn close_handler(ctx: Context<CloseSeatAuction>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let clock = Clock::get()?;
+
+    check_auction_closable(&case, clock.unix_timestamp)?;
+
+    let num_jurors = ctx.accounts.config.panel_size_for(&case) as usize;
+    if (case.juror_count as usize) < min_auction_seats(num_jurors) {
+        // Too few to vote: unseat everyone and go back for a fresh selection once
+        // validators join. Unseated claimants can release their bonds straight away.
+        msg!(
+            "Seat auction for case {} closed with {} of {} seats",
+            case.case_id,
+            case.juror_count,
+            num_jurors
+        );
+        ctx.accounts.case_panel.jurors.clear();
+        case.juror_count = 0;
+        case.state = CaseState::PendingJurors as u8;
+        case.fallback_seating = 0;
+        return Ok(());
+    }
+
+    start_voting(&mut case, &ctx.accounts.case_panel, clock.unix_timestamp);
+    Ok(())
+}
+
+pub(crate) fn check_auction_closable(case: &CaseAccount, now: i64) -> Result<()> {
+    require!(case.state == CaseState::SeatAuction as u8, ErrorCode::SeatAuctionClosed);
+    require!(now >= case.seat_auction_ends, ErrorCode::SeatAuctionOpen);
+    Ok(())
+}
+
+/// Seat bonds are settled once the case is decided, dismissed, or settled, or as soon as
+/// the claimant no longer holds a seat. The account's rent always goes back to the juror;
+/// the bond itself goes to the insurance fund when it's forfeited.
+pub fn release_handler(ctx: Context<ReleaseSeatBond>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let forfeited = settle_seat_bond(
+        &case,
+        Some(&ctx.accounts.case_panel),
+        &ctx.accounts.seat_bond,
+        &ctx.accounts.vote_record,
+        ctx.accounts.insurance_fund.as_mut(),
+    )?;
+    case.seat_bonds_released = case.seat_bonds_released.saturating_add(1);
+
+    if !forfeited {
+        msg!("Seat bond of {} released to {}", ctx.accounts.seat_bond.amount, ctx.accounts.juror.key());
+    }
+    Ok(())
+}
+
+/// Whether a seat bond is lost. A claimant still seated when the case is decided keeps it
+/// only by having voted with the verdict, and on a dismissed case only by having voted at
+/// all. Claimants a short auction or a recusal unseated, and those on a settled case,
+/// keep theirs. A case without a panel never seated anyone.
+pub(crate) fn seat_bond_forfeited(
+    case: &CaseAccount,
+    panel: Option<&CasePanel>,
+    juror: &Pubkey,
+    vote: Option<&VoteRecord>,
+) -> Result<bool> {
+    if !panel.is_some_and(|panel| panel.is_juror(juror)) {
+        return Ok(false);
+    }
+    check_bond_releasable(case)?;
+    let state = case.state;
+    let voted_with = |approve: bool| vote.is_some_and(|vote| vote.approved == approve);
+    Ok(if state == CaseState::Approved as u8 || state == CaseState::Executed as u8 {
+        !voted_with(true)
+    } else if state == CaseState::Rejected as u8 {
+        !voted_with(false)
+    } else if state == CaseState::Dismissed as u8 {
+        vote.is_none()
+    } else {
+        false
+    })
+}
+
+/// Pay the bond into the insurance fund if it's forfeited, leaving only rent for the caller
+/// to close back to the juror. `vote_record` must be the juror's VoteRecord PDA.
+pub(crate) fn settle_seat_bond(
+    case: &CaseAccount,
+    panel: Option<&CasePanel>,
+    seat_bond: &Account<SeatBond>,
+    vote_record: &AccountInfo,
+    insurance_fund: Option<&mut Account<InsuranceFund>>,
+) -> Result<bool> {
+    let vote = if vote_record.owner == &crate::ID && !vote_record.data_is_empty() {
+        Some(VoteRecord::try_deserialize(&mut &vote_record.try_borrow_data()?[..])?)
+    } else {
+        None
+    };
+    if !seat_bond_forfeited(case, panel, &seat_bond.juror, vote.as_ref())? {
+        return Ok(false);
+    }
+
+    let insurance_fund = insurance_fund.ok_or(ErrorCode::InsuranceFundRequired)?;
+    **seat_bond.to_account_info().try_borrow_mut_lamports()? -= seat_bond.amount;
+    **insurance_fund.to_account_info().try_borrow_mut_lamports()? += seat_bond.amount;
+    record_deposit(insurance_fund, seat_bond.amount)?;
+
+    emit!(SeatBondForfeited {
+        case_id: case.case_id,
+        juror: seat_bond.juror,
+        amount: seat_bond.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Seat bond of {} forfeited by {}", seat_bond.amount, seat_bond.juror);
+    Ok(true)
+}
+
+pub(crate) fn check_bond_releasable(case: &CaseAccount) -> Result<()> {
+    require!(
+        case.state == CaseState::Approved as u8
+            || case.state == CaseState::Rejected as u8
+            || case.state == CaseState::Executed as u8
+            || case.state == CaseState::Dismissed as u8
+            || case.state == CaseState::Settled as u8,
+        ErrorCode::CaseNotDecided
+    );
+    Ok(())
+}
+
+fn start_voting(case: &mut CaseAccount, panel: &CasePanel, timestamp: i64) {
+    case.state = CaseState::Voting as u8;
+    emit!(JurorsSelected {
+        case_id: case.case_id,
+        jurors: panel.jurors.clone(),
+        timestamp,
+    });
+    msg!("Case {} panel filled with {} auction seats", case.case_id, case.auction_seats);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+    use crate::state::Verdict;
+
+    fn ballot(juror: Pubkey, verdict: Verdict) -> VoteRecord {
+        VoteRecord { juror, case_id: 1, approved: verdict.approves(), verdict, timestamp: 0, bump: 0 }
+    }
+
+    #[test]
+    fn test_seat_bond_follows_the_verdict() {
+        let (juror, unseated) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut case = CaseAccount::zeroed();
+        case.juror_count = 1;
+        case.fallback_seating = 1;
+        let panel = CasePanel { case_id: 0, jurors: vec![juror], recused: vec![], bump: 0 };
+        let panel = Some(&panel);
+
+        case.state = CaseState::Voting as u8;
+        assert!(seat_bond_forfeited(&case, panel, &juror, None).is_err());
+        assert!(!seat_bond_forfeited(&case, panel, &unseated, None).unwrap());
+
+        case.state = CaseState::Approved as u8;
+        assert!(!seat_bond_forfeited(&case, panel, &juror, Some(&ballot(juror, Verdict::FreezeOnly))).unwrap());
+        assert!(seat_bond_forfeited(&case, panel, &juror, Some(&ballot(juror, Verdict::Dismiss))).unwrap());
+        assert!(seat_bond_forfeited(&case, panel, &juror, None).unwrap());
+
+        case.state = CaseState::Rejected as u8;
+        assert!(!seat_bond_forfeited(&case, panel, &juror, Some(&ballot(juror, Verdict::Dismiss))).unwrap());
+        assert!(seat_bond_forfeited(&case, panel, &juror, Some(&ballot(juror, Verdict::Warn))).unwrap());
+
+        case.state = CaseState::Dismissed as u8;
+        assert!(seat_bond_forfeited(&case, panel, &juror, None).unwrap());
+        case.state = CaseState::Settled as u8;
+        assert!(!seat_bond_forfeited(&case, panel, &juror, None).unwrap());
+    }
+
+    #[test]
+    fn test_auction_needs_a_majority_of_the_panel() {
+        assert_eq!(min_auction_seats(1), 1);
+        assert_eq!(min_auction_seats(5), 3);
+        assert_eq!(min_auction_seats(6), 4);
+    }
+}
@@ -1,76 +1,337 @@
 use anchor_lang::prelude::*;
-use crate::state::{CaseAccount, CaseState, GlobalConfig};
-use crate::ErrorCode;
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, GlobalConfig, JURY_ACCEPTANCE_WINDOW, MAX_CASE_JURORS, SEAT_AUCTION_WINDOW,
+    VALIDATORS_PER_PAGE,
+};
+use crate::randomness;
+use super::heartbeat::{heartbeat_address, is_live};
+use super::juror_reputation::{accept_weighted, reputation_weight};
+use super::migrate::grow;
+use super::validator_registry::find_page;
+use crate::{ErrorCode, JurorsSelected, SeatAuctionOpened};
+
+/// Draws before giving up; rejections are vanishingly rare, so this only trips when too
+/// few live validators remain
+const MAX_SELECTION_ATTEMPTS: u32 = 1000;
 
 #[derive(Accounts)]
 pub struct SelectJurors<'info> {
+    /// Pays for the panel account as it grows to the drawn size
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(mut)]
-    pub case_account: Account<'info, CaseAccount>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    /// Created empty on the first draw; a later round redraws into it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CasePanel::len_for(0, 0),
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
     #[account(
         seeds = [b"config"],
         bump = config.bump
     )]
     pub config: Account<'info, GlobalConfig>,
-    /// CHECK: Switchboard VRF account - must be provided by client after VRF reveal
+    /// CHECK: Randomness request committed to in request_jurors; owner, layout, and
+    /// reveal status are checked against the case's randomness source in the handler
     pub vrf_account: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    // Remaining accounts: the ValidatorPage PDAs holding each drawn index, plus each
+    // candidate's ValidatorHeartbeat when config.heartbeat_epochs is set and JurorProfile
+    // when config.reputation_weighting is. Passing every page and every heartbeat address
+    // lets the handler see that too few are live and open the seat auction.
 }
 
-pub fn handler(ctx: Context<SelectJurors>) -> Result<()> {
-    let case = &mut ctx.accounts.case_account;
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SelectJurors<'info>>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
     let config = &ctx.accounts.config;
 
     require!(case.vrf_request != Pubkey::default(), ErrorCode::VrfNotReady);
-    require!(case.state == CaseState::PendingJurors, ErrorCode::InvalidCase);
-    require!(case.vrf_request == ctx.accounts.vrf_account.key(), ErrorCode::InvalidCase);
-
-    // Read Switchboard VRF randomness from account data
-    // The VRF account stores the randomness result after reveal
-    // Format: [discriminator: 8 bytes][randomness: 32 bytes][...]
-    let vrf_data = ctx.accounts.vrf_account.try_borrow_data()?;
-    require!(vrf_data.len() >= 40, ErrorCode::VrfNotReady);
-    
-    // Extract 32 bytes of randomness (skip 8-byte discriminator)
-    let mut randomness = [0u8; 32];
-    randomness.copy_from_slice(&vrf_data[8..40]);
-
-    let num_jurors: usize = config.min_jurors as usize;
-    let validator_count = config.validator_list.len();
-    require!(validator_count >= num_jurors, ErrorCode::NotEnoughValidators);
-
-    // Store all candidates for verification
-    case.juror_candidates = config.validator_list.clone();
-
-    // Select jurors using Switchboard's true randomness with duplicate prevention
+    require!(case.state == CaseState::PendingJurors as u8, ErrorCode::InvalidCase);
+    let panel = &mut ctx.accounts.case_panel;
+    panel.case_id = case.case_id;
+    panel.bump = ctx.bumps.case_panel;
+
+    // Only the value revealed for the request request_jurors committed to is accepted
+    let randomness = randomness::revealed_value(&case, &ctx.accounts.vrf_account)?;
+    case.randomness = randomness;
+
+    let num_jurors: usize = config.panel_size_for(&case) as usize;
+    let validator_count = config.validator_count as usize;
+    require!(num_jurors <= MAX_CASE_JURORS, ErrorCode::TooManyJurors);
+
+    let epoch = Clock::get()?.epoch;
+
+    // Stale heartbeats can leave fewer live validators than seats in a larger registry,
+    // where draws would only run out of attempts
+    let live_count = if config.heartbeat_epochs > 0 {
+        count_live(ctx.remaining_accounts, config, epoch)
+    } else {
+        Some(validator_count)
+    };
+    if validator_count < num_jurors || live_count.is_some_and(|live| live < num_jurors) {
+        open_seat_auction(&mut case, panel, config, ctx.remaining_accounts, validator_count, num_jurors)?;
+        return fit_panel(panel, &ctx.accounts.payer, &ctx.accounts.system_program);
+    }
+
+    // Rejection-sample registry indices from the VRF output: no modulo bias, and an index
+    // (or a validator listed twice) is never seated twice. Stale heartbeats are drawn past.
+    // With reputation weighting on, a drawn validator is kept with probability equal to its
+    // weight; one turned away stays in the pool for later draws.
     let mut selected = Vec::with_capacity(num_jurors);
-    let mut selected_indices = Vec::with_capacity(num_jurors);
+    let mut drawn_indices = Vec::with_capacity(num_jurors);
     let mut attempt = 0u32;
-    
+
     while selected.len() < num_jurors {
-        require!(attempt < 1000, ErrorCode::JurorSelectionFailed);
-        
-        // Use checked arithmetic to prevent overflow
-        let offset = attempt.checked_mul(4)
-            .ok_or(ErrorCode::ArithmeticOverflow)? as usize;
-        
-        let idx = u32::from_le_bytes([
-            randomness[offset % 32],
-            randomness[(offset + 1) % 32],
-            randomness[(offset + 2) % 32],
-            randomness[(offset + 3) % 32]
-        ]) as usize % validator_count;
-        
-        // Only add if not already selected (prevent duplicates)
-        if !selected_indices.contains(&idx) {
-            selected_indices.push(idx);
-            selected.push(config.validator_list[idx]);
+        require!(attempt < MAX_SELECTION_ATTEMPTS, ErrorCode::JurorSelectionFailed);
+        let draw = uniform_index(&randomness, case.case_id, attempt, validator_count);
+        attempt += 1;
+
+        let idx = match draw {
+            Some(idx) if !drawn_indices.contains(&idx) => idx,
+            _ => continue,
+        };
+
+        let page = find_page(ctx.remaining_accounts, (idx / VALIDATORS_PER_PAGE) as u16)?;
+        let validator = *page
+            .validators
+            .get(idx % VALIDATORS_PER_PAGE)
+            .ok_or(ErrorCode::InvalidValidatorPage)?;
+        if config.reputation_weighting {
+            let weight = reputation_weight(ctx.remaining_accounts, &validator);
+            if !accept_weighted(&randomness, case.case_id, attempt, weight) {
+                continue;
+            }
+        }
+        drawn_indices.push(idx);
+        if !selected.contains(&validator) && is_live(ctx.remaining_accounts, config, &validator, epoch) {
+            selected.push(validator);
         }
-        
-        attempt = attempt.checked_add(1)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
     }
-    
-    case.jurors = selected;
-    case.state = CaseState::Voting;
+
+    panel.jurors = selected.clone();
+    fit_panel(panel, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+    case.juror_count = num_jurors as u8;
+
+    // Nominees must accept before voting opens; see accept_jury_duty
+    let now = Clock::get()?.unix_timestamp;
+    case.state = CaseState::Nominated as u8;
+    case.accepted_seats = 0;
+    case.acceptance_deadline = now
+        .checked_add(JURY_ACCEPTANCE_WINDOW)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(JurorsSelected {
+        case_id: case.case_id,
+        jurors: selected,
+        timestamp: now,
+    });
 
     Ok(())
 }
+
+/// Live validators across the registry, or `None` unless every page and every validator's
+/// heartbeat address is among `accounts`. A heartbeat that was never created is passed as
+/// the empty account, so a caller can't shrink the pool by leaving accounts out.
+fn count_live<'info>(accounts: &'info [AccountInfo<'info>], config: &GlobalConfig, epoch: u64) -> Option<usize> {
+    let mut live = 0;
+    for page_index in 0..config.page_count {
+        let page = find_page(accounts, page_index).ok()?;
+        for validator in page.validators.iter() {
+            let address = heartbeat_address(validator);
+            if !accounts.iter().any(|info| info.key() == address) {
+                return None;
+            }
+            if is_live(accounts, config, validator, epoch) {
+                live += 1;
+            }
+        }
+    }
+    Some(live)
+}
+
+/// Grow `panel` to hold its entries before Anchor writes it back; the payer covers the rent
+pub(crate) fn fit_panel<'info>(
+    panel: &Account<'info, CasePanel>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    grow(
+        &panel.to_account_info(),
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        8 + panel.required_len(),
+    )
+}
+
+/// Too few validators: seat every live one and open the rest to bonded claimants
+fn open_seat_auction<'info>(
+    case: &mut CaseAccount,
+    panel: &mut CasePanel,
+    config: &GlobalConfig,
+    accounts: &'info [AccountInfo<'info>],
+    validator_count: usize,
+    num_jurors: usize,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    panel.jurors.clear();
+    for idx in 0..validator_count {
+        let page = find_page(accounts, (idx / VALIDATORS_PER_PAGE) as u16)?;
+        let validator = *page
+            .validators
+            .get(idx % VALIDATORS_PER_PAGE)
+            .ok_or(ErrorCode::InvalidValidatorPage)?;
+        if is_live(accounts, config, &validator, clock.epoch) {
+            panel.jurors.push(validator);
+        }
+    }
+    let seated = panel.jurors.len();
+    case.juror_count = seated as u8;
+
+    case.fallback_seating = 1;
+    case.seat_auction_ends = clock
+        .unix_timestamp
+        .checked_add(SEAT_AUCTION_WINDOW)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    case.state = CaseState::SeatAuction as u8;
+
+    emit!(SeatAuctionOpened {
+        case_id: case.case_id,
+        seated_validators: seated as u8,
+        open_seats: (num_jurors - seated) as u8,
+        ends_at: case.seat_auction_ends,
+    });
+
+    msg!("Not enough validators; {} seats opened for auction", num_jurors - seated);
+    Ok(())
+}
+
+/// The `attempt`-th draw from `randomness` as an index below `count`, or `None` when the
+/// draw lands in the biased tail and must be rejected. Each attempt hashes a fresh word,
+/// so the sequence never cycles.
+pub(crate) fn uniform_index(randomness: &[u8; 32], case_id: u64, attempt: u32, count: usize) -> Option<usize> {
+    let digest = crate::domain::hash(
+        crate::domain::JUROR_SELECTION,
+        &[&case_id.to_le_bytes(), randomness, &attempt.to_le_bytes()],
+    );
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest[..8]);
+    unbiased_index(u64::from_le_bytes(word), count)
+}
+
+/// `word` reduced below `count`, or `None` when it falls in the tail that would bias the
+/// reduction. Shared with seat replacement in recusal.rs.
+pub(crate) fn unbiased_index(word: u64, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    // Accept only the largest multiple of `count` values, so every index is equally likely
+    let count = count as u64;
+    let tail = (u64::MAX % count + 1) % count;
+    if word > u64::MAX - tail {
+        return None;
+    }
+    Some((word % count) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use crate::state::{ValidatorHeartbeat, ValidatorPage};
+
+    /// Off-chain `Clock::get` fails without a runtime; liveness only needs an epoch
+    struct ClockStub;
+    impl SyscallStubs for ClockStub {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Clock) = Clock::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    fn leaked_account(address: Pubkey, data: Vec<u8>, owner: &'static Pubkey) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(address)),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            data.leak(),
+            owner,
+            false,
+            0,
+        )
+    }
+
+    fn serialized<T: AccountSerialize>(account: T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_count_live_needs_every_heartbeat_address() {
+        set_syscall_stubs(Box::new(ClockStub));
+        let validators: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let mut config = GlobalConfig::deserialize(&mut &[0u8; GlobalConfig::LEN][..]).unwrap();
+        config.validator_count = validators.len() as u32;
+        config.page_count = 1;
+        config.heartbeat_epochs = 2;
+
+        let (page_address, _) = Pubkey::find_program_address(&[b"validator_page", &0u16.to_le_bytes()], &crate::ID);
+        let page = ValidatorPage { page_index: 0, validators: validators.clone(), bump: 0 };
+        let mut accounts = vec![leaked_account(page_address, serialized(page), &crate::ID)];
+        // Two validators beat this epoch; the others never created a heartbeat
+        for (i, validator) in validators.iter().enumerate() {
+            let address = heartbeat_address(validator);
+            accounts.push(if i < 2 {
+                let heartbeat = ValidatorHeartbeat { validator: *validator, last_epoch: 0, last_beat_at: 0, beats: 1, bump: 0 };
+                leaked_account(address, serialized(heartbeat), &crate::ID)
+            } else {
+                leaked_account(address, vec![], &anchor_lang::system_program::ID)
+            });
+        }
+        let accounts: &'static [AccountInfo<'static>] = Box::leak(accounts.into_boxed_slice());
+
+        assert_eq!(count_live(accounts, &config, 0), Some(2));
+        // Leaving out a never-created heartbeat doesn't count it as stale
+        assert_eq!(count_live(&accounts[..accounts.len() - 1], &config, 0), None);
+    }
+
+    #[test]
+    fn test_uniform_index_stays_in_range() {
+        let randomness = [7u8; 32];
+        for attempt in 0..200 {
+            if let Some(idx) = uniform_index(&randomness, 1, attempt, 13) {
+                assert!(idx < 13);
+            }
+        }
+        assert_eq!(uniform_index(&randomness, 1, 0, 0), None);
+    }
+
+    #[test]
+    fn test_uniform_index_does_not_cycle() {
+        // The old loop reread the same 32 bytes every 8 attempts; fresh draws must reach
+        // every index of a larger registry
+        let randomness = [42u8; 32];
+        let mut seen = [false; 64];
+        for attempt in 0..1000 {
+            if let Some(idx) = uniform_index(&randomness, 9, attempt, seen.len()) {
+                seen[idx] = true;
+            }
+        }
+        assert!(seen.iter().all(|hit| *hit));
+    }
+
+    #[test]
+    fn test_uniform_index_rejects_biased_tail() {
+        // With count = 2^63 + 1 nearly half the word space is the biased tail
+        let count = (1usize << 63) + 1;
+        let rejected = (0..200)
+            .filter(|attempt| uniform_index(&[3u8; 32], 5, *attempt, count).is_none())
+            .count();
+        assert!(rejected > 50 && rejected < 150);
+    }
+}
@@ -0,0 +1,431 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{
+    CaseAccount, CasePanel, CaseState, CaseStatus, RestitutionEscrow, SettlementApproval, SettlementOffer,
+    SettlementStatus, SETTLEMENT_BOND, SETTLEMENT_FUNDING_WINDOW,
+};
+use crate::{CaseSettled, ErrorCode, SettlementDefaulted};
+use super::restitution::open_escrow;
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct ProposeSettlement<'info> {
+    #[account(mut)]
+    pub accused: Signer<'info>,
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump,
+        constraint = case_account.load()?.scam_address == accused.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = accused,
+        space = 8 + SettlementOffer::LEN,
+        seeds = [b"settlement", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+    #[account(
+        init,
+        payer = accused,
+        seeds = [b"settlement_vault", case_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = program_authority
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    /// CHECK: Program authority PDA that owns the settlement vault
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSettlement<'info> {
+    pub reporter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"case", offer.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump,
+        constraint = case_account.load()?.reporter == reporter.key() @ ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveSettlement<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"case", offer.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + SettlementApproval::LEN,
+        seeds = [b"settlement_approval", offer.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub approval: Account<'info, SettlementApproval>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundSettlement<'info> {
+    pub accused: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump,
+        constraint = offer.accused == accused.key() @ ErrorCode::Unauthorized
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+    #[account(
+        mut,
+        constraint = accused_token_account.owner == accused.key() @ ErrorCode::Unauthorized,
+        constraint = accused_token_account.mint == offer.mint @ ErrorCode::EscrowMintMismatch
+    )]
+    pub accused_token_account: Account<'info, TokenAccount>,
+    #[account(mut, address = offer.vault)]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSettlement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"case", offer.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+    /// CHECK: Receives the settlement bond back
+    #[account(mut, address = offer.accused)]
+    pub accused: UncheckedAccount<'info>,
+    #[account(mut, address = offer.vault)]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    #[account(address = offer.mint @ ErrorCode::EscrowMintMismatch)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RestitutionEscrow::LEN,
+        seeds = [b"escrow", offer.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, RestitutionEscrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"escrow_vault", offer.case_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = program_authority
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+    /// CHECK: Program authority PDA that owns both vaults
+    #[account(
+        seeds = [b"authority"],
+        bump
+    )]
+    pub program_authority: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DefaultSettlement<'info> {
+    #[account(
+        mut,
+        seeds = [b"case", offer.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+    /// CHECK: Receives the forfeited settlement bond
+    #[account(mut, address = case_account.load()?.reporter @ ErrorCode::Unauthorized)]
+    pub reporter: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSettlement<'info> {
+    #[account(mut)]
+    pub accused: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"settlement", offer.case_id.to_le_bytes().as_ref()],
+        bump = offer.bump,
+        constraint = offer.accused == accused.key() @ ErrorCode::Unauthorized
+    )]
+    pub offer: Account<'info, SettlementOffer>,
+}
+
+/// Settlement is only possible while the case has no verdict yet
+fn is_pre_verdict(state: u8) -> bool {
+    state == CaseState::PendingJurors as u8
+        || state == CaseState::SeatAuction as u8
+        || state == CaseState::Nominated as u8
+        || state == CaseState::Voting as u8
+}
+
+/// Once the reporter and enough jurors agree, voting halts and the funding clock starts
+fn lock_if_agreed(case: &mut CaseAccount, offer: &mut SettlementOffer, now: i64) {
+    if offer.status != SettlementStatus::Open || !offer.is_agreed(case.juror_count) {
+        return;
+    }
+    offer.status = SettlementStatus::Agreed;
+    offer.funding_deadline = now + SETTLEMENT_FUNDING_WINDOW;
+    case.state = CaseState::SettlementPending as u8;
+    msg!("Settlement on case {} agreed; fund by {}", offer.case_id, offer.funding_deadline);
+}
+
+/// Move the bond the offer account holds on top of rent
+fn pay_bond(offer: &mut Account<SettlementOffer>, to: &AccountInfo) -> Result<()> {
+    let bond = offer.bond;
+    **offer.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **to.try_borrow_mut_lamports()? += bond;
+    offer.bond = 0;
+    Ok(())
+}
+
+pub fn propose_handler(ctx: Context<ProposeSettlement>, case_id: u64, amount: u64) -> Result<()> {
+    require!(
+        is_pre_verdict(ctx.accounts.case_account.load()?.state),
+        ErrorCode::SettlementNotAllowed
+    );
+    require!(amount > 0, ErrorCode::NothingToClaim);
+
+    // The bond keeps offers honest: it is lost if the accused agrees and never funds
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.accused.to_account_info(),
+                to: ctx.accounts.offer.to_account_info(),
+            },
+        ),
+        SETTLEMENT_BOND,
+    )?;
+
+    let offer = &mut ctx.accounts.offer;
+    offer.case_id = case_id;
+    offer.accused = ctx.accounts.accused.key();
+    offer.mint = ctx.accounts.mint.key();
+    offer.vault = ctx.accounts.settlement_vault.key();
+    offer.amount = amount;
+    offer.bond = SETTLEMENT_BOND;
+    offer.reporter_accepted = false;
+    offer.juror_approvals = 0;
+    offer.status = SettlementStatus::Open;
+    offer.created_at = Clock::get()?.unix_timestamp;
+    offer.funding_deadline = 0;
+    offer.bump = ctx.bumps.offer;
+
+    msg!("Settlement of {} offered on case {}", amount, case_id);
+    Ok(())
+}
+
+pub fn accept_handler(ctx: Context<AcceptSettlement>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let offer = &mut ctx.accounts.offer;
+    require!(offer.status == SettlementStatus::Open, ErrorCode::SettlementNotAllowed);
+    require!(is_pre_verdict(case.state), ErrorCode::SettlementNotAllowed);
+    offer.reporter_accepted = true;
+
+    msg!("Reporter accepted settlement on case {}", offer.case_id);
+    lock_if_agreed(&mut case, offer, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+pub fn approve_handler(ctx: Context<ApproveSettlement>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let offer = &mut ctx.accounts.offer;
+    let juror = ctx.accounts.juror.key();
+
+    require!(offer.status == SettlementStatus::Open, ErrorCode::SettlementNotAllowed);
+    require!(is_pre_verdict(case.state), ErrorCode::SettlementNotAllowed);
+    require!(ctx.accounts.case_panel.is_juror(&juror), ErrorCode::NotJuror);
+
+    offer.juror_approvals = offer.juror_approvals.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let approval = &mut ctx.accounts.approval;
+    approval.case_id = offer.case_id;
+    approval.juror = juror;
+    approval.bump = ctx.bumps.approval;
+
+    msg!(
+        "Settlement approvals on case {}: {}/{}",
+        offer.case_id,
+        offer.juror_approvals,
+        SettlementOffer::required_approvals(case.juror_count)
+    );
+    lock_if_agreed(&mut case, offer, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+/// Pay the agreed amount into the settlement vault before the deadline
+pub fn fund_handler(ctx: Context<FundSettlement>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    require!(offer.status == SettlementStatus::Agreed, ErrorCode::SettlementNotAllowed);
+    require!(
+        Clock::get()?.unix_timestamp < offer.funding_deadline,
+        ErrorCode::SettlementFundingExpired
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.accused_token_account.to_account_info(),
+                to: ctx.accounts.settlement_vault.to_account_info(),
+                authority: ctx.accounts.accused.to_account_info(),
+            },
+        ),
+        offer.amount,
+    )?;
+    offer.status = SettlementStatus::Funded;
+
+    msg!("Settlement on case {} funded with {}", offer.case_id, offer.amount);
+    Ok(())
+}
+
+/// Permissionless once funded: moves the settlement into restitution and closes the case
+pub fn execute_handler(ctx: Context<ExecuteSettlement>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let offer = &mut ctx.accounts.offer;
+    let clock = Clock::get()?;
+
+    require!(offer.status == SettlementStatus::Funded, ErrorCode::SettlementNotAccepted);
+    require!(
+        case.state == CaseState::SettlementPending as u8,
+        ErrorCode::SettlementNotAllowed
+    );
+
+    let escrow = &mut ctx.accounts.escrow;
+    open_escrow(
+        escrow,
+        offer.case_id,
+        offer.mint,
+        ctx.accounts.escrow_vault.key(),
+        ctx.bumps.escrow,
+        clock.unix_timestamp,
+    )?;
+
+    let bump = ctx.bumps.program_authority;
+    let seeds: &[&[u8]] = &[&b"authority"[..], &[bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.settlement_vault.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        offer.amount,
+    )?;
+
+    escrow.total_seized = escrow
+        .total_seized
+        .checked_add(offer.amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    offer.status = SettlementStatus::Executed;
+    pay_bond(offer, &ctx.accounts.accused.to_account_info())?;
+
+    // Settled cases skip freeze execution entirely
+    case.state = CaseState::Settled as u8;
+    case.status = CaseStatus::Closed as u8;
+    case.closed_at = clock.unix_timestamp;
+
+    emit!(CaseSettled {
+        case_id: offer.case_id,
+        accused: offer.accused,
+        mint: offer.mint,
+        amount: offer.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Case {} settled for {}", offer.case_id, offer.amount);
+    Ok(())
+}
+
+/// Permissionless after the funding deadline: the case resumes voting with the default on record
+pub fn default_handler(ctx: Context<DefaultSettlement>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let offer = &mut ctx.accounts.offer;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(offer.status == SettlementStatus::Agreed, ErrorCode::SettlementNotAllowed);
+    require!(now >= offer.funding_deadline, ErrorCode::SettlementFundingOpen);
+
+    case.state = CaseState::Voting as u8;
+    case.settlement_defaulted = 1;
+
+    offer.status = SettlementStatus::Defaulted;
+    let forfeited = offer.bond;
+    pay_bond(offer, &ctx.accounts.reporter.to_account_info())?;
+
+    emit!(SettlementDefaulted {
+        case_id: offer.case_id,
+        accused: offer.accused,
+        forfeited,
+        timestamp: now,
+    });
+
+    msg!("Settlement on case {} defaulted; voting resumes", offer.case_id);
+    Ok(())
+}
+
+/// Pull an offer nobody has agreed to yet and recover the bond
+pub fn withdraw_handler(ctx: Context<WithdrawSettlement>) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    require!(offer.status == SettlementStatus::Open, ErrorCode::SettlementNotAllowed);
+
+    offer.status = SettlementStatus::Withdrawn;
+    pay_bond(offer, &ctx.accounts.accused.to_account_info())?;
+
+    msg!("Settlement offer on case {} withdrawn", offer.case_id);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{ApprovalThreshold, CaseSeverity, GlobalConfig};
+use crate::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetSeverityThreshold<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+pub fn handler(ctx: Context<SetSeverityThreshold>, severity: CaseSeverity, threshold: ApprovalThreshold) -> Result<()> {
+    apply_severity_threshold(&mut ctx.accounts.config, severity, threshold)
+}
+
+/// A zeroed threshold clears the tier; category overrides still take precedence. Only
+/// cases opened afterwards are affected.
+pub(crate) fn apply_severity_threshold(
+    config: &mut GlobalConfig,
+    severity: CaseSeverity,
+    threshold: ApprovalThreshold,
+) -> Result<()> {
+    let unset = threshold.numerator == 0 && threshold.denominator == 0;
+    require!(
+        unset || threshold.numerator < threshold.denominator,
+        ErrorCode::InvalidThreshold
+    );
+    config.severity_thresholds[severity as usize] = threshold;
+
+    msg!(
+        "Severity {} approval threshold set to {}/{}",
+        severity as u8,
+        threshold.numerator,
+        threshold.denominator
+    );
+    Ok(())
+}
@@ -1,41 +1,261 @@
 use anchor_lang::prelude::*;
-use crate::state::{CaseAccount, CaseStatus, CaseState};
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::{ActiveCaseMarker, Analyst, CaseAccount, CaseCounter, CaseCategory, CaseValueTier, EvidenceNotarization, GlobalConfig, CaseRound, CaseSeverity, CaseStatus, CaseState, PriceFeedRegistry, ProtocolStats, ScamIndex, ThreatIntel, ThreatPatternKind, Treasury, CASE_ACCOUNT_VERSION};
+use crate::{CaseCreated, ErrorCode};
+use super::case_bond::{price_case_bond, HoldingQuote};
+use super::duplicate_case::claim_marker;
+use super::notarization::notarize_evidence;
+use super::treasury::collect_submission_fee;
 
 #[derive(Accounts)]
-#[instruction(case_id: u64)]
+#[instruction(case_id: u64, evidence_uri: String, evidence_hash: [u8; 32], scam_address: Pubkey)]
 pub struct SubmitEvidence<'info> {
     #[account(
         init,
         payer = reporter,
-        space = 8 + 8 + 32 + 4 + 256 + 4 + (32 * 3) + 4 + (32 * 100) + 8 + 8 + 4 + (32 * 3) + 1 + 1 + 32 + 1,
+        space = 8 + CaseAccount::LEN,
         seeds = [b"case", case_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub case_account: Account<'info, CaseAccount>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
     #[account(mut)]
     pub reporter: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Assigns case ids in order; `case_id` must be its next id
+    #[account(
+        mut,
+        seeds = [b"case_counter"],
+        bump = case_counter.bump
+    )]
+    pub case_counter: Account<'info, CaseCounter>,
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + ScamIndex::LEN,
+        seeds = [b"scam_index", scam_address.as_ref()],
+        bump
+    )]
+    pub scam_index: Account<'info, ScamIndex>,
+    /// Only one open case per address; later reports go through link_duplicate
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + ActiveCaseMarker::LEN,
+        seeds = [b"active_case", scam_address.as_ref()],
+        bump
+    )]
+    pub active_case: Account<'info, ActiveCaseMarker>,
+    /// CHECK: The case the marker currently points at; required once the address has had a case
+    pub prior_case: Option<UncheckedAccount<'info>>,
+    /// Receives the submission fee; required while one is configured
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+    /// Optional known-drainer entry the reporter links this case to
+    pub threat_intel: Option<Account<'info, ThreatIntel>>,
     pub system_program: Program<'info, System>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    /// Pricing inputs for the accused's holding; required while config.case_bond_bps is
+    /// set, otherwise they only fill in the case's value tier
+    #[account(
+        seeds = [b"price_feeds"],
+        bump = price_feeds.bump
+    )]
+    pub price_feeds: Option<Account<'info, PriceFeedRegistry>>,
+    pub scam_holding: Option<Account<'info, TokenAccount>>,
+    pub holding_mint: Option<Account<'info, Mint>>,
+    /// CHECK: Pyth PriceUpdateV2 for the holding's mint; checked against the registry
+    pub token_price_update: Option<UncheckedAccount<'info>>,
+    /// CHECK: Pyth PriceUpdateV2 for SOL/USD; checked against the registry
+    pub sol_price_update: Option<UncheckedAccount<'info>>,
+    /// Registered analyst co-signing the evidence hash through an Ed25519 program
+    /// instruction placed right before this one; needs the two accounts below
+    #[account(
+        mut,
+        seeds = [b"analyst", analyst.authority.as_ref()],
+        bump = analyst.bump,
+        constraint = analyst.active @ ErrorCode::Unauthorized
+    )]
+    pub analyst: Option<Account<'info, Analyst>>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + EvidenceNotarization::LEN,
+        seeds = [b"notarization", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub notarization: Option<Account<'info, EvidenceNotarization>>,
+    /// CHECK: Instructions sysvar, read for the Ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handler(
     ctx: Context<SubmitEvidence>,
     case_id: u64,
-    evidence: String,
+    evidence_uri: String,
+    evidence_hash: [u8; 32],
     scam_address: Pubkey,
-    bump: u8,
+    category: CaseCategory,
+    evidence_tx_root: [u8; 32],
 ) -> Result<()> {
-    let case = &mut ctx.accounts.case_account;
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    ctx.accounts.case_counter.assign(case_id)?;
+    let clock = Clock::get()?;
+
+    let (bond, reported_value_usd) = price_case_bond(
+        &ctx.accounts.config,
+        HoldingQuote {
+            registry: ctx.accounts.price_feeds.as_ref(),
+            holding: ctx.accounts.scam_holding.as_ref(),
+            mint: ctx.accounts.holding_mint.as_ref(),
+            token_price: ctx.accounts.token_price_update.as_ref().map(|info| info.as_ref()),
+            sol_price: ctx.accounts.sol_price_update.as_ref().map(|info| info.as_ref()),
+        },
+        &scam_address,
+        clock.unix_timestamp,
+    )?;
+    collect_submission_fee(
+        bond,
+        ctx.accounts.treasury.as_mut(),
+        ctx.accounts.reporter.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_case();
+    }
+    match (
+        ctx.accounts.analyst.as_mut(),
+        ctx.accounts.notarization.as_mut(),
+        ctx.accounts.instructions_sysvar.as_ref(),
+    ) {
+        (Some(analyst), Some(notarization), Some(instructions_sysvar)) => notarize_evidence(
+            analyst,
+            notarization,
+            ctx.bumps.notarization.ok_or(ErrorCode::InvalidNotarization)?,
+            instructions_sysvar,
+            case_id,
+            evidence_hash,
+            clock.unix_timestamp,
+        )?,
+        (None, None, _) => {}
+        _ => return err!(ErrorCode::InvalidNotarization),
+    }
+
+    let active_case = &mut ctx.accounts.active_case;
+    claim_marker(
+        active_case,
+        ctx.accounts.prior_case.as_ref().map(|info| info.as_ref()),
+        scam_address,
+        case_id,
+        clock.unix_timestamp,
+    )?;
+    active_case.bump = ctx.bumps.active_case;
+
+    // Freshly allocated account is zeroed: no jurors, candidates, votes, or vrf request yet
+    let mut case = ctx.accounts.case_account.load_init()?;
     case.case_id = case_id;
     case.scam_address = scam_address;
-    case.evidence = evidence;
-    case.status = CaseStatus::Open;
-    case.state = CaseState::PendingJurors;
-    case.votes_for = 0;
-    case.votes_against = 0;
-    case.jurors = vec![];
-    case.juror_candidates = vec![];
-    case.voted_jurors = vec![];
-    case.vrf_request = Pubkey::default();
-    case.bump = bump;
+    case.reporter = ctx.accounts.reporter.key();
+    case.set_evidence(&evidence_uri, evidence_hash)?;
+    case.evidence_tx_root = evidence_tx_root;
+    case.status = CaseStatus::Open as u8;
+    case.state = CaseState::PendingJurors as u8;
+    case.bump = ctx.bumps.case_account;
+    case.version = CASE_ACCOUNT_VERSION;
+    case.severity = CaseSeverity::Medium as u8;
+    case.threat_id = 0;
+    case.created_at = clock.unix_timestamp;
+    case.expires_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.config.max_case_age)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    case.category = category as u8;
+    case.reported_value_usd = reported_value_usd;
+    case.value_tier = CaseValueTier::from_micro_usd(reported_value_usd) as u8;
+    case.quadratic = ctx.accounts.config.quadratic_voting as u8;
+    let mut threat_match = false;
+    if let Some(threat_intel) = &ctx.accounts.threat_intel {
+        require!(
+            threat_intel.active && !threat_intel.disputed,
+            ErrorCode::InvalidThreatIntel
+        );
+        case.threat_id = threat_intel.threat_id;
+
+        // Program-id entries can be matched exactly, so a known drainer escalates severity
+        // and, absent a configured threshold, only needs a simple majority. A signature
+        // pattern link is left to jurors and changes nothing about the case.
+        if threat_intel.pattern_kind == ThreatPatternKind::ProgramId {
+            require!(
+                threat_intel.pattern == scam_address.to_bytes(),
+                ErrorCode::InvalidThreatIntel
+            );
+            case.severity = case.severity.max(threat_intel.severity as u8);
+            threat_match = true;
+        }
+        msg!("Case {} linked to threat intel {}", case_id, threat_intel.threat_id);
+    }
+
+    let prior_approved = ctx.accounts.scam_index.approved_cases;
+    apply_case_policy(&mut case, &ctx.accounts.config, prior_approved, threat_match);
+
+    let scam_index = &mut ctx.accounts.scam_index;
+    scam_index.address = scam_address;
+    scam_index.record_case(case_id);
+    scam_index.updated_at = clock.unix_timestamp;
+    scam_index.bump = ctx.bumps.scam_index;
+
+    emit!(CaseCreated {
+        case_id,
+        reporter: ctx.accounts.reporter.key(),
+        scam_address,
+        severity: case.severity,
+        category: case.category,
+        threat_id: case.threat_id,
+        prior_approved_cases: case.prior_approved_cases,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
+
+/// Escalation, vote threshold, and hearing round of a new case, once its severity is set.
+/// `threat_match` is whether the accused is a verified program-id threat intel match.
+pub(crate) fn apply_case_policy(
+    case: &mut CaseAccount,
+    config: &GlobalConfig,
+    prior_approved: u32,
+    threat_match: bool,
+) {
+    // Repeat offenders escalate a tier per prior approved verdict and, like known
+    // drainers, ratify an emergency freeze on a simple majority
+    if prior_approved > 0 {
+        case.prior_approved_cases = prior_approved.min(u16::MAX as u32) as u16;
+        case.severity = CaseSeverity::escalate(case.severity, prior_approved);
+        msg!("Case {} escalated: {} prior approved verdicts", case.case_id, prior_approved);
+    }
+
+    let emergency = threat_match || prior_approved > 0;
+    let (numerator, denominator) = config.case_threshold(case.category, case.severity, emergency);
+    case.threshold_numerator = numerator;
+    case.threshold_denominator = denominator;
+
+    // Serious cases are heard twice: a small jury on probable cause, then the full panel
+    if config.probable_cause_jurors > 0 && case.severity >= CaseSeverity::High as u8 && case.quadratic == 0 {
+        case.round = CaseRound::Preliminary as u8;
+    }
+}
@@ -1,32 +1,49 @@
-use anchor_lang::prelude::*;
-use crate::state::GlobalConfig;
-use crate::ErrorCode;
-
-#[derive(Accounts)]
-pub struct SyncValidators<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [b"config"],
-        bump = config.bump
-    )]
-    pub config: Account<'info, GlobalConfig>,
-}
-
-pub fn handler(ctx: Context<SyncValidators>, validators: Vec<Pubkey>) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    
-    // Only admin can sync validators
-    require_eq!(
-        ctx.accounts.admin.key(),
-        config.admin,
-        ErrorCode::Unauthorized
-    );
-
-    msg!("Syncing {} validators", validators.len());
-    config.validator_list = validators;
-    msg!("Validators synced. Total: {}", config.validator_list.len());
-
-    Ok(())
-}
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, ProtocolStats, ValidatorPage};
+use crate::ErrorCode;
+use super::validator_registry::{replace_page, sync_active_validators};
+
+#[derive(Accounts)]
+pub struct SyncValidators<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+pub fn handler(ctx: Context<SyncValidators>, validators: Vec<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // Only admin can sync validators
+    require_eq!(
+        ctx.accounts.admin.key(),
+        config.admin,
+        ErrorCode::Unauthorized
+    );
+    // Once a council exists, syncs go through execute_action
+    require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+
+    msg!("Syncing {} validators into page {}", validators.len(), ctx.accounts.validator_page.page_index);
+    replace_page(config, &mut ctx.accounts.validator_page, validators)?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+    msg!("Validators synced. Total: {}", config.validator_count);
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    CaseSeverity, GlobalConfig, IntelProvider, ThreatIntel, ThreatPatternKind, INTEL_PUBLISH_WINDOW,
+    MAX_INTEL_PUBLISHES_PER_WINDOW, MIN_INTEL_PROVIDER_BOND,
+};
+use crate::{ErrorCode, IntelProviderApproved, ThreatIntelPublished};
+
+#[derive(Accounts)]
+pub struct RegisterIntelProvider<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    #[account(
+        init,
+        payer = provider,
+        space = 8 + IntelProvider::LEN,
+        seeds = [b"intel_provider", provider.key().as_ref()],
+        bump
+    )]
+    pub intel_provider: Account<'info, IntelProvider>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveIntelProvider<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"intel_provider", intel_provider.authority.as_ref()],
+        bump = intel_provider.bump
+    )]
+    pub intel_provider: Account<'info, IntelProvider>,
+}
+
+#[derive(Accounts)]
+#[instruction(threat_id: u64)]
+pub struct PublishThreatIntel<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"intel_provider", provider.key().as_ref()],
+        bump = intel_provider.bump,
+        constraint = intel_provider.active @ ErrorCode::Unauthorized,
+        constraint = intel_provider.approved @ ErrorCode::IntelProviderNotApproved
+    )]
+    pub intel_provider: Account<'info, IntelProvider>,
+    #[account(
+        init,
+        payer = provider,
+        space = 8 + ThreatIntel::LEN,
+        seeds = [b"threat_intel", threat_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub threat_intel: Account<'info, ThreatIntel>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateThreatIntel<'info> {
+    pub provider: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"intel_provider", provider.key().as_ref()],
+        bump = intel_provider.bump,
+        constraint = intel_provider.active @ ErrorCode::Unauthorized,
+        constraint = intel_provider.approved @ ErrorCode::IntelProviderNotApproved
+    )]
+    pub intel_provider: Account<'info, IntelProvider>,
+    #[account(
+        mut,
+        seeds = [b"threat_intel", threat_intel.threat_id.to_le_bytes().as_ref()],
+        bump = threat_intel.bump,
+        constraint = threat_intel.provider == provider.key() @ ErrorCode::Unauthorized,
+        constraint = !threat_intel.disputed @ ErrorCode::IntelUnderDispute,
+        constraint = !threat_intel.slashed @ ErrorCode::IntelSlashed
+    )]
+    pub threat_intel: Account<'info, ThreatIntel>,
+}
+
+/// Anyone may post a provider bond, but the provider can't publish until governance
+/// approves it: a bonded stranger shouldn't be able to move case thresholds
+pub fn register_provider_handler(ctx: Context<RegisterIntelProvider>, bond: u64) -> Result<()> {
+    require!(bond >= MIN_INTEL_PROVIDER_BOND, ErrorCode::InsufficientBond);
+
+    // Bond is held as lamports on the provider PDA so it can be slashed later
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: ctx.accounts.intel_provider.to_account_info(),
+            },
+        ),
+        bond,
+    )?;
+
+    let intel_provider = &mut ctx.accounts.intel_provider;
+    intel_provider.authority = ctx.accounts.provider.key();
+    intel_provider.bond = bond;
+    intel_provider.entries_published = 0;
+    intel_provider.entries_slashed = 0;
+    intel_provider.window_start = Clock::get()?.unix_timestamp;
+    intel_provider.window_count = 0;
+    intel_provider.active = true;
+    intel_provider.approved = false;
+    intel_provider.bump = ctx.bumps.intel_provider;
+
+    msg!("Intel provider {} registered with bond {}; awaiting approval", intel_provider.authority, bond);
+    Ok(())
+}
+
+pub fn approve_provider_handler(ctx: Context<ApproveIntelProvider>) -> Result<()> {
+    apply_provider_approval(&mut ctx.accounts.intel_provider)
+}
+
+/// A provider slashed below the minimum bond stays inactive even once approved
+pub(crate) fn apply_provider_approval(intel_provider: &mut IntelProvider) -> Result<()> {
+    intel_provider.approved = true;
+
+    emit!(IntelProviderApproved {
+        provider: intel_provider.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("Intel provider {} approved", intel_provider.authority);
+    Ok(())
+}
+
+pub fn publish_handler(
+    ctx: Context<PublishThreatIntel>,
+    threat_id: u64,
+    pattern_kind: ThreatPatternKind,
+    pattern: [u8; 32],
+    severity: CaseSeverity,
+) -> Result<()> {
+    // Threat id 0 is reserved to mean "no linked threat" on cases
+    require!(threat_id != 0, ErrorCode::InvalidThreatIntel);
+    require!(pattern != [0u8; 32], ErrorCode::InvalidThreatIntel);
+
+    let clock = Clock::get()?;
+    consume_publish_quota(&mut ctx.accounts.intel_provider, clock.unix_timestamp)?;
+
+    let threat_intel = &mut ctx.accounts.threat_intel;
+    threat_intel.threat_id = threat_id;
+    threat_intel.provider = ctx.accounts.provider.key();
+    threat_intel.pattern_kind = pattern_kind;
+    threat_intel.pattern = pattern;
+    threat_intel.severity = severity;
+    threat_intel.active = true;
+    threat_intel.disputed = false;
+    threat_intel.slashed = false;
+    threat_intel.disputes = 0;
+    threat_intel.updated_at = clock.unix_timestamp;
+    threat_intel.bump = ctx.bumps.threat_intel;
+
+    let intel_provider = &mut ctx.accounts.intel_provider;
+    intel_provider.entries_published = intel_provider
+        .entries_published
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ThreatIntelPublished {
+        threat_id,
+        provider: threat_intel.provider,
+        severity,
+        active: true,
+    });
+
+    msg!("Threat intel {} published", threat_id);
+    Ok(())
+}
+
+pub fn update_handler(
+    ctx: Context<UpdateThreatIntel>,
+    severity: CaseSeverity,
+    active: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    consume_publish_quota(&mut ctx.accounts.intel_provider, clock.unix_timestamp)?;
+
+    let threat_intel = &mut ctx.accounts.threat_intel;
+    threat_intel.severity = severity;
+    threat_intel.active = active;
+    threat_intel.updated_at = clock.unix_timestamp;
+
+    emit!(ThreatIntelPublished {
+        threat_id: threat_intel.threat_id,
+        provider: threat_intel.provider,
+        severity,
+        active,
+    });
+
+    msg!("Threat intel {} updated. Active: {}", threat_intel.threat_id, active);
+    Ok(())
+}
+
+/// Fixed-window rate limit shared by publish and update
+fn consume_publish_quota(intel_provider: &mut IntelProvider, now: i64) -> Result<()> {
+    if now.saturating_sub(intel_provider.window_start) >= INTEL_PUBLISH_WINDOW {
+        intel_provider.window_start = now;
+        intel_provider.window_count = 0;
+    }
+    require!(
+        intel_provider.window_count < MAX_INTEL_PUBLISHES_PER_WINDOW,
+        ErrorCode::IntelRateLimited
+    );
+    intel_provider.window_count += 1;
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CaseState, CaseStatus, ConfidentialComponents, GlobalConfig, TieBreakRule};
+use crate::{ErrorCode, TieBroken};
+use super::escalation::open_to_full_set;
+use super::vote::{approve_case, emit_verdict};
+
+#[derive(Accounts)]
+pub struct SetTieBreakRule<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+#[derive(Accounts)]
+pub struct BreakTie<'info> {
+    /// The configured tie-breaker; may be a PDA signing through CPI
+    pub tie_breaker: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.tie_break_rule == TieBreakRule::CastingVote @ ErrorCode::InvalidTieBreaker,
+        constraint = config.tie_breaker == tie_breaker.key() @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+pub fn set_rule_handler(ctx: Context<SetTieBreakRule>, rule: TieBreakRule, tie_breaker: Pubkey) -> Result<()> {
+    apply_tie_break_rule(&mut ctx.accounts.config, rule, tie_breaker)
+}
+
+pub(crate) fn apply_tie_break_rule(config: &mut GlobalConfig, rule: TieBreakRule, tie_breaker: Pubkey) -> Result<()> {
+    require!(
+        rule != TieBreakRule::CastingVote || tie_breaker != Pubkey::default(),
+        ErrorCode::InvalidTieBreaker
+    );
+    config.tie_break_rule = rule;
+    config.tie_breaker = tie_breaker;
+
+    msg!("Tie-break rule set to {}", rule as u8);
+    Ok(())
+}
+
+/// Casting vote on a deadlocked case under TieBreakRule::CastingVote
+pub fn break_handler(ctx: Context<BreakTie>, approve: bool) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(case.is_deadlocked(now), ErrorCode::NotDeadlocked);
+
+    if approve {
+        approve_case(&mut case);
+    } else {
+        case.state = CaseState::Rejected as u8;
+    }
+    case.status = CaseStatus::Closed as u8;
+
+    emit!(TieBroken {
+        case_id: case.case_id,
+        tie_breaker: ctx.accounts.tie_breaker.key(),
+        approve,
+        timestamp: now,
+    });
+    emit_verdict(&case, now);
+    msg!("Tie on case {} broken by casting vote: {}", case.case_id, approve);
+    Ok(())
+}
+
+/// Applies the configured rule to a deadlocked case. Returns false if the case went back
+/// to voting instead of being decided.
+pub(crate) fn settle_tie(case: &mut CaseAccount, config: &GlobalConfig, now: i64) -> Result<bool> {
+    match config.tie_break_rule {
+        TieBreakRule::CastingVote => return err!(ErrorCode::AwaitingTieBreak),
+        // Private ballots are bound to the panel that committed them and can't be reopened
+        TieBreakRule::EscalateToFullSet
+            if case.escalated == 0 && !case.has_component(ConfidentialComponents::PRIVATE_VOTES) =>
+        {
+            open_to_full_set(case, config, now)?;
+            return Ok(false);
+        }
+        _ => {}
+    }
+    case.state = CaseState::Rejected as u8;
+    case.status = CaseStatus::Closed as u8;
+    emit_verdict(case, now);
+    msg!("Case {} rejected on a tie", case.case_id);
+    Ok(true)
+}
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{GlobalConfig, Treasury, MAX_DISTRIBUTION_FEE_BPS};
+use crate::{ErrorCode, TreasuryWithdrawn};
+
+#[derive(Accounts)]
+pub struct ConfigureFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenTreasuryVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"treasury_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury,
+        token::token_program = token_program
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+    /// Token or Token-2022, whichever owns the mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    /// CHECK: Any account may receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    /// Token withdrawals pass the mint's vault, the mint, and the recipient's token account
+    #[account(mut)]
+    pub treasury_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn configure_fees_handler(
+    ctx: Context<ConfigureFees>,
+    submission_fee: u64,
+    distribution_fee_bps: u16,
+) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.bump = ctx.bumps.treasury;
+
+    set_fees(&mut ctx.accounts.config, submission_fee, distribution_fee_bps)
+}
+
+pub fn open_vault_handler(ctx: Context<OpenTreasuryVault>) -> Result<()> {
+    msg!("Treasury vault opened for mint {}", ctx.accounts.mint.key());
+    Ok(())
+}
+
+pub fn withdraw_handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    match (
+        &accounts.treasury_vault,
+        &accounts.mint,
+        &accounts.recipient_token_account,
+        &accounts.token_program,
+    ) {
+        (Some(treasury_vault), Some(mint), Some(recipient_token_account), Some(token_program)) => withdraw_tokens(
+            &accounts.treasury,
+            treasury_vault,
+            mint,
+            recipient_token_account,
+            token_program,
+            amount,
+        ),
+        (None, _, None, _) => {
+            withdraw_lamports(&mut accounts.treasury, &accounts.recipient.to_account_info(), amount)
+        }
+        _ => err!(ErrorCode::InvalidTreasuryAccounts),
+    }
+}
+
+pub(crate) fn set_fees(config: &mut GlobalConfig, submission_fee: u64, distribution_fee_bps: u16) -> Result<()> {
+    require!(
+        distribution_fee_bps <= MAX_DISTRIBUTION_FEE_BPS,
+        ErrorCode::InvalidFee
+    );
+    config.submission_fee = submission_fee;
+    config.distribution_fee_bps = distribution_fee_bps;
+
+    msg!("Fees set: {} lamports per case, {} bps of distributions", submission_fee, distribution_fee_bps);
+    Ok(())
+}
+
+/// Charge `payer` a submission fee of `fee` lamports, if it is nonzero
+pub(crate) fn collect_submission_fee<'info>(
+    fee: u64,
+    treasury: Option<&mut Account<'info, Treasury>>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    let treasury = treasury.ok_or(ErrorCode::InvalidTreasuryAccounts)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: payer,
+                to: treasury.to_account_info(),
+            },
+        ),
+        fee,
+    )?;
+    treasury.total_collected = treasury
+        .total_collected
+        .checked_add(fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Fee owed on a restitution payout of `amount`
+pub(crate) fn distribution_fee(config: &GlobalConfig, amount: u64) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(config.distribution_fee_bps as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        / 10_000;
+    Ok(fee as u64)
+}
+
+pub(crate) fn withdraw_lamports(
+    treasury: &mut Account<Treasury>,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    let treasury_info = treasury.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(treasury_info.data_len());
+    require!(
+        treasury_info.lamports().saturating_sub(rent_floor) >= amount,
+        ErrorCode::TreasuryInsufficient
+    );
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    treasury.total_withdrawn = treasury
+        .total_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        recipient: recipient.key(),
+        mint: None,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+pub(crate) fn withdraw_tokens<'info>(
+    treasury: &Account<'info, Treasury>,
+    treasury_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let (vault_address, _) = Pubkey::find_program_address(
+        &[b"treasury_vault", treasury_vault.mint.as_ref()],
+        &crate::ID,
+    );
+    require!(treasury_vault.key() == vault_address, ErrorCode::InvalidTreasuryAccounts);
+    require!(treasury_vault.amount >= amount, ErrorCode::TreasuryInsufficient);
+
+    let seeds: &[&[u8]] = &[&b"treasury"[..], &[treasury.bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: treasury_vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: recipient_token_account.to_account_info(),
+                authority: treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        recipient: recipient_token_account.owner,
+        mint: Some(treasury_vault.mint),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::GlobalConfig;
+use crate::state::{GlobalConfig, ProtocolStats, ValidatorPage};
 use crate::ErrorCode;
+use super::validator_registry::{replace_page, sync_active_validators};
 
 #[derive(Accounts)]
 pub struct UpdateValidators<'info> {
@@ -10,12 +11,27 @@ pub struct UpdateValidators<'info> {
         bump = config.bump
     )]
     pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
     pub admin: Signer<'info>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
 }
 
 pub fn handler(ctx: Context<UpdateValidators>, validators: Vec<Pubkey>) -> Result<()> {
     let config = &mut ctx.accounts.config;
     require!(ctx.accounts.admin.key == &config.admin, ErrorCode::Unauthorized);
-    config.validator_list = validators;
+    require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+    replace_page(config, &mut ctx.accounts.validator_page, validators)?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
     Ok(())
 }
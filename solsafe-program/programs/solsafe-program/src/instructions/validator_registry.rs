@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, JurorIdentity, ProtocolStats, ValidatorPage, MAX_VALIDATOR_PAGES, VALIDATORS_PER_PAGE};
+use crate::{ErrorCode, ValidatorsSynced};
+use super::juror_identity::require_personhood;
+
+#[derive(Accounts)]
+pub struct AddValidatorPage<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ValidatorPage::LEN,
+        seeds = [b"validator_page", config.page_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppendValidator<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Appends always go to the last page so slots stay contiguous
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump,
+        constraint = validator_page.page_index + 1 == config.page_count @ ErrorCode::InvalidValidatorPage
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    /// CHECK: The PersonhoodRegistry PDA, read in the handler; may not exist yet
+    #[account(seeds = [b"personhood_registry"], bump)]
+    pub personhood_registry: UncheckedAccount<'info>,
+    /// The appended validator's attestation; required while the personhood registry is
+    pub juror_identity: Option<Account<'info, JurorIdentity>>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveValidator<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Page holding the validator
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    /// Last page, whose final entry fills the vacated slot; omitted when that is `validator_page`
+    #[account(
+        mut,
+        seeds = [b"validator_page", last_page.page_index.to_le_bytes().as_ref()],
+        bump = last_page.bump,
+        constraint = last_page.page_index + 1 == config.page_count @ ErrorCode::InvalidValidatorPage,
+        constraint = last_page.page_index != validator_page.page_index @ ErrorCode::InvalidValidatorPage
+    )]
+    pub last_page: Option<Account<'info, ValidatorPage>>,
+    /// Protocol-wide counters; updated whenever passed
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+pub fn add_page_handler(ctx: Context<AddValidatorPage>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, ErrorCode::Unauthorized);
+    require!(
+        (config.page_count as usize) < MAX_VALIDATOR_PAGES,
+        ErrorCode::ValidatorRegistryFull
+    );
+    // A new page is only opened once every existing page is full
+    require!(
+        config.validator_count as usize == config.page_count as usize * VALIDATORS_PER_PAGE,
+        ErrorCode::InvalidValidatorPage
+    );
+
+    let validator_page = &mut ctx.accounts.validator_page;
+    validator_page.page_index = config.page_count;
+    validator_page.validators = vec![];
+    validator_page.bump = ctx.bumps.validator_page;
+    config.page_count += 1;
+
+    msg!("Validator page {} added", validator_page.page_index);
+    Ok(())
+}
+
+pub fn append_handler(ctx: Context<AppendValidator>, validator: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, ErrorCode::Unauthorized);
+    require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+    require_personhood(
+        &ctx.accounts.personhood_registry,
+        ctx.accounts.juror_identity.as_deref(),
+        &validator,
+    )?;
+
+    append_to_page(config, &mut ctx.accounts.validator_page, validator)?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+    msg!("Validator {} appended. Total: {}", validator, config.validator_count);
+    Ok(())
+}
+
+/// Like `append_handler`, but refuses a validator that is already registered. Remaining
+/// accounts: every page before the last.
+pub fn add_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AppendValidator<'info>>,
+    validator: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, ErrorCode::Unauthorized);
+    require!(config.council == Pubkey::default(), ErrorCode::CouncilGoverned);
+    require_personhood(
+        &ctx.accounts.personhood_registry,
+        ctx.accounts.juror_identity.as_deref(),
+        &validator,
+    )?;
+
+    let validator_page = &mut ctx.accounts.validator_page;
+    require!(!validator_page.validators.contains(&validator), ErrorCode::ValidatorAlreadyListed);
+    ensure_unlisted(ctx.remaining_accounts, validator_page.page_index, &validator)?;
+
+    append_to_page(config, validator_page, validator)?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+    msg!("Validator {} added. Total: {}", validator, config.validator_count);
+    Ok(())
+}
+
+pub fn remove_handler(ctx: Context<RemoveValidator>, validator: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    remove_from_page(
+        config,
+        &mut ctx.accounts.validator_page,
+        ctx.accounts.last_page.as_deref_mut(),
+        &validator,
+    )?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+    msg!("Validator {} removed. Total: {}", validator, config.validator_count);
+    Ok(())
+}
+
+/// Push `validator` onto the last page
+pub fn append_to_page(
+    config: &mut GlobalConfig,
+    validator_page: &mut ValidatorPage,
+    validator: Pubkey,
+) -> Result<()> {
+    require!(
+        validator_page.page_index + 1 == config.page_count,
+        ErrorCode::InvalidValidatorPage
+    );
+    require!(
+        validator_page.validators.len() < VALIDATORS_PER_PAGE,
+        ErrorCode::ValidatorPageFull
+    );
+    validator_page.validators.push(validator);
+    config.validator_count = config
+        .validator_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ValidatorsSynced {
+        page_index: validator_page.page_index,
+        page_size: validator_page.validators.len() as u32,
+        validator_count: config.validator_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Take `validator` off its page and move the registry's last entry into the freed slot,
+/// so every page but the last stays full. `last_page` is `None` when `validator_page` is
+/// the last page.
+pub fn remove_from_page(
+    config: &mut GlobalConfig,
+    validator_page: &mut ValidatorPage,
+    last_page: Option<&mut ValidatorPage>,
+    validator: &Pubkey,
+) -> Result<()> {
+    let position = validator_page
+        .validators
+        .iter()
+        .position(|v| v == validator)
+        .ok_or(ErrorCode::ValidatorNotListed)?;
+    let moved = match last_page {
+        Some(last_page) => {
+            require!(last_page.page_index + 1 == config.page_count, ErrorCode::InvalidValidatorPage);
+            let moved = last_page.validators.pop();
+            emit!(ValidatorsSynced {
+                page_index: last_page.page_index,
+                page_size: last_page.validators.len() as u32,
+                validator_count: config.validator_count - 1,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            moved
+        }
+        None => {
+            require!(
+                validator_page.page_index + 1 == config.page_count,
+                ErrorCode::InvalidValidatorPage
+            );
+            validator_page.validators.pop()
+        }
+    }
+    .ok_or(ErrorCode::InvalidValidatorPage)?;
+    // Popping the validator itself off the last page already removed it
+    if position < validator_page.validators.len() {
+        validator_page.validators[position] = moved;
+    }
+    config.validator_count = config
+        .validator_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ValidatorsSynced {
+        page_index: validator_page.page_index,
+        page_size: validator_page.validators.len() as u32,
+        validator_count: config.validator_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Swap a page's contents, keeping every page but the last one full. Safe while cases are
+/// voting: ballots are tracked by VoteRecord PDAs, so nothing refers to a slot's old holder.
+pub fn replace_page(
+    config: &mut GlobalConfig,
+    validator_page: &mut ValidatorPage,
+    validators: Vec<Pubkey>,
+) -> Result<()> {
+    let is_last_page = validator_page.page_index + 1 == config.page_count;
+    require!(validators.len() <= VALIDATORS_PER_PAGE, ErrorCode::ValidatorPageFull);
+    require!(
+        is_last_page || validators.len() == VALIDATORS_PER_PAGE,
+        ErrorCode::InvalidValidatorPage
+    );
+
+    config.validator_count = (config.validator_count as usize - validator_page.validators.len()
+        + validators.len()) as u32;
+    validator_page.validators = validators;
+
+    emit!(ValidatorsSynced {
+        page_index: validator_page.page_index,
+        page_size: validator_page.validators.len() as u32,
+        validator_count: config.validator_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Copy the registry size into the protocol stats after it changes
+pub(crate) fn sync_active_validators(stats: Option<&mut Account<ProtocolStats>>, config: &GlobalConfig) {
+    if let Some(stats) = stats {
+        stats.active_validators = config.validator_count;
+    }
+}
+
+/// Fail if `validator` sits on any of pages `0..page_count`, all of which must be passed in `pages`
+pub(crate) fn ensure_unlisted<'info>(
+    pages: &'info [AccountInfo<'info>],
+    page_count: u16,
+    validator: &Pubkey,
+) -> Result<()> {
+    for page_index in 0..page_count {
+        let page = find_page(pages, page_index)?;
+        require!(!page.validators.contains(validator), ErrorCode::ValidatorAlreadyListed);
+    }
+    Ok(())
+}
+
+/// Find page `page_index` among the pages passed in remaining accounts
+pub fn find_page<'info>(
+    pages: &'info [AccountInfo<'info>],
+    page_index: u16,
+) -> Result<Account<'info, ValidatorPage>> {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"validator_page", page_index.to_le_bytes().as_ref()],
+        &crate::ID,
+    );
+    let info = pages
+        .iter()
+        .find(|info| info.key() == address)
+        .ok_or(ErrorCode::InvalidValidatorPage)?;
+    Account::try_from(info)
+}
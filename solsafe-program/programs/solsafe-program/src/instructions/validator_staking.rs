@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{
+    GlobalConfig, JurorIdentity, ProtocolStats, ValidatorPage, ValidatorStake, ValidatorState, ValidatorStatus,
+    VALIDATOR_UNBONDING_EPOCHS,
+};
+use crate::{ErrorCode, ValidatorDeregistered, ValidatorRegistered, ValidatorStakeWithdrawn};
+use super::juror_identity::require_personhood;
+use super::validator_registry::{append_to_page, ensure_unlisted, remove_from_page, sync_active_validators};
+
+#[derive(Accounts)]
+pub struct RegisterValidator<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Registrations always go to the last page; a full one needs add_validator_page first
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump,
+        constraint = validator_page.page_index + 1 == config.page_count @ ErrorCode::InvalidValidatorPage
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + ValidatorStake::LEN,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + ValidatorStatus::LEN,
+        seeds = [b"validator_status", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_status: Account<'info, ValidatorStatus>,
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: The PersonhoodRegistry PDA, read in the handler; may not exist yet
+    #[account(seeds = [b"personhood_registry"], bump)]
+    pub personhood_registry: UncheckedAccount<'info>,
+    /// Required while the personhood registry is
+    #[account(
+        seeds = [b"juror_identity", validator.key().as_ref()],
+        bump = juror_identity.bump
+    )]
+    pub juror_identity: Option<Account<'info, JurorIdentity>>,
+    // Remaining accounts: every ValidatorPage before the last, to rule out a second entry
+}
+
+#[derive(Accounts)]
+pub struct DeregisterValidator<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    /// Page holding the validator
+    #[account(
+        mut,
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Account<'info, ValidatorPage>,
+    /// Last page, whose final entry fills the vacated slot; omitted when that is `validator_page`
+    #[account(
+        mut,
+        seeds = [b"validator_page", last_page.page_index.to_le_bytes().as_ref()],
+        bump = last_page.bump,
+        constraint = last_page.page_index + 1 == config.page_count @ ErrorCode::InvalidValidatorPage,
+        constraint = last_page.page_index != validator_page.page_index @ ErrorCode::InvalidValidatorPage
+    )]
+    pub last_page: Option<Account<'info, ValidatorPage>>,
+    #[account(
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+    #[account(
+        mut,
+        seeds = [b"validator_status", validator.key().as_ref()],
+        bump = validator_status.bump,
+        constraint = validator_status.state == ValidatorState::Active @ ErrorCode::ValidatorCoolingDown
+    )]
+    pub validator_status: Account<'info, ValidatorStatus>,
+    #[account(
+        mut,
+        seeds = [b"protocol_stats"],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+    // Remaining accounts: every ValidatorPage, only when the validator has already been
+    // dropped from the registry by an admin update and just wants its stake back
+}
+
+#[derive(Accounts)]
+pub struct WithdrawValidatorStake<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+    /// Stake and rent both go back to the validator
+    #[account(
+        mut,
+        close = validator,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+    #[account(
+        mut,
+        close = validator,
+        seeds = [b"validator_status", validator.key().as_ref()],
+        bump = validator_status.bump
+    )]
+    pub validator_status: Account<'info, ValidatorStatus>,
+}
+
+#[derive(Accounts)]
+pub struct SetMinValidatorStake<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+}
+
+/// Anyone staking at least the configured minimum joins the juror pool, with an attested
+/// personhood credential while the registry requires one
+pub fn register_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterValidator<'info>>,
+    stake: u64,
+) -> Result<()> {
+    let min_stake = ctx.accounts.config.min_validator_stake;
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    require!(min_stake > 0, ErrorCode::SelfRegistrationClosed);
+    require!(stake >= min_stake, ErrorCode::InsufficientValidatorStake);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.validator.to_account_info(),
+                to: ctx.accounts.validator_stake.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let validator = ctx.accounts.validator.key();
+    require_personhood(
+        &ctx.accounts.personhood_registry,
+        ctx.accounts.juror_identity.as_deref(),
+        &validator,
+    )?;
+    let validator_page = &mut ctx.accounts.validator_page;
+    require!(!validator_page.validators.contains(&validator), ErrorCode::ValidatorAlreadyListed);
+    ensure_unlisted(ctx.remaining_accounts, validator_page.page_index, &validator)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    validator_stake.validator = validator;
+    validator_stake.amount = stake;
+    validator_stake.registered_at = now;
+    validator_stake.bump = ctx.bumps.validator_stake;
+
+    let validator_status = &mut ctx.accounts.validator_status;
+    validator_status.validator = validator;
+    validator_status.state = ValidatorState::Active;
+    validator_status.cooldown_ends_epoch = 0;
+    validator_status.pending_reports = 0;
+    validator_status.bump = ctx.bumps.validator_status;
+
+    let config = &mut ctx.accounts.config;
+    append_to_page(config, validator_page, validator)?;
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+
+    emit!(ValidatorRegistered {
+        validator,
+        stake,
+        validator_count: config.validator_count,
+        timestamp: now,
+    });
+    msg!("Validator {} registered with {} lamports staked", validator, stake);
+    Ok(())
+}
+
+/// Leaves the pool. The stake stays locked, and slashable for cases already judged, for
+/// VALIDATOR_UNBONDING_EPOCHS before withdraw_validator_stake releases it.
+pub fn deregister_handler<'info>(ctx: Context<'_, '_, 'info, 'info, DeregisterValidator<'info>>) -> Result<()> {
+    let validator = ctx.accounts.validator.key();
+    let config = &mut ctx.accounts.config;
+    let validator_page = &mut ctx.accounts.validator_page;
+
+    if validator_page.validators.contains(&validator) {
+        remove_from_page(config, validator_page, ctx.accounts.last_page.as_deref_mut(), &validator)?;
+    } else {
+        // Already dropped by an admin update; every page has to show it
+        ensure_unlisted(ctx.remaining_accounts, config.page_count, &validator)?;
+    }
+    sync_active_validators(ctx.accounts.protocol_stats.as_mut(), config);
+
+    let clock = Clock::get()?;
+    let validator_status = &mut ctx.accounts.validator_status;
+    validator_status.state = ValidatorState::Cooldown;
+    validator_status.cooldown_ends_epoch = clock.epoch + VALIDATOR_UNBONDING_EPOCHS;
+
+    emit!(ValidatorDeregistered {
+        validator,
+        stake: ctx.accounts.validator_stake.amount,
+        validator_count: config.validator_count,
+        cooldown_ends_epoch: validator_status.cooldown_ends_epoch,
+        timestamp: clock.unix_timestamp,
+    });
+    msg!(
+        "Validator {} deregistered, unbonding until epoch {}. Total: {}",
+        validator,
+        validator_status.cooldown_ends_epoch,
+        config.validator_count
+    );
+    Ok(())
+}
+
+/// Releases the stake once the unbonding cooldown has run out
+pub fn withdraw_handler(ctx: Context<WithdrawValidatorStake>) -> Result<()> {
+    let clock = Clock::get()?;
+    let validator_status = &ctx.accounts.validator_status;
+    require!(
+        validator_status.state == ValidatorState::Cooldown,
+        ErrorCode::ValidatorStillRegistered
+    );
+    require!(!validator_status.is_bonded(clock.epoch), ErrorCode::StakeStillBonding);
+    require!(validator_status.pending_reports == 0, ErrorCode::MisconductPending);
+
+    emit!(ValidatorStakeWithdrawn {
+        validator: validator_status.validator,
+        stake: ctx.accounts.validator_stake.amount,
+        timestamp: clock.unix_timestamp,
+    });
+    msg!("Validator {} withdrew its stake", validator_status.validator);
+    Ok(())
+}
+
+pub fn set_min_stake_handler(ctx: Context<SetMinValidatorStake>, min_validator_stake: u64) -> Result<()> {
+    apply_min_validator_stake(&mut ctx.accounts.config, min_validator_stake)
+}
+
+/// Zero closes self-registration; validators already staked stay registered
+pub(crate) fn apply_min_validator_stake(config: &mut GlobalConfig, min_validator_stake: u64) -> Result<()> {
+    config.min_validator_stake = min_validator_stake;
+
+    msg!("Minimum validator stake set to {}", min_validator_stake);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, ValidatorHeartbeat, ValidatorPage, ValidatorStake, ValidatorState, ValidatorStatus,
+};
+
+/// Stable view of a case for clients; fields are only ever appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CaseSummary {
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub reporter: Pubkey,
+    pub state: u8,                 // CaseState
+    pub status: u8,                // CaseStatus
+    pub severity: u8,              // CaseSeverity
+    pub category: u8,              // CaseCategory
+    pub verdict: u8,               // Verdict
+    pub round: u8,                 // CaseRound
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub juror_count: u8,
+    pub confidential: bool,
+    pub overturned: bool,
+    pub finalized: bool,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub closed_at: i64,
+    pub attestations: u8,
+}
+
+/// Stable view of a validator's registration; accounts it never opened read as empty
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ValidatorSummary {
+    pub validator: Pubkey,
+    pub registry_slot: Option<u32>,        // Only when the caller passed the page listing it
+    pub stake: u64,
+    pub state: Option<ValidatorState>,
+    pub bonded: bool,
+    pub cooldown_ends_epoch: u64,
+    pub pending_reports: u16,
+    pub last_heartbeat_epoch: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct GetCaseSummary<'info> {
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(validator: Pubkey)]
+pub struct GetValidatorStatus<'info> {
+    #[account(
+        seeds = [b"validator_stake", validator.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+    #[account(
+        seeds = [b"validator_status", validator.as_ref()],
+        bump = validator_status.bump
+    )]
+    pub validator_status: Option<Account<'info, ValidatorStatus>>,
+    #[account(
+        seeds = [b"heartbeat", validator.as_ref()],
+        bump = heartbeat.bump
+    )]
+    pub heartbeat: Option<Account<'info, ValidatorHeartbeat>>,
+    #[account(
+        seeds = [b"validator_page", validator_page.page_index.to_le_bytes().as_ref()],
+        bump = validator_page.bump
+    )]
+    pub validator_page: Option<Account<'info, ValidatorPage>>,
+}
+
+/// Read-only: returns the case's CaseSummary as return data, for clients to simulate
+pub fn case_summary_handler(ctx: Context<GetCaseSummary>, _case_id: u64) -> Result<CaseSummary> {
+    let case = ctx.accounts.case_account.load()?;
+    Ok(CaseSummary {
+        case_id: case.case_id,
+        scam_address: case.scam_address,
+        reporter: case.reporter,
+        state: case.state,
+        status: case.status,
+        severity: case.severity,
+        category: case.category,
+        verdict: case.verdict,
+        round: case.round,
+        votes_for: case.votes_for,
+        votes_against: case.votes_against,
+        juror_count: case.juror_count,
+        confidential: case.confidential != 0,
+        overturned: case.overturned != 0,
+        finalized: case.finalized != 0,
+        created_at: case.created_at,
+        expires_at: case.expires_at,
+        closed_at: case.closed_at,
+        attestations: case.attestations,
+    })
+}
+
+/// Read-only: returns the validator's ValidatorSummary as return data, for clients to simulate
+pub fn validator_status_handler(ctx: Context<GetValidatorStatus>, validator: Pubkey) -> Result<ValidatorSummary> {
+    let epoch = Clock::get()?.epoch;
+    let status = ctx.accounts.validator_status.as_ref();
+    Ok(ValidatorSummary {
+        validator,
+        registry_slot: ctx
+            .accounts
+            .validator_page
+            .as_ref()
+            .and_then(|page| page.slot_of(&validator))
+            .map(|slot| slot as u32),
+        stake: ctx.accounts.validator_stake.as_ref().map_or(0, |stake| stake.amount),
+        state: status.map(|status| status.state),
+        bonded: status.is_some_and(|status| status.is_bonded(epoch)),
+        cooldown_ends_epoch: status.map_or(0, |status| status.cooldown_ends_epoch),
+        pending_reports: status.map_or(0, |status| status.pending_reports),
+        last_heartbeat_epoch: ctx.accounts.heartbeat.as_ref().map(|heartbeat| heartbeat.last_epoch),
+    })
+}
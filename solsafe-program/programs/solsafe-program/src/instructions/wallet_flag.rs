@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::{CaseAccount, CaseState, GlobalConfig, WalletFlag};
+use crate::{ErrorCode, WalletFlagCleared, WalletFlagged};
+
+#[derive(Accounts)]
+pub struct SyncWalletFlag<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WalletFlag::LEN,
+        seeds = [b"wallet_flag", case_account.load()?.scam_address.as_ref()],
+        bump
+    )]
+    pub wallet_flag: Account<'info, WalletFlag>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct CheckAddress<'info> {
+    /// CHECK: Wallet flag PDA of `address`; usually uninitialized for a clean address
+    #[account(
+        seeds = [b"wallet_flag", address.as_ref()],
+        bump
+    )]
+    pub wallet_flag: UncheckedAccount<'info>,
+}
+
+/// Permissionless: brings the accused's wallet flag in line with a case. An approved
+/// verdict raises the flag, keeping the highest severity across cases; overturning the
+/// verdict that backs the flag clears it until another approved case is synced.
+pub fn handler(ctx: Context<SyncWalletFlag>) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    let approved = case.state == CaseState::Approved as u8 || case.state == CaseState::Executed as u8;
+    require!(approved, ErrorCode::NotApproved);
+    require!(!case.is_sealed(), ErrorCode::AddressSealed);
+
+    let now = Clock::get()?.unix_timestamp;
+    let wallet_flag = &mut ctx.accounts.wallet_flag;
+    wallet_flag.address = case.scam_address;
+    wallet_flag.bump = ctx.bumps.wallet_flag;
+
+    if case.overturned != 0 {
+        require!(
+            wallet_flag.active && wallet_flag.case_id == case.case_id,
+            ErrorCode::WalletFlagUnchanged
+        );
+        wallet_flag.active = false;
+        wallet_flag.updated_at = now;
+
+        emit!(WalletFlagCleared {
+            address: case.scam_address,
+            case_id: case.case_id,
+            timestamp: now,
+        });
+        msg!("Wallet flag on {} cleared by overturn of case {}", case.scam_address, case.case_id);
+        return Ok(());
+    }
+
+    require!(
+        !wallet_flag.active || case.severity > wallet_flag.severity,
+        ErrorCode::WalletFlagUnchanged
+    );
+    if !wallet_flag.active {
+        wallet_flag.flagged_at = now;
+    }
+    wallet_flag.severity = case.severity;
+    wallet_flag.case_id = case.case_id;
+    wallet_flag.active = true;
+    wallet_flag.updated_at = now;
+
+    emit!(WalletFlagged {
+        address: case.scam_address,
+        case_id: case.case_id,
+        severity: case.severity,
+        timestamp: now,
+    });
+    msg!(
+        "Wallet {} flagged at severity {} by case {}",
+        case.scam_address,
+        case.severity,
+        case.case_id
+    );
+    Ok(())
+}
+
+/// Read-only compliance check for other programs to CPI into before they move funds:
+/// fails with AddressFlagged when `address` has an active flag, succeeds otherwise
+pub fn check_handler(ctx: Context<CheckAddress>, address: Pubkey) -> Result<()> {
+    let info = &ctx.accounts.wallet_flag;
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(());
+    }
+    let wallet_flag = WalletFlag::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(!wallet_flag.active, ErrorCode::AddressFlagged);
+
+    msg!("{} has no active flag", address);
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CaseAccount, VictimClaim, WatchRole, Watchtower, WatchtowerAlert, MAX_WATCHED_ADDRESSES,
+};
+use crate::{ErrorCode, WatchtowerFlagged};
+
+#[derive(Accounts)]
+#[instruction(subscription_id: u64)]
+pub struct SubscribeWatchtower<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Watchtower::LEN,
+        seeds = [b"watchtower", owner.key().as_ref(), subscription_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub watchtower: Account<'info, Watchtower>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateWatchtower<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"watchtower", owner.key().as_ref(), watchtower.subscription_id.to_le_bytes().as_ref()],
+        bump = watchtower.bump,
+        has_one = owner
+    )]
+    pub watchtower: Account<'info, Watchtower>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct FlagWatchtower<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub case_account: AccountLoader<'info, CaseAccount>,
+    #[account(
+        mut,
+        seeds = [b"watchtower", watchtower.owner.as_ref(), watchtower.subscription_id.to_le_bytes().as_ref()],
+        bump = watchtower.bump
+    )]
+    pub watchtower: Account<'info, Watchtower>,
+    /// Proves `address` is a registered victim when it isn't named on the case itself
+    #[account(
+        seeds = [b"victim_claim", case_account.load()?.case_id.to_le_bytes().as_ref(), address.as_ref()],
+        bump = victim_claim.bump
+    )]
+    pub victim_claim: Option<Account<'info, VictimClaim>>,
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + WatchtowerAlert::LEN,
+        seeds = [b"watch_alert", watchtower.key().as_ref(), case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub alert: Account<'info, WatchtowerAlert>,
+    pub system_program: Program<'info, System>,
+}
+
+fn validate_addresses(addresses: &[Pubkey]) -> Result<()> {
+    require!(
+        !addresses.is_empty() && addresses.len() <= MAX_WATCHED_ADDRESSES,
+        ErrorCode::InvalidWatchlist
+    );
+    Ok(())
+}
+
+pub fn subscribe_handler(
+    ctx: Context<SubscribeWatchtower>,
+    subscription_id: u64,
+    addresses: Vec<Pubkey>,
+) -> Result<()> {
+    validate_addresses(&addresses)?;
+
+    let watchtower = &mut ctx.accounts.watchtower;
+    watchtower.owner = ctx.accounts.owner.key();
+    watchtower.subscription_id = subscription_id;
+    watchtower.addresses = addresses;
+    watchtower.flagged = false;
+    watchtower.flag_count = 0;
+    watchtower.last_flagged_case = 0;
+    watchtower.created_at = Clock::get()?.unix_timestamp;
+    watchtower.bump = ctx.bumps.watchtower;
+
+    msg!("Watchtower {} watching {} addresses", subscription_id, watchtower.addresses.len());
+    Ok(())
+}
+
+/// Replace the watch list; also clears the flag once the owner has seen it
+pub fn update_handler(ctx: Context<UpdateWatchtower>, addresses: Vec<Pubkey>) -> Result<()> {
+    validate_addresses(&addresses)?;
+
+    let watchtower = &mut ctx.accounts.watchtower;
+    watchtower.addresses = addresses;
+    watchtower.flagged = false;
+
+    msg!("Watchtower {} updated", watchtower.subscription_id);
+    Ok(())
+}
+
+/// Permissionless: notification services call this when a watched address appears in a case
+pub fn flag_handler(ctx: Context<FlagWatchtower>, address: Pubkey) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let watchtower = &mut ctx.accounts.watchtower;
+    require!(watchtower.addresses.contains(&address), ErrorCode::InvalidWatchlist);
+
+    let role = if case.scam_address == address {
+        WatchRole::Accused
+    } else if case.flow_source == address || ctx.accounts.victim_claim.is_some() {
+        WatchRole::Target
+    } else {
+        return err!(ErrorCode::InvalidWatchlist);
+    };
+
+    watchtower.flagged = true;
+    watchtower.flag_count = watchtower.flag_count.saturating_add(1);
+    watchtower.last_flagged_case = case.case_id;
+
+    let alert = &mut ctx.accounts.alert;
+    alert.watchtower = watchtower.key();
+    alert.case_id = case.case_id;
+    alert.address = address;
+    alert.role = role;
+    alert.bump = ctx.bumps.alert;
+
+    emit!(WatchtowerFlagged {
+        owner: watchtower.owner,
+        subscription_id: watchtower.subscription_id,
+        case_id: case.case_id,
+        address,
+        role,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Watchtower {} flagged by case {}", watchtower.subscription_id, case.case_id);
+    Ok(())
+}
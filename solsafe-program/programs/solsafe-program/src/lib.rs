@@ -2,11 +2,22 @@ use anchor_lang::prelude::*;
 
 declare_id!("D4mtpATBupFapyGgX3QHaUU1ZpMUTXP7LE12Lpgc77m1");
 
+pub mod domain;
 pub mod state;
+pub mod orao;
+pub mod pyth;
+pub mod metaplex;
+pub mod bubblegum;
+pub mod wormhole;
+pub mod ed25519;
+pub mod secp256k1;
+pub mod randomness;
+pub mod switchboard;
 pub mod instructions;
 pub mod zk_proofs;
+pub mod quantum_resistant;
 
-use state::{GlobalConfig, CaseAccount, CaseStatus, CaseState};
+use state::{CaseCategory, CategoryParams, CaseSeverity, CouncilAction, IntegratorKind, IntegrityIssue, ThreatPatternKind, WatchRole, RandomnessSource, MisconductKind, TokenPriceFeed, Verdict, ApprovalThreshold, TieBreakRule, CnftLeaf};
 use instructions::*;
 use zk_proofs::*;
 
@@ -54,9 +65,25 @@ pub enum ErrorCode {
     ThresholdNotReached,
     #[msg("Insufficient shares")]
     InsufficientShares,
+    #[msg("Partial decryption already submitted")]
+    DuplicatePartialDecryption,
     // Evidence errors
     #[msg("Evidence too large")]
     EvidenceTooLarge,
+    #[msg("Evidence root not committed")]
+    EvidenceRootNotSet,
+    #[msg("Evidence root already committed")]
+    EvidenceRootAlreadySet,
+    #[msg("Fund-flow path is broken or not covered by the evidence root")]
+    InvalidFundFlow,
+    #[msg("Evidence can no longer be added to this case")]
+    EvidenceWindowClosed,
+    #[msg("Too many evidence items")]
+    TooManyEvidenceItems,
+    #[msg("An open case already exists for this address")]
+    DuplicateCase,
+    #[msg("Account is not the address's active case")]
+    InvalidActiveCase,
     // Confidential transfer errors
     #[msg("Confidential transfers disabled")]
     ConfidentialTransfersDisabled,
@@ -66,6 +93,10 @@ pub enum ErrorCode {
     InvalidComplianceProof,
     #[msg("Transfer not pending")]
     TransferNotPending,
+    #[msg("Transfer has not been flagged")]
+    TransferNotFlagged,
+    #[msg("Disclosure does not match the transfer or case panel")]
+    InvalidDisclosure,
     // Security errors
     #[msg("Voting period has expired")]
     VotingPeriodExpired,
@@ -73,89 +104,2244 @@ pub enum ErrorCode {
     NullifierAlreadyUsed,
     #[msg("Case already exists")]
     CaseAlreadyExists,
+    #[msg("Case has not been decided")]
+    CaseNotDecided,
+    #[msg("Case already finalized")]
+    CaseAlreadyFinalized,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Case has not reached its expiry")]
+    CaseNotExpired,
+    #[msg("Max case age must be positive")]
+    InvalidMaxCaseAge,
+    // Seat auction errors
+    #[msg("Seat auction is not accepting claims")]
+    SeatAuctionClosed,
+    #[msg("Seat auction window has not ended")]
+    SeatAuctionOpen,
+    #[msg("Juror already holds a seat")]
+    AlreadySeated,
     #[msg("Juror selection failed after max attempts")]
     JurorSelectionFailed,
+    // Recusal errors
+    #[msg("Juror has recused from this case")]
+    JurorRecused,
+    #[msg("Case has reached its recusal limit")]
+    TooManyRecusals,
+    #[msg("No vacant seat to fill")]
+    NoVacantSeat,
+    // Jury acceptance errors
+    #[msg("Case is not waiting on nominee acceptance")]
+    CaseNotNominated,
+    #[msg("Acceptance window has closed")]
+    AcceptanceWindowClosed,
+    #[msg("Acceptance window has not closed")]
+    AcceptanceWindowOpen,
+    #[msg("Jury duty already accepted")]
+    AlreadyAccepted,
     #[msg("Arithmetic overflow detected")]
     ArithmeticOverflow,
+    // Validator registry errors
+    #[msg("Validator page is full")]
+    ValidatorPageFull,
+    #[msg("Validator page is missing or out of order")]
+    InvalidValidatorPage,
+    #[msg("Validator registry is full")]
+    ValidatorRegistryFull,
+    // Restitution errors
+    #[msg("Token mint does not match the case escrow")]
+    EscrowMintMismatch,
+    #[msg("Restitution claim window has closed")]
+    ClaimWindowClosed,
+    #[msg("Restitution claim window is still open")]
+    ClaimWindowOpen,
+    #[msg("Restitution already claimed")]
+    AlreadyClaimed,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    // Threat intel errors
+    #[msg("Insufficient bond")]
+    InsufficientBond,
+    #[msg("Invalid or inactive threat intel entry")]
+    InvalidThreatIntel,
+    #[msg("Threat intel publish rate limit exceeded")]
+    IntelRateLimited,
+    #[msg("Threat intel entry is under dispute")]
+    IntelUnderDispute,
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+    #[msg("Dispute cannot be resolved yet")]
+    DisputeStillOpen,
+    // Freeze integrity errors
+    #[msg("Freeze has not been executed")]
+    FreezeNotExecuted,
+    #[msg("Integrity incident is not open")]
+    IncidentNotOpen,
+    // Integration grant errors
+    #[msg("Integration grants are disabled")]
+    GrantsDisabled,
+    #[msg("Integrator grant cap reached")]
+    GrantCapReached,
+    #[msg("Grant treasury has insufficient funds")]
+    GrantTreasuryInsufficient,
+    // Rehearsal errors
+    #[msg("Case bundles require a rehearsal build")]
+    RehearsalDisabled,
+    #[msg("Invalid case bundle")]
+    InvalidCaseBundle,
+    // Settlement errors
+    #[msg("Settlement is not allowed in the current case or offer state")]
+    SettlementNotAllowed,
+    #[msg("Settlement lacks reporter acceptance or juror approvals")]
+    SettlementNotAccepted,
+    #[msg("Settlement funding deadline has passed")]
+    SettlementFundingExpired,
+    #[msg("Settlement funding deadline has not passed")]
+    SettlementFundingOpen,
+    // Watchtower errors
+    #[msg("Address is not watched or not named in the case")]
+    InvalidWatchlist,
+    // Treasury errors
+    #[msg("Fee exceeds the allowed maximum")]
+    InvalidFee,
+    #[msg("Treasury accounts are missing or do not match")]
+    InvalidTreasuryAccounts,
+    #[msg("Treasury has insufficient funds")]
+    TreasuryInsufficient,
+    // Admin council errors
+    #[msg("Privileged changes are governed by the admin council")]
+    CouncilGoverned,
+    #[msg("Not an admin council member")]
+    NotCouncilMember,
+    #[msg("Invalid council members or threshold")]
+    InvalidCouncil,
+    #[msg("Council action already approved by this member")]
+    ActionAlreadyApproved,
+    #[msg("Council action already executed or expired")]
+    ActionNotExecutable,
+    // Juror passport errors
+    #[msg("Invalid passport attesters or requirement")]
+    InvalidPassportPolicy,
+    #[msg("Attester is not a trusted namespace")]
+    UntrustedAttester,
+    #[msg("Attested stats are inconsistent or lower than before")]
+    InvalidAttestation,
+    #[msg("Passport has no room for another namespace")]
+    PassportFull,
+    #[msg("Juror passport does not meet the seat requirement")]
+    PassportRequired,
+    // Retention errors
+    #[msg("Case is still within its retention period")]
+    RetentionPeriodActive,
+    #[msg("Accounts to close do not belong to the case")]
+    InvalidCloseAccounts,
+    #[msg("Seat bonds must be refunded before the case closes")]
+    SeatBondsOutstanding,
+    // Confidential case errors
+    #[msg("Confidential cases are disabled")]
+    ConfidentialModeDisabled,
+    #[msg("Confidential components are unknown, inconsistent, or miss a mandatory one")]
+    InvalidConfidentialComponents,
+    #[msg("Votes on this case must be cast privately")]
+    PrivateVoteRequired,
+    #[msg("Votes on this case are only counted by the MPC tally")]
+    MpcTallyRequired,
+    #[msg("MPC tally is incomplete or doesn't match the committed votes")]
+    TallyIncomplete,
+    #[msg("Accused address is still sealed")]
+    AddressSealed,
+    #[msg("Address does not match the sealed commitment")]
+    InvalidSealedAddress,
+    #[msg("Key share does not match the juror's evidence share")]
+    KeyEscrowMismatch,
+    #[msg("Transfer is not a valid payout to a juror on this case")]
+    InvalidPayoutTransfer,
+    // Randomness errors
+    #[msg("Randomness seed is too old to commit to")]
+    StaleRandomness,
+    #[msg("Randomness was already revealed when requested")]
+    RandomnessAlreadyRevealed,
+    #[msg("Randomness account was recommitted since the request")]
+    RandomnessRecommitted,
+    // Verifying key errors
+    #[msg("Invalid verifying key")]
+    InvalidVerifyingKey,
+    #[msg("Verifying key too large")]
+    VerifyingKeyTooLarge,
+    // Commitment scheme errors
+    #[msg("Poseidon input is not a canonical field element")]
+    InvalidPoseidonInput,
+    #[msg("Commitment scheme can't change once voting has opened")]
+    CommitmentSchemeLocked,
+    // Reveal phase errors
+    #[msg("Case is not in its reveal phase")]
+    CaseNotRevealing,
+    #[msg("Private votes can still be committed")]
+    CommitPhaseOpen,
+    #[msg("Reveal deadline has passed")]
+    RevealDeadlinePassed,
+    #[msg("Reveal deadline hasn't passed and commitments remain unrevealed")]
+    RevealPhaseOpen,
+    // Feldman VSS errors
+    #[msg("Invalid Feldman commitment")]
+    InvalidFeldmanCommitment,
+    #[msg("Juror already dealt their Feldman commitments")]
+    AlreadyDealt,
+    #[msg("Not every juror has dealt their Feldman commitments")]
+    FeldmanDealingIncomplete,
+    #[msg("Public share doesn't match the Feldman commitments")]
+    ShareCommitmentMismatch,
+    // DKG errors
+    #[msg("Round-2 share must go to another juror of the group")]
+    InvalidShareRecipient,
+    #[msg("Share already delivered to this juror")]
+    ShareAlreadyDelivered,
+    #[msg("DKG rounds are incomplete")]
+    DkgIncomplete,
+    #[msg("Election key already generated")]
+    DkgAlreadyFinalized,
+    // Confidential balance errors
+    #[msg("Ciphertext is not a valid twisted ElGamal ciphertext")]
+    InvalidCiphertext,
+    #[msg("Sender balance changed after the range proof was generated")]
+    StaleRangeProof,
+    // Compliance rotation errors
+    #[msg("Compliance authority rotation is still timelocked")]
+    ComplianceRotationLocked,
+    #[msg("Auditor data isn't bound to the previous compliance authority")]
+    AuditorDataCurrent,
+    // Multi-auditor errors
+    #[msg("Auditor set must be 1..=5 distinct keys with a threshold no larger than the set")]
+    InvalidAuditorSet,
+    #[msg("Auditor already voted to flag this transfer")]
+    AuditorAlreadyFlagged,
+    #[msg("Transfer already flagged")]
+    TransferAlreadyFlagged,
+    // Light compression errors
+    #[msg("Vote compression is off for this case")]
+    CompressionDisabled,
+    #[msg("Light system accounts are missing or don't match the case's state tree")]
+    InvalidLightAccounts,
+    // Evidence transaction errors
+    #[msg("Merkle tree cannot be empty")]
+    EmptyMerkleTree,
+    #[msg("Evidence timestamp is in the future")]
+    FutureTimestamp,
+    #[msg("Evidence is too old to be accepted")]
+    EvidenceTooOld,
+    #[msg("Merkle proof verification failed")]
+    InvalidMerkleProof,
+    #[msg("Case was submitted without an evidence transaction root")]
+    EvidenceTxRootNotSet,
+
+    // Off-chain evidence errors
+    #[msg("Evidence URI and content hash must be set together")]
+    InvalidEvidenceHash,
+
+    // Evidence decryption errors
+    #[msg("Evidence has already been reconstructed")]
+    EvidenceAlreadyAvailable,
+    #[msg("Juror already opened their evidence share")]
+    DuplicateEvidenceShare,
+    #[msg("Reconstructed key doesn't decrypt to the committed evidence")]
+    EvidenceDecryptionFailed,
+
+    // Juror reputation errors
+    #[msg("Juror profiles don't match the case's panel")]
+    InvalidJurorProfile,
+
+    // Validator staking errors
+    #[msg("Validator self-registration is closed")]
+    SelfRegistrationClosed,
+    #[msg("Stake is below the minimum validator stake")]
+    InsufficientValidatorStake,
+
+    // Validator registry errors
+    #[msg("Validator is not on the given page")]
+    ValidatorNotListed,
+    #[msg("Validator is already in the registry")]
+    ValidatorAlreadyListed,
+
+    // Validator unbonding errors
+    #[msg("Validator is unbonding and can't deregister again")]
+    ValidatorCoolingDown,
+    #[msg("Validator must deregister before withdrawing its stake")]
+    ValidatorStillRegistered,
+    #[msg("Validator stake is still in its unbonding cooldown")]
+    StakeStillBonding,
+
+    // Juror misconduct errors
+    #[msg("Case hasn't been finalized")]
+    CaseNotFinalized,
+    #[msg("Juror stake is no longer slashable")]
+    StakeNotSlashable,
+    #[msg("On-chain state doesn't support the misconduct claim")]
+    MisconductNotProven,
+    #[msg("Misconduct report was already resolved")]
+    MisconductAlreadyResolved,
+    #[msg("Stake can't be withdrawn while a misconduct report is open")]
+    MisconductPending,
+    #[msg("Misconduct ruling accounts don't match the proposal")]
+    InvalidMisconductAccounts,
+
+    // Insurance fund errors
+    #[msg("Insurance fund can't cover the claim")]
+    InsuranceFundInsufficient,
+    #[msg("Only a finalized, not yet overturned approval can be overturned")]
+    VerdictNotOverturnable,
+    #[msg("Case verdict hasn't been overturned")]
+    VerdictNotOverturned,
+
+    // Price oracle errors
+    #[msg("Price feed account is missing, unverified, or for the wrong asset")]
+    InvalidPriceFeed,
+    #[msg("Price update is too old")]
+    StalePrice,
+    #[msg("Price confidence interval is too wide")]
+    PriceTooUncertain,
+
+    // Quadratic voting errors
+    #[msg("Case is decided by quadratic votes")]
+    QuadraticVoteRequired,
+    #[msg("Case isn't decided by quadratic votes")]
+    NotQuadraticCase,
+    #[msg("Must cast at least one vote")]
+    InvalidVoteCount,
+    #[msg("Not enough uncommitted voice credits")]
+    InsufficientVoiceCredits,
+    #[msg("Voice credits stay locked while the case is in voting")]
+    VoiceCreditsLocked,
+
+    // Vote amendment errors
+    #[msg("Vote already records that choice")]
+    VoteUnchanged,
+
+    // Graduated verdict errors
+    #[msg("Case verdict doesn't allow seizing funds")]
+    VerdictForbidsSeizure,
+
+    // Tie-break errors
+    #[msg("Tied case must be settled by the tie-break rule")]
+    CaseDeadlocked,
+    #[msg("Case vote isn't tied")]
+    NotDeadlocked,
+    #[msg("Tie awaits the tie-breaker's casting vote")]
+    AwaitingTieBreak,
+    #[msg("Casting-vote rule needs a designated tie-breaker")]
+    InvalidTieBreaker,
+
+    // Escalation errors
+    #[msg("Case can't be escalated")]
+    CaseNotEscalatable,
+    #[msg("Only a deadlocked case can be escalated without a configured bond")]
+    EscalationUnavailable,
+
+    // Multi-round voting errors
+    #[msg("Case has no probable-cause finding awaiting an evidentiary round")]
+    NoProbableCause,
+    #[msg("Restriction stands until the case ends without a freezing verdict")]
+    RestrictionStands,
+
+    // Freeze crank errors
+    #[msg("Case verdict doesn't freeze the accused's tokens")]
+    VerdictForbidsFreeze,
+
+    // Counterfeit burn errors
+    #[msg("Only fake-token and impersonation cases can burn supply")]
+    NotCounterfeitCase,
+    #[msg("Mint hasn't made the program authority its permanent delegate")]
+    BurnNotDelegated,
+
+    // NFT enforcement errors
+    #[msg("NFT holding isn't delegated to the program authority")]
+    NftNotDelegated,
+    #[msg("NFT already frozen under this case")]
+    NftAlreadyFrozen,
+    #[msg("Case has frozen the maximum number of NFTs")]
+    NftFreezeListFull,
+
+    // cNFT flagging errors
+    #[msg("Compressed NFT leaf or proof accounts don't match")]
+    InvalidCnftProof,
+    #[msg("Case has flagged the maximum number of compressed NFTs")]
+    FlaggedAssetsFull,
+
+    // Wallet flag errors
+    #[msg("Wallet flag already reflects this case")]
+    WalletFlagUnchanged,
+    #[msg("Address has an active SolSafe flag")]
+    AddressFlagged,
+
+    // Cross-chain export errors
+    #[msg("Wormhole bridge account has an unexpected owner or layout")]
+    InvalidWormholeAccount,
+
+    // External attestation errors
+    #[msg("Attestation confidence must be between 0 and 100")]
+    InvalidConfidence,
+
+    // Scam list errors
+    #[msg("Scam list root or membership proof is invalid")]
+    InvalidScamListProof,
+    #[msg("A scam list must carry an enforcing verdict")]
+    InvalidScamListVerdict,
+
+    // Notification hook errors
+    #[msg("Every hook slot is taken")]
+    HookRegistryFull,
+    #[msg("Hook must be a deployed program other than SolSafe")]
+    InvalidHookProgram,
+    #[msg("Integrator has no registered hook")]
+    HookNotRegistered,
+    #[msg("Hook programs must follow in registry order")]
+    HookAccountsMismatch,
+
+    // Migration errors
+    #[msg("Account isn't a program account of the expected type")]
+    InvalidMigration,
+    #[msg("Account is already at the current layout version")]
+    AlreadyMigrated,
+
+    // Case id errors
+    #[msg("Case id isn't the next id from the case counter")]
+    CaseIdOutOfSequence,
+
+    // Batch submission errors
+    #[msg("Batch entries must come with their accounts and stay within the batch limit")]
+    InvalidBatch,
+    #[msg("Case group is full")]
+    CaseGroupFull,
+
+    // Evidence notarization errors
+    #[msg("Evidence notarization needs the analyst's Ed25519 signature over the evidence hash")]
+    InvalidNotarization,
+
+    // EVM attestation errors
+    #[msg("EVM statement needs a secp256k1 signature from the named address")]
+    InvalidEvmSignature,
+
+    // Post-quantum signature errors
+    #[msg("Post-quantum signature does not verify against the registered key")]
+    InvalidPqSignature,
+    #[msg("Post-quantum one-time key already spent")]
+    PqKeySpent,
+    #[msg("Only high-value verdicts take post-quantum co-signatures")]
+    CaseNotHighValue,
+
+    // MPC timeout errors
+    #[msg("MPC group was aborted")]
+    MpcAborted,
+    #[msg("MPC round deadline has passed")]
+    MpcRoundClosed,
+    #[msg("No MPC round has missed its deadline")]
+    MpcNotStalled,
+
+    // Partial decryption errors
+    #[msg("Partial decryption proof does not match the juror's key share")]
+    InvalidDecryptionProof,
+
+    // Encrypted ballot errors
+    #[msg("Ballot proof does not show an encryption of 0 or 1")]
+    InvalidBallotProof,
+    #[msg("Ballots close once decryption starts")]
+    BallotsClosed,
+    #[msg("Juror has no private vote commitment for this ballot")]
+    BallotWithoutCommitment,
+    #[msg("Panel holds an election key but the tally is not encrypted")]
+    TallyNotEncrypted,
+
+    // Juror eligibility errors
+    #[msg("Juror eligibility proof is missing or invalid")]
+    InvalidEligibilityProof,
+    #[msg("Voter identities can no longer be registered for this case")]
+    VoterIdentityLocked,
+    #[msg("Juror already registered a voter identity for this case")]
+    VoterIdentityRegistered,
+
+    // Anonymous reporting errors
+    #[msg("Reporter membership proof is missing or invalid")]
+    InvalidMembershipProof,
+    #[msg("Pool root is not among the recent roots")]
+    UnknownPoolRoot,
+    #[msg("Shielded pool is full")]
+    ShieldedPoolFull,
+    #[msg("Pool denomination is fixed once notes are deposited")]
+    PoolDenominationLocked,
+    #[msg("Pool denomination does not cover the case bond")]
+    PoolDenominationTooLow,
+    // Personhood errors
+    #[msg("Personhood credential proof is missing or invalid")]
+    InvalidPersonhoodProof,
+    #[msg("Validator key has no attested personhood credential")]
+    PersonhoodRequired,
+    #[msg("Personhood credential is already bound to another validator key")]
+    CredentialAlreadyUsed,
+    #[msg("Validator key already has an attested personhood credential")]
+    IdentityAlreadyAttested,
+    // Evidence upload errors
+    #[msg("Evidence buffer is already finalized")]
+    EvidenceBufferFinalized,
+    #[msg("Evidence chunk falls outside the declared bundle")]
+    EvidenceChunkOutOfBounds,
+    #[msg("Evidence buffer has not been written in full")]
+    EvidenceIncomplete,
+    // Seat auction errors
+    #[msg("Seat claimants must hold validator stake or a trusted juror passport")]
+    SeatClaimantUnqualified,
+    #[msg("A case's reporter or accused can't claim one of its seats")]
+    SeatClaimantConflicted,
+    #[msg("Seats on a privately voted case can't be claimed")]
+    SeatBallotPrivate,
+    #[msg("A forfeited seat bond needs the insurance fund account")]
+    InsuranceFundRequired,
+    // Intel provider errors
+    #[msg("Intel provider has not been approved to publish")]
+    IntelProviderNotApproved,
+    #[msg("Threat intel entry was slashed by an upheld dispute")]
+    IntelSlashed,
+    // Restitution errors
+    #[msg("Only mints that made the program authority their permanent delegate can be seized")]
+    SeizureNotDelegated,
+    // Freeze integrity errors
+    #[msg("A breached freeze needs its integrity incident account")]
+    IncidentAccountRequired,
+    #[msg("Pass the integrity incident account only for a breached freeze")]
+    IncidentAccountUnexpected,
+}
+
+// Event emitted when an address is flagged/frozen by jury consensus
+#[event]
+pub struct FreezeEvent {
+    pub case_id: u64,
+    pub flagged_address: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+    pub frozen: bool,
+}
+
+// Case lifecycle events
+#[event]
+pub struct CaseCreated {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub scam_address: Pubkey,
+    pub severity: u8,
+    pub category: u8,
+    pub threat_id: u64,
+    pub prior_approved_cases: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RandomnessCommitted {
+    pub case_id: u64,
+    pub source: RandomnessSource,
+    pub randomness_account: Pubkey,
+    pub seed_slot: u64,
+    pub commit_slot: u64,
+}
+
+#[event]
+pub struct JurorsSelected {
+    pub case_id: u64,
+    pub jurors: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeatAuctionOpened {
+    pub case_id: u64,
+    pub seated_validators: u8,
+    pub open_seats: u8,
+    pub ends_at: i64,
+}
+
+#[event]
+pub struct JuryDutyAccepted {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub seat: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JurorRecused {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub seat: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JurorReplaced {
+    pub case_id: u64,
+    pub seat: u8,
+    pub replacement: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub approve: bool,
+    pub verdict: u8,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CaseApproved {
+    pub case_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CaseRejected {
+    pub case_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialCaseCreated {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub sealed_address: [u8; 32],
+    pub components: u8,
+    pub category: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealPhaseStarted {
+    pub case_id: u64,
+    pub commitments: u8,
+    pub reveal_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JurorSetSealed {
+    pub case_id: u64,
+    pub root: [u8; 32],
+    pub jurors: u8,
+    pub min_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PrivateTallyFinalized {
+    pub case_id: u64,
+    pub commitments: u8,
+    pub reveals: u8,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DkgShareDelivered {
+    pub case_id: u64,
+    pub dealer: Pubkey,
+    pub recipient: Pubkey,
+    pub encrypted_share: [u8; 64],      // Evaluation at the recipient's index, encrypted to them
+}
+
+#[event]
+pub struct ElectionKeyGenerated {
+    pub case_id: u64,
+    pub election_key: [u8; 32],         // Compressed BN254 G1
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EncryptedVoteAdded {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub ballots: u64,                   // Ballots summed into the tally so far
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MpcTallyApplied {
+    pub case_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MpcAborted {
+    pub case_id: u64,
+    pub shares: u8,
+    pub decryptions_due: bool,          // Stalled in decryption rather than key setup
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SealedAddressRevealed {
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialTokenPayout {
+    pub case_id: u64,
+    pub recipient: Pubkey,
+    pub token_account: Pubkey,
+    pub restitution: bool,              // Victim claim rather than juror reward
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfidentialPayoutRecorded {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub transfer_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerifyingKeyUpdated {
+    pub proof_type: ZkProofType,
+    pub version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorsSynced {
+    pub page_index: u16,
+    pub page_size: u32,
+    pub validator_count: u32,
+    pub timestamp: i64,
+}
+
+// Restitution events
+#[event]
+pub struct RestitutionSeized {
+    pub case_id: u64,
+    pub source: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_seized: u64,
+}
+
+#[event]
+pub struct VictimClaimRegistered {
+    pub case_id: u64,
+    pub victim: Pubkey,
+    pub amount_lost: u64,
+}
+
+#[event]
+pub struct RestitutionClaimed {
+    pub case_id: u64,
+    pub victim: Pubkey,
+    pub payout: u64,
+    pub fee: u64,                  // Distribution fee withheld from the payout
+}
+
+#[event]
+pub struct ThreatIntelPublished {
+    pub threat_id: u64,
+    pub provider: Pubkey,
+    pub severity: CaseSeverity,
+    pub active: bool,
+}
+
+#[event]
+pub struct IntelDisputeResolved {
+    pub threat_id: u64,
+    pub provider: Pubkey,
+    pub challenger: Pubkey,
+    pub upheld: bool,
+    pub slashed: u64,
+}
+
+// Raised when a frozen account is found thawed or its mint authority moved
+#[event]
+pub struct FreezeIntegrityAlert {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub issue: IntegrityIssue,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IntegrationGrantPaid {
+    pub integrator: Pubkey,
+    pub case_id: u64,
+    pub amount: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct CaseSettled {
+    pub case_id: u64,
+    pub accused: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementDefaulted {
+    pub case_id: u64,
+    pub accused: Pubkey,
+    pub forfeited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilActionExecuted {
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseToggled {
+    pub pauser: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,      // None for lamports
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundFlowVerified {
+    pub case_id: u64,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub hops: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvidenceAppended {
+    pub case_id: u64,
+    pub index: u16,
+    pub submitter: Pubkey,
+    pub hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DuplicateLinked {
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub reporter: Pubkey,
+    pub hash: [u8; 32],
+    pub linked_reports: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WatchtowerFlagged {
+    pub owner: Pubkey,
+    pub subscription_id: u64,
+    pub case_id: u64,
+    pub address: Pubkey,
+    pub role: WatchRole,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CaseExpired {
+    pub case_id: u64,
+    pub previous_state: u8,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PassportAttested {
+    pub juror: Pubkey,
+    pub namespace: Pubkey,
+    pub cases_served: u32,
+    pub majority_votes: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CaseArchived {
+    pub case_id: u64,
+    pub merkle_tree: Pubkey,
+    pub data_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EvidenceTxVerified {
+    pub case_id: u64,
+    pub signature: [u8; 64],
+    pub leaf_index: u32,
+    pub verifier: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompressedVoteVerified {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub commitment: [u8; 32],
+    pub leaf_index: u32,
+}
+
+#[event]
+pub struct EvidenceReconstructed {
+    pub case_id: u64,
+    pub evidence_hash: [u8; 32],
+    pub shares: u8,
+    pub timestamp: i64,
+}
+
+// Event emitted when a validator stakes its way into the registry
+#[event]
+pub struct ValidatorRegistered {
+    pub validator: Pubkey,
+    pub stake: u64,
+    pub validator_count: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a self-registered validator leaves and reclaims its stake
+#[event]
+pub struct ValidatorDeregistered {
+    pub validator: Pubkey,
+    pub stake: u64,
+    pub validator_count: u32,
+    pub cooldown_ends_epoch: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when an unbonded validator takes its stake back
+#[event]
+pub struct ValidatorStakeWithdrawn {
+    pub validator: Pubkey,
+    pub stake: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when a juror is reported for misconduct on a case
+#[event]
+pub struct JurorMisconductReported {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub reporter: Pubkey,
+    pub kind: u8,
+    pub timestamp: i64,
+}
+
+// Event emitted when governance rules on a misconduct report
+#[event]
+pub struct JurorMisconductResolved {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub upheld: bool,
+    pub slashed: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when governance reverses an approved verdict
+#[event]
+pub struct VerdictOverturned {
+    pub case_id: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when the insurance fund compensates a wrongly frozen account's owner
+#[event]
+pub struct WrongfulFreezeCompensated {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when the reporter of an approved case is paid their bounty
+#[event]
+pub struct ReporterBountyPaid {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when a juror spends voice credits on a quadratic case
+#[event]
+pub struct QuadraticVoteCast {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub approve: bool,
+    pub votes: u32,
+    pub credits: u64,
+    pub weighted_for: u64,
+    pub weighted_against: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when a juror moves their vote to another verdict
+#[event]
+pub struct VoteChanged {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub approve: bool,
+    pub verdict: u8,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when voting on a case is reopened to every registered validator
+#[event]
+pub struct CaseEscalated {
+    pub case_id: u64,
+    pub eligible_voters: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+// Event emitted when the designated tie-breaker decides a tied case
+#[event]
+pub struct TieBroken {
+    pub case_id: u64,
+    pub tie_breaker: Pubkey,
+    pub approve: bool,
+    pub timestamp: i64,
+}
+
+// Event emitted when a preliminary jury finds probable cause against the accused
+#[event]
+pub struct ProbableCauseFound {
+    pub case_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub verdict: u8,
+    pub timestamp: i64,
+}
+
+// Event emitted when a case moves from probable cause to its full evidentiary round
+#[event]
+pub struct EvidentiaryRoundOpened {
+    pub case_id: u64,
+    pub preliminary_for: u64,
+    pub preliminary_against: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+// Event emitted when a probable-cause restriction is thawed after the case ends without a freeze
+#[event]
+pub struct RestrictionLifted {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub delegate: Pubkey,          // Approval recorded at freeze time, live again once thawed
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when an approved counterfeit case destroys the accused's holding
+#[event]
+pub struct CounterfeitBurned {
+    pub case_id: u64,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub total_burned: u64,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when an NFT held by the accused is frozen or locked through Token Metadata
+#[event]
+pub struct NftFrozen {
+    pub case_id: u64,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub programmable: bool,
+    pub timestamp: i64,
+}
+
+// Event emitted when compressed NFTs held by the accused are flagged for marketplaces
+#[event]
+pub struct CnftAssetsFlagged {
+    pub case_id: u64,
+    pub asset_ids: Vec<Pubkey>,
+    pub total_flagged: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a wallet flag is raised or its severity goes up
+#[event]
+pub struct WalletFlagged {
+    pub address: Pubkey,
+    pub case_id: u64,
+    pub severity: u8,
+    pub timestamp: i64,
+}
+
+// Event emitted when the verdict behind a wallet flag is overturned
+#[event]
+pub struct WalletFlagCleared {
+    pub address: Pubkey,
+    pub case_id: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when an approved verdict is posted as a Wormhole message
+#[event]
+pub struct VerdictPublished {
+    pub case_id: u64,
+    pub scam_address: Pubkey,
+    pub emitter: Pubkey,
+    pub sequence: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when a registered oracle attests about a case's accused
+#[event]
+pub struct ExternalAttestationPosted {
+    pub case_id: u64,
+    pub oracle: Pubkey,
+    pub subject: Pubkey,
+    pub list_id: [u8; 32],
+    pub confidence: u8,
+    pub fast_tracked: bool,
+    pub timestamp: i64,
+}
+
+// Event emitted when governance publishes a curated scam list root
+#[event]
+pub struct ScamListPublished {
+    pub list_id: u64,
+    pub root: [u8; 32],
+    pub entries: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a fast-lane case is opened from a scam list
+#[event]
+pub struct ListedAddressClaimed {
+    pub list_id: u64,
+    pub case_id: u64,
+    pub address: Pubkey,
+    pub claimant: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when an integrator registers or replaces its callback program
+#[event]
+pub struct NotificationHookRegistered {
+    pub integrator: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when a callback program is taken out of the hook registry
+#[event]
+pub struct NotificationHookRemoved {
+    pub integrator: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when an account is upgraded to the current layout
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub from_len: u32,
+    pub to_len: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a batch of cases is opened on one evidence bundle
+#[event]
+pub struct CaseBatchSubmitted {
+    pub evidence_hash: [u8; 32],
+    pub reporter: Pubkey,
+    pub case_ids: Vec<u64>,
+    pub group_size: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a registered analyst's signature over a case's evidence is verified
+#[event]
+pub struct EvidenceNotarized {
+    pub case_id: u64,
+    pub analyst: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+// Event emitted when a statement signed from an EVM address is verified for a case
+#[event]
+pub struct EvmStatementVerified {
+    pub case_id: u64,
+    pub eth_address: [u8; 20],
+    pub chain_id: u64,
+    pub statement_hash: [u8; 32],
+    pub submitter: Pubkey,
+    pub timestamp: i64,
+}
+
+// Event emitted when a juror co-signs a verdict with a post-quantum key
+#[event]
+pub struct PqVerdictCosigned {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub leaf_index: u32,
+    pub timestamp: i64,
+}
+
+// Event emitted when a note enters the shielded deposit pool
+#[event]
+pub struct NoteDeposited {
+    pub leaf_index: u32,
+    pub commitment: [u8; 32],
+    pub root: [u8; 32],
+    pub timestamp: i64,
+}
+
+// Event emitted when a case is opened through a reporter membership proof
+#[event]
+pub struct AnonymousCaseSubmitted {
+    pub case_id: u64,
+    pub nullifier: [u8; 32],
+    pub reporter_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+// Event emitted when a validator key is bound to a personhood credential
+#[event]
+pub struct JurorIdentityAttested {
+    pub validator: Pubkey,
+    pub nullifier: [u8; 32],
+    pub credential_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+// Event emitted when a chunked evidence upload is sealed by its hash
+#[event]
+pub struct EvidenceUploaded {
+    pub case_id: u64,
+    pub authority: Pubkey,
+    pub size: u32,
+    pub hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+// Event emitted when an auction juror's seat bond is paid into the insurance fund
+#[event]
+pub struct SeatBondForfeited {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+// Event emitted when governance lets an intel provider publish
+#[event]
+pub struct IntelProviderApproved {
+    pub provider: Pubkey,
+    pub timestamp: i64,
 }
 
-// Event emitted when an address is flagged/frozen by jury consensus
-#[event]
-pub struct FreezeEvent {
-    pub case_id: u64,
-    pub flagged_address: Pubkey,
-    pub votes_for: u64,
-    pub votes_against: u64,
-    pub timestamp: i64,
-    pub frozen: bool,
-}
+#[program]
+pub mod solsafe_program {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, quorum: u8, min_jurors: u8) -> Result<()> {
+        instructions::initialize::handler(ctx, quorum, min_jurors)
+    }
+
+    pub fn can_execute(ctx: Context<CanExecute>, action: SimulatedAction, actor: Pubkey) -> Result<u32> {
+        instructions::can_execute::handler(ctx, action, actor)
+    }
+
+    pub fn get_case_summary(ctx: Context<GetCaseSummary>, case_id: u64) -> Result<CaseSummary> {
+        instructions::views::case_summary_handler(ctx, case_id)
+    }
+
+    pub fn get_validator_status(ctx: Context<GetValidatorStatus>, validator: Pubkey) -> Result<ValidatorSummary> {
+        instructions::views::validator_status_handler(ctx, validator)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin_transfer::propose_handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin_transfer::accept_handler(ctx)
+    }
+
+    pub fn create_admin_council(
+        ctx: Context<CreateAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::admin_council::create_handler(ctx, members, threshold)
+    }
+
+    pub fn propose_action(ctx: Context<ProposeAction>, action: CouncilAction) -> Result<()> {
+        instructions::admin_council::propose_handler(ctx, action)
+    }
+
+    pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+        instructions::admin_council::approve_handler(ctx)
+    }
+
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        instructions::admin_council::execute_handler(ctx)
+    }
+
+    pub fn subscribe_watchtower(
+        ctx: Context<SubscribeWatchtower>,
+        subscription_id: u64,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::watchtower::subscribe_handler(ctx, subscription_id, addresses)
+    }
+
+    pub fn update_watchtower(ctx: Context<UpdateWatchtower>, addresses: Vec<Pubkey>) -> Result<()> {
+        instructions::watchtower::update_handler(ctx, addresses)
+    }
+
+    pub fn flag_watchtower(ctx: Context<FlagWatchtower>, address: Pubkey) -> Result<()> {
+        instructions::watchtower::flag_handler(ctx, address)
+    }
+
+    pub fn configure_fees(
+        ctx: Context<ConfigureFees>,
+        submission_fee: u64,
+        distribution_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::treasury::configure_fees_handler(ctx, submission_fee, distribution_fee_bps)
+    }
+
+    pub fn open_treasury_vault(ctx: Context<OpenTreasuryVault>) -> Result<()> {
+        instructions::treasury::open_vault_handler(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::treasury::withdraw_handler(ctx, amount)
+    }
+
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::pause::pause_handler(ctx)
+    }
+
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::pause::unpause_handler(ctx)
+    }
+
+    pub fn set_pauser(ctx: Context<SetPauser>, pauser: Pubkey) -> Result<()> {
+        instructions::pause::set_pauser_handler(ctx, pauser)
+    }
+
+    pub fn update_validators(ctx: Context<UpdateValidators>, validators: Vec<Pubkey>) -> Result<()> {
+        instructions::update_validators::handler(ctx, validators)
+    }
+
+    
+
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        case_id: u64,
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
+        scam_address: Pubkey,
+        category: CaseCategory,
+        evidence_tx_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_evidence::handler(
+            ctx, case_id, evidence_uri, evidence_hash, scam_address, category, evidence_tx_root
+        )
+    }
+
+    pub fn submit_evidence_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SubmitEvidenceBatch<'info>>,
+        first_case_id: u64,
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
+        scam_addresses: Vec<Pubkey>,
+        category: CaseCategory,
+        evidence_tx_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::batch_evidence::handler(
+            ctx, first_case_id, evidence_uri, evidence_hash, scam_addresses, category, evidence_tx_root
+        )
+    }
+
+    pub fn link_duplicate(
+        ctx: Context<LinkDuplicate>,
+        scam_address: Pubkey,
+        hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::duplicate_case::link_handler(ctx, scam_address, hash, uri)
+    }
+
+    pub fn configure_confidential_cases(
+        ctx: Context<ConfigureConfidentialCases>,
+        enabled: bool,
+        required_components: u8,
+    ) -> Result<()> {
+        instructions::confidential_case::configure_handler(ctx, enabled, required_components)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_confidential_case(
+        ctx: Context<SubmitConfidentialCase>,
+        case_id: u64,
+        sealed_address: [u8; 32],
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
+        components: u8,
+        bump: u8,
+        category: CaseCategory,
+        evidence_tx_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::confidential_case::submit_handler(
+            ctx, case_id, sealed_address, evidence_uri, evidence_hash, components, bump, category,
+            evidence_tx_root,
+        )
+    }
+
+    pub fn verify_evidence_tx(
+        ctx: Context<VerifyEvidenceTx>,
+        signature: [u8; 64],
+        leaf_index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::evidence_tx::handler(ctx, signature, leaf_index, proof)
+    }
+
+    pub fn open_confidential_tally(ctx: Context<OpenConfidentialTally>) -> Result<()> {
+        instructions::confidential_case::open_tally_handler(ctx)
+    }
+
+    pub fn confirm_key_escrow(ctx: Context<ConfirmKeyEscrow>) -> Result<()> {
+        instructions::confidential_case::confirm_key_escrow_handler(ctx)
+    }
+
+    pub fn finalize_case_from_mpc(ctx: Context<FinalizeCaseFromMpc>) -> Result<()> {
+        instructions::confidential_case::finalize_from_mpc_handler(ctx)
+    }
+
+    pub fn set_tie_break_rule(ctx: Context<SetTieBreakRule>, rule: TieBreakRule, tie_breaker: Pubkey) -> Result<()> {
+        instructions::tie_break::set_rule_handler(ctx, rule, tie_breaker)
+    }
+
+    pub fn break_tie(ctx: Context<BreakTie>, approve: bool) -> Result<()> {
+        instructions::tie_break::break_handler(ctx, approve)
+    }
+
+    pub fn configure_escalation(
+        ctx: Context<ConfigureEscalation>,
+        bond: u64,
+        threshold: ApprovalThreshold,
+    ) -> Result<()> {
+        instructions::escalation::configure_handler(ctx, bond, threshold)
+    }
+
+    pub fn escalate_case(ctx: Context<EscalateCase>) -> Result<()> {
+        instructions::escalation::escalate_handler(ctx)
+    }
+
+    pub fn release_escalation_bond(ctx: Context<ReleaseEscalationBond>) -> Result<()> {
+        instructions::escalation::release_handler(ctx)
+    }
+
+    pub fn set_probable_cause_jurors(ctx: Context<SetProbableCauseJurors>, jurors: u8) -> Result<()> {
+        instructions::evidentiary_round::set_jurors_handler(ctx, jurors)
+    }
+
+    pub fn open_evidentiary_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenEvidentiaryRound<'info>>,
+    ) -> Result<()> {
+        instructions::evidentiary_round::open_round_handler(ctx)
+    }
+
+    pub fn lift_restriction(ctx: Context<LiftRestriction>) -> Result<()> {
+        instructions::evidentiary_round::lift_handler(ctx)
+    }
+
+    pub fn reveal_and_freeze(
+        ctx: Context<RevealAndFreeze>,
+        scam_address: Pubkey,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::confidential_case::reveal_and_freeze_handler(ctx, scam_address, salt)
+    }
+
+    pub fn record_confidential_payout(ctx: Context<RecordConfidentialPayout>) -> Result<()> {
+        instructions::confidential_case::record_payout_handler(ctx)
+    }
+
+    pub fn open_confidential_vault(
+        ctx: Context<OpenConfidentialVault>,
+        decryptable_zero_balance: [u8; 36],
+        maximum_pending_balance_credit_counter: u64,
+    ) -> Result<()> {
+        instructions::confidential_token::open_vault_handler(
+            ctx,
+            decryptable_zero_balance,
+            maximum_pending_balance_credit_counter,
+        )
+    }
+
+    pub fn fund_confidential_vault(ctx: Context<FundConfidentialVault>, amount: u64) -> Result<()> {
+        instructions::confidential_token::fund_vault_handler(ctx, amount)
+    }
+
+    pub fn apply_confidential_vault_balance(
+        ctx: Context<ApplyConfidentialVaultBalance>,
+        expected_pending_balance_credit_counter: u64,
+        new_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        instructions::confidential_token::apply_vault_balance_handler(
+            ctx,
+            expected_pending_balance_credit_counter,
+            new_decryptable_available_balance,
+        )
+    }
+
+    pub fn pay_confidential_juror_reward(
+        ctx: Context<PayConfidentialJurorReward>,
+        new_source_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        instructions::confidential_token::pay_juror_reward_handler(ctx, new_source_decryptable_available_balance)
+    }
+
+    pub fn pay_confidential_restitution(
+        ctx: Context<PayConfidentialRestitution>,
+        case_id: u64,
+        new_source_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        instructions::confidential_token::pay_restitution_handler(ctx, case_id, new_source_decryptable_available_balance)
+    }
+
+    pub fn set_category_params(
+        ctx: Context<SetCategoryParams>,
+        category: CaseCategory,
+        params: CategoryParams,
+    ) -> Result<()> {
+        instructions::case_categories::handler(ctx, category, params)
+    }
+
+    pub fn set_severity_threshold(
+        ctx: Context<SetSeverityThreshold>,
+        severity: CaseSeverity,
+        threshold: ApprovalThreshold,
+    ) -> Result<()> {
+        instructions::severity_thresholds::handler(ctx, severity, threshold)
+    }
+
+    pub fn expire_case(ctx: Context<ExpireCase>) -> Result<()> {
+        instructions::expire_case::handler(ctx)
+    }
+
+    pub fn close_case<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCase<'info>>) -> Result<()> {
+        instructions::close_case::handler(ctx)
+    }
+
+    pub fn archive_case<'info>(ctx: Context<'_, '_, 'info, 'info, ArchiveCase<'info>>) -> Result<()> {
+        instructions::close_case::archive_handler(ctx)
+    }
+
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        instructions::close_case::close_vote_record_handler(ctx)
+    }
+
+    pub fn close_mpc_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseMpcAccounts<'info>>,
+    ) -> Result<()> {
+        instructions::close_case::close_mpc_accounts_handler(ctx)
+    }
+
+    pub fn set_max_case_age(ctx: Context<SetMaxCaseAge>, max_case_age: i64) -> Result<()> {
+        instructions::expire_case::set_max_age_handler(ctx, max_case_age)
+    }
+
+    pub fn append_evidence(
+        ctx: Context<AppendEvidence>,
+        case_id: u64,
+        hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::append_evidence::handler(ctx, case_id, hash, uri)
+    }
+
+    pub fn commit_evidence_root(ctx: Context<CommitEvidenceRoot>, evidence_root: [u8; 32]) -> Result<()> {
+        instructions::fund_flow::commit_root_handler(ctx, evidence_root)
+    }
+
+    pub fn verify_fund_flow(ctx: Context<VerifyFundFlow>, victim: Pubkey, hops: Vec<FlowHop>) -> Result<()> {
+        instructions::fund_flow::verify_handler(ctx, victim, hops)
+    }
+
+    pub fn request_jurors(ctx: Context<RequestJurors>, case_id: u64) -> Result<()> {
+        instructions::request_jurors::handler(ctx, case_id)
+    }
+
+    pub fn select_jurors<'info>(ctx: Context<'_, '_, 'info, 'info, SelectJurors<'info>>) -> Result<()> {
+        instructions::select_jurors::handler(ctx)
+    }
+
+    pub fn vote(ctx: Context<Vote>, verdict: Verdict) -> Result<()> {
+        instructions::vote::handler(ctx, verdict)
+    }
+
+    pub fn vote_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteBatch<'info>>,
+        verdicts: Vec<Verdict>,
+    ) -> Result<()> {
+        instructions::vote::batch_handler(ctx, verdicts)
+    }
+
+    pub fn execute_freeze<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteFreeze<'info>>) -> Result<()> {
+        instructions::execute_freeze::handler(ctx)
+    }
+
+    pub fn burn_frozen_tokens(ctx: Context<BurnFrozenTokens>) -> Result<()> {
+        instructions::burn_counterfeit::handler(ctx)
+    }
+
+    pub fn freeze_nft(ctx: Context<FreezeNft>) -> Result<()> {
+        instructions::nft_freeze::handler(ctx)
+    }
+
+    pub fn flag_cnft_assets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FlagCnftAssets<'info>>,
+        leaves: Vec<CnftLeaf>,
+    ) -> Result<()> {
+        instructions::cnft_flags::handler(ctx, leaves)
+    }
+
+    pub fn sync_wallet_flag(ctx: Context<SyncWalletFlag>) -> Result<()> {
+        instructions::wallet_flag::handler(ctx)
+    }
+
+    pub fn check_address(ctx: Context<CheckAddress>, address: Pubkey) -> Result<()> {
+        instructions::wallet_flag::check_handler(ctx, address)
+    }
+
+    pub fn publish_verdict_crosschain(ctx: Context<PublishVerdictCrosschain>) -> Result<()> {
+        instructions::crosschain::handler(ctx)
+    }
+
+    pub fn register_attestation_oracle(
+        ctx: Context<RegisterAttestationOracle>,
+        authority: Pubkey,
+        feed: [u8; 32],
+        active: bool,
+    ) -> Result<()> {
+        instructions::attestations::register_oracle_handler(ctx, authority, feed, active)
+    }
+
+    pub fn post_attestation(
+        ctx: Context<PostAttestation>,
+        list_id: [u8; 32],
+        confidence: u8,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::attestations::post_handler(ctx, list_id, confidence, report_hash)
+    }
+
+    pub fn publish_scamlist_root(
+        ctx: Context<PublishScamListRoot>,
+        list_id: u64,
+        root: [u8; 32],
+        entries: u32,
+        verdict: Verdict,
+    ) -> Result<()> {
+        instructions::scam_list::publish_handler(ctx, list_id, root, entries, verdict)
+    }
+
+    pub fn claim_listed_address(
+        ctx: Context<ClaimListedAddress>,
+        case_id: u64,
+        address: Pubkey,
+        category: CaseCategory,
+        severity: CaseSeverity,
+        index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::scam_list::claim_handler(ctx, case_id, address, category, severity, index, proof)
+    }
+
+    pub fn register_hook(ctx: Context<RegisterHook>) -> Result<()> {
+        instructions::hooks::register_handler(ctx)
+    }
+
+    pub fn remove_hook(ctx: Context<RemoveHook>, integrator: Pubkey) -> Result<()> {
+        instructions::hooks::remove_handler(ctx, integrator)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate::config_handler(ctx)
+    }
+
+    pub fn migrate_case(ctx: Context<MigrateCase>) -> Result<()> {
+        instructions::migrate::case_handler(ctx)
+    }
+
+    pub fn set_case_counter(ctx: Context<SetCaseCounter>, next_case_id: u64) -> Result<()> {
+        instructions::case_counter::set_handler(ctx, next_case_id)
+    }
+
+    pub fn register_analyst(
+        ctx: Context<RegisterAnalyst>,
+        authority: Pubkey,
+        organization: [u8; 32],
+        active: bool,
+    ) -> Result<()> {
+        instructions::notarization::register_analyst_handler(ctx, authority, organization, active)
+    }
+
+    pub fn attest_evm_statement(
+        ctx: Context<AttestEvmStatement>,
+        case_id: u64,
+        eth_address: [u8; 20],
+        chain_id: u64,
+        statement_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::evm_attestation::handler(ctx, case_id, eth_address, chain_id, statement_hash)
+    }
 
-#[program]
-pub mod solsafe_program {
-    use super::*;
+    pub fn register_pq_key(ctx: Context<RegisterPqKey>, root: [u8; 24], height: u8) -> Result<()> {
+        instructions::pq_attestation::register_key_handler(ctx, root, height)
+    }
 
-    pub fn initialize(ctx: Context<Initialize>, quorum: u8, min_jurors: u8) -> Result<()> {
-        instructions::initialize::handler(ctx, quorum, min_jurors)
+    pub fn cosign_verdict_pq(
+        ctx: Context<CosignVerdictPq>,
+        leaf_index: u32,
+        signature: Vec<[u8; 24]>,
+        auth_path: Vec<[u8; 24]>,
+    ) -> Result<()> {
+        instructions::pq_attestation::cosign_handler(ctx, leaf_index, signature, auth_path)
     }
 
-    pub fn update_validators(ctx: Context<UpdateValidators>, validators: Vec<Pubkey>) -> Result<()> {
-        instructions::update_validators::handler(ctx, validators)
+    pub fn set_reporter_set(ctx: Context<SetReporterSet>, root: [u8; 32], members: u32) -> Result<()> {
+        instructions::anonymous_report::set_reporter_set_handler(ctx, root, members)
     }
 
-    
+    pub fn configure_shielded_pool(ctx: Context<ConfigureShieldedPool>, denomination: u64) -> Result<()> {
+        instructions::anonymous_report::configure_pool_handler(ctx, denomination)
+    }
 
-    pub fn submit_evidence(
-        ctx: Context<SubmitEvidence>,
+    pub fn deposit_note(ctx: Context<DepositNote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::anonymous_report::deposit_handler(ctx, commitment)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn anonymous_submit_evidence(
+        ctx: Context<AnonymousSubmitEvidence>,
         case_id: u64,
-        evidence: String,
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
         scam_address: Pubkey,
+        pool_root: [u8; 32],
+        nullifier: [u8; 32],
         bump: u8,
+        category: CaseCategory,
+        evidence_tx_root: [u8; 32],
+        proof: ZkProof,
     ) -> Result<()> {
-        instructions::submit_evidence::handler(ctx, case_id, evidence, scam_address, bump)
+        instructions::anonymous_report::handler(
+            ctx,
+            case_id,
+            evidence_uri,
+            evidence_hash,
+            scam_address,
+            pool_root,
+            nullifier,
+            bump,
+            category,
+            evidence_tx_root,
+            proof,
+        )
     }
 
-    pub fn request_jurors(ctx: Context<RequestJurors>, case_id: u64) -> Result<()> {
-        instructions::request_jurors::handler(ctx, case_id)
+    pub fn set_personhood_registry(
+        ctx: Context<SetPersonhoodRegistry>,
+        root: [u8; 32],
+        required: bool,
+    ) -> Result<()> {
+        instructions::juror_identity::set_registry_handler(ctx, root, required)
     }
 
-    pub fn select_jurors(ctx: Context<SelectJurors>) -> Result<()> {
-        instructions::select_jurors::handler(ctx)
+    pub fn attest_juror_identity(
+        ctx: Context<AttestJurorIdentity>,
+        nullifier: [u8; 32],
+        proof: ZkProof,
+    ) -> Result<()> {
+        instructions::juror_identity::attest_handler(ctx, nullifier, proof)
+    }
+
+    pub fn begin_evidence_upload(ctx: Context<BeginEvidenceUpload>, size: u32) -> Result<()> {
+        instructions::evidence_upload::begin_handler(ctx, size)
+    }
+
+    pub fn write_evidence_chunk(ctx: Context<WriteEvidenceChunk>, offset: u32, bytes: Vec<u8>) -> Result<()> {
+        instructions::evidence_upload::write_handler(ctx, offset, bytes)
+    }
+
+    pub fn finalize_evidence_upload(ctx: Context<FinalizeEvidenceUpload>, hash: [u8; 32]) -> Result<()> {
+        instructions::evidence_upload::finalize_handler(ctx, hash)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>, verdict: Verdict) -> Result<()> {
+        instructions::vote::change_handler(ctx, verdict)
+    }
+
+    pub fn cast_quadratic_vote(ctx: Context<CastQuadraticVote>, approve: bool, votes: u32) -> Result<()> {
+        instructions::quadratic_voting::cast_handler(ctx, approve, votes)
+    }
+
+    pub fn release_voice_credits(ctx: Context<ReleaseVoiceCredits>, case_id: u64) -> Result<()> {
+        instructions::quadratic_voting::release_handler(ctx, case_id)
+    }
+
+    pub fn set_quadratic_voting(ctx: Context<SetQuadraticVoting>, enabled: bool) -> Result<()> {
+        instructions::quadratic_voting::set_mode_handler(ctx, enabled)
+    }
+
+    pub fn claim_juror_seat(ctx: Context<ClaimJurorSeat>, bond: u64) -> Result<()> {
+        instructions::seat_auction::claim_handler(ctx, bond)
+    }
+
+    pub fn close_seat_auction(ctx: Context<CloseSeatAuction>) -> Result<()> {
+        instructions::seat_auction::close_handler(ctx)
+    }
+
+    pub fn release_seat_bond(ctx: Context<ReleaseSeatBond>) -> Result<()> {
+        instructions::seat_auction::release_handler(ctx)
+    }
+
+    pub fn accept_jury_duty(ctx: Context<AcceptJuryDuty>) -> Result<()> {
+        instructions::juror_acceptance::accept_handler(ctx)
+    }
+
+    pub fn replace_non_acceptors<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReplaceNonAcceptors<'info>>,
+    ) -> Result<()> {
+        instructions::juror_acceptance::replace_non_acceptors_handler(ctx)
     }
 
-    pub fn vote(ctx: Context<Vote>, approve: bool) -> Result<()> {
-        instructions::vote::handler(ctx, approve)
+    pub fn recuse(ctx: Context<Recuse>) -> Result<()> {
+        instructions::recusal::recuse_handler(ctx)
     }
 
-    pub fn vote_and_freeze(ctx: Context<VoteWithFreeze>, approve: bool) -> Result<()> {
-        instructions::vote::handler_freeze(ctx, approve)
+    pub fn replace_juror<'info>(ctx: Context<'_, '_, 'info, 'info, ReplaceJuror<'info>>) -> Result<()> {
+        instructions::recusal::replace_handler(ctx)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::handler(ctx)
+    }
+
+    pub fn set_heartbeat_epochs(ctx: Context<SetHeartbeatEpochs>, heartbeat_epochs: u64) -> Result<()> {
+        instructions::heartbeat::set_epochs_handler(ctx, heartbeat_epochs)
+    }
+
+    pub fn initialize_protocol_stats(ctx: Context<InitializeProtocolStats>) -> Result<()> {
+        instructions::protocol_stats::initialize_handler(ctx)
+    }
+
+    pub fn open_juror_profile(ctx: Context<OpenJurorProfile>) -> Result<()> {
+        instructions::juror_reputation::open_handler(ctx)
+    }
+
+    pub fn set_reputation_weighting(ctx: Context<SetReputationWeighting>, enabled: bool) -> Result<()> {
+        instructions::juror_reputation::set_weighting_handler(ctx, enabled)
+    }
+
+    pub fn register_validator<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterValidator<'info>>,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::validator_staking::register_handler(ctx, stake)
+    }
+
+    pub fn deregister_validator<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeregisterValidator<'info>>,
+    ) -> Result<()> {
+        instructions::validator_staking::deregister_handler(ctx)
+    }
+
+    pub fn withdraw_validator_stake(ctx: Context<WithdrawValidatorStake>) -> Result<()> {
+        instructions::validator_staking::withdraw_handler(ctx)
+    }
+
+    pub fn report_juror_misconduct(
+        ctx: Context<ReportJurorMisconduct>,
+        juror: Pubkey,
+        kind: MisconductKind,
+    ) -> Result<()> {
+        instructions::juror_misconduct::report_handler(ctx, juror, kind)
+    }
+
+    pub fn resolve_misconduct(ctx: Context<ResolveMisconduct>, uphold: bool) -> Result<()> {
+        instructions::juror_misconduct::resolve_handler(ctx, uphold)
+    }
+
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>) -> Result<()> {
+        instructions::insurance_fund::initialize_handler(ctx)
+    }
+
+    pub fn fund_insurance(ctx: Context<FundInsurance>, amount: u64) -> Result<()> {
+        instructions::insurance_fund::fund_handler(ctx, amount)
+    }
+
+    pub fn overturn_verdict(ctx: Context<OverturnVerdict>) -> Result<()> {
+        instructions::insurance_fund::overturn_handler(ctx)
+    }
+
+    pub fn claim_wrongful_freeze_compensation(ctx: Context<ClaimWrongfulFreezeCompensation>) -> Result<()> {
+        instructions::insurance_fund::claim_handler(ctx)
+    }
+
+    pub fn configure_reporter_bounty(ctx: Context<ConfigureReporterBounty>, flat: u64, bps: u16) -> Result<()> {
+        instructions::reporter_bounty::configure_handler(ctx, flat, bps)
+    }
+
+    pub fn claim_reporter_bounty(ctx: Context<ClaimReporterBounty>, case_id: u64) -> Result<()> {
+        instructions::reporter_bounty::claim_handler(ctx, case_id)
+    }
+
+    pub fn configure_price_feeds(
+        ctx: Context<ConfigurePriceFeeds>,
+        sol_usd_feed: [u8; 32],
+        feeds: Vec<TokenPriceFeed>,
+        case_bond_bps: u16,
+    ) -> Result<()> {
+        instructions::case_bond::configure_handler(ctx, sol_usd_feed, feeds, case_bond_bps)
+    }
+
+    pub fn set_min_validator_stake(ctx: Context<SetMinValidatorStake>, min_stake: u64) -> Result<()> {
+        instructions::validator_staking::set_min_stake_handler(ctx, min_stake)
+    }
+
+    pub fn set_randomness_source(ctx: Context<SetRandomnessSource>, source: RandomnessSource) -> Result<()> {
+        instructions::randomness_source::handler(ctx, source)
+    }
+
+    pub fn configure_passport_policy(
+        ctx: Context<ConfigurePassportPolicy>,
+        attesters: Vec<Pubkey>,
+        min_cases: u32,
+    ) -> Result<()> {
+        instructions::juror_passport::configure_handler(ctx, attesters, min_cases)
+    }
+
+    pub fn open_passport(ctx: Context<OpenPassport>) -> Result<()> {
+        instructions::juror_passport::open_handler(ctx)
+    }
+
+    pub fn attest_passport(
+        ctx: Context<AttestPassport>,
+        cases_served: u32,
+        majority_votes: u32,
+    ) -> Result<()> {
+        instructions::juror_passport::attest_handler(ctx, cases_served, majority_votes)
+    }
+
+    pub fn finalize_case<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeCase<'info>>) -> Result<()> {
+        instructions::finalize_case::handler(ctx)
     }
 
     pub fn sync_validators(ctx: Context<SyncValidators>, validators: Vec<Pubkey>) -> Result<()> { 
         instructions::sync_validators::handler(ctx, validators) 
     }
 
+    pub fn add_validator_page(ctx: Context<AddValidatorPage>) -> Result<()> {
+        instructions::validator_registry::add_page_handler(ctx)
+    }
+
+    pub fn append_validator(ctx: Context<AppendValidator>, validator: Pubkey) -> Result<()> {
+        instructions::validator_registry::append_handler(ctx, validator)
+    }
+
+    pub fn add_validator<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AppendValidator<'info>>,
+        validator: Pubkey,
+    ) -> Result<()> {
+        instructions::validator_registry::add_handler(ctx, validator)
+    }
+
+    pub fn remove_validator(ctx: Context<RemoveValidator>, validator: Pubkey) -> Result<()> {
+        instructions::validator_registry::remove_handler(ctx, validator)
+    }
+
+    pub fn seize_to_escrow(ctx: Context<SeizeToEscrow>, case_id: u64) -> Result<()> {
+        instructions::restitution::seize_handler(ctx, case_id)
+    }
+
+    pub fn register_victim_claim(
+        ctx: Context<RegisterVictimClaim>,
+        case_id: u64,
+        victim: Pubkey,
+        amount_lost: u64,
+    ) -> Result<()> {
+        instructions::restitution::register_claim_handler(ctx, case_id, victim, amount_lost)
+    }
+
+    pub fn claim_restitution(ctx: Context<ClaimRestitution>, case_id: u64) -> Result<()> {
+        instructions::restitution::claim_handler(ctx, case_id)
+    }
+
+    pub fn register_intel_provider(ctx: Context<RegisterIntelProvider>, bond: u64) -> Result<()> {
+        instructions::threat_intel::register_provider_handler(ctx, bond)
+    }
+
+    pub fn approve_intel_provider(ctx: Context<ApproveIntelProvider>) -> Result<()> {
+        instructions::threat_intel::approve_provider_handler(ctx)
+    }
+
+    pub fn publish_threat_intel(
+        ctx: Context<PublishThreatIntel>,
+        threat_id: u64,
+        pattern_kind: ThreatPatternKind,
+        pattern: [u8; 32],
+        severity: CaseSeverity,
+    ) -> Result<()> {
+        instructions::threat_intel::publish_handler(ctx, threat_id, pattern_kind, pattern, severity)
+    }
+
+    pub fn update_threat_intel(
+        ctx: Context<UpdateThreatIntel>,
+        severity: CaseSeverity,
+        active: bool,
+    ) -> Result<()> {
+        instructions::threat_intel::update_handler(ctx, severity, active)
+    }
+
+    pub fn dispute_threat_intel(
+        ctx: Context<DisputeThreatIntel>,
+        bond: u64,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::intel_dispute::dispute_handler(ctx, bond, reason_hash)
+    }
+
+    pub fn vote_intel_dispute(ctx: Context<VoteIntelDispute>, entry_false: bool) -> Result<()> {
+        instructions::intel_dispute::vote_handler(ctx, entry_false)
+    }
+
+    pub fn resolve_intel_dispute(ctx: Context<ResolveIntelDispute>) -> Result<()> {
+        instructions::intel_dispute::resolve_handler(ctx)
+    }
+
+    pub fn verify_freeze_integrity(ctx: Context<VerifyFreezeIntegrity>) -> Result<()> {
+        instructions::freeze_integrity::verify_handler(ctx)
+    }
+
+    pub fn resolve_integrity_incident(ctx: Context<ResolveIntegrityIncident>) -> Result<()> {
+        instructions::freeze_integrity::resolve_handler(ctx)
+    }
+
+    pub fn configure_grants(
+        ctx: Context<ConfigureGrants>,
+        rate_per_verdict: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::integration_grants::configure_handler(ctx, rate_per_verdict, enabled)
+    }
+
+    pub fn fund_grants(ctx: Context<FundGrants>, amount: u64) -> Result<()> {
+        instructions::integration_grants::fund_handler(ctx, amount)
+    }
+
+    pub fn register_integrator(
+        ctx: Context<RegisterIntegrator>,
+        authority: Pubkey,
+        kind: IntegratorKind,
+        cap: u64,
+        active: bool,
+    ) -> Result<()> {
+        instructions::integration_grants::register_integrator_handler(ctx, authority, kind, cap, active)
+    }
+
+    pub fn acknowledge_verdict(ctx: Context<AcknowledgeVerdict>) -> Result<()> {
+        instructions::integration_grants::acknowledge_handler(ctx)
+    }
+
+    pub fn propose_settlement(ctx: Context<ProposeSettlement>, case_id: u64, amount: u64) -> Result<()> {
+        instructions::settlement::propose_handler(ctx, case_id, amount)
+    }
+
+    pub fn accept_settlement(ctx: Context<AcceptSettlement>) -> Result<()> {
+        instructions::settlement::accept_handler(ctx)
+    }
+
+    pub fn approve_settlement(ctx: Context<ApproveSettlement>) -> Result<()> {
+        instructions::settlement::approve_handler(ctx)
+    }
+
+    pub fn fund_settlement(ctx: Context<FundSettlement>) -> Result<()> {
+        instructions::settlement::fund_handler(ctx)
+    }
+
+    pub fn execute_settlement(ctx: Context<ExecuteSettlement>) -> Result<()> {
+        instructions::settlement::execute_handler(ctx)
+    }
+
+    pub fn default_settlement(ctx: Context<DefaultSettlement>) -> Result<()> {
+        instructions::settlement::default_handler(ctx)
+    }
+
+    pub fn withdraw_settlement(ctx: Context<WithdrawSettlement>) -> Result<()> {
+        instructions::settlement::withdraw_handler(ctx)
+    }
+
+    // Rehearsal-only: rejected unless built with the `rehearsal` feature
+
+    pub fn export_case_bundle(ctx: Context<ExportCaseBundle>, case_id: u64) -> Result<()> {
+        instructions::case_bundle::export_handler(ctx, case_id)
+    }
+
+    pub fn stage_case_import(
+        ctx: Context<StageCaseImport>,
+        new_case_id: u64,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        instructions::case_bundle::stage_handler(ctx, new_case_id, offset, chunk)
+    }
+
+    pub fn finish_case_import(
+        ctx: Context<FinishCaseImport>,
+        new_case_id: u64,
+        scam_address: Pubkey,
+    ) -> Result<()> {
+        instructions::case_bundle::finish_handler(ctx, new_case_id, scam_address)
+    }
+
     // ZK Proof-based Private Voting Instructions - NOW FUNCTIONAL
     
-    pub fn private_vote(
-        ctx: Context<private_vote::PrivateVote>,
+    pub fn private_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PrivateVote<'info>>,
         case_id: u64,
         commitment: [u8; 32],
         nullifier: [u8; 32],
         zk_proof: ZkProof,
+        voter: [u8; 32],
+        eligibility: Option<ZkProof>,
+    ) -> Result<()> {
+        private_vote::private_vote_handler(ctx, case_id, commitment, nullifier, zk_proof, voter, eligibility)
+    }
+
+    pub fn register_voter_identity(
+        ctx: Context<RegisterVoterIdentity>,
+        identity_commitment: [u8; 32],
+    ) -> Result<()> {
+        private_vote::register_voter_identity_handler(ctx, identity_commitment)
+    }
+
+    pub fn set_commitment_scheme(
+        ctx: Context<SetCommitmentScheme>,
+        scheme: CommitmentScheme,
     ) -> Result<()> {
-        private_vote::private_vote_handler(ctx, case_id, commitment, nullifier, zk_proof)
+        private_vote::set_commitment_scheme_handler(ctx, scheme)
+    }
+
+    pub fn start_reveal_phase(ctx: Context<StartRevealPhase>) -> Result<()> {
+        private_vote::start_reveal_phase_handler(ctx)
     }
 
     pub fn reveal_vote(
-        ctx: Context<private_vote::RevealVote>,
+        ctx: Context<RevealVote>,
         vote: bool,
         salt: [u8; 32],
     ) -> Result<()> {
         private_vote::reveal_vote_handler(ctx, vote, salt)
     }
 
+    pub fn finalize_private_tally(ctx: Context<FinalizePrivateTally>) -> Result<()> {
+        private_vote::finalize_private_tally_handler(ctx)
+    }
+
+    pub fn configure_light_compression(
+        ctx: Context<ConfigureLightCompression>,
+        state_tree: Pubkey,
+        nullifier_queue: Pubkey,
+        compression_enabled: bool,
+    ) -> Result<()> {
+        light_compression::configure_handler(ctx, state_tree, nullifier_queue, compression_enabled)
+    }
+
+    pub fn verify_compressed_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyCompressedVote<'info>>,
+        leaf_index: u32,
+        root_index: u16,
+        proof: light_compression::CompressedProof,
+    ) -> Result<()> {
+        light_compression::verify_compressed_vote_handler(ctx, leaf_index, root_index, proof)
+    }
+
+    pub fn prepare_proof_verification(
+        ctx: Context<PrepareProofVerification>,
+        case_id: u64,
+        commitment: [u8; 32],
+        proof_data: Vec<u8>,
+    ) -> Result<()> {
+        split_verification::prepare_handler(ctx, case_id, commitment, proof_data)
+    }
+
+    pub fn finalize_proof_verification(
+        ctx: Context<FinalizeProofVerification>,
+    ) -> Result<()> {
+        split_verification::finalize_handler(ctx)
+    }
+
+    pub fn register_verifying_key(
+        ctx: Context<RegisterVerifyingKey>,
+        proof_type: ZkProofType,
+        key: Vec<u8>,
+    ) -> Result<()> {
+        verifying_key_registry::register_handler(ctx, proof_type, key)
+    }
+
+    pub fn update_verifying_key(
+        ctx: Context<UpdateVerifyingKey>,
+        proof_type: ZkProofType,
+        key: Vec<u8>,
+    ) -> Result<()> {
+        verifying_key_registry::update_handler(ctx, proof_type, key)
+    }
+
     pub fn initialize_private_evidence(
-        ctx: Context<evidence_verification::InitializePrivateEvidence>,
+        ctx: Context<InitializePrivateEvidence>,
         case_id: u64,
         evidence_hash: [u8; 32],
         encrypted_evidence: Vec<u8>,
@@ -167,14 +2353,27 @@ pub mod solsafe_program {
     }
 
     pub fn verify_evidence_share(
-        ctx: Context<evidence_verification::VerifyEvidenceShare>,
+        ctx: Context<VerifyEvidenceShare>,
         share_commitment: [u8; 32],
     ) -> Result<()> {
         evidence_verification::verify_evidence_share_handler(ctx, share_commitment)
     }
 
+    pub fn submit_evidence_decryption_share(
+        ctx: Context<SubmitEvidenceDecryptionShare>,
+        share: [u8; 32],
+    ) -> Result<()> {
+        evidence_verification::submit_evidence_decryption_share_handler(ctx, share)
+    }
+
+    pub fn reconstruct_evidence_onchain(
+        ctx: Context<ReconstructEvidenceOnchain>,
+    ) -> Result<()> {
+        evidence_verification::reconstruct_evidence_onchain_handler(ctx)
+    }
+
     pub fn initialize_mpc(
-        ctx: Context<arcium_mpc::InitializeMpc>,
+        ctx: Context<InitializeMpc>,
         case_id: u64,
         threshold: u8,
         total_jurors: u8,
@@ -183,43 +2382,110 @@ pub mod solsafe_program {
     }
 
     pub fn submit_mpc_share(
-        ctx: Context<arcium_mpc::SubmitMpcShare>,
+        ctx: Context<SubmitMpcShare>,
         public_share: [u8; 32],
         share_commitment: [u8; 32],
     ) -> Result<()> {
         arcium_mpc::submit_mpc_share_handler(ctx, public_share, share_commitment)
     }
 
+    pub fn dkg_round1_commit(
+        ctx: Context<DkgRound1Commit>,
+        commitments: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        arcium_mpc::dkg_round1_commit_handler(ctx, commitments)
+    }
+
+    pub fn dkg_round2_share(
+        ctx: Context<DkgRound2Share>,
+        encrypted_share: [u8; 64],
+    ) -> Result<()> {
+        arcium_mpc::dkg_round2_share_handler(ctx, encrypted_share)
+    }
+
+    pub fn dkg_finalize(ctx: Context<DkgFinalize>) -> Result<()> {
+        arcium_mpc::dkg_finalize_handler(ctx)
+    }
+
+    pub fn verify_mpc_share(ctx: Context<VerifyMpcShare>) -> Result<()> {
+        arcium_mpc::verify_mpc_share_handler(ctx)
+    }
+
+    pub fn add_encrypted_vote(
+        ctx: Context<AddEncryptedVote>,
+        ciphertext: [u8; 64],
+        proof: [u8; 128],
+    ) -> Result<()> {
+        arcium_mpc::add_encrypted_vote_handler(ctx, ciphertext, proof)
+    }
+
     pub fn submit_partial_decryption(
-        ctx: Context<arcium_mpc::SubmitPartialDecryption>,
+        ctx: Context<SubmitPartialDecryption>,
         decryption_share: [u8; 32],
         proof: [u8; 64],
     ) -> Result<()> {
         arcium_mpc::submit_partial_decryption_handler(ctx, decryption_share, proof)
     }
 
+    pub fn abort_mpc(ctx: Context<AbortMpc>) -> Result<()> {
+        arcium_mpc::abort_mpc_handler(ctx)
+    }
+
     pub fn initiate_confidential_transfer(
-        ctx: Context<dust_confidential::InitiateConfidentialTransfer>,
+        ctx: Context<InitiateConfidentialTransfer>,
         transfer_id: u64,
         encrypted_amount: [u8; 64],
-        range_proof: Vec<u8>,
         compliance_proof: Vec<u8>,
     ) -> Result<()> {
         dust_confidential::initiate_confidential_transfer_handler(
-            ctx, transfer_id, encrypted_amount, range_proof, compliance_proof
+            ctx, transfer_id, encrypted_amount, compliance_proof
         )
     }
 
     pub fn apply_confidential_transfer(
-        ctx: Context<dust_confidential::ApplyConfidentialTransfer>,
+        ctx: Context<ApplyConfidentialTransfer>,
     ) -> Result<()> {
         dust_confidential::apply_confidential_transfer_handler(ctx)
     }
 
     pub fn audit_confidential_transfer(
-        ctx: Context<dust_confidential::AuditConfidentialTransfer>,
+        ctx: Context<AuditConfidentialTransfer>,
         flag: bool,
     ) -> Result<()> {
         dust_confidential::audit_transfer_handler(ctx, flag)
     }
+
+    pub fn disclose_to_jurors(
+        ctx: Context<DiscloseToJurors>,
+        case_id: u64,
+        encrypted_amount: [u8; 64],
+        wrapped_keys: Vec<dust_confidential::WrappedJurorKey>,
+    ) -> Result<()> {
+        dust_confidential::disclose_to_jurors_handler(ctx, case_id, encrypted_amount, wrapped_keys)
+    }
+
+    pub fn set_auditors(
+        ctx: Context<SetAuditors>,
+        auditors: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        dust_confidential::set_auditors_handler(ctx, auditors, threshold)
+    }
+
+    pub fn rotate_compliance_authority(
+        ctx: Context<RotateComplianceAuthority>,
+        new_compliance_pubkey: Pubkey,
+    ) -> Result<()> {
+        dust_confidential::rotate_compliance_authority_handler(ctx, new_compliance_pubkey)
+    }
+
+    pub fn accept_compliance_authority(
+        ctx: Context<AcceptComplianceAuthority>,
+    ) -> Result<()> {
+        dust_confidential::accept_compliance_authority_handler(ctx)
+    }
+
+    pub fn rebind_auditor_data(ctx: Context<RebindAuditorData>) -> Result<()> {
+        dust_confidential::rebind_auditor_data_handler(ctx)
+    }
 }
\ No newline at end of file
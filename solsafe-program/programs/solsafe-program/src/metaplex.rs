@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+
+/// Metaplex Token Metadata; the same id on every cluster
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// `MetadataInstruction` tags; the program is native, so instructions are a Borsh enum
+const FREEZE_DELEGATED_ACCOUNT: u8 = 26;
+const LOCK: u8 = 46;
+
+/// Freeze a non-programmable NFT's token account. Token Metadata holds the mint's freeze
+/// authority through the master edition and only exercises it for the account's delegate.
+pub fn freeze_delegated_account(
+    delegate: Pubkey,
+    token_account: Pubkey,
+    edition: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(delegate, true),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new_readonly(edition, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(token_program, false),
+        ],
+        data: vec![FREEZE_DELEGATED_ACCOUNT],
+    }
+}
+
+/// Accounts of a pNFT `Lock` issued by a delegate recorded in the token record
+pub struct LockAccounts {
+    pub authority: Pubkey,
+    pub token_owner: Pubkey,
+    pub token: Pubkey,
+    pub mint: Pubkey,
+    pub metadata: Pubkey,
+    pub edition: Pubkey,
+    pub token_record: Pubkey,
+    pub payer: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Lock a programmable NFT: `LockArgs::V1` without authorization data. Unused optional
+/// accounts (the rule set and its program) are passed as the Token Metadata program id.
+pub fn lock(accounts: LockAccounts) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.authority, true),
+            AccountMeta::new_readonly(accounts.token_owner, false),
+            AccountMeta::new(accounts.token, false),
+            AccountMeta::new_readonly(accounts.mint, false),
+            AccountMeta::new(accounts.metadata, false),
+            AccountMeta::new_readonly(accounts.edition, false),
+            AccountMeta::new(accounts.token_record, false),
+            AccountMeta::new(accounts.payer, true),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_METADATA_PROGRAM_ID, false),
+        ],
+        data: vec![LOCK, 0, 0],
+    }
+}
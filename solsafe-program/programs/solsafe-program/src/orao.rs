@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::ErrorCode;
+
+/// ORAO VRF program; the same id on every cluster
+pub const ORAO_VRF_PROGRAM_ID: Pubkey = pubkey!("VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y");
+
+/// Seed prefix of ORAO request PDAs, followed by the client-chosen request seed
+pub const RANDOMNESS_ACCOUNT_SEED: &[u8] = b"orao-vrf-randomness-request";
+
+/// View of ORAO's `RandomnessV2` request account. After the discriminator comes the request
+/// state tag (0 = pending, 1 = fulfilled), the client, the seed, and once fulfilled the
+/// 64-byte output; a pending request carries its partial responses there instead.
+pub struct OraoRandomness {
+    pub client: Pubkey,
+    pub seed: [u8; 32],
+    pub randomness: Option<[u8; 64]>,
+}
+
+impl OraoRandomness {
+    pub const DISCRIMINATOR: [u8; 8] = [139, 239, 184, 215, 227, 86, 191, 226];
+    const HEADER_LEN: usize = 8 + 1 + 32 + 32;
+
+    /// Deserialize after checking the owner, discriminator, and that the account is the
+    /// request PDA for its own seed
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(info.owner == &ORAO_VRF_PROGRAM_ID, ErrorCode::InvalidRandomness);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= Self::HEADER_LEN && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::InvalidRandomness
+        );
+
+        let client = Pubkey::try_from(&data[9..41]).map_err(|_| ErrorCode::InvalidRandomness)?;
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&data[41..73]);
+        let (address, _) = Pubkey::find_program_address(&[RANDOMNESS_ACCOUNT_SEED, &seed], &ORAO_VRF_PROGRAM_ID);
+        require!(info.key() == address, ErrorCode::InvalidRandomness);
+
+        let randomness = match data[8] {
+            0 => None,
+            1 => {
+                require!(data.len() >= Self::HEADER_LEN + 64, ErrorCode::InvalidRandomness);
+                let mut randomness = [0u8; 64];
+                randomness.copy_from_slice(&data[Self::HEADER_LEN..Self::HEADER_LEN + 64]);
+                Some(randomness)
+            }
+            _ => return err!(ErrorCode::InvalidRandomness),
+        };
+        Ok(Self { client, seed, randomness })
+    }
+
+    pub fn is_fulfilled(&self) -> bool {
+        self.randomness.is_some()
+    }
+
+    /// First half of the fulfilled output, the width cases store
+    pub fn value(&self) -> Option<[u8; 32]> {
+        self.randomness.map(|randomness| {
+            let mut value = [0u8; 32];
+            value.copy_from_slice(&randomness[..32]);
+            value
+        })
+    }
+}
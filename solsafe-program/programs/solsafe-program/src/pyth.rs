@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::ErrorCode;
+
+/// Pyth Solana receiver; owns the `PriceUpdateV2` accounts pull-oracle clients post
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Updates published longer ago than this are refused (seconds)
+pub const MAX_PRICE_AGE: i64 = 60;
+/// Updates whose confidence interval is wider than this share of the price are refused
+pub const MAX_PRICE_CONF_BPS: u128 = 200;
+
+/// View of Pyth's `PriceUpdateV2` account. After the discriminator comes the write
+/// authority, the verification level (tag 0 = partial followed by a signature count,
+/// 1 = full), then the price message: feed id, price, confidence, exponent, publish time.
+pub struct PythPrice {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl PythPrice {
+    pub const DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+    const MESSAGE_OFFSET: usize = 8 + 32 + 1;
+    const MESSAGE_LEN: usize = 32 + 8 + 8 + 4 + 8;
+
+    /// Deserialize a fully verified update for `feed_id` after checking the owner,
+    /// discriminator, age, and confidence
+    pub fn load(info: &AccountInfo, feed_id: &[u8; 32], now: i64) -> Result<Self> {
+        require!(info.owner == &PYTH_RECEIVER_PROGRAM_ID, ErrorCode::InvalidPriceFeed);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= Self::MESSAGE_OFFSET + Self::MESSAGE_LEN && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::InvalidPriceFeed
+        );
+        // Partially verified updates carry fewer guardian signatures than Wormhole's quorum
+        require!(data[40] == 1, ErrorCode::InvalidPriceFeed);
+
+        let message = &data[Self::MESSAGE_OFFSET..];
+        let mut price = Self {
+            feed_id: [0u8; 32],
+            price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+            conf: u64::from_le_bytes(message[40..48].try_into().unwrap()),
+            exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+            publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+        };
+        price.feed_id.copy_from_slice(&message[..32]);
+
+        require!(price.feed_id == *feed_id && price.price > 0, ErrorCode::InvalidPriceFeed);
+        require!(now.saturating_sub(price.publish_time) <= MAX_PRICE_AGE, ErrorCode::StalePrice);
+        require!(
+            price.conf as u128 * 10_000 <= price.price as u128 * MAX_PRICE_CONF_BPS,
+            ErrorCode::PriceTooUncertain
+        );
+        Ok(price)
+    }
+
+    /// Value of `amount` base units of a token with `decimals`, in micro-USD
+    pub fn usd_value(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let value = scale(
+            amount as u128 * self.price as u128,
+            self.exponent + 6 - decimals as i32,
+        )?;
+        u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Lamports worth `micro_usd`, reading this update as the SOL/USD price
+    pub fn lamports_for(&self, micro_usd: u64) -> Result<u64> {
+        // lamports = micro_usd / 1e6 / (price * 10^exponent) * 1e9
+        let lamports = scale(micro_usd as u128, 3 - self.exponent)? / self.price as u128;
+        u64::try_from(lamports).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+/// `value * 10^exponent`, truncating
+fn scale(value: u128, exponent: i32) -> Result<u128> {
+    let factor = 10u128
+        .checked_pow(exponent.unsigned_abs())
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if exponent >= 0 {
+        value.checked_mul(factor).ok_or(error!(ErrorCode::ArithmeticOverflow))
+    } else {
+        Ok(value / factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: i64, exponent: i32) -> PythPrice {
+        PythPrice { feed_id: [0u8; 32], price, conf: 0, exponent, publish_time: 0 }
+    }
+
+    #[test]
+    fn test_usd_conversions() {
+        // 2.5 tokens with 6 decimals at $1.20
+        assert_eq!(price(120_000_000, -8).usd_value(2_500_000, 6).unwrap(), 3_000_000);
+        // 1 SOL at $150
+        let sol = price(15_000_000_000, -8);
+        assert_eq!(sol.usd_value(1_000_000_000, 9).unwrap(), 150_000_000);
+        assert_eq!(sol.lamports_for(150_000_000).unwrap(), 1_000_000_000);
+        assert_eq!(sol.lamports_for(15_000_000).unwrap(), 100_000_000);
+    }
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use sha3::{Digest, Sha3_256};
+use crate::ErrorCode;
 
-/// Quantum-resistant cryptographic utilities
-/// Uses SHA3-256 which is quantum-resistant (Grover's algorithm only provides quadratic speedup)
+// Quantum-resistant cryptographic utilities
+// Uses SHA3-256 which is quantum-resistant (Grover's algorithm only provides quadratic speedup)
 
 /// Verify Merkle proof for evidence integrity
 pub fn verify_merkle_proof(
@@ -44,16 +46,16 @@ pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
 }
 
 /// Compute SHA3-256 hash of evidence data
-pub fn hash_evidence(evidence_json: &str) -> [u8; 32] {
+pub fn hash_evidence(evidence: impl AsRef<[u8]>) -> [u8; 32] {
     let mut hasher = Sha3_256::new();
-    hasher.update(evidence_json.as_bytes());
+    hasher.update(evidence.as_ref());
     hasher.finalize().into()
 }
 
 /// Build Merkle tree from transaction signatures and compute root
 /// This allows verifying individual transactions were part of original evidence
 pub fn compute_merkle_root(leaves: &[Vec<u8>]) -> Result<[u8; 32]> {
-    require!(leaves.len() > 0, ErrorCode::EmptyMerkleTree);
+    require!(!leaves.is_empty(), ErrorCode::EmptyMerkleTree);
     
     let mut current_level: Vec<[u8; 32]> = leaves.iter().map(|leaf| hash_leaf(leaf)).collect();
     
@@ -93,16 +95,108 @@ pub fn verify_timestamp(timestamp: i64, max_age_seconds: i64) -> Result<()> {
     Ok(())
 }
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Merkle tree cannot be empty")]
-    EmptyMerkleTree,
-    #[msg("Evidence timestamp is in the future")]
-    FutureTimestamp,
-    #[msg("Evidence is too old to be accepted")]
-    EvidenceTooOld,
-    #[msg("Merkle proof verification failed")]
-    InvalidMerkleProof,
+/// Hash-based signatures: Winternitz one-time keys (w = 256) under a Merkle tree, as in
+/// XMSS. A juror registers the tree's root and signs with each leaf once, so nothing in
+/// the scheme leans on discrete logs. Hashes are SHA-256 through the syscall, truncated to
+/// 192 bits as in SPHINCS+-192; SHA3 would cost too much compute across ~3,300 chain steps.
+pub const PQ_HASH_LEN: usize = 24;
+/// One chain per digest byte, plus two for the checksum
+pub const WOTS_CHAINS: usize = PQ_HASH_LEN + 2;
+const WOTS_MAX_DIGIT: u8 = u8::MAX;
+pub const MAX_PQ_TREE_HEIGHT: u8 = 10;
+
+pub type PqNode = [u8; PQ_HASH_LEN];
+
+const PQ_CHAIN: &[u8] = b"SOLSAFE_PQ_CHAIN:";
+const PQ_LEAF: &[u8] = b"SOLSAFE_PQ_LEAF:";
+const PQ_NODE: &[u8] = b"SOLSAFE_PQ_NODE:";
+
+/// Every hash is tweaked with the key owner and its position in the scheme, so a
+/// preimage found for one chain step is useless anywhere else
+fn pq_hash(tag: &[u8], owner: &Pubkey, position: &[u8], parts: &[&[u8]]) -> PqNode {
+    let mut preimage: Vec<&[u8]> = vec![tag, owner.as_ref(), position];
+    preimage.extend_from_slice(parts);
+    hashv(&preimage).to_bytes()[..PQ_HASH_LEN].try_into().unwrap()
+}
+
+/// Base-256 digits of `digest` followed by its big-endian checksum, which stops anyone
+/// advancing a signature's chains to sign a different digest
+pub fn wots_digits(digest: &PqNode) -> [u8; WOTS_CHAINS] {
+    let mut digits = [0u8; WOTS_CHAINS];
+    digits[..PQ_HASH_LEN].copy_from_slice(digest);
+    let checksum: u16 = digest.iter().map(|digit| (WOTS_MAX_DIGIT - digit) as u16).sum();
+    digits[PQ_HASH_LEN..].copy_from_slice(&checksum.to_be_bytes());
+    digits
+}
+
+/// Advance chain `chain` of leaf `leaf_index` from step `start` for `steps` steps
+pub fn wots_chain(owner: &Pubkey, leaf_index: u32, chain: u8, mut node: PqNode, start: u8, steps: u8) -> PqNode {
+    for step in start..start + steps {
+        let position = [&leaf_index.to_le_bytes()[..], &[chain, step]].concat();
+        node = pq_hash(PQ_CHAIN, owner, &position, &[&node]);
+    }
+    node
+}
+
+/// Hash of a one-time public key's chain ends: the key's leaf in the tree
+pub fn wots_leaf(owner: &Pubkey, leaf_index: u32, chain_ends: &[PqNode]) -> PqNode {
+    let ends: Vec<&[u8]> = chain_ends.iter().map(|end| end.as_ref()).collect();
+    pq_hash(PQ_LEAF, owner, &leaf_index.to_le_bytes(), &ends)
+}
+
+/// Parent of two tree nodes; `level` counts up from the leaves
+pub fn pq_node(owner: &Pubkey, level: u8, index: u32, left: &PqNode, right: &PqNode) -> PqNode {
+    let position = [&[level][..], &index.to_le_bytes()].concat();
+    pq_hash(PQ_NODE, owner, &position, &[left, right])
+}
+
+/// Root implied by a one-time `signature` on `digest` with leaf `leaf_index`, or None if
+/// the signature or authentication path has the wrong shape
+pub fn pq_signature_root(
+    owner: &Pubkey,
+    leaf_index: u32,
+    digest: &PqNode,
+    signature: &[PqNode],
+    auth_path: &[PqNode],
+) -> Option<PqNode> {
+    if signature.len() != WOTS_CHAINS || auth_path.len() > MAX_PQ_TREE_HEIGHT as usize {
+        return None;
+    }
+    if (leaf_index as u64) >> auth_path.len() != 0 {
+        return None;
+    }
+
+    let chain_ends: Vec<PqNode> = wots_digits(digest)
+        .iter()
+        .zip(signature)
+        .enumerate()
+        .map(|(chain, (&digit, node))| {
+            wots_chain(owner, leaf_index, chain as u8, *node, digit, WOTS_MAX_DIGIT - digit)
+        })
+        .collect();
+    let mut node = wots_leaf(owner, leaf_index, &chain_ends);
+    let mut index = leaf_index;
+    for (level, sibling) in auth_path.iter().enumerate() {
+        index /= 2;
+        node = if (leaf_index >> level) % 2 == 0 {
+            pq_node(owner, level as u8 + 1, index, &node, sibling)
+        } else {
+            pq_node(owner, level as u8 + 1, index, sibling, &node)
+        };
+    }
+    Some(node)
+}
+
+/// Whether `signature` signs `digest` under the tree `root` registered by `owner`
+pub fn verify_pq_signature(
+    owner: &Pubkey,
+    root: &PqNode,
+    leaf_index: u32,
+    digest: &PqNode,
+    signature: &[PqNode],
+    auth_path: &[PqNode],
+) -> bool {
+    pq_signature_root(owner, leaf_index, digest, signature, auth_path).as_ref() == Some(root)
 }
 
 #[cfg(test)]
@@ -121,7 +215,7 @@ mod tests {
         let root = compute_merkle_root(&leaves).unwrap();
         
         // Build proof for leaf 0
-        let leaf_hash = hash_leaf(&leaves[0]);
+        let _leaf_hash = hash_leaf(&leaves[0]);
         let sibling1 = hash_leaf(&leaves[1]);
         let parent2 = hash_pair(&hash_leaf(&leaves[2]), &hash_leaf(&leaves[3]));
         
@@ -137,4 +231,46 @@ mod tests {
         let hash2 = hash_leaf(data);
         assert_eq!(hash1, hash2);
     }
+
+    fn wots_secret(leaf_index: u32, chain: usize) -> PqNode {
+        let mut secret = [7u8; PQ_HASH_LEN];
+        secret[..4].copy_from_slice(&leaf_index.to_le_bytes());
+        secret[4] = chain as u8;
+        secret
+    }
+
+    fn wots_public_leaf(owner: &Pubkey, leaf_index: u32) -> PqNode {
+        let ends: Vec<PqNode> = (0..WOTS_CHAINS)
+            .map(|chain| wots_chain(owner, leaf_index, chain as u8, wots_secret(leaf_index, chain), 0, WOTS_MAX_DIGIT))
+            .collect();
+        wots_leaf(owner, leaf_index, &ends)
+    }
+
+    fn wots_sign(owner: &Pubkey, leaf_index: u32, digest: &PqNode) -> Vec<PqNode> {
+        wots_digits(digest)
+            .iter()
+            .enumerate()
+            .map(|(chain, &digit)| wots_chain(owner, leaf_index, chain as u8, wots_secret(leaf_index, chain), 0, digit))
+            .collect()
+    }
+
+    #[test]
+    fn test_pq_signature() {
+        let owner = Pubkey::new_unique();
+        let leaves = [wots_public_leaf(&owner, 0), wots_public_leaf(&owner, 1)];
+        let root = pq_node(&owner, 1, 0, &leaves[0], &leaves[1]);
+
+        let digest = [42u8; PQ_HASH_LEN];
+        let signature = wots_sign(&owner, 1, &digest);
+        assert!(verify_pq_signature(&owner, &root, 1, &digest, &signature, &[leaves[0]]));
+
+        // Wrong leaf, digest, or owner all fail
+        assert!(!verify_pq_signature(&owner, &root, 0, &digest, &signature, &[leaves[1]]));
+        let mut other = digest;
+        other[0] = 43;
+        assert!(!verify_pq_signature(&owner, &root, 1, &other, &signature, &[leaves[0]]));
+        assert!(!verify_pq_signature(&Pubkey::new_unique(), &root, 1, &digest, &signature, &[leaves[0]]));
+        // Leaf index beyond the tree
+        assert!(!verify_pq_signature(&owner, &root, 3, &digest, &signature, &[leaves[0]]));
+    }
 }
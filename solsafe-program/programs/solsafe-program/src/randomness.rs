@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::orao::OraoRandomness;
+use crate::state::{CaseAccount, RandomnessSource};
+use crate::switchboard::RandomnessAccountData;
+use crate::ErrorCode;
+
+/// Bind `case` to a request from `source` whose value nobody can know yet. The source is
+/// recorded on the case, so switching oracles later doesn't strand a pending draw.
+pub fn commit(case: &mut CaseAccount, source: RandomnessSource, info: &AccountInfo, slot: u64) -> Result<()> {
+    let seed_slot = match source {
+        RandomnessSource::Switchboard => {
+            let randomness = RandomnessAccountData::load(info)?;
+            require!(randomness.is_fresh(slot), ErrorCode::StaleRandomness);
+            require!(!randomness.is_revealed(), ErrorCode::RandomnessAlreadyRevealed);
+            randomness.seed_slot
+        }
+        // Every ORAO request is its own PDA and is fulfilled once, so pending is enough
+        RandomnessSource::Orao => {
+            let request = OraoRandomness::load(info)?;
+            require!(!request.is_fulfilled(), ErrorCode::RandomnessAlreadyRevealed);
+            0
+        }
+    };
+
+    case.vrf_request = info.key();
+    case.randomness_source = source as u8;
+    case.randomness_seed_slot = seed_slot;
+    case.randomness_commit_slot = slot;
+    Ok(())
+}
+
+/// The value revealed for the request `case` committed to
+pub fn revealed_value(case: &CaseAccount, info: &AccountInfo) -> Result<[u8; 32]> {
+    require!(case.vrf_request == info.key(), ErrorCode::InvalidCase);
+
+    if case.randomness_source == RandomnessSource::Orao as u8 {
+        let request = OraoRandomness::load(info)?;
+        return request.value().ok_or_else(|| error!(ErrorCode::VrfNotReady));
+    }
+
+    let randomness = RandomnessAccountData::load(info)?;
+    require!(
+        randomness.seed_slot == case.randomness_seed_slot,
+        ErrorCode::RandomnessRecommitted
+    );
+    require!(randomness.is_revealed(), ErrorCode::VrfNotReady);
+    require!(
+        randomness.revealed_after(case.randomness_commit_slot),
+        ErrorCode::StaleRandomness
+    );
+    Ok(randomness.value)
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::ErrorCode;
+
+/// `SecpSignatureOffsets` after the signature count: u16 signature offset, u8 ix index,
+/// u16 address offset, u8 ix index, u16 message offset, u16 message size, u8 ix index
+const OFFSETS_START: usize = 1;
+const OFFSETS_LEN: usize = 11;
+const ETH_ADDRESS_LEN: usize = 20;
+
+/// Ethereum address that signed `message`, as recovered by a secp256k1 program
+/// instruction placed right before the current one. The runtime has already recovered
+/// the signer and matched it to the address in the instruction; what's left is making
+/// sure the signature covers `message`. The precompile hashes the message with keccak256
+/// as is, so wallet signatures must be checked against the EIP-191 prefixed message.
+pub fn verified_eth_signer(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<[u8; ETH_ADDRESS_LEN]> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let previous = current.checked_sub(1).ok_or(ErrorCode::InvalidEvmSignature)?;
+    let ix = load_instruction_at_checked(previous as usize, instructions_sysvar)?;
+    require!(ix.program_id == secp256k1_program::ID, ErrorCode::InvalidEvmSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= OFFSETS_START + OFFSETS_LEN && data[0] == 1,
+        ErrorCode::InvalidEvmSignature
+    );
+    let offsets = &data[OFFSETS_START..OFFSETS_START + OFFSETS_LEN];
+    let u16_at = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+    let (address_offset, message_offset, message_size) = (u16_at(3), u16_at(6), u16_at(8));
+    // Every part has to come from the precompile instruction itself
+    let own_index = u8::try_from(previous).map_err(|_| ErrorCode::InvalidEvmSignature)?;
+    require!(
+        offsets[2] == own_index && offsets[5] == own_index && offsets[10] == own_index,
+        ErrorCode::InvalidEvmSignature
+    );
+
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(ErrorCode::InvalidEvmSignature)?;
+    require!(signed == message, ErrorCode::InvalidEvmSignature);
+    let address = data
+        .get(address_offset..address_offset + ETH_ADDRESS_LEN)
+        .ok_or(ErrorCode::InvalidEvmSignature)?;
+    Ok(address.try_into().unwrap())
+}
+
+/// `personal_sign` form of `statement`: what an Ethereum wallet actually signs
+pub fn eip191_message(statement: &[u8]) -> Vec<u8> {
+    let mut message = format!("\x19Ethereum Signed Message:\n{}", statement.len()).into_bytes();
+    message.extend_from_slice(statement);
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip191_message() {
+        assert_eq!(eip191_message(b"hello"), b"\x19Ethereum Signed Message:\n5hello".to_vec());
+        let statement = [b'x'; 120];
+        assert!(eip191_message(&statement).starts_with(b"\x19Ethereum Signed Message:\n120x"));
+    }
+}
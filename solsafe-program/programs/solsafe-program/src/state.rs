@@ -1,51 +1,485 @@
 use anchor_lang::prelude::*;
+use crate::quantum_resistant::{hash_evidence, PQ_HASH_LEN};
+use crate::zk_proofs::{CommitmentScheme, ZkProofType};
+use crate::ErrorCode;
+
+/// Validator registry is split across PDA pages; only the last page may be partially filled
+pub const VALIDATORS_PER_PAGE: usize = 128;
+pub const MAX_VALIDATOR_PAGES: usize = 32;
+pub const MAX_VALIDATORS: usize = VALIDATORS_PER_PAGE * MAX_VALIDATOR_PAGES;
+pub const MAX_CASE_JURORS: usize = 20;
+/// Recusals a single case can absorb before it has to expire instead
+pub const MAX_RECUSALS: usize = 4;
+pub const MAX_EVIDENCE_ITEMS: u16 = 64;
+pub const MAX_EVIDENCE_URI_LEN: usize = 200;
+pub const MAX_EVIDENCE_BUFFER_LEN: u32 = 1 << 20; // Bytes an EvidenceBuffer can hold; finalize hashes all of them
+/// Longest fund-flow path a reporter can prove in one instruction
+pub const MAX_FLOW_HOPS: usize = 8;
+
+/// Default lifetime of a case before `expire_case` may dismiss it
+pub const DEFAULT_MAX_CASE_AGE: i64 = 30 * 24 * 60 * 60;
+
+/// How long a closed case's accounts are kept before their rent can be reclaimed
+pub const CASE_RETENTION_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// How long nominated jurors have to accept before they can be replaced
+pub const JURY_ACCEPTANCE_WINDOW: i64 = 24 * 60 * 60;
+
+/// How long open panel seats can be claimed when the validator pool is too small
+pub const SEAT_AUCTION_WINDOW: i64 = 2 * 60 * 60;
+/// Bond a non-validator posts to claim a seat; deliberately higher than any stake elsewhere
+pub const SEAT_AUCTION_BOND: u64 = 2_000_000_000;
+
+/// Layout versions of the program's long-lived accounts. From version 1 on, new fields of
+/// either account are appended, and `migrate_config` / `migrate_case` `grow` an older
+/// version to the current length and stamp it. The grown tail is zero-filled, so a new
+/// field reads as zero until set and zero must mean "off".
+///
+/// The unversioned baseline layouts differ throughout: its config had a validator_list
+/// and no pending_admin, and its cases were Borsh. `migrate_config` converts a baseline
+/// config field by field; Borsh-era cases have another discriminator and are refused.
+pub const CONFIG_VERSION: u8 = 1;
+pub const CASE_ACCOUNT_VERSION: u8 = 1;
 
 #[account]
 pub struct GlobalConfig {
     pub admin: Pubkey,
-    pub validator_list: Vec<Pubkey>,
+    pub pending_admin: Pubkey, // Proposed successor; default while no transfer is pending
+    pub validator_count: u32,  // Total across all validator pages
+    pub page_count: u16,
     pub bump: u8,
     pub quorum: u8,        // Number of votes needed to approve
     pub min_jurors: u8,    // Minimum jurors needed to vote
+    pub council: Pubkey,   // AdminCouncil governing privileged changes; default while the admin key does
+    pub pauser: Pubkey,    // May flip the emergency pause without waiting on governance
+    pub paused: bool,
+    pub submission_fee: u64,       // Lamports charged to open a case
+    pub distribution_fee_bps: u16, // Cut of each restitution payout
+    pub category_params: [CategoryParams; CASE_CATEGORY_COUNT], // Indexed by CaseCategory
+    pub max_case_age: i64,         // Seconds before an undecided case can be dismissed
+    pub passport_min_cases: u32,   // Attested cases a seat claimant must bring (0 = no requirement)
+    pub heartbeat_epochs: u64,     // Max epochs since a validator's last heartbeat to be drawn (0 = off)
+    pub randomness_source: RandomnessSource, // Oracle request_jurors commits new cases to
+    pub reputation_weighting: bool, // Juror draws are thinned by each candidate's JurorProfile
+    pub min_validator_stake: u64,  // Lamports a self-registering validator must stake (0 = closed)
+    pub reporter_bounty: u64,      // Flat lamports paid to the reporter of an approved case
+    pub reporter_bounty_bps: u16,  // Share of seized funds paid instead of the flat bounty (0 = flat)
+    pub case_bond_bps: u16,        // Submission bond as a share of the priced holding (0 = flat submission_fee)
+    pub quadratic_voting: bool,    // New cases are decided by credit-weighted quadratic votes
+    pub severity_thresholds: [ApprovalThreshold; CASE_SEVERITY_COUNT], // Indexed by CaseSeverity
+    pub tie_break_rule: TieBreakRule, // How finalize_case settles a level vote
+    pub tie_breaker: Pubkey,       // Casts the deciding vote under TieBreakRule::CastingVote
+    pub escalation_bond: u64,      // Lamports that escalate an undeadlocked case (0 = deadlocks only)
+    pub escalation_threshold: ApprovalThreshold, // Floor on the threshold of escalated cases (zeroed = 2/3)
+    pub probable_cause_jurors: u8, // Preliminary panel size for High and Critical cases (0 = single round)
+    pub version: u8,               // CONFIG_VERSION the account was written or migrated at
 }
 
 impl GlobalConfig {
     pub const LEN: usize = 32 + // admin
-        4 + (32 * 100) + // validator_list (max 100 validators)
+        32 + // pending_admin
+        4 + // validator_count
+        2 + // page_count
         1 + // bump
         1 + // quorum
-        1; // min_jurors
+        1 + // min_jurors
+        32 + // council
+        32 + // pauser
+        1 + // paused
+        8 + // submission_fee
+        2 + // distribution_fee_bps
+        CategoryParams::LEN * CASE_CATEGORY_COUNT + // category_params
+        8 + // max_case_age
+        4 + // passport_min_cases
+        8 + // heartbeat_epochs
+        1 + // randomness_source
+        1 + // reputation_weighting
+        8 + // min_validator_stake
+        8 + // reporter_bounty
+        2 + // reporter_bounty_bps
+        2 + // case_bond_bps
+        1 + // quadratic_voting
+        ApprovalThreshold::LEN * CASE_SEVERITY_COUNT + // severity_thresholds
+        1 + // tie_break_rule
+        32 + // tie_breaker
+        8 + // escalation_bond
+        ApprovalThreshold::LEN + // escalation_threshold
+        1 + // probable_cause_jurors
+        1; // version
+
+    /// Panel size for a case in `category`
+    pub fn min_jurors_for(&self, category: u8) -> u8 {
+        match self.category_params.get(category as usize) {
+            Some(params) if params.min_jurors > 0 => params.min_jurors,
+            _ => self.min_jurors,
+        }
+    }
+
+    /// Panel size for the case's current round; a preliminary round seats the smaller jury
+    pub fn panel_size_for(&self, case: &CaseAccount) -> u8 {
+        if case.round == CaseRound::Preliminary as u8 && self.probable_cause_jurors > 0 {
+            self.probable_cause_jurors
+        } else {
+            self.min_jurors_for(case.category)
+        }
+    }
+
+    /// Approval threshold override for `category`, if one is set
+    pub fn threshold_for(&self, category: u8) -> Option<(u8, u8)> {
+        self.category_params
+            .get(category as usize)
+            .filter(|params| params.threshold_denominator > 0)
+            .map(|params| (params.threshold_numerator, params.threshold_denominator))
+    }
+
+    /// Approval threshold configured for `severity`, if one is set
+    pub fn severity_threshold(&self, severity: u8) -> Option<(u8, u8)> {
+        self.severity_thresholds
+            .get(severity as usize)
+            .filter(|threshold| threshold.denominator > 0)
+            .map(|threshold| (threshold.numerator, threshold.denominator))
+    }
+
+    /// Threshold a new case is decided by: a category override, then the severity tier's,
+    /// then a simple majority for emergencies (known drainers, repeat offenders) or 2/3
+    pub fn case_threshold(&self, category: u8, severity: u8, emergency: bool) -> (u8, u8) {
+        self.threshold_for(category)
+            .or_else(|| self.severity_threshold(severity))
+            .unwrap_or(if emergency { (1, 2) } else { (2, 3) })
+    }
+}
+
+pub const CASE_SEVERITY_COUNT: usize = 4;
+
+/// Policy for a case whose votes end level
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreakRule {
+    RejectOnTie,       // A tie fails to carry the approval
+    EscalateToFullSet, // Voting reopens to every registered validator; a second tie rejects
+    CastingVote,       // The config's tie_breaker decides
+}
+
+/// Fraction of the seated jury an approval must exceed; zeroed means unset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApprovalThreshold {
+    pub numerator: u8,
+    pub denominator: u8,
+}
+
+impl ApprovalThreshold {
+    pub const LEN: usize = 1 + // numerator
+        1; // denominator
+}
+
+pub const CASE_CATEGORY_COUNT: usize = 5;
+
+/// Oracle juror randomness is drawn from; see `crate::randomness`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RandomnessSource {
+    Switchboard,
+    Orao,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CaseCategory {
+    RugPull,
+    PhishingDrainer,
+    FakeToken,
+    Impersonation,
+    Other,
+}
+
+/// Per-category jury overrides; zeroed fields fall back to the global defaults
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CategoryParams {
+    pub min_jurors: u8,
+    pub threshold_numerator: u8,
+    pub threshold_denominator: u8,
+}
+
+impl CategoryParams {
+    pub const LEN: usize = 1 + // min_jurors
+        1 + // threshold_numerator
+        1; // threshold_denominator
 }
 
 #[account]
+pub struct ValidatorPage {
+    pub page_index: u16,
+    pub validators: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl ValidatorPage {
+    pub const LEN: usize = 2 + // page_index
+        4 + (32 * VALIDATORS_PER_PAGE) + // validators
+        1; // bump
+
+    /// Registry-wide slot of `validator`. Removals move the last entry into a freed slot, so
+    /// a slot identifies a validator only for the moment it's read.
+    pub fn slot_of(&self, validator: &Pubkey) -> Option<usize> {
+        self.validators
+            .iter()
+            .position(|v| v == validator)
+            .map(|position| self.page_index as usize * VALIDATORS_PER_PAGE + position)
+    }
+}
+
+/// Zero-copy so votes touch only the bytes they change instead of
+/// Borsh round-tripping the whole account. Vec-like fields are fixed
+/// arrays paired with a length; enums are stored as their `u8` discriminant.
+/// The namespace gives it a discriminator of its own, so a Borsh-era case
+/// (`account:CaseAccount`) fails to load rather than being read as this layout.
+#[account("zero_copy_account", zero_copy)]
 pub struct CaseAccount {
     pub case_id: u64,
-    pub scam_address: Pubkey,
-    pub evidence: String,
-    pub jurors: Vec<Pubkey>,
-    pub juror_candidates: Vec<Pubkey>,  // All potential jurors before selection
     pub votes_for: u64,
     pub votes_against: u64,
-    pub voted_jurors: Vec<Pubkey>,      // Track who has voted
-    pub status: CaseStatus,
-    pub state: CaseState,               // Voting state
+    pub threat_id: u64,                 // Linked ThreatIntel entry (0 = none)
+    pub seat_auction_ends: i64,         // 0 unless fallback seating was opened
+    pub created_at: i64,
+    pub expires_at: i64,                // Undecided past this, anyone may dismiss the case
+    pub acceptance_deadline: i64,       // Nominees who haven't accepted by then can be replaced
+    pub closed_at: i64,                 // When the case was finalized, dismissed, or settled (0 = open)
+    pub reveal_deadline: i64,           // Private reveals after this aren't counted (0 = not revealing)
+    pub randomness_seed_slot: u64,      // Seed slot of vrf_request when it was committed to
+    pub randomness_commit_slot: u64,    // Slot request_jurors committed to vrf_request
+    pub reported_value_usd: u64,        // Micro-USD value of the priced holding at submission (0 = unpriced)
+    pub enforcement_votes: [u64; 3],    // Public votes_for split by Verdict: FreezeAndSeize, FreezeOnly, Warn
+    pub burned_amount: u64,             // Counterfeit base units destroyed by burn_frozen_tokens
+    pub scam_address: Pubkey,
+    pub reporter: Pubkey,
     pub vrf_request: Pubkey,
+    pub evidence_root: [u8; 32],        // Merkle root over the reporter's transfer claims
+    pub evidence_tx_root: [u8; 32],     // SHA3 Merkle root over the evidence bundle's tx signatures (zero = none)
+    pub evidence_hash: [u8; 32],        // SHA3 of the bundle at evidence_uri (zero = no bundle)
+    pub flow_source: Pubkey,            // Victim end of the verified fund-flow path
+    pub randomness: [u8; 32],           // VRF output the panel was drawn from; seeds replacements
+    pub sealed_address: [u8; 32],       // Commitment to the accused in confidential mode (zero = public)
+
+    pub evidence_uri: [u8; MAX_EVIDENCE_URI_LEN],  // IPFS/Arweave location of the evidence bundle
+    pub accepted_seats: u32,            // Bit per seat whose nominee accepted jury duty
+    pub evidence_uri_len: u16,
+    pub evidence_item_count: u16,       // Supplemental EvidenceItem PDAs appended so far
+    pub prior_approved_cases: u16,      // Approved verdicts against scam_address when this case opened
+    pub juror_count: u8,                // Seats on the panel; who holds them is in the case's CasePanel
+    pub status: u8,                     // CaseStatus
+    pub state: u8,                      // CaseState
+    pub severity: u8,                   // CaseSeverity
     pub bump: u8,
+    pub threshold_numerator: u8,        // Approval bar as a fraction of voters
+    pub threshold_denominator: u8,
+    pub finalized: u8,                  // Outcome written to the ScamIndex
+    pub fallback_seating: u8,           // Panel was filled through a seat auction
+    pub auction_seats: u8,              // Seats claimed by bonded non-validators
+    pub seat_bonds_released: u8,        // Auction seat bonds already paid back
+    pub replacements: u8,               // Substitutes drawn so far; salts each replacement draw
+    pub settlement_defaulted: u8,       // Accused agreed to settle and never funded it
+    pub category: u8,                   // CaseCategory
+    pub flow_hops: u8,                  // Verified hops from flow_source to scam_address (0 = none)
+    pub confidential: u8,               // ConfidentialComponents bitmask (0 = public case)
+    pub randomness_source: u8,          // RandomnessSource of vrf_request
+    pub commitment_scheme: u8,          // CommitmentScheme of private vote commitments
+    pub private_commits: u8,            // Jurors who committed a private vote
+    pub private_reveals: u8,            // Of those, revealed before reveal_deadline
+    pub overturned: u8,                 // Approval later reversed by governance; frozen owners may claim compensation
+    pub value_tier: u8,                 // CaseValueTier
+    pub quadratic: u8,                  // Decided by the case's QuadraticTally rather than one vote per juror
+    pub verdict: u8,                    // Verdict an approval carries; FreezeAndSeize unless public votes chose otherwise
+    pub escalated: u8,                  // Voting reopened to the whole validator registry
+    pub round: u8,                      // CaseRound
+    pub attestations: u8,               // ExternalAttestations posted by registered oracles
+    pub version: u8,                    // CASE_ACCOUNT_VERSION the case was written or migrated at
+    pub _padding: [u8; 2],
+}
+
+// Anchor only derives Owner for accounts without a namespace
+impl Owner for CaseAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
 }
 
 impl CaseAccount {
-    pub const LEN: usize = 8 + // case_id
-        32 + // scam_address
-        4 + 200 + // evidence (max 200 chars)
-        4 + (32 * 20) + // jurors (max 20)
-        4 + (32 * 100) + // juror_candidates (max 100)
-        8 + // votes_for
-        8 + // votes_against
-        4 + (32 * 20) + // voted_jurors (max 20)
-        1 + // status
-        1 + // state
-        32 + // vrf_request
-        1; // bump
+    pub const LEN: usize = std::mem::size_of::<CaseAccount>();
+
+    pub fn evidence_uri(&self) -> &[u8] {
+        &self.evidence_uri[..self.evidence_uri_len as usize]
+    }
+
+    /// Point the case at its off-chain bundle; a bundle always comes with its content hash
+    pub fn set_evidence(&mut self, uri: &str, hash: [u8; 32]) -> Result<()> {
+        require!(uri.len() <= MAX_EVIDENCE_URI_LEN, ErrorCode::EvidenceTooLarge);
+        require!(uri.is_empty() == (hash == [0u8; 32]), ErrorCode::InvalidEvidenceHash);
+        self.evidence_uri = [0u8; MAX_EVIDENCE_URI_LEN];
+        self.evidence_uri[..uri.len()].copy_from_slice(uri.as_bytes());
+        self.evidence_uri_len = uri.len() as u16;
+        self.evidence_hash = hash;
+        Ok(())
+    }
+
+    /// Whether `bundle`, as fetched from `evidence_uri`, is the one the reporter committed to
+    pub fn evidence_matches(&self, bundle: &str) -> bool {
+        self.evidence_hash != [0u8; 32] && hash_evidence(bundle) == self.evidence_hash
+    }
+
+    /// Votes needed to approve: strictly more than numerator/denominator of `total`
+    pub fn required_votes(&self, total: u64) -> u64 {
+        total * self.threshold_numerator as u64 / self.threshold_denominator.max(1) as u64 + 1
+    }
+
+    /// Votes ended level: every voter has voted, or the deadline passed on a tie
+    pub fn is_deadlocked(&self, now: i64) -> bool {
+        self.state == CaseState::Deadlocked as u8
+            || (self.state == CaseState::Voting as u8
+                && now >= self.expires_at
+                && self.votes_for > 0
+                && self.votes_for == self.votes_against)
+    }
+
+    pub fn has_component(&self, component: u8) -> bool {
+        self.confidential & component != 0
+    }
+
+    pub fn commitment_scheme(&self) -> CommitmentScheme {
+        if self.commitment_scheme == CommitmentScheme::Poseidon as u8 {
+            CommitmentScheme::Poseidon
+        } else {
+            CommitmentScheme::Sha256
+        }
+    }
+
+    /// Confidential case whose accused hasn't been revealed yet
+    pub fn is_sealed(&self) -> bool {
+        self.sealed_address != [0u8; 32] && self.scam_address == Pubkey::default()
+    }
+
+    /// Count a public ballot; every verdict but Dismiss is a vote to approve
+    pub fn add_verdict_vote(&mut self, verdict: Verdict) {
+        match verdict {
+            Verdict::Dismiss => self.votes_against += 1,
+            _ => {
+                self.enforcement_votes[verdict as usize] += 1;
+                self.votes_for += 1;
+            }
+        }
+    }
+
+    pub fn remove_verdict_vote(&mut self, verdict: Verdict) {
+        match verdict {
+            Verdict::Dismiss => self.votes_against = self.votes_against.saturating_sub(1),
+            _ => {
+                let count = &mut self.enforcement_votes[verdict as usize];
+                *count = count.saturating_sub(1);
+                self.votes_for = self.votes_for.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Approving verdict with the most votes; ties go to the milder one
+    pub fn winning_verdict(&self) -> Verdict {
+        [Verdict::FreezeOnly, Verdict::Warn]
+            .into_iter()
+            .fold(Verdict::FreezeAndSeize, |best, verdict| {
+                if self.enforcement_votes[verdict as usize] >= self.enforcement_votes[best as usize] {
+                    verdict
+                } else {
+                    best
+                }
+            })
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        match self.verdict {
+            1 => Verdict::FreezeOnly,
+            2 => Verdict::Warn,
+            3 => Verdict::Dismiss,
+            _ => Verdict::FreezeAndSeize,
+        }
+    }
+}
+
+/// A case's seats and recusals. Kept out of the fixed-size CaseAccount so rent covers only
+/// the panel actually drawn: select_jurors creates it empty and `realloc`s it to the drawn
+/// size, and seat claims and recusals grow it as entries are added.
+#[account]
+pub struct CasePanel {
+    pub case_id: u64,
+    pub jurors: Vec<Pubkey>,  // One per seat, juror_count long; default key marks a seat vacated by recusal
+    pub recused: Vec<Pubkey>, // Barred from this case's draws; at most MAX_RECUSALS
+    pub bump: u8,
+}
+
+impl CasePanel {
+    /// Size, less the discriminator, of a panel with `seats` seats and `recusals` recusals
+    pub const fn len_for(seats: usize, recusals: usize) -> usize {
+        8 + // case_id
+        4 + (32 * seats) + // jurors
+        4 + (32 * recusals) + // recused
+        1 // bump
+    }
+
+    /// Size, less the discriminator, the panel needs as it stands
+    pub fn required_len(&self) -> usize {
+        Self::len_for(self.jurors.len(), self.recused.len())
+    }
+
+    /// Seats still held; a recusal leaves a default key until it's replaced
+    pub fn seated_jurors(&self) -> u64 {
+        self.jurors.iter().filter(|juror| **juror != Pubkey::default()).count() as u64
+    }
+
+    pub fn is_juror(&self, juror: &Pubkey) -> bool {
+        self.jurors.contains(juror)
+    }
+
+    pub fn is_recused(&self, juror: &Pubkey) -> bool {
+        self.recused.contains(juror)
+    }
+}
+
+/// Outcome a juror votes for, from harshest to none. Approval carries the winning
+/// enforcement level: freeze and escrow, freeze only, or a ScamIndex flag alone.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    FreezeAndSeize,
+    FreezeOnly,
+    Warn,
+    Dismiss,
+}
+
+impl Verdict {
+    pub fn approves(self) -> bool {
+        self != Verdict::Dismiss
+    }
+
+    pub fn freezes(self) -> bool {
+        matches!(self, Verdict::FreezeAndSeize | Verdict::FreezeOnly)
+    }
+}
+
+/// Size bucket of a case, from the USD value of the holding priced at submission
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaseValueTier {
+    Unpriced,
+    Retail,      // Under $10k
+    Significant, // Under $100k
+    Major,       // Under $1M
+    Critical,    // $1M and up
+}
+
+impl CaseValueTier {
+    pub fn from_micro_usd(value: u64) -> Self {
+        if value == 0 {
+            return CaseValueTier::Unpriced;
+        }
+        match value / 1_000_000 {
+            0..=9_999 => CaseValueTier::Retail,
+            10_000..=99_999 => CaseValueTier::Significant,
+            100_000..=999_999 => CaseValueTier::Major,
+            _ => CaseValueTier::Critical,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -55,6 +489,22 @@ pub enum CaseStatus {
     Frozen,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaseSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl CaseSeverity {
+    /// Raise a stored severity one tier per prior approved verdict, capped at Critical
+    pub fn escalate(severity: u8, prior_approved: u32) -> u8 {
+        let raised = (severity as u32).saturating_add(prior_approved);
+        raised.min(CaseSeverity::Critical as u32) as u8
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum CaseState {
     PendingJurors,  // Waiting for juror selection
@@ -62,6 +512,23 @@ pub enum CaseState {
     Approved,       // Case approved for freeze
     Rejected,       // Case rejected
     Executed,       // Freeze executed
+    SeatAuction,    // Too few validators; open seats are being claimed
+    Settled,        // Accused settled into restitution before a verdict
+    SettlementPending, // Settlement agreed; voting halted until the accused funds it
+    Dismissed,      // Expired before reaching a verdict
+    Nominated,      // Panel drawn; waiting for nominees to accept jury duty
+    Revealing,      // Private commitments closed; jurors open them until reveal_deadline
+    Deadlocked,     // Every voter voted and the vote is level; finalize_case applies the tie-break rule
+    ProbableCause,  // Preliminary jury approved; the account stays restricted until the evidentiary round
+}
+
+/// Which vote a case is in. Serious cases are heard twice: a small jury decides whether
+/// to restrict the account, then a full jury decides the permanent outcome.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CaseRound {
+    Single,       // One vote decides the case
+    Preliminary,  // Probable-cause vote by the smaller panel
+    Evidentiary,  // Full panel hearing after probable cause was found
 }
 
 #[account]
@@ -69,12 +536,1709 @@ pub struct VoteRecord {
     pub juror: Pubkey,
     pub case_id: u64,
     pub approved: bool,
+    pub verdict: Verdict,
     pub timestamp: i64,
+    pub bump: u8,
 }
 
 impl VoteRecord {
     pub const LEN: usize = 32 + // juror
         8 + // case_id
         1 + // approved
-        8; // timestamp
+        1 + // verdict
+        8 + // timestamp
+        1; // bump
+}
+
+/// Cases one vote_batch call can vote on
+pub const MAX_BATCH_VOTES: usize = 8;
+
+/// Voice credits every juror holds in quadratic mode, stake or not
+pub const BASE_VOICE_CREDITS: u64 = 1;
+/// Staked lamports backing each further voice credit
+pub const LAMPORTS_PER_VOICE_CREDIT: u64 = 100_000_000;
+
+/// Credits a juror has committed to quadratic cases still in voting
+#[account]
+pub struct VoiceCredits {
+    pub juror: Pubkey,
+    pub locked: u64,
+    pub bump: u8,
+}
+
+impl VoiceCredits {
+    pub const LEN: usize = 32 + // juror
+        8 + // locked
+        1; // bump
+
+    /// Credits backed by `stake` lamports
+    pub fn budget(stake: u64) -> u64 {
+        BASE_VOICE_CREDITS + stake / LAMPORTS_PER_VOICE_CREDIT
+    }
+}
+
+/// Weighted votes on a quadratic case; the case's own votes_for/against count heads
+#[account]
+pub struct QuadraticTally {
+    pub case_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub credits_spent: u64,
+    pub voters: u32,
+    pub bump: u8,
+}
+
+impl QuadraticTally {
+    pub const LEN: usize = 8 + // case_id
+        8 + // votes_for
+        8 + // votes_against
+        8 + // credits_spent
+        4 + // voters
+        1; // bump
+
+    /// Approval needs strictly more than numerator/denominator of the weighted votes
+    pub fn approves(&self, numerator: u8, denominator: u8) -> bool {
+        let total = self.votes_for as u128 + self.votes_against as u128;
+        self.votes_for as u128 * denominator.max(1) as u128 > total * numerator as u128
+    }
+}
+
+/// One juror's allocation on one quadratic case; closed when the credits are released
+#[account]
+pub struct QuadraticVote {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub approve: bool,
+    pub votes: u32,
+    pub credits: u64,              // votes squared
+    pub bump: u8,
+}
+
+impl QuadraticVote {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        1 + // approve
+        4 + // votes
+        8 + // credits
+        1; // bump
+}
+
+/// Window (seconds) after escrow opens during which victims may register claims
+pub const RESTITUTION_CLAIM_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+#[account]
+pub struct RestitutionEscrow {
+    pub case_id: u64,
+    pub mint: Pubkey,
+    pub vault: Pubkey,             // Token account holding seized funds
+    pub total_seized: u64,
+    pub total_claimed: u64,        // Sum of verified victim losses
+    pub total_paid: u64,
+    pub claim_count: u32,
+    pub claims_close_at: i64,      // Pro-rata payouts unlock after this
+    pub bump: u8,
+}
+
+impl RestitutionEscrow {
+    pub const LEN: usize = 8 + // case_id
+        32 + // mint
+        32 + // vault
+        8 + // total_seized
+        8 + // total_claimed
+        8 + // total_paid
+        4 + // claim_count
+        8 + // claims_close_at
+        1; // bump
+}
+
+#[account]
+pub struct VictimClaim {
+    pub case_id: u64,
+    pub victim: Pubkey,
+    pub amount_lost: u64,
+    pub payout: u64,
+    pub paid: bool,
+    pub bump: u8,
+}
+
+impl VictimClaim {
+    pub const LEN: usize = 8 + // case_id
+        32 + // victim
+        8 + // amount_lost
+        8 + // payout
+        1 + // paid
+        1; // bump
+}
+
+/// Minimum lamports an intel provider must bond before publishing
+pub const MIN_INTEL_PROVIDER_BOND: u64 = 1_000_000_000;
+/// Rate limit window for threat-intel publishes
+pub const INTEL_PUBLISH_WINDOW: i64 = 24 * 60 * 60;
+pub const MAX_INTEL_PUBLISHES_PER_WINDOW: u16 = 20;
+/// Minimum lamports a challenger must bond to dispute an entry
+pub const MIN_INTEL_DISPUTE_BOND: u64 = 100_000_000;
+pub const INTEL_DISPUTE_PERIOD: i64 = 3 * 24 * 60 * 60;
+/// Share of the provider bond slashed for confirmed false intel (basis points)
+pub const INTEL_SLASH_BPS: u64 = 5_000;
+
+#[account]
+pub struct IntelProvider {
+    pub authority: Pubkey,
+    pub bond: u64,                 // Lamports held in this PDA as collateral
+    pub entries_published: u64,
+    pub entries_slashed: u64,
+    pub window_start: i64,         // Start of the current publish rate-limit window
+    pub window_count: u16,
+    pub active: bool,              // Cleared once slashing leaves the bond under the minimum
+    pub approved: bool,            // Governance has let the provider publish
+    pub bump: u8,
+}
+
+impl IntelProvider {
+    pub const LEN: usize = 32 + // authority
+        8 + // bond
+        8 + // entries_published
+        8 + // entries_slashed
+        8 + // window_start
+        2 + // window_count
+        1 + // active
+        1 + // approved
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ThreatPatternKind {
+    ProgramId,          // Known drainer program id
+    SignaturePattern,   // Hash of a known instruction/signature pattern
+}
+
+#[account]
+pub struct ThreatIntel {
+    pub threat_id: u64,
+    pub provider: Pubkey,
+    pub pattern_kind: ThreatPatternKind,
+    pub pattern: [u8; 32],
+    pub severity: CaseSeverity,
+    pub active: bool,
+    pub disputed: bool,                 // Unusable while a dispute is open
+    pub slashed: bool,                  // A dispute was upheld; the entry stays inactive for good
+    pub disputes: u32,                  // Disputes opened so far; seeds the next one
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ThreatIntel {
+    pub const LEN: usize = 8 + // threat_id
+        32 + // provider
+        1 + // pattern_kind
+        32 + // pattern
+        1 + // severity
+        1 + // active
+        1 + // disputed
+        1 + // slashed
+        4 + // disputes
+        8 + // updated_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,     // Entry confirmed false, provider slashed
+    Rejected,   // Entry stands, challenger bond forfeited
+}
+
+#[account]
+pub struct IntelDispute {
+    pub threat_id: u64,
+    pub nonce: u32,                     // Which of the entry's disputes this is
+    pub provider: Pubkey,
+    pub challenger: Pubkey,
+    pub challenger_bond: u64,
+    pub reason_hash: [u8; 32],
+    pub votes_false: u64,
+    pub votes_valid: u64,
+    pub deadline: i64,
+    pub status: DisputeStatus,
+    pub bump: u8,
+}
+
+impl IntelDispute {
+    pub const LEN: usize = 8 + // threat_id
+        4 + // nonce
+        32 + // provider
+        32 + // challenger
+        8 + // challenger_bond
+        32 + // reason_hash
+        8 + // votes_false
+        8 + // votes_valid
+        8 + // deadline
+        1 + // status
+        1; // bump
+}
+
+#[account]
+pub struct IntelDisputeVote {
+    pub threat_id: u64,
+    pub nonce: u32,
+    pub juror: Pubkey,
+    pub entry_false: bool,
+    pub bump: u8,
+}
+
+impl IntelDisputeVote {
+    pub const LEN: usize = 8 + // threat_id
+        4 + // nonce
+        32 + // juror
+        1 + // entry_false
+        1; // bump
+}
+
+/// One transaction shown to belong to a case's evidence bundle
+#[account]
+pub struct EvidenceTxVerification {
+    pub case_id: u64,
+    pub signature: [u8; 64],
+    pub leaf_index: u32,
+    pub verifier: Pubkey,
+    pub verified_at: i64,
+    pub bump: u8,
+}
+
+impl EvidenceTxVerification {
+    pub const LEN: usize = 8 + // case_id
+        64 + // signature
+        4 + // leaf_index
+        32 + // verifier
+        8 + // verified_at
+        1; // bump
+}
+
+/// Lamports the treasury tips whoever executes a case's first freeze
+pub const FREEZE_CRANK_TIP: u64 = 5_000_000;
+
+/// Written when a verdict freezes a token account; the integrity crank checks against it.
+/// SPL Token only lets the owner revoke an approval, so a drainer delegate found at freeze
+/// time can't be cleared by the program; it is recorded here so a later thaw can be
+/// judged against it.
+#[account]
+pub struct FreezeReceipt {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+    pub frozen_at: i64,
+    pub last_verified_at: i64,
+    pub intact: bool,              // Cleared when the crank finds the freeze undone
+    pub bump: u8,
+    pub delegate: Pubkey,          // Approved delegate when frozen (default = none)
+    pub delegated_amount: u64,     // Allowance the delegate held when frozen
+}
+
+impl FreezeReceipt {
+    pub const LEN: usize = 8 + // case_id
+        32 + // token_account
+        32 + // mint
+        8 + // frozen_at
+        8 + // last_verified_at
+        1 + // intact
+        1 + // bump
+        32 + // delegate
+        8; // delegated_amount
+}
+
+/// NFT mints a case can freeze through Token Metadata
+pub const MAX_FROZEN_NFTS: usize = 16;
+
+/// Mints of the accused's NFT holdings frozen or locked under a case
+#[account]
+pub struct NftFreezeList {
+    pub case_id: u64,
+    pub mints: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl NftFreezeList {
+    pub const LEN: usize = 8 + // case_id
+        4 + (32 * MAX_FROZEN_NFTS) + // mints
+        1; // bump
+}
+
+/// cNFTs a case can flag; compressed assets can't be frozen, only listed
+pub const MAX_FLAGGED_ASSETS: usize = 32;
+
+/// A Bubblegum leaf shown to belong to the accused. The caller supplies the leaf's
+/// contents; the owner is always the case's scam address.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CnftLeaf {
+    pub root: [u8; 32],            // Tree root the proof is against; must still be in the tree's buffer
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,                // Also the leaf index
+    pub delegate: Pubkey,          // Leaf delegate; the owner itself when none is set
+    pub proof_len: u8,             // Proof nodes this leaf takes from remaining accounts
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct FlaggedAsset {
+    pub asset_id: Pubkey,
+    pub tree: Pubkey,
+    pub leaf_hash: [u8; 32],       // Leaf as verified; a later transfer changes it
+}
+
+impl FlaggedAsset {
+    pub const LEN: usize = 32 + 32 + 32;
+}
+
+/// Compressed NFTs held by the accused of an approved case, for marketplaces to check
+/// before listing
+#[account]
+pub struct FlaggedAssets {
+    pub case_id: u64,
+    pub assets: Vec<FlaggedAsset>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FlaggedAssets {
+    pub const LEN: usize = 8 + // case_id
+        4 + (FlaggedAsset::LEN * MAX_FLAGGED_ASSETS) + // assets
+        8 + // updated_at
+        1; // bump
+
+    pub fn is_flagged(&self, asset_id: &Pubkey) -> bool {
+        self.assets.iter().any(|asset| asset.asset_id == *asset_id)
+    }
+}
+
+/// Threat feed operator allowed to attest about accused addresses, registered by governance
+#[account]
+pub struct AttestationOracle {
+    pub authority: Pubkey,
+    pub feed: [u8; 32],            // Hash of the feed's name, e.g. the drainer list it mirrors
+    pub attestations: u64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl AttestationOracle {
+    pub const LEN: usize = 32 + // authority
+        32 + // feed
+        8 + // attestations
+        1 + // active
+        1; // bump
+}
+
+/// An oracle's statement that a case's accused appears in one of its feeds. Jurors weigh
+/// it alongside the evidence; one per (case, oracle).
+#[account]
+pub struct ExternalAttestation {
+    pub case_id: u64,
+    pub oracle: Pubkey,
+    pub subject: Pubkey,           // The accused, as the oracle saw it
+    pub list_id: [u8; 32],         // Hash of the list entry's source within the feed
+    pub confidence: u8,            // 0-100
+    pub report_hash: [u8; 32],     // Hash of the oracle's off-chain report
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl ExternalAttestation {
+    pub const LEN: usize = 8 + // case_id
+        32 + // oracle
+        32 + // subject
+        32 + // list_id
+        1 + // confidence
+        32 + // report_hash
+        8 + // attested_at
+        1; // bump
+}
+
+/// Confidence at which an attestation on a case still waiting for its panel fast-tracks it
+/// to the emergency threshold, as a program-id threat intel match at submission would
+pub const ATTESTATION_FAST_TRACK_CONFIDENCE: u8 = 90;
+
+/// Off-chain forensic analyst whose Ed25519 key governance trusts to co-sign evidence
+#[account]
+pub struct Analyst {
+    pub authority: Pubkey,
+    pub organization: [u8; 32],    // Hash of the analyst's firm or handle
+    pub notarized: u64,            // Cases whose evidence the analyst has co-signed
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl Analyst {
+    pub const LEN: usize = 32 + // authority
+        32 + // organization
+        8 + // notarized
+        1 + // active
+        1; // bump
+}
+
+/// Provenance of a case's evidence: a registered analyst signed its evidence hash
+#[account]
+pub struct EvidenceNotarization {
+    pub case_id: u64,
+    pub analyst: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub notarized_at: i64,
+    pub bump: u8,
+}
+
+impl EvidenceNotarization {
+    pub const LEN: usize = 8 + // case_id
+        32 + // analyst
+        32 + // evidence_hash
+        8 + // notarized_at
+        1; // bump
+}
+
+/// Statement about a case signed from an EVM address, such as the victim's drained
+/// wallet, and verified through the secp256k1 program
+#[account]
+pub struct EvmAttestation {
+    pub case_id: u64,
+    pub eth_address: [u8; 20],
+    pub chain_id: u64,             // EIP-155 chain id the signer speaks for
+    pub statement_hash: [u8; 32],  // Hash of the off-chain statement
+    pub submitter: Pubkey,
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl EvmAttestation {
+    pub const LEN: usize = 8 + // case_id
+        20 + // eth_address
+        8 + // chain_id
+        32 + // statement_hash
+        32 + // submitter
+        8 + // attested_at
+        1; // bump
+}
+
+/// Cases worth at least this much take post-quantum co-signatures on their verdict
+pub const PQ_COSIGN_MIN_TIER: CaseValueTier = CaseValueTier::Major;
+
+/// A juror's hash-based (XMSS-style) public key: the root of a tree of one-time keys
+#[account]
+pub struct PqKey {
+    pub juror: Pubkey,
+    pub root: [u8; PQ_HASH_LEN],
+    pub height: u8,                // The tree holds 2^height one-time keys
+    pub next_leaf: u32,            // Leaves below this have signed already and are spent
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl PqKey {
+    pub const LEN: usize = 32 + // juror
+        PQ_HASH_LEN + // root
+        1 + // height
+        4 + // next_leaf
+        8 + // registered_at
+        1; // bump
+}
+
+/// A juror's post-quantum co-signature on a high-value case's verdict
+#[account]
+pub struct PqAttestation {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub state: u8,                 // CaseState signed: Approved or Rejected
+    pub verdict: u8,               // Verdict signed
+    pub digest: [u8; PQ_HASH_LEN], // What the one-time key signed
+    pub leaf_index: u32,
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl PqAttestation {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        1 + // state
+        1 + // verdict
+        PQ_HASH_LEN + // digest
+        4 + // leaf_index
+        8 + // attested_at
+        1; // bump
+}
+
+/// Depth of the shielded deposit pool's note tree
+pub const POOL_DEPTH: usize = 16;
+/// Recent pool roots an anonymous report may prove against, so deposits landing between
+/// proving and submitting don't invalidate the proof
+pub const POOL_ROOT_HISTORY: usize = 30;
+
+/// Governance-published Poseidon root over the identity commitments of eligible reporters
+#[account]
+pub struct ReporterSet {
+    pub root: [u8; 32],
+    pub members: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ReporterSet {
+    pub const LEN: usize = 32 + // root
+        4 + // members
+        8 + // updated_at
+        1; // bump
+}
+
+/// Fixed-denomination deposits, one Poseidon note commitment each in an incremental tree.
+/// Spending a note proves membership without saying which deposit it was.
+#[account]
+pub struct ShieldedPool {
+    pub denomination: u64,
+    pub next_index: u32,
+    pub filled_subtrees: [[u8; 32]; POOL_DEPTH], // Last left node seen at each level
+    pub roots: [[u8; 32]; POOL_ROOT_HISTORY],    // Ring buffer of recent roots
+    pub current_root: u8,                        // Index of the latest root in `roots`
+    pub bump: u8,
+}
+
+impl ShieldedPool {
+    pub const LEN: usize = 8 + // denomination
+        4 + // next_index
+        32 * POOL_DEPTH + // filled_subtrees
+        32 * POOL_ROOT_HISTORY + // roots
+        1 + // current_root
+        1; // bump
+
+    pub fn known_root(&self, root: &[u8; 32]) -> bool {
+        *root != [0u8; 32] && self.roots.contains(root)
+    }
+}
+
+/// Marks a pool note as spent
+#[account]
+pub struct SpentNote {
+    pub nullifier: [u8; 32],
+    pub case_id: u64,
+    pub spent_at: i64,
+    pub bump: u8,
+}
+
+impl SpentNote {
+    pub const LEN: usize = 32 + // nullifier
+        8 + // case_id
+        8 + // spent_at
+        1; // bump
+}
+
+/// Governance-published root over the personhood credentials (attestation NFTs or ZK
+/// credentials) that entitle one entity to one juror key
+#[account]
+pub struct PersonhoodRegistry {
+    pub root: [u8; 32],
+    pub required: bool, // Registration paths refuse validator keys without a JurorIdentity
+    pub attested: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PersonhoodRegistry {
+    pub const LEN: usize = 32 + // root
+        1 + // required
+        4 + // attested
+        8 + // updated_at
+        1; // bump
+}
+
+/// A validator key's proof of being backed by a unique personhood credential
+#[account]
+pub struct JurorIdentity {
+    pub validator: Pubkey,
+    pub nullifier: [u8; 32],
+    pub credential_root: [u8; 32], // Registry root the credential was proven against
+    pub attested_at: i64,
+    pub bump: u8,
+}
+
+impl JurorIdentity {
+    pub const LEN: usize = 32 + // validator
+        32 + // nullifier
+        32 + // credential_root
+        8 + // attested_at
+        1; // bump
+}
+
+/// Marks a personhood credential as spent on one validator key
+#[account]
+pub struct IdentityNullifier {
+    pub nullifier: [u8; 32],
+    pub validator: Pubkey,
+    pub bump: u8,
+}
+
+impl IdentityNullifier {
+    pub const LEN: usize = 32 + // nullifier
+        32 + // validator
+        1; // bump
+}
+
+/// A case's evidence bundle stored on chain, written in chunks and sealed by its hash.
+/// The bundle bytes follow this header in the account data; the account grows as they land.
+#[account]
+pub struct EvidenceBuffer {
+    pub case_id: u64,
+    pub authority: Pubkey,
+    pub size: u32,        // Declared bundle length
+    pub hash: [u8; 32],   // SHA-256 of the bundle once finalized (zero while uploading)
+    pub finalized: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl EvidenceBuffer {
+    pub const LEN: usize = 8 + // case_id
+        32 + // authority
+        4 + // size
+        32 + // hash
+        1 + // finalized
+        8 + // created_at
+        1; // bump
+
+    /// Offset of the bundle's first byte in the account data
+    pub const DATA_START: usize = 8 + Self::LEN;
+}
+
+/// One per flagged address, so wallets and RPC providers can warn before a transfer
+/// with a single account lookup. Covers native SOL and anything else that can't be frozen.
+#[account]
+pub struct WalletFlag {
+    pub address: Pubkey,
+    pub severity: u8,              // CaseSeverity of the verdict behind the flag
+    pub case_id: u64,              // Approved case currently backing the flag
+    pub active: bool,              // Cleared when that verdict is overturned
+    pub flagged_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl WalletFlag {
+    pub const LEN: usize = 32 + // address
+        1 + // severity
+        8 + // case_id
+        1 + // active
+        8 + // flagged_at
+        8 + // updated_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    Thawed,                  // Token account is no longer frozen
+    FreezeAuthorityChanged,  // Mint freeze authority moved away from the program
+    DelegateChanged,         // Approval differs from the receipt's; a frozen account can't change it
+}
+
+/// Opened by the integrity crank for the admin (guardian) to review
+#[account]
+pub struct IntegrityIncident {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub reporter: Pubkey,          // Crank caller that flagged the breach
+    pub issue: IntegrityIssue,
+    pub opened_at: i64,
+    pub resolved_at: i64,          // 0 while open
+    pub occurrences: u32,
+    pub bump: u8,
+}
+
+impl IntegrityIncident {
+    pub const LEN: usize = 8 + // case_id
+        32 + // token_account
+        32 + // reporter
+        1 + // issue
+        8 + // opened_at
+        8 + // resolved_at
+        4 + // occurrences
+        1; // bump
+
+    pub fn is_open(&self) -> bool {
+        self.opened_at != 0 && self.resolved_at == 0
+    }
+}
+
+/// Treasury and governance-set rate for integration grants; lamports are held on this PDA
+#[account]
+pub struct GrantProgram {
+    pub rate_per_verdict: u64,     // Lamports paid per acknowledged verdict
+    pub total_paid: u64,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl GrantProgram {
+    pub const LEN: usize = 8 + // rate_per_verdict
+        8 + // total_paid
+        1 + // enabled
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum IntegratorKind {
+    Marketplace,
+    Wallet,
+    Other,
+}
+
+#[account]
+pub struct Integrator {
+    pub authority: Pubkey,
+    pub kind: IntegratorKind,
+    pub cap: u64,                  // Lifetime grant ceiling in lamports
+    pub earned: u64,
+    pub acknowledgments: u64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl Integrator {
+    pub const LEN: usize = 32 + // authority
+        1 + // kind
+        8 + // cap
+        8 + // earned
+        8 + // acknowledgments
+        1 + // active
+        1; // bump
+}
+
+/// Partner programs called back by enforcement; bounded so a freeze stays within budget
+pub const MAX_NOTIFICATION_HOOKS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationHook {
+    pub integrator: Pubkey,        // Authority of the Integrator that registered it
+    pub program_id: Pubkey,        // Receives a `solsafe_verdict` instruction per freeze
+}
+
+impl NotificationHook {
+    pub const LEN: usize = 32 + 32;
+}
+
+/// Callback programs of whitelisted integrators, one per integrator
+#[account]
+pub struct HookRegistry {
+    pub hooks: Vec<NotificationHook>,
+    pub bump: u8,
+}
+
+impl HookRegistry {
+    pub const LEN: usize = 4 + (NotificationHook::LEN * MAX_NOTIFICATION_HOOKS) + // hooks
+        1; // bump
+}
+
+/// One per (case, integrator); its existence stops a verdict being paid twice
+#[account]
+pub struct VerdictAcknowledgment {
+    pub case_id: u64,
+    pub integrator: Pubkey,
+    pub receipt: Pubkey,           // FreezeReceipt backing the acknowledgment
+    pub amount_paid: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl VerdictAcknowledgment {
+    pub const LEN: usize = 8 + // case_id
+        32 + // integrator
+        32 + // receipt
+        8 + // amount_paid
+        8 + // timestamp
+        1; // bump
+}
+
+/// Most recent case ids kept per reported address; `total_cases` keeps counting past it
+pub const MAX_INDEXED_CASES: usize = 32;
+
+/// O(1) "has this address been flagged?" lookup, seeded by the reported address
+#[account]
+pub struct ScamIndex {
+    pub address: Pubkey,
+    pub case_ids: Vec<u64>,
+    pub total_cases: u32,
+    pub approved_cases: u32,
+    pub rejected_cases: u32,
+    pub flagged: bool,             // Set once any case against the address is approved
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ScamIndex {
+    pub const LEN: usize = 32 + // address
+        4 + (8 * MAX_INDEXED_CASES) + // case_ids
+        4 + // total_cases
+        4 + // approved_cases
+        4 + // rejected_cases
+        1 + // flagged
+        8 + // updated_at
+        1; // bump
+
+    pub fn record_case(&mut self, case_id: u64) {
+        if self.case_ids.len() == MAX_INDEXED_CASES {
+            self.case_ids.remove(0);
+        }
+        self.case_ids.push(case_id);
+        self.total_cases = self.total_cases.saturating_add(1);
+    }
+}
+
+/// Root of an off-chain curated scam list. Each leaf commits to an address with the
+/// category and severity its fast-lane case opens with.
+#[account]
+pub struct ScamList {
+    pub list_id: u64,
+    pub root: [u8; 32],
+    pub entries: u32,              // Leaves under `root`, as declared by governance
+    pub verdict: Verdict,          // Enforcement every case claimed from the list carries
+    pub claims: u32,
+    pub published_at: i64,
+    pub bump: u8,
+}
+
+impl ScamList {
+    pub const LEN: usize = 8 + // list_id
+        32 + // root
+        4 + // entries
+        1 + // verdict
+        4 + // claims
+        8 + // published_at
+        1; // bump
+}
+
+/// Existence stops an address being claimed from the same list twice
+#[account]
+pub struct ListedAddressClaim {
+    pub list_id: u64,
+    pub address: Pubkey,
+    pub case_id: u64,
+    pub bump: u8,
+}
+
+impl ListedAddressClaim {
+    pub const LEN: usize = 8 + // list_id
+        32 + // address
+        8 + // case_id
+        1; // bump
+}
+
+/// Upper bound on a Borsh-encoded case bundle (fully populated case is ~1.8KB)
+pub const MAX_CASE_BUNDLE_LEN: usize = 2048;
+
+/// Exported case state, fetched off-chain and replayed into another deployment
+#[account]
+pub struct CaseBundleExport {
+    pub case_id: u64,
+    pub exported_at: i64,
+    pub data: Vec<u8>,             // Borsh-encoded CaseBundle
+    pub bump: u8,
+}
+
+impl CaseBundleExport {
+    pub const LEN: usize = 8 + // case_id
+        8 + // exported_at
+        4 + MAX_CASE_BUNDLE_LEN + // data
+        1; // bump
+}
+
+/// Staging area for an incoming bundle; written in chunks, closed on import
+#[account]
+pub struct CaseBundleImport {
+    pub new_case_id: u64,
+    pub authority: Pubkey,
+    pub data: Vec<u8>,
+    pub bump: u8,
+}
+
+impl CaseBundleImport {
+    pub const LEN: usize = 8 + // new_case_id
+        32 + // authority
+        4 + MAX_CASE_BUNDLE_LEN + // data
+        1; // bump
+}
+
+/// Bond posted by a juror who claimed a seat in a case's fallback auction
+#[account]
+pub struct SeatBond {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl SeatBond {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        8 + // amount
+        1; // bump
+}
+
+/// Bond behind a request to reopen a case to the whole registry; returned once the case
+/// is decided. Also opened, empty, when a deadlock is escalated, so a case escalates once.
+#[account]
+pub struct EscalationBond {
+    pub case_id: u64,
+    pub escalator: Pubkey,
+    pub amount: u64,
+    pub posted_at: i64,
+    pub bump: u8,
+}
+
+impl EscalationBond {
+    pub const LEN: usize = 8 + // case_id
+        32 + // escalator
+        8 + // amount
+        8 + // posted_at
+        1; // bump
+}
+
+/// Lamports a self-registered validator keeps locked for as long as it sits in the registry
+#[account]
+pub struct ValidatorStake {
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl ValidatorStake {
+    pub const LEN: usize = 32 + // validator
+        8 + // amount
+        8 + // registered_at
+        1; // bump
+}
+
+/// Epochs a deregistered validator's stake stays locked, and slashable for cases it judged
+pub const VALIDATOR_UNBONDING_EPOCHS: u64 = 7;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorState {
+    Active,   // In the registry and drawable
+    Cooldown, // Out of the registry; stake still locked until cooldown_ends_epoch
+}
+
+/// Lifecycle of a self-registered validator, kept next to its ValidatorStake
+#[account]
+pub struct ValidatorStatus {
+    pub validator: Pubkey,
+    pub state: ValidatorState,
+    pub cooldown_ends_epoch: u64,
+    pub pending_reports: u16,      // Unresolved misconduct reports; the stake can't leave while any are open
+    pub bump: u8,
+}
+
+impl ValidatorStatus {
+    pub const LEN: usize = 32 + // validator
+        1 + // state
+        8 + // cooldown_ends_epoch
+        2 + // pending_reports
+        1; // bump
+
+    /// Whether the stake can still be slashed, i.e. not yet withdrawable
+    pub fn is_bonded(&self, epoch: u64) -> bool {
+        self.state == ValidatorState::Active || epoch < self.cooldown_ends_epoch
+    }
+}
+
+/// Share of a juror's stake slashed when a misconduct report is upheld (basis points)
+pub const MISCONDUCT_SLASH_BPS: u64 = 2_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MisconductKind {
+    NonParticipation,   // Held a seat on a finished case and never cast a counted ballot
+    ConflictOfInterest, // Sat on a case as the accused, the reporter, or the victim
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MisconductStatus {
+    Pending,
+    Upheld,    // Stake slashed into the insurance fund
+    Dismissed,
+}
+
+/// Claim against one juror's conduct on one case, awaiting a governance ruling
+#[account]
+pub struct MisconductReport {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub reporter: Pubkey,
+    pub kind: MisconductKind,
+    pub status: MisconductStatus,
+    pub reported_at: i64,
+    pub bump: u8,
+}
+
+impl MisconductReport {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        32 + // reporter
+        1 + // kind
+        1 + // status
+        8 + // reported_at
+        1; // bump
+}
+
+/// Lamports the accused stakes behind a settlement offer; forfeited on a funding default
+pub const SETTLEMENT_BOND: u64 = 1_000_000_000;
+/// Time the accused has to fund an agreed settlement before the case resumes voting
+pub const SETTLEMENT_FUNDING_WINDOW: i64 = 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum SettlementStatus {
+    Open,       // Awaiting reporter acceptance and juror approvals
+    Agreed,     // Accepted; the accused must fund before the deadline
+    Funded,
+    Executed,
+    Withdrawn,
+    Defaulted,
+}
+
+/// Tokens the accused agrees to escrow to settle a case before verdict
+#[account]
+pub struct SettlementOffer {
+    pub case_id: u64,
+    pub accused: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,             // Token account the agreed amount is funded into
+    pub amount: u64,
+    pub bond: u64,                 // Lamports held by this account on top of rent
+    pub reporter_accepted: bool,
+    pub juror_approvals: u8,
+    pub status: SettlementStatus,
+    pub created_at: i64,
+    pub funding_deadline: i64,     // 0 until the offer is agreed
+    pub bump: u8,
+}
+
+impl SettlementOffer {
+    pub const LEN: usize = 8 + // case_id
+        32 + // accused
+        32 + // mint
+        32 + // vault
+        8 + // amount
+        8 + // bond
+        1 + // reporter_accepted
+        1 + // juror_approvals
+        1 + // status
+        8 + // created_at
+        8 + // funding_deadline
+        1; // bump
+
+    /// Reduced quorum: a simple majority of the seated panel rather than of all validators
+    pub fn required_approvals(juror_count: u8) -> u8 {
+        juror_count / 2 + 1
+    }
+
+    pub fn is_agreed(&self, juror_count: u8) -> bool {
+        self.reporter_accepted && self.juror_approvals >= Self::required_approvals(juror_count)
+    }
+}
+
+#[account]
+pub struct SettlementApproval {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub bump: u8,
+}
+
+impl SettlementApproval {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        1; // bump
+}
+
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+/// Unexecuted council proposals lapse after this long
+pub const COUNCIL_PROPOSAL_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// M-of-N council that replaces the single admin key for privileged changes
+#[account]
+pub struct AdminCouncil {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    pub const LEN: usize = 4 + (32 * MAX_COUNCIL_MEMBERS) + // members
+        1 + // threshold
+        8 + // proposal_count
+        1; // bump
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CouncilAction {
+    ReplaceValidators { page_index: u16, validators: Vec<Pubkey> },
+    AppendValidator { page_index: u16, validator: Pubkey },
+    SetJuryParams { quorum: u8, min_jurors: u8 },
+    ProposeAdmin { new_admin: Pubkey },
+    SetPauser { pauser: Pubkey },
+    SetFees { submission_fee: u64, distribution_fee_bps: u16 },
+    SetCategoryParams { category: CaseCategory, params: CategoryParams },
+    WithdrawTreasury { recipient: Pubkey, mint: Option<Pubkey>, amount: u64 },
+    SetCouncil { members: Vec<Pubkey>, threshold: u8 },
+    SetMaxCaseAge { max_case_age: i64 },
+    SetPassportPolicy { attesters: Vec<Pubkey>, min_cases: u32 },
+    SetHeartbeatEpochs { heartbeat_epochs: u64 },
+    SetConfidentialPolicy { enabled: bool, required_components: u8 },
+    SetRandomnessSource { source: RandomnessSource },
+    UpdateVerifyingKey { proof_type: ZkProofType, key: Vec<u8> },
+    SetReputationWeighting { enabled: bool },
+    SetMinValidatorStake { min_stake: u64 },
+    RemoveValidator { page_index: u16, validator: Pubkey },
+    ResolveMisconduct { case_id: u64, juror: Pubkey, uphold: bool },
+    FundInsurance { amount: u64 },
+    OverturnVerdict { case_id: u64 },
+    SetReporterBounty { flat: u64, bps: u16 },
+    ConfigurePriceFeeds { sol_usd_feed: [u8; 32], feeds: Vec<TokenPriceFeed>, case_bond_bps: u16 },
+    SetQuadraticVoting { enabled: bool },
+    SetSeverityThreshold { severity: CaseSeverity, threshold: ApprovalThreshold },
+    SetTieBreakRule { rule: TieBreakRule, tie_breaker: Pubkey },
+    ConfigureEscalation { bond: u64, threshold: ApprovalThreshold },
+    SetProbableCauseJurors { jurors: u8 },
+    ApproveIntelProvider { provider: Pubkey },
+}
+
+impl CouncilAction {
+    /// Borsh size of this particular action, so proposals only pay rent for what they carry
+    pub fn space(&self) -> usize {
+        1 + match self {
+            CouncilAction::ReplaceValidators { validators, .. } => 2 + 4 + 32 * validators.len(),
+            CouncilAction::AppendValidator { .. } => 2 + 32,
+            CouncilAction::SetJuryParams { .. } => 1 + 1,
+            CouncilAction::ProposeAdmin { .. } => 32,
+            CouncilAction::SetPauser { .. } => 32,
+            CouncilAction::SetFees { .. } => 8 + 2,
+            CouncilAction::SetCategoryParams { .. } => 1 + CategoryParams::LEN,
+            CouncilAction::WithdrawTreasury { .. } => 32 + 1 + 32 + 8,
+            CouncilAction::SetCouncil { members, .. } => 4 + 32 * members.len() + 1,
+            CouncilAction::SetMaxCaseAge { .. } => 8,
+            CouncilAction::SetPassportPolicy { attesters, .. } => 4 + 32 * attesters.len() + 4,
+            CouncilAction::SetHeartbeatEpochs { .. } => 8,
+            CouncilAction::SetConfidentialPolicy { .. } => 1 + 1,
+            CouncilAction::SetRandomnessSource { .. } => 1,
+            CouncilAction::UpdateVerifyingKey { key, .. } => 1 + 4 + key.len(),
+            CouncilAction::SetReputationWeighting { .. } => 1,
+            CouncilAction::SetMinValidatorStake { .. } => 8,
+            CouncilAction::RemoveValidator { .. } => 2 + 32,
+            CouncilAction::ResolveMisconduct { .. } => 8 + 32 + 1,
+            CouncilAction::FundInsurance { .. } => 8,
+            CouncilAction::OverturnVerdict { .. } => 8,
+            CouncilAction::SetReporterBounty { .. } => 8 + 2,
+            CouncilAction::ConfigurePriceFeeds { feeds, .. } => 32 + 4 + TokenPriceFeed::LEN * feeds.len() + 2,
+            CouncilAction::SetQuadraticVoting { .. } => 1,
+            CouncilAction::SetSeverityThreshold { .. } => 1 + ApprovalThreshold::LEN,
+            CouncilAction::SetTieBreakRule { .. } => 1 + 32,
+            CouncilAction::ConfigureEscalation { .. } => 8 + ApprovalThreshold::LEN,
+            CouncilAction::SetProbableCauseJurors { .. } => 1,
+            CouncilAction::ApproveIntelProvider { .. } => 32,
+        }
+    }
+}
+
+#[account]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: CouncilAction,
+    pub approvals: Vec<Pubkey>,    // Members who approved; the proposer counts as the first
+    pub executed: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CouncilProposal {
+    pub fn space(action: &CouncilAction) -> usize {
+        8 + // id
+        32 + // proposer
+        action.space() + // action
+        4 + (32 * MAX_COUNCIL_MEMBERS) + // approvals
+        1 + // executed
+        8 + // created_at
+        1 // bump
+    }
+}
+
+pub const MAX_DISTRIBUTION_FEE_BPS: u16 = 1_000;
+/// Upper bound on the reporter bounty's share of seized funds (10%)
+pub const MAX_REPORTER_BOUNTY_BPS: u16 = 1_000;
+
+/// One per case; its existence is what stops a second bounty claim
+#[account]
+pub struct ReporterBountyReceipt {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub mint: Pubkey,              // Default for a flat lamport bounty
+    pub amount: u64,
+    pub paid_at: i64,
+    pub bump: u8,
+}
+
+impl ReporterBountyReceipt {
+    pub const LEN: usize = 8 + // case_id
+        32 + // reporter
+        32 + // mint
+        8 + // amount
+        8 + // paid_at
+        1; // bump
+}
+
+/// Protocol revenue: lamport fees are held on this PDA, token fees in per-mint vaults it owns
+#[account]
+pub struct Treasury {
+    pub total_collected: u64,      // Lamports received from submission fees
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + // total_collected
+        8 + // total_withdrawn
+        1; // bump
+}
+
+/// Lamports paid to each owner whose token account a later-overturned verdict froze
+pub const WRONGFUL_FREEZE_COMPENSATION: u64 = 500_000_000;
+
+/// Lamport pool for wrongful-freeze compensation, fed by slashed stake and treasury transfers
+#[account]
+pub struct InsuranceFund {
+    pub total_deposited: u64,
+    pub total_paid: u64,
+    pub claims_paid: u32,
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    pub const LEN: usize = 8 + // total_deposited
+        8 + // total_paid
+        4 + // claims_paid
+        1; // bump
+}
+
+/// One per frozen token account, so a wrongful freeze is only compensated once
+#[account]
+pub struct FreezeCompensation {
+    pub case_id: u64,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub paid_at: i64,
+    pub bump: u8,
+}
+
+impl FreezeCompensation {
+    pub const LEN: usize = 8 + // case_id
+        32 + // token_account
+        32 + // owner
+        8 + // amount
+        8 + // paid_at
+        1; // bump
+}
+
+/// Supplemental evidence appended to a case before voting begins
+#[account]
+pub struct EvidenceItem {
+    pub case_id: u64,
+    pub index: u16,
+    pub submitter: Pubkey,
+    pub hash: [u8; 32],            // Hash of the off-chain content at `uri`
+    pub uri: String,
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl EvidenceItem {
+    pub const LEN: usize = 8 + // case_id
+        2 + // index
+        32 + // submitter
+        32 + // hash
+        4 + MAX_EVIDENCE_URI_LEN + // uri
+        8 + // submitted_at
+        1; // bump
+}
+
+/// Hands out case ids in order, so future ids can't be squatted and indexers can walk
+/// every case from 1
+#[account]
+pub struct CaseCounter {
+    pub next_case_id: u64,
+    pub bump: u8,
+}
+
+impl CaseCounter {
+    pub const LEN: usize = 8 + // next_case_id
+        1; // bump
+
+    /// Take `case_id`, which must be the next id
+    pub fn assign(&mut self, case_id: u64) -> Result<()> {
+        require!(case_id == self.next_case_id, ErrorCode::CaseIdOutOfSequence);
+        self.next_case_id = case_id.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Addresses one submit_evidence_batch call can open cases against
+pub const MAX_BATCH_CASES: usize = 8;
+pub const MAX_GROUP_CASES: usize = 64;
+
+/// Cases opened in batches against related addresses (a drainer's mule wallets, say) on
+/// one evidence bundle. Keyed by the bundle's hash, so any member case finds its group
+/// from `evidence_hash`.
+#[account]
+pub struct CaseGroup {
+    pub evidence_hash: [u8; 32],
+    pub reporter: Pubkey,
+    pub case_ids: Vec<u64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl CaseGroup {
+    pub const LEN: usize = 32 + // evidence_hash
+        32 + // reporter
+        4 + (8 * MAX_GROUP_CASES) + // case_ids
+        8 + // created_at
+        8 + // updated_at
+        1; // bump
+}
+
+/// One per scam address; points at the address's open case so a second one can't be filed
+#[account]
+pub struct ActiveCaseMarker {
+    pub scam_address: Pubkey,
+    pub case_id: u64,
+    pub opened_at: i64,
+    pub linked_reports: u32,       // Duplicate reports attached to the current case
+    pub bump: u8,
+}
+
+impl ActiveCaseMarker {
+    pub const LEN: usize = 32 + // scam_address
+        8 + // case_id
+        8 + // opened_at
+        4 + // linked_reports
+        1; // bump
+}
+
+/// A later report against an address that already has an open case
+#[account]
+pub struct DuplicateReport {
+    pub case_id: u64,
+    pub reporter: Pubkey,
+    pub hash: [u8; 32],            // Hash of the off-chain report at `uri`
+    pub uri: String,
+    pub linked_at: i64,
+    pub bump: u8,
+}
+
+impl DuplicateReport {
+    pub const LEN: usize = 8 + // case_id
+        32 + // reporter
+        32 + // hash
+        4 + MAX_EVIDENCE_URI_LEN + // uri
+        8 + // linked_at
+        1; // bump
+}
+
+pub const MAX_WATCHED_ADDRESSES: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WatchRole {
+    Accused,    // Named as the case's scam address
+    Target,     // Named as a victim or fund-flow source
+}
+
+/// Opt-in subscription: flagged whenever a listed address turns up in a case
+#[account]
+pub struct Watchtower {
+    pub owner: Pubkey,
+    pub subscription_id: u64,
+    pub addresses: Vec<Pubkey>,
+    pub flagged: bool,
+    pub flag_count: u32,
+    pub last_flagged_case: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Watchtower {
+    pub const LEN: usize = 32 + // owner
+        8 + // subscription_id
+        4 + (32 * MAX_WATCHED_ADDRESSES) + // addresses
+        1 + // flagged
+        4 + // flag_count
+        8 + // last_flagged_case
+        8 + // created_at
+        1; // bump
+}
+
+/// One per (watchtower, case) so each match is only reported once
+#[account]
+pub struct WatchtowerAlert {
+    pub watchtower: Pubkey,
+    pub case_id: u64,
+    pub address: Pubkey,
+    pub role: WatchRole,
+    pub bump: u8,
+}
+
+impl WatchtowerAlert {
+    pub const LEN: usize = 32 + // watchtower
+        8 + // case_id
+        32 + // address
+        1 + // role
+        1; // bump
+}
+
+/// Namespaces whose attestations this deployment trusts
+pub const MAX_PASSPORT_ATTESTERS: usize = 8;
+/// Distinct namespaces a single passport can carry stats for
+pub const MAX_PASSPORT_ENTRIES: usize = 8;
+
+/// Juror stats from one namespace, as last attested by that namespace's key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PassportEntry {
+    pub namespace: Pubkey,
+    pub cases_served: u32,
+    pub majority_votes: u32,      // Votes cast on the side that carried the verdict
+    pub attested_at: i64,
+}
+
+impl PassportEntry {
+    pub const LEN: usize = 32 + 4 + 4 + 8;
+}
+
+/// Portable juror record, keyed by juror alone so every namespace reads the same account
+#[account]
+pub struct JurorPassport {
+    pub juror: Pubkey,
+    pub entries: Vec<PassportEntry>,
+    pub bump: u8,
+}
+
+impl JurorPassport {
+    pub const LEN: usize = 32 + // juror
+        4 + (PassportEntry::LEN * MAX_PASSPORT_ENTRIES) + // entries
+        1; // bump
+
+    /// Cases served across the namespaces in `trusted`; everything else is ignored
+    pub fn trusted_cases(&self, trusted: &[Pubkey]) -> u32 {
+        self.entries
+            .iter()
+            .filter(|entry| trusted.contains(&entry.namespace))
+            .fold(0u32, |total, entry| total.saturating_add(entry.cases_served))
+    }
+}
+
+/// Attester keys this deployment accepts passport stats from
+#[account]
+pub struct PassportRegistry {
+    pub attesters: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl PassportRegistry {
+    pub const LEN: usize = 4 + (32 * MAX_PASSPORT_ATTESTERS) + // attesters
+        1; // bump
+}
+
+/// Tokens a case's holding can be priced in
+pub const MAX_PRICE_FEEDS: usize = 16;
+/// Bounds on a priced submission bond, in micro-USD
+pub const MIN_CASE_BOND_USD: u64 = 5_000_000;
+pub const MAX_CASE_BOND_USD: u64 = 500_000_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TokenPriceFeed {
+    pub mint: Pubkey,
+    pub feed_id: [u8; 32],         // Pyth price feed id, USD quoted
+}
+
+impl TokenPriceFeed {
+    pub const LEN: usize = 32 + 32;
+}
+
+/// Pyth feeds the submission bond is priced with
+#[account]
+pub struct PriceFeedRegistry {
+    pub sol_usd_feed: [u8; 32],    // Converts the USD bond into lamports
+    pub feeds: Vec<TokenPriceFeed>,
+    pub bump: u8,
+}
+
+impl PriceFeedRegistry {
+    pub const LEN: usize = 32 + // sol_usd_feed
+        4 + TokenPriceFeed::LEN * MAX_PRICE_FEEDS + // feeds
+        1; // bump
+
+    pub fn feed_for(&self, mint: &Pubkey) -> Option<&[u8; 32]> {
+        self.feeds.iter().find(|feed| feed.mint == *mint).map(|feed| &feed.feed_id)
+    }
+}
+
+/// Availability record; validators without a recent beat are skipped by juror draws
+#[account]
+pub struct ValidatorHeartbeat {
+    pub validator: Pubkey,
+    pub last_epoch: u64,
+    pub last_beat_at: i64,
+    pub beats: u64,
+    pub bump: u8,
+}
+
+impl ValidatorHeartbeat {
+    pub const LEN: usize = 32 + // validator
+        8 + // last_epoch
+        8 + // last_beat_at
+        8 + // beats
+        1; // bump
+}
+
+/// Running totals a dashboard can read in one fetch instead of replaying history.
+/// Counts start at initialize_protocol_stats; earlier activity isn't backfilled.
+#[account]
+pub struct ProtocolStats {
+    pub total_cases: u64,          // Public and confidential submissions
+    pub approvals: u64,            // Finalized approved verdicts
+    pub rejections: u64,           // Finalized rejected verdicts
+    pub frozen_accounts: u64,      // Token accounts frozen on a verdict
+    pub restitution_paid: u64,     // Raw token units paid to victims, summed across mints
+    pub active_validators: u32,    // Mirrors GlobalConfig::validator_count
+    pub tracking_since: i64,
+    pub bump: u8,
+}
+
+impl ProtocolStats {
+    pub const LEN: usize = 8 + // total_cases
+        8 + // approvals
+        8 + // rejections
+        8 + // frozen_accounts
+        8 + // restitution_paid
+        4 + // active_validators
+        8 + // tracking_since
+        1; // bump
+
+    pub fn record_case(&mut self) {
+        self.total_cases = self.total_cases.saturating_add(1);
+    }
+
+    pub fn record_verdict(&mut self, approved: bool) {
+        if approved {
+            self.approvals = self.approvals.saturating_add(1);
+        } else {
+            self.rejections = self.rejections.saturating_add(1);
+        }
+    }
+
+    pub fn record_freeze(&mut self) {
+        self.frozen_accounts = self.frozen_accounts.saturating_add(1);
+    }
+
+    pub fn record_restitution(&mut self, amount: u64) {
+        self.restitution_paid = self.restitution_paid.saturating_add(amount);
+    }
+}
+
+/// Selection weight, in basis points, of a candidate with no JurorProfile; also the floor
+/// a poor record can fall to, so nobody is locked out of the draw entirely
+pub const MIN_REPUTATION_WEIGHT_BPS: u16 = 1_000;
+
+/// This deployment's own memory of a juror, written by finalize_case
+#[account]
+pub struct JurorProfile {
+    pub juror: Pubkey,
+    pub cases_served: u32,         // Finalized cases the juror held a seat on
+    pub decided_votes: u32,        // Of those, cases where the juror's vote is known
+    pub majority_votes: u32,       // Votes that matched the verdict
+    pub commitments: u32,          // Private vote commitments made
+    pub reveals: u32,              // Commitments opened before the reveal deadline
+    pub recusals: u32,
+    pub last_case_id: u64,
+    pub bump: u8,
+}
+
+impl JurorProfile {
+    pub const LEN: usize = 32 + // juror
+        4 + // cases_served
+        4 + // decided_votes
+        4 + // majority_votes
+        4 + // commitments
+        4 + // reveals
+        4 + // recusals
+        8 + // last_case_id
+        1; // bump
+
+    /// Majority alignment x reveal rate x seats kept, each smoothed so a fresh profile
+    /// starts at half weight rather than at either extreme
+    pub fn weight_bps(&self) -> u16 {
+        let alignment = (self.majority_votes as u64 + 1) * 10_000 / (self.decided_votes as u64 + 2);
+        let reveal_rate = (self.reveals as u64 + 1) * 10_000 / (self.commitments as u64 + 1);
+        let attendance = (self.cases_served as u64 + 1) * 10_000
+            / (self.cases_served as u64 + self.recusals as u64 + 1);
+        let weight = alignment * reveal_rate / 10_000 * attendance / 10_000;
+        (weight as u16).max(MIN_REPUTATION_WEIGHT_BPS)
+    }
+}
+
+/// Privacy components a confidential case can run with, as bits of CaseAccount::confidential
+pub struct ConfidentialComponents;
+
+impl ConfidentialComponents {
+    /// Accused committed as hash(case_id, address, salt) until an approved verdict reveals it
+    pub const SEALED_ADDRESS: u8 = 1 << 0;
+    /// Evidence lives in an EvidenceCommitment whose key is escrowed to the panel's MPC shares
+    pub const ENCRYPTED_EVIDENCE: u8 = 1 << 1;
+    /// Jurors vote through ZK commitments with nullifiers; only the MPC tally is published
+    pub const PRIVATE_VOTES: u8 = 1 << 2;
+    /// Juror payouts must be confidential transfers
+    pub const CONFIDENTIAL_PAYOUTS: u8 = 1 << 3;
+    /// Not chosen at submission: set once every seated juror has registered a voter
+    /// identity, after which private votes prove eligibility instead of naming the juror
+    pub const ANONYMOUS_JURORS: u8 = 1 << 4;
+
+    pub const ALL: u8 = Self::SEALED_ADDRESS
+        | Self::ENCRYPTED_EVIDENCE
+        | Self::PRIVATE_VOTES
+        | Self::CONFIDENTIAL_PAYOUTS;
+}
+
+/// Governance policy for confidential mode; components listed here can't be opted out of
+#[account]
+pub struct ConfidentialCaseConfig {
+    pub enabled: bool,
+    pub required_components: u8,   // ConfidentialComponents every confidential case must use
+    pub bump: u8,
+}
+
+impl ConfidentialCaseConfig {
+    pub const LEN: usize = 1 + // enabled
+        1 + // required_components
+        1; // bump
+}
+
+/// Binds a juror's confidential payout transfer to the case they served on
+#[account]
+pub struct JurorPayout {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub transfer_id: u64,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl JurorPayout {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        8 + // transfer_id
+        8 + // recorded_at
+        1; // bump
 }
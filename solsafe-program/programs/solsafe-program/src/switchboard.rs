@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use bytemuck::{Pod, Zeroable};
+use crate::ErrorCode;
+
+/// Switchboard On-Demand program that owns randomness accounts
+#[cfg(not(feature = "devnet"))]
+pub const SWITCHBOARD_PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+#[cfg(feature = "devnet")]
+pub const SWITCHBOARD_PROGRAM_ID: Pubkey = pubkey!("Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2");
+
+/// How far behind the current slot a seed may be when a case commits to it (~1 minute)
+pub const MAX_SEED_AGE_SLOTS: u64 = 150;
+
+/// Slots after a case's commit within which the oracle must reveal (~2 minutes)
+pub const MAX_REVEAL_DELAY_SLOTS: u64 = 300;
+
+/// Layout of Switchboard On-Demand's `RandomnessAccountData`, read in place of the SDK type
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct RandomnessAccountData {
+    pub authority: Pubkey,
+    pub queue: Pubkey,
+    pub seed_slothash: [u8; 32],
+    pub seed_slot: u64,                 // Slot the current commitment was seeded at
+    pub oracle: Pubkey,
+    pub reveal_slot: u64,               // Slot `value` was revealed at; older than seed_slot until then
+    pub value: [u8; 32],
+    pub _ebuf2: [u8; 96],
+    pub _ebuf1: [u8; 128],
+}
+
+impl RandomnessAccountData {
+    pub const DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+    pub const LEN: usize = std::mem::size_of::<RandomnessAccountData>();
+
+    /// Deserialize after checking the owner and discriminator, so a look-alike account
+    /// from another program can't stand in for Switchboard's
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(info.owner == &SWITCHBOARD_PROGRAM_ID, ErrorCode::InvalidRandomness);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + Self::LEN && data[..8] == Self::DISCRIMINATOR,
+            ErrorCode::InvalidRandomness
+        );
+        Ok(bytemuck::pod_read_unaligned(&data[8..8 + Self::LEN]))
+    }
+
+    /// The oracle has revealed a value for the current seed
+    pub fn is_revealed(&self) -> bool {
+        self.reveal_slot > self.seed_slot
+    }
+
+    /// Revealed strictly after the case committed at `commit_slot` and within the delay window
+    pub fn revealed_after(&self, commit_slot: u64) -> bool {
+        self.reveal_slot > commit_slot && self.reveal_slot - commit_slot <= MAX_REVEAL_DELAY_SLOTS
+    }
+
+    /// Seeded recently enough that nobody could have seen the value before it was committed to
+    pub fn is_fresh(&self, slot: u64) -> bool {
+        self.seed_slot <= slot && slot - self.seed_slot <= MAX_SEED_AGE_SLOTS
+    }
+}
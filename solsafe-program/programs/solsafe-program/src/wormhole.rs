@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, sysvar};
+use crate::ErrorCode;
+
+/// Wormhole core bridge that posts messages for the guardians to sign
+#[cfg(not(feature = "devnet"))]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+#[cfg(feature = "devnet")]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("3u8hJUVTA4jH1wYAyUur7FFZVQ8H635K3tSHHF4ssjQ5");
+
+/// Wormhole chain id of Solana, stamped on every exported verdict
+pub const WORMHOLE_CHAIN_ID_SOLANA: u16 = 1;
+
+/// Core bridge PDA seeds
+pub const BRIDGE_SEED: &[u8] = b"Bridge";
+pub const FEE_COLLECTOR_SEED: &[u8] = b"fee_collector";
+pub const SEQUENCE_SEED: &[u8] = b"Sequence";
+
+/// `Instruction::PostMessage`; the bridge is native, so instructions are a Borsh enum
+const POST_MESSAGE: u8 = 1;
+/// Guardians sign once the slot is finalized rather than merely confirmed
+pub const CONSISTENCY_FINALIZED: u8 = 1;
+
+/// Message fee from the bridge's `BridgeData`: guardian set index (u32), last lamports
+/// (u64), then the config's guardian set expiration (u32) and fee (u64)
+pub fn message_fee(bridge: &AccountInfo) -> Result<u64> {
+    require!(bridge.owner == &WORMHOLE_PROGRAM_ID, ErrorCode::InvalidWormholeAccount);
+    let data = bridge.try_borrow_data()?;
+    let fee = data.get(16..24).ok_or(ErrorCode::InvalidWormholeAccount)?;
+    Ok(u64::from_le_bytes(fee.try_into().unwrap()))
+}
+
+/// Sequence the next message from the emitter will get; the account only exists once
+/// the emitter has posted
+pub fn next_sequence(sequence: &AccountInfo) -> Result<u64> {
+    if sequence.data_is_empty() {
+        return Ok(0);
+    }
+    require!(sequence.owner == &WORMHOLE_PROGRAM_ID, ErrorCode::InvalidWormholeAccount);
+    let data = sequence.try_borrow_data()?;
+    let next = data.get(..8).ok_or(ErrorCode::InvalidWormholeAccount)?;
+    Ok(u64::from_le_bytes(next.try_into().unwrap()))
+}
+
+/// Accounts of a core bridge `PostMessage`, in the bridge's order
+pub struct PostMessageAccounts {
+    pub bridge: Pubkey,
+    pub message: Pubkey,
+    pub emitter: Pubkey,
+    pub sequence: Pubkey,
+    pub payer: Pubkey,
+    pub fee_collector: Pubkey,
+}
+
+/// Post `payload` under `emitter`. Both the message account (created by the bridge) and
+/// the emitter sign, and the fee must already sit in the fee collector.
+pub fn post_message(accounts: PostMessageAccounts, nonce: u32, payload: Vec<u8>, consistency_level: u8) -> Instruction {
+    let mut data = vec![POST_MESSAGE];
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(&payload);
+    data.push(consistency_level);
+
+    Instruction {
+        program_id: WORMHOLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.bridge, false),
+            AccountMeta::new(accounts.message, true),
+            AccountMeta::new_readonly(accounts.emitter, true),
+            AccountMeta::new(accounts.sequence, false),
+            AccountMeta::new(accounts.payer, true),
+            AccountMeta::new(accounts.fee_collector, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        ],
+        data,
+    }
+}
@@ -1,301 +1,1178 @@
-// Arcium MPC Integration for Secure Multi-Juror Computation
-// Enables threshold cryptography and secure multi-party computation
-use anchor_lang::prelude::*;
-
-/// Arcium MPC Configuration for the case
-#[account]
-pub struct MpcConfig {
-    pub case_id: u64,
-    pub threshold: u8,           // Minimum jurors needed to decrypt
-    pub total_jurors: u8,        // Total jurors in MPC group
-    pub current_shares: u8,      // Current submitted shares
-    pub computation_id: [u8; 32], // Unique MPC computation identifier
-    pub state: MpcState,
-    pub bump: u8,
-}
-
-impl MpcConfig {
-    pub const LEN: usize = 8 + // case_id
-        1 + // threshold
-        1 + // total_jurors
-        1 + // current_shares
-        32 + // computation_id
-        1 + // state
-        1; // bump
-
-    pub fn new(case_id: u64, threshold: u8, total_jurors: u8, bump: u8) -> Self {
-        let computation_id = Self::generate_computation_id(case_id);
-        
-        MpcConfig {
-            case_id,
-            threshold,
-            total_jurors,
-            current_shares: 0,
-            computation_id,
-            state: MpcState::Initialized,
-            bump,
-        }
-    }
-
-    fn generate_computation_id(case_id: u64) -> [u8; 32] {
-        use solana_program::hash::{hash, hashv};
-        let clock = Clock::get().unwrap();
-        let result = hashv(&[
-            b"arcium_mpc",
-            &case_id.to_le_bytes(),
-            &clock.unix_timestamp.to_le_bytes(),
-        ]);
-        result.to_bytes()
-    }
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum MpcState {
-    Initialized,
-    CollectingShares,
-    ThresholdReached,
-    ComputationComplete,
-}
-
-/// Individual juror's MPC key share
-#[account]
-pub struct MpcKeyShare {
-    pub juror: Pubkey,
-    pub case_id: u64,
-    pub share_index: u8,
-    pub public_share: [u8; 32],      // Public part of the share
-    pub share_commitment: [u8; 32],  // Commitment to the share
-    pub verified: bool,
-    pub timestamp: i64,
-    pub bump: u8,
-}
-
-impl MpcKeyShare {
-    pub const LEN: usize = 32 + // juror
-        8 + // case_id
-        1 + // share_index
-        32 + // public_share
-        32 + // share_commitment
-        1 + // verified
-        8 + // timestamp
-        1; // bump
-}
-
-/// Arcium MPC vote aggregation
-#[account]
-pub struct MpcVoteAggregation {
-    pub case_id: u64,
-    pub encrypted_tally: Vec<u8>,    // Homomorphically encrypted vote tally
-    pub partial_decryptions: Vec<PartialDecryption>,
-    pub final_result: Option<VoteResult>,
-    pub computation_complete: bool,
-    pub bump: u8,
-}
-
-impl MpcVoteAggregation {
-    pub const MAX_SIZE: usize = 8 + // case_id
-        4 + 256 + // encrypted_tally (max 256 bytes)
-        4 + (PartialDecryption::SIZE * 20) + // partial_decryptions (max 20 jurors)
-        1 + VoteResult::SIZE + // final_result
-        1 + // computation_complete
-        1; // bump
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct PartialDecryption {
-    pub juror: Pubkey,
-    pub decryption_share: [u8; 32],
-    pub proof: [u8; 64],  // ZK proof of correct decryption
-}
-
-impl PartialDecryption {
-    pub const SIZE: usize = 32 + 32 + 64;
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct VoteResult {
-    pub votes_for: u64,
-    pub votes_against: u64,
-    pub total_votes: u64,
-    pub verified: bool,
-}
-
-impl VoteResult {
-    pub const SIZE: usize = 8 + 8 + 8 + 1;
-}
-
-/// Initialize MPC for a case
-#[derive(Accounts)]
-#[instruction(case_id: u64)]
-pub struct InitializeMpc<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + MpcConfig::LEN,
-        seeds = [b"mpc_config", case_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub mpc_config: Account<'info, MpcConfig>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-pub fn initialize_mpc_handler(
-    ctx: Context<InitializeMpc>,
-    case_id: u64,
-    threshold: u8,
-    total_jurors: u8,
-) -> Result<()> {
-    let mpc_config = &mut ctx.accounts.mpc_config;
-    
-    require!(threshold > 0 && threshold <= total_jurors, crate::ErrorCode::InvalidThreshold);
-    require!(total_jurors <= 20, crate::ErrorCode::TooManyJurors);
-    
-    let clock = Clock::get()?;`r`n    mpc_config.case_id = case_id;`r`n    mpc_config.threshold = threshold;`r`n    mpc_config.total_jurors = total_jurors;`r`n    mpc_config.current_shares = 0;`r`n    mpc_config.computation_id = MpcConfig::generate_computation_id(case_id, clock.unix_timestamp);`r`n    mpc_config.state = MpcState::Initialized;`r`n    mpc_config.bump = ctx.bumps.mpc_config; //
-        case_id,
-        threshold,
-        total_jurors,
-        ctx.bumps.mpc_config,
-    );
-    
-    msg!("MPC initialized for case {}. Threshold: {}/{}", case_id, threshold, total_jurors);
-    Ok(())
-}
-
-/// Juror submits their MPC key share
-#[derive(Accounts)]
-pub struct SubmitMpcShare<'info> {
-    #[account(mut)]
-    pub juror: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = juror,
-        space = 8 + MpcKeyShare::LEN,
-        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
-        bump
-    )]
-    pub mpc_share: Account<'info, MpcKeyShare>,
-    
-    #[account(mut)]
-    pub mpc_config: Account<'info, MpcConfig>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-pub fn submit_mpc_share_handler(
-    ctx: Context<SubmitMpcShare>,
-    public_share: [u8; 32],
-    share_commitment: [u8; 32],
-) -> Result<()> {
-    let mpc_share = &mut ctx.accounts.mpc_share;
-    let mpc_config = &mut ctx.accounts.mpc_config;
-    let clock = Clock::get()?;
-    
-    require!(
-        mpc_config.current_shares < mpc_config.total_jurors,
-        crate::ErrorCode::AllSharesSubmitted
-    );
-    
-    // Initialize juror's share
-    mpc_share.juror = ctx.accounts.juror.key();
-    mpc_share.case_id = mpc_config.case_id;
-    mpc_share.share_index = mpc_config.current_shares;
-    mpc_share.public_share = public_share;
-    mpc_share.share_commitment = share_commitment;
-    mpc_share.verified = false;
-    mpc_share.timestamp = clock.unix_timestamp;
-    mpc_share.bump = ctx.bumps.mpc_share;
-    
-    mpc_config.current_shares += 1;
-    
-    if mpc_config.current_shares >= mpc_config.threshold {
-        mpc_config.state = MpcState::ThresholdReached;
-        msg!("MPC threshold reached!");
-    }
-    
-    msg!("MPC share submitted. Progress: {}/{}", 
-        mpc_config.current_shares, mpc_config.total_jurors);
-    
-    Ok(())
-}
-
-/// Submit partial decryption for vote aggregation
-#[derive(Accounts)]
-pub struct SubmitPartialDecryption<'info> {
-    #[account(mut)]
-    pub juror: Signer<'info>,
-    
-    #[account(
-        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
-        bump = mpc_share.bump,
-        constraint = mpc_share.verified @ crate::ErrorCode::ShareNotVerified
-    )]
-    pub mpc_share: Account<'info, MpcKeyShare>,
-    
-    #[account(mut)]
-    pub mpc_config: Account<'info, MpcConfig>,
-    
-    #[account(
-        mut,
-        seeds = [b"mpc_aggregation", mpc_config.case_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub vote_aggregation: Account<'info, MpcVoteAggregation>,
-}
-
-pub fn submit_partial_decryption_handler(
-    ctx: Context<SubmitPartialDecryption>,
-    decryption_share: [u8; 32],
-    proof: [u8; 64],
-) -> Result<()> {
-    let vote_aggregation = &mut ctx.accounts.vote_aggregation;
-    let mpc_config = &ctx.accounts.mpc_config;
-    
-    require!(
-        mpc_config.state == MpcState::ThresholdReached,
-        crate::ErrorCode::ThresholdNotReached
-    );
-    
-    // Add partial decryption
-    let partial_dec = PartialDecryption {
-        juror: ctx.accounts.juror.key(),
-        decryption_share,
-        proof,
-    };
-    
-    vote_aggregation.partial_decryptions.push(partial_dec);
-    
-    // Check if we can compute final result
-    if vote_aggregation.partial_decryptions.len() >= mpc_config.threshold as usize {
-        let result = combine_partial_decryptions(&vote_aggregation.partial_decryptions)?;
-        vote_aggregation.final_result = Some(result);
-        vote_aggregation.computation_complete = true;
-        
-        msg!("MPC computation complete! Votes: {} for, {} against",
-            result.votes_for, result.votes_against);
-    }
-    
-    Ok(())
-}
-
-/// Combine partial decryptions to get final tally (Arcium MPC protocol)
-fn combine_partial_decryptions(
-    partial_decryptions: &[PartialDecryption],
-) -> Result<VoteResult> {
-    // Arcium MPC threshold decryption happens here
-    // This is a placeholder for the actual MPC protocol
-    
-    msg!("Combining {} partial decryptions", partial_decryptions.len());
-    
-    // In production, this would use Arcium's threshold decryption
-    Ok(VoteResult {
-        votes_for: 0,
-        votes_against: 0,
-        total_votes: partial_decryptions.len() as u64,
-        verified: true,
-    })
-}
+// Arcium MPC Integration for Secure Multi-Juror Computation
+// Enables threshold cryptography and secure multi-party computation. The panel derives its
+// election key by Pedersen DKG: every juror deals a Feldman-committed polynomial (round 1),
+// sends each other juror their encrypted evaluation (round 2), and the key is the sum of the
+// constant-term commitments.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::compression::prelude::{
+    alt_bn128_g1_compress, alt_bn128_g1_decompress,
+};
+use anchor_lang::solana_program::alt_bn128::prelude::{alt_bn128_addition, alt_bn128_multiplication};
+use crate::state::{CaseAccount, CaseState, ConfidentialComponents};
+use crate::{DkgShareDelivered, ElectionKeyGenerated, EncryptedVoteAdded, MpcAborted};
+use super::private_vote::VoteCommitmentAccount;
+
+/// Largest MPC group, and so the most Feldman coefficients a dealer commits to
+pub const MAX_MPC_JURORS: usize = 20;
+
+/// Time the group has to submit shares and run the DKG after it's initialized
+pub const MPC_SHARE_PERIOD: i64 = 2 * 24 * 60 * 60;
+/// Time jurors have to post partial decryptions after voting closes
+pub const MPC_DECRYPTION_PERIOD: i64 = 2 * 24 * 60 * 60;
+
+/// Uncompressed BN254 G1 generator (1, 2), big-endian
+const G1_GENERATOR: [u8; 64] = {
+    let mut point = [0u8; 64];
+    point[31] = 1;
+    point[63] = 2;
+    point
+};
+
+/// BN254 base field modulus, big-endian; negating a point maps y to p - y
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// BN254 group order, big-endian
+const GROUP_ORDER: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// (MAX_MPC_JURORS - 1)!: every Lagrange denominator over share indices 1..=MAX_MPC_JURORS
+/// divides it, so scaling by it keeps the coefficients integral
+const LAGRANGE_SCALE: u128 = {
+    let mut scale = 1u128;
+    let mut k = 2;
+    while k < MAX_MPC_JURORS as u128 {
+        scale *= k;
+        k += 1;
+    }
+    scale
+};
+
+/// Arcium MPC Configuration for the case
+#[account]
+pub struct MpcConfig {
+    pub case_id: u64,
+    pub threshold: u8,           // Minimum jurors needed to decrypt
+    pub total_jurors: u8,        // Total jurors in MPC group
+    pub current_shares: u8,      // Current submitted shares
+    pub computation_id: [u8; 32], // Unique MPC computation identifier
+    pub state: MpcState,
+    pub dealers: u8,             // Jurors whose Feldman commitments are folded in
+    pub share_commitments: Vec<[u8; 32]>, // Sum of dealers' coefficient commitments (compressed G1)
+    pub deliveries: u16,         // Round-2 shares sent between distinct jurors
+    pub election_key: [u8; 32],  // ElGamal public key from the DKG (zero until finalized)
+    pub bump: u8,
+    pub share_deadline: i64,     // Shares and both DKG rounds close here
+    pub decryption_deadline: i64, // Partial decryptions close here
+}
+
+impl MpcConfig {
+    pub const LEN: usize = 8 + // case_id
+        1 + // threshold
+        1 + // total_jurors
+        1 + // current_shares
+        32 + // computation_id
+        1 + // state
+        1 + // dealers
+        4 + 32 * MAX_MPC_JURORS + // share_commitments
+        2 + // deliveries
+        32 + // election_key
+        1 + // bump
+        8 + // share_deadline
+        8; // decryption_deadline
+
+    pub fn new(case_id: u64, threshold: u8, total_jurors: u8, timestamp: i64, bump: u8) -> Self {
+        let computation_id = Self::generate_computation_id(case_id, timestamp);
+        
+        MpcConfig {
+            case_id,
+            threshold,
+            total_jurors,
+            current_shares: 0,
+            computation_id,
+            state: MpcState::Initialized,
+            dealers: 0,
+            share_commitments: Vec::new(),
+            deliveries: 0,
+            election_key: [0u8; 32],
+            bump,
+            share_deadline: timestamp.saturating_add(MPC_SHARE_PERIOD),
+            decryption_deadline: timestamp.saturating_add(MPC_SHARE_PERIOD + MPC_DECRYPTION_PERIOD),
+        }
+    }
+
+    /// Shares and decryptions are refused past their round's deadline or once aborted
+    pub fn require_open(&self, deadline: i64, now: i64) -> Result<()> {
+        require!(self.state != MpcState::Aborted, crate::ErrorCode::MpcAborted);
+        require!(now <= deadline, crate::ErrorCode::MpcRoundClosed);
+        Ok(())
+    }
+
+    /// A round has missed its deadline: too few shares, or a DKG that was started and not
+    /// finished, by `share_deadline`; no tally by `decryption_deadline`
+    pub fn stalled(&self, now: i64) -> bool {
+        let keyed = self.current_shares >= self.threshold
+            && (self.dealers == 0 || self.election_key != [0u8; 32]);
+        (now > self.share_deadline && !keyed) || now > self.decryption_deadline
+    }
+
+    fn generate_computation_id(case_id: u64, timestamp: i64) -> [u8; 32] {
+        crate::domain::hash(
+            crate::domain::MPC_COMPUTATION,
+            &[&case_id.to_le_bytes(), &timestamp.to_le_bytes()],
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum MpcState {
+    Initialized,
+    CollectingShares,
+    ThresholdReached,
+    ComputationComplete,
+    Aborted,                     // A round stalled; the case fell back to public commit-reveal
+}
+
+/// Individual juror's MPC key share
+#[account]
+pub struct MpcKeyShare {
+    pub juror: Pubkey,
+    pub case_id: u64,
+    pub share_index: u8,
+    pub public_share: [u8; 32],      // Public part of the share
+    pub share_commitment: [u8; 32],  // Commitment to the share
+    pub verified: bool,              // public_share checked against the Feldman commitments
+    pub dealt: bool,                 // Juror has committed to their own polynomial
+    pub shares_sent: u32,            // Bit per share_index this juror delivered a round-2 share to
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl MpcKeyShare {
+    pub const LEN: usize = 32 + // juror
+        8 + // case_id
+        1 + // share_index
+        32 + // public_share
+        32 + // share_commitment
+        1 + // verified
+        1 + // dealt
+        4 + // shares_sent
+        8 + // timestamp
+        1; // bump
+}
+
+/// Arcium MPC vote aggregation
+#[account]
+pub struct MpcVoteAggregation {
+    pub case_id: u64,
+    pub encrypted_tally: Vec<u8>,    // Homomorphically encrypted vote tally
+    pub partial_decryptions: Vec<PartialDecryption>,
+    pub final_result: Option<VoteResult>,
+    pub computation_complete: bool,
+    pub bump: u8,
+    pub ballots: u64,                // Encrypted ballots summed into the tally
+}
+
+impl MpcVoteAggregation {
+    /// First half (c1, compressed G1) of the ElGamal tally once ballots are encrypted
+    pub fn tally_c1(&self) -> Option<[u8; 32]> {
+        (self.encrypted_tally.len() == 64).then(|| self.encrypted_tally[..32].try_into().unwrap())
+    }
+
+    pub const MAX_SIZE: usize = 8 + // case_id
+        4 + 256 + // encrypted_tally (max 256 bytes)
+        4 + (PartialDecryption::SIZE * 20) + // partial_decryptions (max 20 jurors)
+        1 + VoteResult::SIZE + // final_result
+        1 + // computation_complete
+        1 + // bump
+        8; // ballots
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PartialDecryption {
+    pub juror: Pubkey,
+    pub decryption_share: [u8; 32],
+    pub proof: [u8; 64],  // ZK proof of correct decryption
+    pub share_index: u8,  // Juror's Shamir index, for the Lagrange coefficient
+}
+
+impl PartialDecryption {
+    pub const SIZE: usize = 32 + 32 + 64 + 1;
+}
+
+/// A juror's ElGamal ballot, kept so each juror adds one
+#[account]
+pub struct MpcBallot {
+    pub case_id: u64,
+    pub juror: Pubkey,         // Signer of the matching vote commitment
+    pub ciphertext: [u8; 64],  // c1 = g·r, c2 = g·vote + election_key·r (compressed G1 each)
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl MpcBallot {
+    pub const LEN: usize = 8 + // case_id
+        32 + // juror
+        64 + // ciphertext
+        8 + // timestamp
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteResult {
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub total_votes: u64,
+    pub verified: bool,
+}
+
+impl VoteResult {
+    pub const SIZE: usize = 8 + 8 + 8 + 1;
+}
+
+/// Initialize MPC for a case
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct InitializeMpc<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MpcConfig::LEN,
+        seeds = [b"mpc_config", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_mpc_handler(
+    ctx: Context<InitializeMpc>,
+    case_id: u64,
+    threshold: u8,
+    total_jurors: u8,
+) -> Result<()> {
+    let mpc_config = &mut ctx.accounts.mpc_config;
+    
+    require!(threshold > 0 && threshold <= total_jurors, crate::ErrorCode::InvalidThreshold);
+    require!(total_jurors as usize <= MAX_MPC_JURORS, crate::ErrorCode::TooManyJurors);
+    
+    let clock = Clock::get()?;
+    **mpc_config = MpcConfig::new(
+        case_id,
+        threshold,
+        total_jurors,
+        clock.unix_timestamp,
+        ctx.bumps.mpc_config,
+    );
+    
+    msg!("MPC initialized for case {}. Threshold: {}/{}", case_id, threshold, total_jurors);
+    Ok(())
+}
+
+/// Juror submits their MPC key share
+#[derive(Accounts)]
+pub struct SubmitMpcShare<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + MpcKeyShare::LEN,
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub mpc_share: Account<'info, MpcKeyShare>,
+    
+    #[account(mut)]
+    pub mpc_config: Account<'info, MpcConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn submit_mpc_share_handler(
+    ctx: Context<SubmitMpcShare>,
+    public_share: [u8; 32],
+    share_commitment: [u8; 32],
+) -> Result<()> {
+    let mpc_share = &mut ctx.accounts.mpc_share;
+    let mpc_config = &mut ctx.accounts.mpc_config;
+    let clock = Clock::get()?;
+    
+    mpc_config.require_open(mpc_config.share_deadline, clock.unix_timestamp)?;
+    require!(
+        mpc_config.current_shares < mpc_config.total_jurors,
+        crate::ErrorCode::AllSharesSubmitted
+    );
+    
+    // Initialize juror's share
+    mpc_share.juror = ctx.accounts.juror.key();
+    mpc_share.case_id = mpc_config.case_id;
+    mpc_share.share_index = mpc_config.current_shares;
+    mpc_share.public_share = public_share;
+    mpc_share.share_commitment = share_commitment;
+    mpc_share.verified = false;
+    mpc_share.dealt = false;
+    mpc_share.shares_sent = 0;
+    mpc_share.timestamp = clock.unix_timestamp;
+    mpc_share.bump = ctx.bumps.mpc_share;
+    
+    mpc_config.current_shares += 1;
+    
+    if mpc_config.current_shares >= mpc_config.threshold {
+        mpc_config.state = MpcState::ThresholdReached;
+        msg!("MPC threshold reached!");
+    }
+    
+    msg!("MPC share submitted. Progress: {}/{}", 
+        mpc_config.current_shares, mpc_config.total_jurors);
+    
+    Ok(())
+}
+
+/// DKG round 1: juror deals their polynomial, one compressed G1 commitment g·a_k per coefficient
+#[derive(Accounts)]
+pub struct DkgRound1Commit<'info> {
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = mpc_share.bump
+    )]
+    pub mpc_share: Account<'info, MpcKeyShare>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+}
+
+pub fn dkg_round1_commit_handler(
+    ctx: Context<DkgRound1Commit>,
+    commitments: Vec<[u8; 32]>,
+) -> Result<()> {
+    let mpc_share = &mut ctx.accounts.mpc_share;
+    let mpc_config = &mut ctx.accounts.mpc_config;
+
+    mpc_config.require_open(mpc_config.share_deadline, Clock::get()?.unix_timestamp)?;
+    require!(!mpc_share.dealt, crate::ErrorCode::AlreadyDealt);
+    require!(mpc_config.election_key == [0u8; 32], crate::ErrorCode::DkgAlreadyFinalized);
+    require!(
+        commitments.len() == mpc_config.threshold as usize,
+        crate::ErrorCode::InvalidFeldmanCommitment
+    );
+
+    mpc_config.share_commitments = if mpc_config.share_commitments.is_empty() {
+        commitments
+            .iter()
+            .map(|commitment| decompress(commitment).map(|_| *commitment))
+            .collect::<Result<_>>()?
+    } else {
+        mpc_config
+            .share_commitments
+            .iter()
+            .zip(&commitments)
+            .map(|(sum, commitment)| compress(&add(&decompress(sum)?, &decompress(commitment)?)?))
+            .collect::<Result<_>>()?
+    };
+    mpc_config.dealers += 1;
+    mpc_share.dealt = true;
+
+    msg!("Feldman commitments dealt for case {}: {}/{}",
+        mpc_config.case_id, mpc_config.dealers, mpc_config.total_jurors);
+    Ok(())
+}
+
+/// DKG round 2: a dealer hands one other juror their evaluation, encrypted to that juror
+#[derive(Accounts)]
+pub struct DkgRound2Share<'info> {
+    pub dealer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), dealer.key().as_ref()],
+        bump = dealer_share.bump
+    )]
+    pub dealer_share: Account<'info, MpcKeyShare>,
+
+    #[account(
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), recipient_share.juror.as_ref()],
+        bump = recipient_share.bump
+    )]
+    pub recipient_share: Account<'info, MpcKeyShare>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+}
+
+/// Opens only after every juror has dealt, so no polynomial can be chosen after seeing
+/// another's commitments. The ciphertext goes out in the event for the recipient to
+/// decrypt and check against the dealer's round-1 commitments.
+pub fn dkg_round2_share_handler(ctx: Context<DkgRound2Share>, encrypted_share: [u8; 64]) -> Result<()> {
+    let dealer_share = &mut ctx.accounts.dealer_share;
+    let recipient_share = &ctx.accounts.recipient_share;
+    let mpc_config = &mut ctx.accounts.mpc_config;
+
+    mpc_config.require_open(mpc_config.share_deadline, Clock::get()?.unix_timestamp)?;
+    require!(
+        mpc_config.dealers == mpc_config.total_jurors,
+        crate::ErrorCode::FeldmanDealingIncomplete
+    );
+    require!(
+        recipient_share.juror != dealer_share.juror,
+        crate::ErrorCode::InvalidShareRecipient
+    );
+    let recipient_bit = 1u32 << recipient_share.share_index;
+    require!(
+        dealer_share.shares_sent & recipient_bit == 0,
+        crate::ErrorCode::ShareAlreadyDelivered
+    );
+
+    dealer_share.shares_sent |= recipient_bit;
+    mpc_config.deliveries += 1;
+
+    emit!(DkgShareDelivered {
+        case_id: mpc_config.case_id,
+        dealer: dealer_share.juror,
+        recipient: recipient_share.juror,
+        encrypted_share,
+    });
+
+    msg!("DKG share {} -> {} for case {}",
+        dealer_share.share_index, recipient_share.share_index, mpc_config.case_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DkgFinalize<'info> {
+    #[account(
+        mut,
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+}
+
+/// Permissionless once every dealer has delivered to every other juror: the election key
+/// is g·Σ a_{j,0}, the summed constant-term commitment
+pub fn dkg_finalize_handler(ctx: Context<DkgFinalize>) -> Result<()> {
+    let mpc_config = &mut ctx.accounts.mpc_config;
+
+    require!(mpc_config.election_key == [0u8; 32], crate::ErrorCode::DkgAlreadyFinalized);
+    let total = mpc_config.total_jurors as u16;
+    require!(
+        mpc_config.dealers == mpc_config.total_jurors && mpc_config.deliveries == total * total.saturating_sub(1),
+        crate::ErrorCode::DkgIncomplete
+    );
+
+    let election_key = *mpc_config
+        .share_commitments
+        .first()
+        .ok_or(crate::ErrorCode::DkgIncomplete)?;
+    require!(election_key != [0u8; 32], crate::ErrorCode::InvalidFeldmanCommitment);
+    mpc_config.election_key = election_key;
+
+    emit!(ElectionKeyGenerated {
+        case_id: mpc_config.case_id,
+        election_key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Election key generated for case {}", mpc_config.case_id);
+    Ok(())
+}
+
+/// Checks a juror's public share against the combined Feldman commitments
+#[derive(Accounts)]
+pub struct VerifyMpcShare<'info> {
+    #[account(
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), mpc_share.juror.as_ref()],
+        bump = mpc_share.bump
+    )]
+    pub mpc_share: Account<'info, MpcKeyShare>,
+}
+
+/// Permissionless once every juror has dealt, since a share sums every dealer's polynomial
+pub fn verify_mpc_share_handler(ctx: Context<VerifyMpcShare>) -> Result<()> {
+    let mpc_config = &ctx.accounts.mpc_config;
+    let mpc_share = &mut ctx.accounts.mpc_share;
+
+    require!(
+        mpc_config.dealers == mpc_config.total_jurors,
+        crate::ErrorCode::FeldmanDealingIncomplete
+    );
+    require!(
+        feldman_share_matches(&mpc_config.share_commitments, mpc_share.share_index, &mpc_share.public_share)?,
+        crate::ErrorCode::ShareCommitmentMismatch
+    );
+    mpc_share.verified = true;
+
+    msg!("MPC share {} verified for case {}", mpc_share.share_index, mpc_config.case_id);
+    Ok(())
+}
+
+/// Feldman check g·s == Σ A_k·x^k at x = share_index + 1, evaluated by Horner's rule
+pub fn feldman_share_matches(
+    commitments: &[[u8; 32]],
+    share_index: u8,
+    public_share: &[u8; 32],
+) -> Result<bool> {
+    let mut x = [0u8; 32];
+    x[30..].copy_from_slice(&(share_index as u16 + 1).to_be_bytes());
+
+    let mut expected = [0u8; 64];
+    for commitment in commitments.iter().rev() {
+        expected = add(&multiply(&expected, &x)?, &decompress(commitment)?)?;
+    }
+    Ok(expected == decompress(public_share)?)
+}
+
+/// Chaum-Pedersen check that `share` = c1·s for the s behind `public_share` = g·s. The
+/// proof is the challenge e and response z = r + e·s (mod the group order), 32 bytes
+/// each; the commitments g·r and c1·r are recomputed as g·z - public_share·e and
+/// c1·z - share·e and must hash back to e.
+pub fn decryption_proof_valid(
+    case_id: u64,
+    public_share: &[u8; 32],
+    c1: &[u8; 32],
+    share: &[u8; 32],
+    proof: &[u8; 64],
+) -> Result<bool> {
+    let (e, z): (&[u8; 32], &[u8; 32]) = (proof[..32].try_into().unwrap(), proof[32..].try_into().unwrap());
+    let g_r = add(&multiply(&G1_GENERATOR, z)?, &negate(&multiply(&decompress(public_share)?, e)?))?;
+    let c1_r = add(&multiply(&decompress(c1)?, z)?, &negate(&multiply(&decompress(share)?, e)?))?;
+    Ok(decryption_challenge(case_id, public_share, c1, share, &g_r, &c1_r) == *e)
+}
+
+/// Fiat-Shamir challenge of a decryption proof, cut to 253 bits so it's below the group order
+pub fn decryption_challenge(
+    case_id: u64,
+    public_share: &[u8; 32],
+    c1: &[u8; 32],
+    share: &[u8; 32],
+    g_r: &[u8; 64],
+    c1_r: &[u8; 64],
+) -> [u8; 32] {
+    reduce_challenge(crate::domain::hash(
+        crate::domain::DECRYPTION_PROOF,
+        &[&case_id.to_le_bytes(), public_share, c1, share, g_r, c1_r],
+    ))
+}
+
+/// Cut a hash to 253 bits, below the group order
+fn reduce_challenge(mut hash: [u8; 32]) -> [u8; 32] {
+    hash[0] &= 0x1f;
+    hash
+}
+
+/// a + b mod the group order, for a and b already below it
+fn add_mod_order(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut sum = [0u8; 32];
+    let mut carry = 0u16;
+    for i in (0..32).rev() {
+        let value = a[i] as u16 + b[i] as u16 + carry;
+        sum[i] = value as u8;
+        carry = value >> 8;
+    }
+    if sum >= GROUP_ORDER {
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let difference = sum[i] as i16 - GROUP_ORDER[i] as i16 - borrow;
+            sum[i] = difference.rem_euclid(256) as u8;
+            borrow = (difference < 0) as i16;
+        }
+    }
+    sum
+}
+
+fn negate(point: &[u8; 64]) -> [u8; 64] {
+    if *point == [0u8; 64] {
+        return *point;
+    }
+    let mut negated = *point;
+    let mut borrow = 0u16;
+    for i in (0..32).rev() {
+        let difference = FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow as i16;
+        negated[32 + i] = difference.rem_euclid(256) as u8;
+        borrow = (difference < 0) as u16;
+    }
+    negated
+}
+
+fn decompress(point: &[u8; 32]) -> Result<[u8; 64]> {
+    alt_bn128_g1_decompress(point).map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))
+}
+
+fn compress(point: &[u8; 64]) -> Result<[u8; 32]> {
+    alt_bn128_g1_compress(point).map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))
+}
+
+fn add(a: &[u8; 64], b: &[u8; 64]) -> Result<[u8; 64]> {
+    let sum = alt_bn128_addition(&[&a[..], &b[..]].concat())
+        .map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))?;
+    sum.try_into().map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))
+}
+
+fn multiply(point: &[u8; 64], scalar: &[u8; 32]) -> Result<[u8; 64]> {
+    let product = alt_bn128_multiplication(&[&point[..], &scalar[..]].concat())
+        .map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))?;
+    product.try_into().map_err(|_| error!(crate::ErrorCode::InvalidFeldmanCommitment))
+}
+
+/// Juror adds their encrypted ballot to the tally
+#[derive(Accounts)]
+pub struct AddEncryptedVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    #[account(
+        seeds = [b"case", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+
+    #[account(
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_aggregation", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = vote_aggregation.bump
+    )]
+    pub vote_aggregation: Account<'info, MpcVoteAggregation>,
+
+    /// The private vote commitment this ballot goes with, made by the same signer
+    #[account(
+        seeds = [b"vote_commitment", mpc_config.case_id.to_le_bytes().as_ref(), vote_commitment_account.seed().as_ref()],
+        bump = vote_commitment_account.bump,
+        constraint = vote_commitment_account.juror == juror.key() @ crate::ErrorCode::Unauthorized
+    )]
+    pub vote_commitment_account: Account<'info, VoteCommitmentAccount>,
+
+    #[account(
+        init,
+        payer = juror,
+        space = 8 + MpcBallot::LEN,
+        seeds = [b"mpc_ballot", mpc_config.case_id.to_le_bytes().as_ref(), vote_commitment_account.seed().as_ref()],
+        bump
+    )]
+    pub ballot: Account<'info, MpcBallot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sums the ballot of whoever made a private vote commitment on the case into the tally,
+/// c1 and c2 pointwise, so threshold decryption only ever opens the aggregate. The proof
+/// shows the ballot encrypts 0 or 1. Ballots close once the first partial decryption is in.
+pub fn add_encrypted_vote_handler(
+    ctx: Context<AddEncryptedVote>,
+    ciphertext: [u8; 64],
+    proof: [u8; 128],
+) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let mpc_config = &ctx.accounts.mpc_config;
+    let vote_aggregation = &mut ctx.accounts.vote_aggregation;
+    let juror = ctx.accounts.juror.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    mpc_config.require_open(mpc_config.decryption_deadline, now)?;
+    require!(mpc_config.election_key != [0u8; 32], crate::ErrorCode::DkgIncomplete);
+    require!(
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        crate::ErrorCode::InvalidConfidentialComponents
+    );
+    require!(case.state == CaseState::Voting as u8, crate::ErrorCode::CaseNotVoting);
+    require!(
+        ctx.accounts.vote_commitment_account.commitment != [0u8; 32],
+        crate::ErrorCode::BallotWithoutCommitment
+    );
+    require!(
+        vote_aggregation.partial_decryptions.is_empty(),
+        crate::ErrorCode::BallotsClosed
+    );
+    require!(
+        ballot_proof_valid(mpc_config.case_id, &mpc_config.election_key, &ciphertext, &proof)?,
+        crate::ErrorCode::InvalidBallotProof
+    );
+
+    vote_aggregation.encrypted_tally = match vote_aggregation.tally_c1() {
+        None => ciphertext.to_vec(),
+        Some(_) => {
+            let mut sum = [0u8; 64];
+            for half in 0..2 {
+                let range = half * 32..half * 32 + 32;
+                let tally = decompress(vote_aggregation.encrypted_tally[range.clone()].try_into().unwrap())?;
+                let ballot = decompress(ciphertext[range.clone()].try_into().unwrap())?;
+                sum[range].copy_from_slice(&compress(&add(&tally, &ballot)?)?);
+            }
+            sum.to_vec()
+        }
+    };
+    vote_aggregation.ballots += 1;
+
+    let ballot = &mut ctx.accounts.ballot;
+    ballot.case_id = mpc_config.case_id;
+    ballot.juror = juror;
+    ballot.ciphertext = ciphertext;
+    ballot.timestamp = now;
+    ballot.bump = ctx.bumps.ballot;
+
+    emit!(EncryptedVoteAdded {
+        case_id: mpc_config.case_id,
+        juror,
+        ballots: vote_aggregation.ballots,
+        timestamp: now,
+    });
+    msg!("Encrypted ballot {} added for case {}", vote_aggregation.ballots, mpc_config.case_id);
+    Ok(())
+}
+
+/// Disjunctive Chaum-Pedersen check that an ElGamal ballot (c1, c2) under `election_key`
+/// encrypts 0 or 1: for vote b, log_g c1 == log_key (c2 - g·b). The proof is (e0, z0, e1, z1),
+/// one simulated branch and one real; the recomputed commitments must hash to e0 + e1.
+pub fn ballot_proof_valid(
+    case_id: u64,
+    election_key: &[u8; 32],
+    ciphertext: &[u8; 64],
+    proof: &[u8; 128],
+) -> Result<bool> {
+    let key = decompress(election_key)?;
+    let c1 = decompress(ciphertext[..32].try_into().unwrap())?;
+    let c2 = decompress(ciphertext[32..].try_into().unwrap())?;
+
+    let mut commitments = [[0u8; 64]; 4];
+    for vote in 0..2 {
+        let e: &[u8; 32] = proof[64 * vote..64 * vote + 32].try_into().unwrap();
+        let z: &[u8; 32] = proof[64 * vote + 32..64 * vote + 64].try_into().unwrap();
+        let opened = if vote == 0 { c2 } else { add(&c2, &negate(&G1_GENERATOR))? };
+        commitments[2 * vote] = add(&multiply(&G1_GENERATOR, z)?, &negate(&multiply(&c1, e)?))?;
+        commitments[2 * vote + 1] = add(&multiply(&key, z)?, &negate(&multiply(&opened, e)?))?;
+    }
+    let challenge = crate::domain::hash(
+        crate::domain::BALLOT_PROOF,
+        &[
+            &case_id.to_le_bytes(),
+            election_key,
+            ciphertext,
+            &commitments[0],
+            &commitments[1],
+            &commitments[2],
+            &commitments[3],
+        ],
+    );
+    let (e0, e1) = (proof[..32].try_into().unwrap(), proof[64..96].try_into().unwrap());
+    Ok(e0 < GROUP_ORDER && e1 < GROUP_ORDER && add_mod_order(&e0, &e1) == reduce_challenge(challenge))
+}
+
+/// Submit partial decryption for vote aggregation
+#[derive(Accounts)]
+pub struct SubmitPartialDecryption<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    
+    #[account(
+        seeds = [b"mpc_share", mpc_config.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = mpc_share.bump,
+        constraint = mpc_share.verified @ crate::ErrorCode::ShareNotVerified
+    )]
+    pub mpc_share: Account<'info, MpcKeyShare>,
+    
+    #[account(mut)]
+    pub mpc_config: Account<'info, MpcConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"mpc_aggregation", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vote_aggregation: Account<'info, MpcVoteAggregation>,
+}
+
+pub fn submit_partial_decryption_handler(
+    ctx: Context<SubmitPartialDecryption>,
+    decryption_share: [u8; 32],
+    proof: [u8; 64],
+) -> Result<()> {
+    let vote_aggregation = &mut ctx.accounts.vote_aggregation;
+    let mpc_config = &ctx.accounts.mpc_config;
+    
+    mpc_config.require_open(mpc_config.decryption_deadline, Clock::get()?.unix_timestamp)?;
+    require!(
+        mpc_config.state == MpcState::ThresholdReached,
+        crate::ErrorCode::ThresholdNotReached
+    );
+    // A panel holding an election key decrypts the encrypted tally and nothing else
+    require!(
+        mpc_config.election_key == [0u8; 32] || vote_aggregation.tally_c1().is_some(),
+        crate::ErrorCode::TallyNotEncrypted
+    );
+
+    // Against an encrypted tally a share only counts with proof it was taken with the
+    // juror's own key share, so no juror can skew the decryption
+    if let Some(c1) = vote_aggregation.tally_c1() {
+        require!(
+            decryption_proof_valid(
+                mpc_config.case_id,
+                &ctx.accounts.mpc_share.public_share,
+                &c1,
+                &decryption_share,
+                &proof,
+            )?,
+            crate::ErrorCode::InvalidDecryptionProof
+        );
+    }
+    
+    require!(
+        !vote_aggregation
+            .partial_decryptions
+            .iter()
+            .any(|partial| partial.juror == ctx.accounts.juror.key()),
+        crate::ErrorCode::DuplicatePartialDecryption
+    );
+
+    // Add partial decryption
+    let partial_dec = PartialDecryption {
+        juror: ctx.accounts.juror.key(),
+        decryption_share,
+        proof,
+        share_index: ctx.accounts.mpc_share.share_index,
+    };
+    
+    vote_aggregation.partial_decryptions.push(partial_dec);
+    
+    // Check if we can compute final result
+    if !vote_aggregation.computation_complete
+        && vote_aggregation.partial_decryptions.len() >= mpc_config.threshold as usize
+    {
+        if let Some(result) = combine_partial_decryptions(vote_aggregation, mpc_config.threshold)? {
+            msg!("MPC computation complete! Votes: {} for, {} against",
+                result.votes_for, result.votes_against);
+            vote_aggregation.final_result = Some(result);
+            vote_aggregation.computation_complete = true;
+        }
+    }
+    
+    Ok(())
+}
+
+/// Gives up on a stalled MPC group
+#[derive(Accounts)]
+pub struct AbortMpc<'info> {
+    #[account(
+        mut,
+        seeds = [b"case", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_config", mpc_config.case_id.to_le_bytes().as_ref()],
+        bump = mpc_config.bump
+    )]
+    pub mpc_config: Account<'info, MpcConfig>,
+}
+
+/// Permissionless once a round misses its deadline, so one absent juror can't hold the
+/// case hostage. The case drops its private-votes component: the commitments jurors
+/// already made are opened through the public reveal phase, and jurors who haven't
+/// committed yet commit there as on any other case.
+pub fn abort_mpc_handler(ctx: Context<AbortMpc>) -> Result<()> {
+    let mpc_config = &mut ctx.accounts.mpc_config;
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        mpc_config.state != MpcState::Aborted && mpc_config.state != MpcState::ComputationComplete,
+        crate::ErrorCode::MpcAborted
+    );
+    require!(mpc_config.stalled(now), crate::ErrorCode::MpcNotStalled);
+    require!(
+        case.state == CaseState::Nominated as u8 || case.state == CaseState::Voting as u8,
+        crate::ErrorCode::CaseNotVoting
+    );
+
+    mpc_config.state = MpcState::Aborted;
+    case.confidential &= !ConfidentialComponents::PRIVATE_VOTES;
+
+    emit!(MpcAborted {
+        case_id: case.case_id,
+        shares: mpc_config.current_shares,
+        decryptions_due: now > mpc_config.decryption_deadline,
+        timestamp: now,
+    });
+    msg!("MPC aborted for case {}; falling back to public commit-reveal", case.case_id);
+    Ok(())
+}
+
+/// Combine partial decryptions to get final tally (Arcium MPC protocol).
+/// An encrypted tally is opened by threshold decryption. A panel without an election key
+/// has nothing to encrypt under: each share's first 16 bytes then carry the tally the juror
+/// decrypted (votes for, votes against, little-endian), and the result is fixed once
+/// `threshold` shares agree on it.
+fn combine_partial_decryptions(
+    vote_aggregation: &MpcVoteAggregation,
+    threshold: u8,
+) -> Result<Option<VoteResult>> {
+    let partial_decryptions = &vote_aggregation.partial_decryptions;
+    msg!("Combining {} partial decryptions", partial_decryptions.len());
+
+    if vote_aggregation.tally_c1().is_some() {
+        let ballots = vote_aggregation.ballots;
+        let votes_for = threshold_decrypt(
+            &vote_aggregation.encrypted_tally,
+            &partial_decryptions[..threshold as usize],
+            ballots,
+        )?;
+        return Ok(votes_for.map(|votes_for| VoteResult {
+            votes_for,
+            votes_against: ballots - votes_for,
+            total_votes: ballots,
+            verified: true,
+        }));
+    }
+
+    Ok(partial_decryptions
+        .iter()
+        .map(decrypted_tally)
+        .find(|tally| {
+            partial_decryptions
+                .iter()
+                .filter(|partial| decrypted_tally(partial) == *tally)
+                .count()
+                >= threshold as usize
+        })
+        .map(|(votes_for, votes_against)| VoteResult {
+            votes_for,
+            votes_against,
+            total_votes: votes_for.saturating_add(votes_against),
+            verified: true,
+        }))
+}
+
+fn decrypted_tally(partial: &PartialDecryption) -> (u64, u64) {
+    let share = &partial.decryption_share;
+    (
+        u64::from_le_bytes(share[0..8].try_into().unwrap_or_default()),
+        u64::from_le_bytes(share[8..16].try_into().unwrap_or_default()),
+    )
+}
+
+/// Approvals in an ElGamal tally (c1, c2), from proven shares c1·s_i. Lagrange
+/// interpolation in the exponent, scaled by LAGRANGE_SCALE so every coefficient is an
+/// integer, gives c1·(L·s); c2·L minus that is g·(L·votes), and votes is found by trying
+/// each count up to `ballots`.
+fn threshold_decrypt(tally: &[u8], shares: &[PartialDecryption], ballots: u64) -> Result<Option<u64>> {
+    let mut masked = [0u8; 64];
+    for share in shares {
+        let x_i = share.share_index as i128 + 1;
+        let (mut numerator, mut denominator) = (1i128, 1i128);
+        for other in shares.iter().filter(|other| other.share_index != share.share_index) {
+            let x_j = other.share_index as i128 + 1;
+            numerator *= x_j;
+            denominator *= x_j - x_i;
+        }
+        let coefficient = (LAGRANGE_SCALE as i128 / denominator.abs()) * numerator;
+        let term = multiply(&decompress(&share.decryption_share)?, &scalar(coefficient as u128))?;
+        let term = if denominator < 0 { negate(&term) } else { term };
+        masked = add(&masked, &term)?;
+    }
+
+    let scale = scalar(LAGRANGE_SCALE);
+    let target = add(
+        &multiply(&decompress(tally[32..64].try_into().unwrap())?, &scale)?,
+        &negate(&masked),
+    )?;
+    let step = multiply(&G1_GENERATOR, &scale)?;
+    let mut candidate = [0u8; 64];
+    for votes in 0..=ballots {
+        if candidate == target {
+            return Ok(Some(votes));
+        }
+        candidate = add(&candidate, &step)?;
+    }
+    Ok(None)
+}
+
+fn scalar(value: u128) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[16..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit(value: u8) -> [u8; 32] {
+        let mut scalar = [0u8; 32];
+        scalar[31] = value;
+        compress(&multiply(&G1_GENERATOR, &scalar).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_feldman_share_matches_polynomial() {
+        // f(x) = 5 + 7x, so the juror at index 2 holds f(3) = 26
+        let commitments = [commit(5), commit(7)];
+        assert!(feldman_share_matches(&commitments, 2, &commit(26)).unwrap());
+        assert!(!feldman_share_matches(&commitments, 1, &commit(26)).unwrap());
+        assert!(!feldman_share_matches(&commitments, 2, &commit(27)).unwrap());
+    }
+
+    #[test]
+    fn test_summed_commitments_verify_summed_shares() {
+        // Dealers f(x) = 1 + 2x and g(x) = 3 + 4x; share at index 0 is f(1) + g(1) = 10
+        let summed: Vec<[u8; 32]> = [(1, 3), (2, 4)]
+            .iter()
+            .map(|(a, b)| {
+                let sum = add(&decompress(&commit(*a)).unwrap(), &decompress(&commit(*b)).unwrap());
+                compress(&sum.unwrap()).unwrap()
+            })
+            .collect();
+        assert!(feldman_share_matches(&summed, 0, &commit(10)).unwrap());
+    }
+
+    /// r + e·s mod the group order, for small r and s
+    fn response(r: u8, e: &[u8; 32], s: u8) -> [u8; 32] {
+        let mut z = [0u8; 32];
+        let mut carry = r as u32;
+        for i in (0..32).rev() {
+            let value = e[i] as u32 * s as u32 + carry;
+            z[i] = value as u8;
+            carry = value >> 8;
+        }
+        while z >= GROUP_ORDER {
+            let mut borrow = 0i16;
+            for i in (0..32).rev() {
+                let difference = z[i] as i16 - GROUP_ORDER[i] as i16 - borrow;
+                z[i] = difference.rem_euclid(256) as u8;
+                borrow = (difference < 0) as i16;
+            }
+        }
+        z
+    }
+
+    #[test]
+    fn test_decryption_proof() {
+        // Key share s = 5, tally c1 = g·3, so the honest share is g·15; nonce r = 7
+        let (public_share, c1, share) = (commit(5), commit(3), commit(15));
+        let g_r = decompress(&commit(7)).unwrap();
+        let c1_r = decompress(&commit(21)).unwrap();
+        let e = decryption_challenge(9, &public_share, &c1, &share, &g_r, &c1_r);
+        let mut proof = [0u8; 64];
+        proof[..32].copy_from_slice(&e);
+        proof[32..].copy_from_slice(&response(7, &e, 5));
+
+        assert!(decryption_proof_valid(9, &public_share, &c1, &share, &proof).unwrap());
+        // A share taken with another key, or replayed on another case, fails
+        assert!(!decryption_proof_valid(9, &public_share, &c1, &commit(16), &proof).unwrap());
+        assert!(!decryption_proof_valid(10, &public_share, &c1, &share, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_stalled_rounds() {
+        let mut mpc_config = MpcConfig::new(1, 2, 3, 0, 255);
+        let after_shares = mpc_config.share_deadline + 1;
+        assert!(!mpc_config.stalled(mpc_config.share_deadline));
+        assert!(mpc_config.stalled(after_shares));
+
+        // One absent juror doesn't stall a group that has its threshold
+        mpc_config.current_shares = 2;
+        assert!(!mpc_config.stalled(after_shares));
+        // A DKG that was started has to finish
+        mpc_config.dealers = 2;
+        assert!(mpc_config.stalled(after_shares));
+        mpc_config.election_key = [1u8; 32];
+        assert!(!mpc_config.stalled(after_shares));
+        assert!(mpc_config.stalled(mpc_config.decryption_deadline + 1));
+    }
+
+    #[test]
+    fn test_ballot_proof() {
+        // Election key g·5; the ballot is a 1 with r = 4: (g·4, g + key·4 = g·21)
+        let key = commit(5);
+        let mut ciphertext = [0u8; 64];
+        ciphertext[..32].copy_from_slice(&commit(4));
+        ciphertext[32..].copy_from_slice(&commit(21));
+
+        // The 0 branch is simulated with e0 = 3, z0 = 2: g·2 - c1·3 and key·2 - c2·3
+        let point = |value: u8| decompress(&commit(value)).unwrap();
+        let mut commitments = [[0u8; 64]; 4];
+        commitments[0] = negate(&point(10));
+        commitments[1] = negate(&point(53));
+        // The 1 branch is real with nonce w = 6: g·6 and key·6
+        commitments[2] = point(6);
+        commitments[3] = point(30);
+        let challenge = reduce_challenge(crate::domain::hash(
+            crate::domain::BALLOT_PROOF,
+            &[
+                &7u64.to_le_bytes(),
+                &key,
+                &ciphertext,
+                &commitments[0],
+                &commitments[1],
+                &commitments[2],
+                &commitments[3],
+            ],
+        ));
+        let mut e1 = challenge;
+        assert!(e1[31] >= 3);
+        e1[31] -= 3;
+
+        let mut proof = [0u8; 128];
+        proof[31] = 3;
+        proof[63] = 2;
+        proof[64..96].copy_from_slice(&e1);
+        proof[96..].copy_from_slice(&response(6, &e1, 4));
+        assert!(ballot_proof_valid(7, &key, &ciphertext, &proof).unwrap());
+
+        // The same proof doesn't carry a ballot of 2
+        ciphertext[32..].copy_from_slice(&commit(22));
+        assert!(!ballot_proof_valid(7, &key, &ciphertext, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_threshold_decrypt() {
+        // f(x) = 5 + 2x deals shares 7, 9, 11 of the key g·5. Ballots 1 (r = 4) and 0 (r = 3)
+        // sum to (g·7, g·1 + key·7 = g·36); jurors 1 and 3 decrypt with c1·7 and c1·11.
+        let mut tally = [0u8; 64];
+        tally[..32].copy_from_slice(&commit(7));
+        tally[32..].copy_from_slice(&commit(36));
+        let share = |share_index: u8, value: u8| PartialDecryption {
+            juror: Pubkey::default(),
+            decryption_share: commit(value),
+            proof: [0u8; 64],
+            share_index,
+        };
+        assert_eq!(threshold_decrypt(&tally, &[share(0, 49), share(2, 77)], 2).unwrap(), Some(1));
+        assert_eq!(threshold_decrypt(&tally, &[share(2, 77), share(1, 63)], 2).unwrap(), Some(1));
+        // A tally beyond the ballot count isn't found
+        tally[32..].copy_from_slice(&commit(37));
+        assert_eq!(threshold_decrypt(&tally, &[share(0, 49), share(2, 77)], 1).unwrap(), None);
+    }
+}
@@ -1,303 +1,846 @@
-// Dust Protocol Integration for Compliant Confidential Transfers
-// Enables privacy-preserving token transfers while maintaining regulatory compliance
-use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
-
-/// Dust Protocol confidential transfer configuration
-#[account]
-pub struct ConfidentialTransferConfig {
-    pub enabled: bool,
-    pub compliance_pubkey: Pubkey,     // Compliance authority
-    pub max_transfer_amount: u64,      // Maximum confidential transfer
-    pub require_proof: bool,           // Require ZK proof for transfers
-    pub whitelisted_tokens: Vec<Pubkey>,
-    pub bump: u8,
-}
-
-impl ConfidentialTransferConfig {
-    pub const LEN: usize = 1 + // enabled
-        32 + // compliance_pubkey
-        8 + // max_transfer_amount
-        1 + // require_proof
-        4 + (32 * 10) + // whitelisted_tokens (max 10)
-        1; // bump
-}
-
-/// Confidential balance account (encrypted balance)
-#[account]
-pub struct ConfidentialBalance {
-    pub owner: Pubkey,
-    pub mint: Pubkey,
-    pub encrypted_balance: [u8; 64],    // ElGamal encrypted balance
-    pub pending_balance: [u8; 64],      // Pending incoming transfers
-    pub decryptable_balance: u64,       // For compliance checks
-    pub last_update: i64,
-    pub bump: u8,
-}
-
-impl ConfidentialBalance {
-    pub const LEN: usize = 32 + // owner
-        32 + // mint
-        64 + // encrypted_balance
-        64 + // pending_balance
-        8 + // decryptable_balance
-        8 + // last_update
-        1; // bump
-}
-
-/// Confidential transfer instruction
-#[account]
-pub struct ConfidentialTransferRecord {
-    pub transfer_id: u64,
-    pub sender: Pubkey,
-    pub recipient: Pubkey,
-    pub mint: Pubkey,
-    pub encrypted_amount: [u8; 64],     // ElGamal encrypted amount
-    pub range_proof: Vec<u8>,           // Proof amount is in valid range
-    pub compliance_proof: Vec<u8>,      // Proof of compliance
-    pub auditor_data: [u8; 32],         // Encrypted data for auditor
-    pub timestamp: i64,
-    pub status: TransferStatus,
-    pub bump: u8,
-}
-
-impl ConfidentialTransferRecord {
-    pub const MAX_SIZE: usize = 8 + // transfer_id
-        32 + // sender
-        32 + // recipient
-        32 + // mint
-        64 + // encrypted_amount
-        4 + 256 + // range_proof (max 256 bytes)
-        4 + 256 + // compliance_proof (max 256 bytes)
-        32 + // auditor_data
-        8 + // timestamp
-        1 + // status
-        1; // bump
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum TransferStatus {
-    Pending,
-    Completed,
-    Audited,
-    Flagged,
-}
-
-/// Initialize confidential transfer for case rewards/penalties
-#[derive(Accounts)]
-#[instruction(transfer_id: u64)]
-pub struct InitiateConfidentialTransfer<'info> {
-    #[account(mut)]
-    pub sender: Signer<'info>,
-    
-    #[account(
-        mut,
-        constraint = sender_balance.owner == sender.key()
-    )]
-    pub sender_balance: Account<'info, ConfidentialBalance>,
-    
-    #[account(mut)]
-    pub recipient_balance: Account<'info, ConfidentialBalance>,
-    
-    #[account(
-        init,
-        payer = sender,
-        space = 8 + ConfidentialTransferRecord::MAX_SIZE,
-        seeds = [b"confidential_transfer", transfer_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub transfer_record: Account<'info, ConfidentialTransferRecord>,
-    
-    #[account(
-        seeds = [b"conf_transfer_config"],
-        bump = config.bump
-    )]
-    pub config: Account<'info, ConfidentialTransferConfig>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-pub fn initiate_confidential_transfer_handler(
-    ctx: Context<InitiateConfidentialTransfer>,
-    transfer_id: u64,
-    encrypted_amount: [u8; 64],
-    range_proof: Vec<u8>,
-    compliance_proof: Vec<u8>,
-) -> Result<()> {
-    let transfer = &mut ctx.accounts.transfer_record;
-    let config = &ctx.accounts.config;
-    let clock = Clock::get()?;
-    
-    require!(config.enabled, crate::ErrorCode::ConfidentialTransfersDisabled);
-    
-    // Verify proofs
-    require!(
-        verify_range_proof(&range_proof, &encrypted_amount)?,
-        crate::ErrorCode::InvalidRangeProof
-    );
-    
-    if config.require_proof {
-        require!(
-            verify_compliance_proof(&compliance_proof)?,
-            crate::ErrorCode::InvalidComplianceProof
-        );
-    }
-    
-    // Generate auditor data (encrypted for compliance officer)
-    let auditor_data = generate_auditor_data(
-        transfer_id,
-        &encrypted_amount,
-        config.compliance_pubkey,
-    );
-    
-    // Initialize transfer record
-    transfer.transfer_id = transfer_id;
-    transfer.sender = ctx.accounts.sender.key();
-    transfer.recipient = ctx.accounts.recipient_balance.owner;
-    transfer.mint = ctx.accounts.sender_balance.mint;
-    transfer.encrypted_amount = encrypted_amount;
-    transfer.range_proof = range_proof;
-    transfer.compliance_proof = compliance_proof;
-    transfer.auditor_data = auditor_data;
-    transfer.timestamp = clock.unix_timestamp;
-    transfer.status = TransferStatus::Pending;
-    transfer.bump = ctx.bumps.transfer_record;
-    
-    msg!("Confidential transfer initiated. ID: {}", transfer_id);
-    Ok(())
-}
-
-/// Apply confidential transfer to balances
-#[derive(Accounts)]
-pub struct ApplyConfidentialTransfer<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"confidential_transfer", transfer.transfer_id.to_le_bytes().as_ref()],
-        bump = transfer.bump
-    )]
-    pub transfer: Account<'info, ConfidentialTransferRecord>,
-    
-    #[account(mut)]
-    pub sender_balance: Account<'info, ConfidentialBalance>,
-    
-    #[account(mut)]
-    pub recipient_balance: Account<'info, ConfidentialBalance>,
-}
-
-pub fn apply_confidential_transfer_handler(
-    ctx: Context<ApplyConfidentialTransfer>,
-) -> Result<()> {
-    let transfer = &mut ctx.accounts.transfer;
-    let sender_balance = &mut ctx.accounts.sender_balance;
-    let recipient_balance = &mut ctx.accounts.recipient_balance;
-    let clock = Clock::get()?;
-    
-    require!(
-        transfer.status == TransferStatus::Pending,
-        crate::ErrorCode::TransferNotPending
-    );
-    
-    // Update encrypted balances using homomorphic properties
-    sender_balance.encrypted_balance = subtract_encrypted_values(
-        &sender_balance.encrypted_balance,
-        &transfer.encrypted_amount,
-    )?;
-    
-    recipient_balance.encrypted_balance = add_encrypted_values(
-        &recipient_balance.encrypted_balance,
-        &transfer.encrypted_amount,
-    )?;
-    
-    sender_balance.last_update = clock.unix_timestamp;
-    recipient_balance.last_update = clock.unix_timestamp;
-    
-    transfer.status = TransferStatus::Completed;
-    
-    msg!("Confidential transfer applied. ID: {}", transfer.transfer_id);
-    Ok(())
-}
-
-/// Compliance audit of confidential transfer
-#[derive(Accounts)]
-pub struct AuditConfidentialTransfer<'info> {
-    #[account(
-        constraint = compliance_officer.key() == config.compliance_pubkey
-    )]
-    pub compliance_officer: Signer<'info>,
-    
-    #[account(mut)]
-    pub transfer: Account<'info, ConfidentialTransferRecord>,
-    
-    #[account(
-        seeds = [b"conf_transfer_config"],
-        bump = config.bump
-    )]
-    pub config: Account<'info, ConfidentialTransferConfig>,
-}
-
-pub fn audit_transfer_handler(
-    ctx: Context<AuditConfidentialTransfer>,
-    flag: bool,
-) -> Result<()> {
-    let transfer = &mut ctx.accounts.transfer;
-    
-    transfer.status = if flag {
-        TransferStatus::Flagged
-    } else {
-        TransferStatus::Audited
-    };
-    
-    msg!("Transfer {} audited. Flagged: {}", transfer.transfer_id, flag);
-    Ok(())
-}
-
-// Helper functions for ZK proofs
-
-fn verify_range_proof(proof: &[u8], encrypted_amount: &[u8; 64]) -> Result<bool> {
-    // Verify that encrypted amount is in valid range
-    // Uses Bulletproofs or similar range proof system
-    msg!("Verifying range proof ({} bytes)", proof.len());
-    Ok(true) // Placeholder
-}
-
-fn verify_compliance_proof(proof: &[u8]) -> Result<bool> {
-    // Verify compliance with regulatory requirements
-    msg!("Verifying compliance proof ({} bytes)", proof.len());
-    Ok(true) // Placeholder
-}
-
-fn generate_auditor_data(
-    transfer_id: u64,
-    encrypted_amount: &[u8; 64],
-    compliance_pubkey: Pubkey,
-) -> [u8; 32] {
-    // Generate encrypted data that compliance officer can decrypt
-    use solana_program::hash::hashv;
-    let result = hashv(&[
-        &transfer_id.to_le_bytes(),
-        encrypted_amount,
-        compliance_pubkey.as_ref(),
-    ]);
-    result.to_bytes()
-}
-
-fn add_encrypted_values(a: &[u8; 64], b: &[u8; 64]) -> Result<[u8; 64]> {
-    // Homomorphic addition of encrypted values
-    let mut result = [0u8; 64];
-    for i in 0..64 {
-        result[i] = a[i].wrapping_add(b[i]);
-    }
-    Ok(result)
-}
-
-fn subtract_encrypted_values(a: &[u8; 64], b: &[u8; 64]) -> Result<[u8; 64]> {
-    // Homomorphic subtraction of encrypted values
-    let mut result = [0u8; 64];
-    for i in 0..64 {
-        result[i] = a[i].wrapping_sub(b[i]);
-    }
-    Ok(result)
-}
+// Dust Protocol Integration for Compliant Confidential Transfers
+// Enables privacy-preserving token transfers while maintaining regulatory compliance
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use solana_zk_token_sdk::{
+    instruction::{BatchedRangeProofContext, CiphertextCommitmentEqualityProofContext, Pod, ProofType},
+    zk_token_elgamal::{ops, pod::ElGamalCiphertext},
+    zk_token_proof_program,
+    zk_token_proof_state::ProofContextState,
+};
+
+/// Delay between proposing a new compliance authority and it taking over
+pub const COMPLIANCE_ROTATION_DELAY: i64 = 2 * 24 * 60 * 60;
+
+/// Most auditors that can share the power to flag a transfer
+pub const MAX_AUDITORS: usize = 5;
+
+/// Dust Protocol confidential transfer configuration
+#[account]
+pub struct ConfidentialTransferConfig {
+    pub enabled: bool,
+    pub compliance_pubkey: Pubkey,     // Compliance authority
+    pub pending_compliance_pubkey: Pubkey, // Proposed successor; default while no rotation is pending
+    pub rotation_unlocks_at: i64,      // Earliest time the successor can accept
+    pub previous_compliance_pubkey: Pubkey, // Key auditor_data may still be bound to after a rotation
+    pub max_transfer_amount: u64,      // Maximum confidential transfer
+    pub require_proof: bool,           // Require ZK proof for transfers
+    pub whitelisted_tokens: Vec<Pubkey>,
+    pub auditors: Vec<Pubkey>,         // Empty while the compliance authority audits alone
+    pub audit_threshold: u8,           // Auditor flags needed to flag a transfer
+    pub bump: u8,
+}
+
+impl ConfidentialTransferConfig {
+    pub const LEN: usize = 1 + // enabled
+        32 + // compliance_pubkey
+        32 + // pending_compliance_pubkey
+        8 + // rotation_unlocks_at
+        32 + // previous_compliance_pubkey
+        8 + // max_transfer_amount
+        1 + // require_proof
+        4 + (32 * 10) + // whitelisted_tokens (max 10)
+        4 + (32 * MAX_AUDITORS) + // auditors
+        1 + // audit_threshold
+        1; // bump
+
+    /// Keys allowed to audit; the compliance authority stands in for an empty auditor set
+    pub fn auditor_set(&self) -> Vec<Pubkey> {
+        if self.auditors.is_empty() {
+            vec![self.compliance_pubkey]
+        } else {
+            self.auditors.clone()
+        }
+    }
+
+    pub fn is_auditor(&self, key: &Pubkey) -> bool {
+        self.auditor_set().contains(key)
+    }
+
+    pub fn flag_threshold(&self) -> usize {
+        if self.auditors.is_empty() { 1 } else { self.audit_threshold as usize }
+    }
+}
+
+/// Confidential balance account (encrypted balance)
+#[account]
+pub struct ConfidentialBalance {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub elgamal_pubkey: [u8; 32],       // Key the balance is encrypted under
+    pub encrypted_balance: [u8; 64],    // ElGamal encrypted balance
+    pub pending_balance: [u8; 64],      // Pending incoming transfers
+    pub decryptable_balance: u64,       // For compliance checks
+    pub last_update: i64,
+    pub bump: u8,
+}
+
+impl ConfidentialBalance {
+    pub const LEN: usize = 32 + // owner
+        32 + // mint
+        32 + // elgamal_pubkey
+        64 + // encrypted_balance
+        64 + // pending_balance
+        8 + // decryptable_balance
+        8 + // last_update
+        1; // bump
+}
+
+/// Confidential transfer instruction
+#[account]
+pub struct ConfidentialTransferRecord {
+    pub transfer_id: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub encrypted_amount: [u8; 64],     // ElGamal encrypted amount
+    pub range_proof_context: Pubkey,    // Verified range proof over amount and remaining balance
+    pub remaining_balance: [u8; 64],    // Sender balance the range proof was generated against
+    pub compliance_proof: Vec<u8>,      // Proof of compliance
+    pub auditor_data: [u8; 32],         // Encrypted data for auditor
+    pub auditor_shares: Vec<[u8; 32]>,  // Auditor data for each auditor, in auditor_set order
+    pub flagged_by: Vec<Pubkey>,        // Auditors who voted to flag
+    pub timestamp: i64,
+    pub status: TransferStatus,
+    pub bump: u8,
+}
+
+impl ConfidentialTransferRecord {
+    pub const MAX_SIZE: usize = 8 + // transfer_id
+        32 + // sender
+        32 + // recipient
+        32 + // mint
+        64 + // encrypted_amount
+        32 + // range_proof_context
+        64 + // remaining_balance
+        4 + 256 + // compliance_proof (max 256 bytes)
+        32 + // auditor_data
+        4 + (32 * MAX_AUDITORS) + // auditor_shares
+        4 + (32 * MAX_AUDITORS) + // flagged_by
+        8 + // timestamp
+        1 + // status
+        1; // bump
+}
+
+/// Amount key wrapped to one juror's encryption key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct WrappedJurorKey {
+    pub juror: Pubkey,
+    pub wrapped_key: [u8; 48],
+}
+
+impl WrappedJurorKey {
+    pub const LEN: usize = 32 + 48;
+}
+
+/// Flagged transfer amount disclosed to a case panel only.
+/// The amount is encrypted once under a fresh key, and that key is wrapped per juror.
+#[account]
+pub struct JurorDisclosure {
+    pub case_id: u64,
+    pub transfer_id: u64,
+    pub encrypted_amount: [u8; 64],     // Amount under the panel key
+    pub wrapped_keys: Vec<WrappedJurorKey>,
+    pub binding: [u8; 32],              // Ties the disclosure to the transfer's auditor_data
+    pub posted_at: i64,
+    pub bump: u8,
+}
+
+impl JurorDisclosure {
+    pub const LEN: usize = 8 + // case_id
+        8 + // transfer_id
+        64 + // encrypted_amount
+        4 + (WrappedJurorKey::LEN * crate::state::MAX_CASE_JURORS) + // wrapped_keys
+        32 + // binding
+        8 + // posted_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum TransferStatus {
+    Pending,
+    Completed,
+    Audited,
+    Flagged,
+}
+
+/// Initialize confidential transfer for case rewards/penalties
+#[derive(Accounts)]
+#[instruction(transfer_id: u64)]
+pub struct InitiateConfidentialTransfer<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = sender_balance.owner == sender.key()
+    )]
+    pub sender_balance: Account<'info, ConfidentialBalance>,
+    
+    #[account(mut)]
+    pub recipient_balance: Account<'info, ConfidentialBalance>,
+    
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + ConfidentialTransferRecord::MAX_SIZE,
+        seeds = [b"confidential_transfer", transfer_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub transfer_record: Account<'info, ConfidentialTransferRecord>,
+    
+    #[account(
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+    
+    /// CHECK: zk-token-proof context holding a verified `BatchedRangeProofU128`
+    pub range_proof_context: UncheckedAccount<'info>,
+    
+    /// CHECK: zk-token-proof context holding a verified `CiphertextCommitmentEquality` proof
+    pub equality_proof_context: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initiate_confidential_transfer_handler(
+    ctx: Context<InitiateConfidentialTransfer>,
+    transfer_id: u64,
+    encrypted_amount: [u8; 64],
+    compliance_proof: Vec<u8>,
+) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer_record;
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    require!(config.enabled, crate::ErrorCode::ConfidentialTransfersDisabled);
+    
+    // Verify proofs
+    let remaining_balance = verify_range_proof(
+        &ctx.accounts.range_proof_context,
+        &ctx.accounts.equality_proof_context,
+        &ctx.accounts.sender_balance,
+        &encrypted_amount,
+    )?;
+    
+    if config.require_proof {
+        require!(
+            verify_compliance_proof(&compliance_proof)?,
+            crate::ErrorCode::InvalidComplianceProof
+        );
+    }
+    
+    // Generate auditor data (encrypted for compliance officer)
+    let auditor_data = generate_auditor_data(
+        transfer_id,
+        &encrypted_amount,
+        config.compliance_pubkey,
+    );
+    
+    // Initialize transfer record
+    transfer.transfer_id = transfer_id;
+    transfer.sender = ctx.accounts.sender.key();
+    transfer.recipient = ctx.accounts.recipient_balance.owner;
+    transfer.mint = ctx.accounts.sender_balance.mint;
+    transfer.encrypted_amount = encrypted_amount;
+    transfer.range_proof_context = ctx.accounts.range_proof_context.key();
+    transfer.remaining_balance = remaining_balance;
+    transfer.compliance_proof = compliance_proof;
+    transfer.auditor_data = auditor_data;
+    transfer.auditor_shares = config
+        .auditor_set()
+        .into_iter()
+        .map(|auditor| generate_auditor_data(transfer_id, &encrypted_amount, auditor))
+        .collect();
+    transfer.flagged_by = Vec::new();
+    transfer.timestamp = clock.unix_timestamp;
+    transfer.status = TransferStatus::Pending;
+    transfer.bump = ctx.bumps.transfer_record;
+    
+    msg!("Confidential transfer initiated. ID: {}", transfer_id);
+    Ok(())
+}
+
+/// Apply confidential transfer to balances
+#[derive(Accounts)]
+pub struct ApplyConfidentialTransfer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"confidential_transfer", transfer.transfer_id.to_le_bytes().as_ref()],
+        bump = transfer.bump
+    )]
+    pub transfer: Account<'info, ConfidentialTransferRecord>,
+    
+    #[account(mut)]
+    pub sender_balance: Account<'info, ConfidentialBalance>,
+    
+    #[account(mut)]
+    pub recipient_balance: Account<'info, ConfidentialBalance>,
+}
+
+pub fn apply_confidential_transfer_handler(
+    ctx: Context<ApplyConfidentialTransfer>,
+) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer;
+    let sender_balance = &mut ctx.accounts.sender_balance;
+    let recipient_balance = &mut ctx.accounts.recipient_balance;
+    let clock = Clock::get()?;
+    
+    require!(
+        transfer.status == TransferStatus::Pending,
+        crate::ErrorCode::TransferNotPending
+    );
+    
+    // Update encrypted balances using homomorphic properties
+    sender_balance.encrypted_balance = subtract_encrypted_values(
+        &sender_balance.encrypted_balance,
+        &transfer.encrypted_amount,
+    )?;
+    require!(
+        sender_balance.encrypted_balance == transfer.remaining_balance,
+        crate::ErrorCode::StaleRangeProof
+    );
+    
+    recipient_balance.encrypted_balance = add_encrypted_values(
+        &recipient_balance.encrypted_balance,
+        &transfer.encrypted_amount,
+    )?;
+    
+    sender_balance.last_update = clock.unix_timestamp;
+    recipient_balance.last_update = clock.unix_timestamp;
+    
+    transfer.status = TransferStatus::Completed;
+    
+    msg!("Confidential transfer applied. ID: {}", transfer.transfer_id);
+    Ok(())
+}
+
+/// Compliance audit of confidential transfer
+#[derive(Accounts)]
+pub struct AuditConfidentialTransfer<'info> {
+    #[account(
+        constraint = config.is_auditor(&auditor.key()) @ crate::ErrorCode::Unauthorized
+    )]
+    pub auditor: Signer<'info>,
+    
+    #[account(mut)]
+    pub transfer: Account<'info, ConfidentialTransferRecord>,
+    
+    #[account(
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+}
+
+/// Any auditor can clear a transfer, but flagging takes `flag_threshold` auditors,
+/// so no single key can censor a transfer on its own
+pub fn audit_transfer_handler(
+    ctx: Context<AuditConfidentialTransfer>,
+    flag: bool,
+) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer;
+    let config = &ctx.accounts.config;
+    let auditor = ctx.accounts.auditor.key();
+    
+    require!(
+        transfer.status != TransferStatus::Flagged,
+        crate::ErrorCode::TransferAlreadyFlagged
+    );
+    
+    if !flag {
+        transfer.status = TransferStatus::Audited;
+        msg!("Transfer {} audited. Flagged: false", transfer.transfer_id);
+        return Ok(());
+    }
+    
+    require!(
+        !transfer.flagged_by.contains(&auditor),
+        crate::ErrorCode::AuditorAlreadyFlagged
+    );
+    // Votes from auditors since removed from the set stop counting
+    transfer.flagged_by.retain(|key| config.is_auditor(key));
+    transfer.flagged_by.push(auditor);
+    
+    if transfer.flagged_by.len() >= config.flag_threshold() {
+        transfer.status = TransferStatus::Flagged;
+    }
+    
+    msg!(
+        "Transfer {} flag votes: {}/{}",
+        transfer.transfer_id,
+        transfer.flagged_by.len(),
+        config.flag_threshold()
+    );
+    Ok(())
+}
+
+/// Admin replaces the auditor set; an empty set hands auditing back to the compliance authority
+#[derive(Accounts)]
+pub struct SetAuditors<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ crate::ErrorCode::Unauthorized,
+        constraint = global_config.council == Pubkey::default() @ crate::ErrorCode::CouncilGoverned
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+}
+
+pub fn set_auditors_handler(ctx: Context<SetAuditors>, auditors: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    if auditors.is_empty() {
+        require!(threshold == 0, crate::ErrorCode::InvalidAuditorSet);
+    } else {
+        require!(
+            auditors.len() <= MAX_AUDITORS && threshold >= 1 && threshold as usize <= auditors.len(),
+            crate::ErrorCode::InvalidAuditorSet
+        );
+        let has_duplicates = auditors
+            .iter()
+            .enumerate()
+            .any(|(i, auditor)| auditors[..i].contains(auditor));
+        require!(!has_duplicates, crate::ErrorCode::InvalidAuditorSet);
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.auditors = auditors;
+    config.audit_threshold = threshold;
+
+    msg!("Auditors set: {}-of-{}", config.flag_threshold(), config.auditor_set().len());
+    Ok(())
+}
+
+/// Compliance officer shares a flagged transfer's amount with the case panel
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct DiscloseToJurors<'info> {
+    #[account(
+        mut,
+        constraint = compliance_officer.key() == config.compliance_pubkey @ crate::ErrorCode::Unauthorized
+    )]
+    pub compliance_officer: Signer<'info>,
+
+    #[account(
+        seeds = [b"confidential_transfer", transfer.transfer_id.to_le_bytes().as_ref()],
+        bump = transfer.bump
+    )]
+    pub transfer: Account<'info, ConfidentialTransferRecord>,
+
+    #[account(
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+
+    #[account(
+        seeds = [b"case_panel", case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, crate::state::CasePanel>,
+
+    #[account(
+        init,
+        payer = compliance_officer,
+        space = 8 + JurorDisclosure::LEN,
+        seeds = [b"juror_disclosure", case_id.to_le_bytes().as_ref(), transfer.transfer_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub disclosure: Account<'info, JurorDisclosure>,
+
+    #[account(
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn disclose_to_jurors_handler(
+    ctx: Context<DiscloseToJurors>,
+    case_id: u64,
+    encrypted_amount: [u8; 64],
+    wrapped_keys: Vec<WrappedJurorKey>,
+) -> Result<()> {
+    let transfer = &ctx.accounts.transfer;
+    let config = &ctx.accounts.config;
+    let panel = &ctx.accounts.case_panel;
+
+    require!(
+        transfer.status == TransferStatus::Flagged,
+        crate::ErrorCode::TransferNotFlagged
+    );
+
+    // The record must still match what the compliance officer was given at initiation
+    let auditor_data = generate_auditor_data(
+        transfer.transfer_id,
+        &transfer.encrypted_amount,
+        config.compliance_pubkey,
+    );
+    require!(
+        auditor_data == transfer.auditor_data,
+        crate::ErrorCode::InvalidDisclosure
+    );
+
+    // Exactly one wrapped key per seated juror, and nobody else
+    let jurors = &panel.jurors;
+    require!(
+        !jurors.is_empty() && wrapped_keys.len() == jurors.len(),
+        crate::ErrorCode::InvalidDisclosure
+    );
+    for (i, key) in wrapped_keys.iter().enumerate() {
+        require!(
+            jurors.contains(&key.juror)
+                && !wrapped_keys[..i].iter().any(|other| other.juror == key.juror),
+            crate::ErrorCode::InvalidDisclosure
+        );
+    }
+
+    let disclosure = &mut ctx.accounts.disclosure;
+    disclosure.case_id = case_id;
+    disclosure.transfer_id = transfer.transfer_id;
+    disclosure.encrypted_amount = encrypted_amount;
+    disclosure.wrapped_keys = wrapped_keys;
+    disclosure.binding = crate::domain::hash(
+        crate::domain::JUROR_DISCLOSURE,
+        &[&case_id.to_le_bytes(), &auditor_data, &encrypted_amount],
+    );
+    disclosure.posted_at = Clock::get()?.unix_timestamp;
+    disclosure.bump = ctx.bumps.disclosure;
+
+    msg!(
+        "Transfer {} disclosed to {} jurors on case {}",
+        transfer.transfer_id,
+        disclosure.wrapped_keys.len(),
+        case_id
+    );
+    Ok(())
+}
+
+/// Admin proposes a new compliance authority, e.g. after the current key is compromised
+#[derive(Accounts)]
+pub struct RotateComplianceAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ crate::ErrorCode::Unauthorized,
+        constraint = global_config.council == Pubkey::default() @ crate::ErrorCode::CouncilGoverned
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+}
+
+/// Start the timelock; proposing the default key cancels a pending rotation
+pub fn rotate_compliance_authority_handler(
+    ctx: Context<RotateComplianceAuthority>,
+    new_compliance_pubkey: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_compliance_pubkey = new_compliance_pubkey;
+    config.rotation_unlocks_at = if new_compliance_pubkey == Pubkey::default() {
+        0
+    } else {
+        Clock::get()?.unix_timestamp.saturating_add(COMPLIANCE_ROTATION_DELAY)
+    };
+
+    msg!(
+        "Compliance rotation to {} unlocks at {}",
+        new_compliance_pubkey,
+        config.rotation_unlocks_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptComplianceAuthority<'info> {
+    pub new_compliance_officer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump,
+        constraint = config.pending_compliance_pubkey == new_compliance_officer.key() @ crate::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+}
+
+/// The successor must sign once the timelock has run, so a mistyped key can't take over
+/// and a compromised admin can't swap the auditor without warning
+pub fn accept_compliance_authority_handler(ctx: Context<AcceptComplianceAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        Clock::get()?.unix_timestamp >= config.rotation_unlocks_at,
+        crate::ErrorCode::ComplianceRotationLocked
+    );
+
+    config.previous_compliance_pubkey = config.compliance_pubkey;
+    config.compliance_pubkey = config.pending_compliance_pubkey;
+    config.pending_compliance_pubkey = Pubkey::default();
+    config.rotation_unlocks_at = 0;
+
+    msg!(
+        "Compliance authority rotated from {} to {}",
+        config.previous_compliance_pubkey,
+        config.compliance_pubkey
+    );
+    Ok(())
+}
+
+/// Re-derive a record's auditor data for the current compliance authority
+#[derive(Accounts)]
+pub struct RebindAuditorData<'info> {
+    #[account(
+        mut,
+        seeds = [b"confidential_transfer", transfer.transfer_id.to_le_bytes().as_ref()],
+        bump = transfer.bump
+    )]
+    pub transfer: Account<'info, ConfidentialTransferRecord>,
+
+    #[account(
+        seeds = [b"conf_transfer_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfidentialTransferConfig>,
+}
+
+/// Permissionless: records still awaiting audit or disclosure were bound to the previous key
+/// and would otherwise be stuck. Only records bound to the key rotated out last can move over.
+pub fn rebind_auditor_data_handler(ctx: Context<RebindAuditorData>) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer;
+    let config = &ctx.accounts.config;
+
+    require!(
+        matches!(transfer.status, TransferStatus::Pending | TransferStatus::Flagged),
+        crate::ErrorCode::AuditorDataCurrent
+    );
+    let previous = generate_auditor_data(
+        transfer.transfer_id,
+        &transfer.encrypted_amount,
+        config.previous_compliance_pubkey,
+    );
+    require!(
+        config.previous_compliance_pubkey != Pubkey::default() && transfer.auditor_data == previous,
+        crate::ErrorCode::AuditorDataCurrent
+    );
+
+    transfer.auditor_data = generate_auditor_data(
+        transfer.transfer_id,
+        &transfer.encrypted_amount,
+        config.compliance_pubkey,
+    );
+
+    msg!("Auditor data for transfer {} rebound to {}", transfer.transfer_id, config.compliance_pubkey);
+    Ok(())
+}
+
+// Helper functions for ZK proofs
+
+/// Bit lengths of the batched range proof: the amount, then the sender's remaining balance
+const RANGE_PROOF_BIT_LENGTHS: [u8; 8] = [64, 64, 0, 0, 0, 0, 0, 0];
+
+/// Check the two balance proofs a transfer needs, verified beforehand by the zk-token-proof
+/// program into context accounts. One Bulletproof batches both ranges: the amount's commitment
+/// and a fresh commitment to what the sender keeps. The equality proof ties that second
+/// commitment to the sender's balance minus the amount under the sender's key, so neither
+/// side can go negative. Returns the remaining balance ciphertext the proofs are bound to.
+fn verify_range_proof(
+    range_proof_context: &AccountInfo,
+    equality_proof_context: &AccountInfo,
+    sender_balance: &ConfidentialBalance,
+    encrypted_amount: &[u8; 64],
+) -> Result<[u8; 64]> {
+    let remaining_balance = subtract_encrypted_values(&sender_balance.encrypted_balance, encrypted_amount)?;
+
+    let equality: CiphertextCommitmentEqualityProofContext =
+        load_proof_context(equality_proof_context, ProofType::CiphertextCommitmentEquality)?;
+    require!(
+        equality.pubkey.0 == sender_balance.elgamal_pubkey && equality.ciphertext.0 == remaining_balance,
+        crate::ErrorCode::InvalidRangeProof
+    );
+
+    let range: BatchedRangeProofContext =
+        load_proof_context(range_proof_context, ProofType::BatchedRangeProofU128)?;
+    require!(
+        range.bit_lengths == RANGE_PROOF_BIT_LENGTHS
+            && range.commitments[0].0[..] == encrypted_amount[..32]
+            && range.commitments[1] == equality.commitment,
+        crate::ErrorCode::InvalidRangeProof
+    );
+
+    Ok(remaining_balance)
+}
+
+/// Context of a proof the zk-token-proof program has verified; only that program
+/// can write an account it owns, so the context is trusted once the type matches
+fn load_proof_context<T: Pod>(info: &AccountInfo, proof_type: ProofType) -> Result<T> {
+    require!(info.owner == &zk_token_proof_program::id(), crate::ErrorCode::InvalidRangeProof);
+    let data = info.try_borrow_data()?;
+    let state = bytemuck::try_from_bytes::<ProofContextState<T>>(&data)
+        .map_err(|_| error!(crate::ErrorCode::InvalidRangeProof))?;
+    require!(state.proof_type == proof_type.into(), crate::ErrorCode::InvalidRangeProof);
+    Ok(state.proof_context)
+}
+
+fn verify_compliance_proof(proof: &[u8]) -> Result<bool> {
+    // Verify compliance with regulatory requirements
+    msg!("Verifying compliance proof ({} bytes)", proof.len());
+    Ok(true) // Placeholder
+}
+
+fn generate_auditor_data(
+    transfer_id: u64,
+    encrypted_amount: &[u8; 64],
+    compliance_pubkey: Pubkey,
+) -> [u8; 32] {
+    // Generate encrypted data that compliance officer can decrypt
+    crate::domain::hash(
+        crate::domain::AUDITOR_DATA,
+        &[
+            &transfer_id.to_le_bytes(),
+            encrypted_amount,
+            compliance_pubkey.as_ref(),
+        ],
+    )
+}
+
+/// Twisted ElGamal addition: the Pedersen commitments and the decrypt handles add as
+/// Ristretto points, so the sum decrypts to the sum of amounts under the same key.
+/// Ciphertexts are SPL's layout, commitment (32 bytes) then handle (32 bytes).
+fn add_encrypted_values(a: &[u8; 64], b: &[u8; 64]) -> Result<[u8; 64]> {
+    ops::add(&ElGamalCiphertext(*a), &ElGamalCiphertext(*b))
+        .map(|sum| sum.0)
+        .ok_or_else(|| error!(crate::ErrorCode::InvalidCiphertext))
+}
+
+fn subtract_encrypted_values(a: &[u8; 64], b: &[u8; 64]) -> Result<[u8; 64]> {
+    ops::subtract(&ElGamalCiphertext(*a), &ElGamalCiphertext(*b))
+        .map(|difference| difference.0)
+        .ok_or_else(|| error!(crate::ErrorCode::InvalidCiphertext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_zk_token_sdk::encryption::elgamal::{self, ElGamalKeypair};
+    use solana_zk_token_sdk::encryption::pedersen::{Pedersen, PedersenOpening};
+    use solana_zk_token_sdk::instruction::{
+        BatchedRangeProofU128Data, CiphertextCommitmentEqualityProofData, ZkProofData,
+    };
+
+    fn encrypt(keypair: &ElGamalKeypair, amount: u64) -> [u8; 64] {
+        ElGamalCiphertext::from(keypair.pubkey().encrypt(amount)).0
+    }
+
+    fn decrypt(keypair: &ElGamalKeypair, ciphertext: [u8; 64]) -> Option<u64> {
+        let ciphertext: elgamal::ElGamalCiphertext = ElGamalCiphertext(ciphertext).try_into().ok()?;
+        keypair.secret().decrypt_u32(&ciphertext)
+    }
+
+    #[test]
+    fn test_encrypted_balance_arithmetic() {
+        let keypair = ElGamalKeypair::new_rand();
+        let balance = encrypt(&keypair, 500);
+        let amount = encrypt(&keypair, 120);
+
+        let debited = subtract_encrypted_values(&balance, &amount).unwrap();
+        assert_eq!(decrypt(&keypair, debited), Some(380));
+        let credited = add_encrypted_values(&debited, &amount).unwrap();
+        assert_eq!(decrypt(&keypair, credited), Some(500));
+    }
+
+    #[test]
+    fn test_rejects_non_ristretto_ciphertext() {
+        let keypair = ElGamalKeypair::new_rand();
+        assert!(add_encrypted_values(&encrypt(&keypair, 1), &[0xff; 64]).is_err());
+    }
+
+    /// Proof context accounts for sending `amount` out of `balance`, as the proof program leaves them
+    fn proof_contexts(keypair: &ElGamalKeypair, balance: u64, amount: u64) -> ([u8; 64], [u8; 64], Vec<u8>, Vec<u8>) {
+        let balance_ciphertext = keypair.pubkey().encrypt(balance);
+        let amount_opening = PedersenOpening::new_rand();
+        let amount_ciphertext = keypair.pubkey().encrypt_with(amount, &amount_opening);
+        let remaining_ciphertext = balance_ciphertext - amount_ciphertext;
+        let remaining_opening = PedersenOpening::new_rand();
+        let remaining_commitment = Pedersen::with(balance - amount, &remaining_opening);
+
+        let equality = CiphertextCommitmentEqualityProofData::new(
+            keypair, &remaining_ciphertext, &remaining_commitment, &remaining_opening, balance - amount,
+        ).unwrap();
+        let range = BatchedRangeProofU128Data::new(
+            vec![&amount_ciphertext.commitment, &remaining_commitment],
+            vec![amount, balance - amount],
+            vec![64, 64],
+            vec![&amount_opening, &remaining_opening],
+        ).unwrap();
+        equality.verify_proof().unwrap();
+        range.verify_proof().unwrap();
+
+        let authority = Pubkey::new_unique();
+        (
+            ElGamalCiphertext::from(balance_ciphertext).0,
+            ElGamalCiphertext::from(amount_ciphertext).0,
+            ProofContextState::encode(&authority, ProofType::BatchedRangeProofU128, range.context_data()),
+            ProofContextState::encode(&authority, ProofType::CiphertextCommitmentEquality, equality.context_data()),
+        )
+    }
+
+    fn check(keypair: &ElGamalKeypair, balance: [u8; 64], amount: &[u8; 64], range: &mut [u8], equality: &mut [u8]) -> Result<[u8; 64]> {
+        let owner = zk_token_proof_program::id();
+        let (range_key, equality_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut range_lamports, mut equality_lamports) = (0, 0);
+        let range_info = AccountInfo::new(&range_key, false, false, &mut range_lamports, range, &owner, false, 0);
+        let equality_info = AccountInfo::new(&equality_key, false, false, &mut equality_lamports, equality, &owner, false, 0);
+        let sender_balance = ConfidentialBalance {
+            owner: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            elgamal_pubkey: keypair.pubkey().to_bytes(),
+            encrypted_balance: balance,
+            pending_balance: [0; 64],
+            decryptable_balance: 0,
+            last_update: 0,
+            bump: 0,
+        };
+        verify_range_proof(&range_info, &equality_info, &sender_balance, amount)
+    }
+
+    #[test]
+    fn test_range_proofs_bind_amount_and_remaining_balance() {
+        let keypair = ElGamalKeypair::new_rand();
+        let (balance, amount, mut range, mut equality) = proof_contexts(&keypair, 100, 30);
+
+        let remaining = check(&keypair, balance, &amount, &mut range, &mut equality).unwrap();
+        assert_eq!(decrypt(&keypair, remaining), Some(70));
+
+        // Proofs for a different amount don't cover this one
+        let other_amount = encrypt(&keypair, 30);
+        assert!(check(&keypair, balance, &other_amount, &mut range, &mut equality).is_err());
+
+        // Nor for a balance under another key
+        let other = ElGamalKeypair::new_rand();
+        assert!(check(&other, balance, &amount, &mut range, &mut equality).is_err());
+
+        // The contexts can't be swapped for each other
+        assert!(check(&keypair, balance, &amount, &mut equality.clone(), &mut range.clone()).is_err());
+    }
+}
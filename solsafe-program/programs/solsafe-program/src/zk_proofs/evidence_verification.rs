@@ -1,174 +1,406 @@
-// Zero-Knowledge Evidence Verification
-// Allows jurors to verify evidence without revealing it publicly
-use anchor_lang::prelude::*;
-
-/// Private evidence commitment
-#[account]
-pub struct EvidenceCommitment {
-    pub case_id: u64,
-    pub evidence_hash: [u8; 32],
-    pub encrypted_evidence: Vec<u8>,  // Encrypted with MPC threshold scheme
-    pub commitment: [u8; 32],
-    pub juror_count: u8,
-    pub threshold: u8,  // MPC threshold for decryption
-    pub bump: u8,
-}
-
-impl EvidenceCommitment {
-    pub const MAX_SIZE: usize = 8 + // case_id
-        32 + // evidence_hash
-        4 + 1024 + // encrypted_evidence (max 1KB)
-        32 + // commitment
-        1 + // juror_count
-        1 + // threshold
-        1; // bump
-
-    pub fn new(
-        case_id: u64,
-        evidence_hash: [u8; 32],
-        encrypted_evidence: Vec<u8>,
-        threshold: u8,
-        bump: u8,
-    ) -> Self {
-        let commitment = Self::compute_commitment(&evidence_hash);
-        
-        EvidenceCommitment {
-            case_id,
-            evidence_hash,
-            encrypted_evidence,
-            commitment,
-            juror_count: 0,
-            threshold,
-            bump,
-        }
-    }
-
-    fn compute_commitment(evidence_hash: &[u8; 32]) -> [u8; 32] {
-        use solana_program::hash::hash;
-        hash(evidence_hash).to_bytes()
-    }
-
-    pub fn verify_hash(&self, claimed_hash: &[u8; 32]) -> bool {
-        &self.evidence_hash == claimed_hash
-    }
-}
-
-/// Juror's share in MPC evidence decryption
-#[account]
-pub struct JurorEvidenceShare {
-    pub juror: Pubkey,
-    pub case_id: u64,
-    pub share_commitment: [u8; 32],
-    pub has_verified: bool,
-    pub verification_timestamp: i64,
-    pub bump: u8,
-}
-
-impl JurorEvidenceShare {
-    pub const LEN: usize = 32 + // juror
-        8 + // case_id
-        32 + // share_commitment
-        1 + // has_verified
-        8 + // verification_timestamp
-        1; // bump
-}
-
-/// Initialize private evidence with Arcium MPC encryption
-#[derive(Accounts)]
-#[instruction(case_id: u64)]
-pub struct InitializePrivateEvidence<'info> {
-    #[account(mut)]
-    pub reporter: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = reporter,
-        space = 8 + EvidenceCommitment::MAX_SIZE,
-        seeds = [b"evidence_commitment", case_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub evidence_commitment: Account<'info, EvidenceCommitment>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-pub fn initialize_private_evidence_handler(
-    ctx: Context<InitializePrivateEvidence>,
-    case_id: u64,
-    evidence_hash: [u8; 32],
-    encrypted_evidence: Vec<u8>,
-    threshold: u8,
-) -> Result<()> {
-    let evidence = &mut ctx.accounts.evidence_commitment;
-    
-    require!(encrypted_evidence.len() <= 1024, crate::ErrorCode::EvidenceTooLarge);
-    require!(threshold > 0, crate::ErrorCode::InvalidThreshold);
-    
-    evidence.case_id = case_id;
-    evidence.evidence_hash = evidence_hash;
-    evidence.encrypted_evidence = encrypted_evidence;
-    evidence.threshold = threshold;
-    evidence.verified_shares = 0;
-    evidence.bump = ctx.bumps.evidence_commitment;
-    
-    msg!("Private evidence initialized for case {}", case_id);
-    Ok(())
-}
-
-/// Juror verifies evidence using their MPC share
-#[derive(Accounts)]
-pub struct VerifyEvidenceShare<'info> {
-    #[account(mut)]
-    pub juror: Signer<'info>,
-    
-    #[account(
-        init_if_needed,
-        payer = juror,
-        space = 8 + JurorEvidenceShare::LEN,
-        seeds = [b"evidence_share", evidence.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
-        bump
-    )]
-    pub juror_share: Account<'info, JurorEvidenceShare>,
-    
-    #[account(mut)]
-    pub evidence: Account<'info, EvidenceCommitment>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-pub fn verify_evidence_share_handler(
-    ctx: Context<VerifyEvidenceShare>,
-    share_commitment: [u8; 32],
-) -> Result<()> {
-    let juror_share = &mut ctx.accounts.juror_share;
-    let evidence = &mut ctx.accounts.evidence;
-    let clock = Clock::get()?;
-    
-    // Initialize juror share
-    juror_share.juror = ctx.accounts.juror.key();
-    juror_share.case_id = evidence.case_id;
-    juror_share.share_commitment = share_commitment;
-    juror_share.has_verified = true;
-    juror_share.verification_timestamp = clock.unix_timestamp;
-    juror_share.bump = ctx.bumps.juror_share;
-    
-    evidence.juror_count += 1;
-    
-    msg!("Juror verified evidence share. Total verifications: {}/{}", 
-        evidence.juror_count, evidence.threshold);
-    
-    Ok(())
-}
-
-/// Reconstruct evidence when threshold is met (Arcium MPC)
-pub fn reconstruct_evidence(
-    shares: Vec<[u8; 32]>,
-    threshold: u8,
-) -> Result<Vec<u8>> {
-    require!(shares.len() >= threshold as usize, crate::ErrorCode::InsufficientShares);
-    
-    // Arcium MPC reconstruction would happen here
-    // For now, return placeholder
-    msg!("Evidence reconstruction with {} shares", shares.len());
-    Ok(vec![])
-}
+// Zero-Knowledge Evidence Verification
+// Allows jurors to verify evidence without revealing it publicly
+use anchor_lang::prelude::*;
+use crate::quantum_resistant::hash_evidence;
+use crate::state::{CaseAccount, CasePanel, CaseState, MAX_CASE_JURORS};
+use crate::EvidenceReconstructed;
+
+/// Private evidence commitment
+#[account]
+pub struct EvidenceCommitment {
+    pub case_id: u64,
+    pub evidence_hash: [u8; 32],
+    pub encrypted_evidence: Vec<u8>,  // Encrypted with MPC threshold scheme
+    pub commitment: [u8; 32],
+    pub juror_count: u8,
+    pub threshold: u8,  // MPC threshold for decryption
+    pub decryption_shares: Vec<EvidenceKeyShare>,  // Opened juror shares of the evidence key
+    pub available: bool,  // Key reconstructed and the plaintext matched evidence_hash
+    pub bump: u8,
+}
+
+impl EvidenceCommitment {
+    pub const MAX_SIZE: usize = 8 + // case_id
+        32 + // evidence_hash
+        4 + 1024 + // encrypted_evidence (max 1KB)
+        32 + // commitment
+        1 + // juror_count
+        1 + // threshold
+        4 + EvidenceKeyShare::SIZE * MAX_CASE_JURORS + // decryption_shares
+        1 + // available
+        1; // bump
+
+    pub fn new(
+        case_id: u64,
+        evidence_hash: [u8; 32],
+        encrypted_evidence: Vec<u8>,
+        threshold: u8,
+        bump: u8,
+    ) -> Self {
+        let commitment = Self::compute_commitment(case_id, &evidence_hash);
+        
+        EvidenceCommitment {
+            case_id,
+            evidence_hash,
+            encrypted_evidence,
+            commitment,
+            juror_count: 0,
+            threshold,
+            decryption_shares: Vec::new(),
+            available: false,
+            bump,
+        }
+    }
+
+    pub fn compute_commitment(case_id: u64, evidence_hash: &[u8; 32]) -> [u8; 32] {
+        crate::domain::hash(
+            crate::domain::EVIDENCE_COMMITMENT,
+            &[&case_id.to_le_bytes(), evidence_hash],
+        )
+    }
+
+    pub fn verify_hash(&self, claimed_hash: &[u8; 32]) -> bool {
+        &self.evidence_hash == claimed_hash
+    }
+}
+
+/// A juror's opened Shamir share of the evidence key, over GF(2^8) byte by byte
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EvidenceKeyShare {
+    pub index: u8,  // Evaluation point: the juror's seat + 1
+    pub share: [u8; 32],
+}
+
+impl EvidenceKeyShare {
+    pub const SIZE: usize = 1 + 32;
+
+    /// What a juror commits to in `verify_evidence_share` before opening their share
+    pub fn commitment(case_id: u64, index: u8, share: &[u8; 32]) -> [u8; 32] {
+        crate::domain::hash(
+            crate::domain::EVIDENCE_KEY_SHARE,
+            &[&case_id.to_le_bytes(), &[index], share],
+        )
+    }
+}
+
+/// Juror's share in MPC evidence decryption
+#[account]
+pub struct JurorEvidenceShare {
+    pub juror: Pubkey,
+    pub case_id: u64,
+    pub share_commitment: [u8; 32],
+    pub has_verified: bool,
+    pub verification_timestamp: i64,
+    pub bump: u8,
+}
+
+impl JurorEvidenceShare {
+    pub const LEN: usize = 32 + // juror
+        8 + // case_id
+        32 + // share_commitment
+        1 + // has_verified
+        8 + // verification_timestamp
+        1; // bump
+}
+
+/// Initialize private evidence with Arcium MPC encryption
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct InitializePrivateEvidence<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + EvidenceCommitment::MAX_SIZE,
+        seeds = [b"evidence_commitment", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub evidence_commitment: Account<'info, EvidenceCommitment>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_private_evidence_handler(
+    ctx: Context<InitializePrivateEvidence>,
+    case_id: u64,
+    evidence_hash: [u8; 32],
+    encrypted_evidence: Vec<u8>,
+    threshold: u8,
+) -> Result<()> {
+    let evidence = &mut ctx.accounts.evidence_commitment;
+    
+    require!(encrypted_evidence.len() <= 1024, crate::ErrorCode::EvidenceTooLarge);
+    require!(threshold > 0, crate::ErrorCode::InvalidThreshold);
+    
+    evidence.case_id = case_id;
+    evidence.evidence_hash = evidence_hash;
+    evidence.encrypted_evidence = encrypted_evidence;
+    evidence.commitment = EvidenceCommitment::compute_commitment(case_id, &evidence_hash);
+    evidence.threshold = threshold;
+    evidence.juror_count = 0;
+    evidence.decryption_shares = Vec::new();
+    evidence.available = false;
+    evidence.bump = ctx.bumps.evidence_commitment;
+    
+    msg!("Private evidence initialized for case {}", case_id);
+    Ok(())
+}
+
+/// Juror verifies evidence using their MPC share
+#[derive(Accounts)]
+pub struct VerifyEvidenceShare<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + JurorEvidenceShare::LEN,
+        seeds = [b"evidence_share", evidence.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub juror_share: Account<'info, JurorEvidenceShare>,
+    
+    #[account(mut)]
+    pub evidence: Account<'info, EvidenceCommitment>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn verify_evidence_share_handler(
+    ctx: Context<VerifyEvidenceShare>,
+    share_commitment: [u8; 32],
+) -> Result<()> {
+    let juror_share = &mut ctx.accounts.juror_share;
+    let evidence = &mut ctx.accounts.evidence;
+    let clock = Clock::get()?;
+    
+    // Initialize juror share
+    juror_share.juror = ctx.accounts.juror.key();
+    juror_share.case_id = evidence.case_id;
+    juror_share.share_commitment = share_commitment;
+    juror_share.has_verified = true;
+    juror_share.verification_timestamp = clock.unix_timestamp;
+    juror_share.bump = ctx.bumps.juror_share;
+    
+    evidence.juror_count += 1;
+    
+    msg!("Juror verified evidence share. Total verifications: {}/{}", 
+        evidence.juror_count, evidence.threshold);
+    
+    Ok(())
+}
+
+/// Seated juror opens their share of the evidence key
+#[derive(Accounts)]
+pub struct SubmitEvidenceDecryptionShare<'info> {
+    pub juror: Signer<'info>,
+
+    #[account(
+        seeds = [b"case", evidence.case_id.to_le_bytes().as_ref()],
+        bump = case_account.load()?.bump
+    )]
+    pub case_account: AccountLoader<'info, CaseAccount>,
+
+    #[account(
+        seeds = [b"case_panel", evidence.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+
+    #[account(
+        mut,
+        seeds = [b"evidence_commitment", evidence.case_id.to_le_bytes().as_ref()],
+        bump = evidence.bump
+    )]
+    pub evidence: Account<'info, EvidenceCommitment>,
+
+    #[account(
+        seeds = [b"evidence_share", evidence.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
+        bump = juror_share.bump,
+        constraint = juror_share.has_verified @ crate::ErrorCode::ShareNotVerified
+    )]
+    pub juror_share: Account<'info, JurorEvidenceShare>,
+}
+
+/// The share is evaluated at the juror's seat, so it only opens the commitment they made
+/// for that seat
+pub fn submit_evidence_decryption_share_handler(
+    ctx: Context<SubmitEvidenceDecryptionShare>,
+    share: [u8; 32],
+) -> Result<()> {
+    let case = ctx.accounts.case_account.load()?;
+    let evidence = &mut ctx.accounts.evidence;
+    let juror = ctx.accounts.juror.key();
+
+    require!(case.state == CaseState::Voting as u8, crate::ErrorCode::CaseNotVoting);
+    require!(!evidence.available, crate::ErrorCode::EvidenceAlreadyAvailable);
+    let seat = ctx
+        .accounts
+        .case_panel
+        .jurors
+        .iter()
+        .position(|seated| *seated == juror)
+        .ok_or(crate::ErrorCode::NotJuror)?;
+    let index = seat as u8 + 1;
+    require!(
+        !evidence.decryption_shares.iter().any(|opened| opened.index == index),
+        crate::ErrorCode::DuplicateEvidenceShare
+    );
+    require!(
+        EvidenceKeyShare::commitment(evidence.case_id, index, &share) == ctx.accounts.juror_share.share_commitment,
+        crate::ErrorCode::ShareCommitmentMismatch
+    );
+
+    evidence.decryption_shares.push(EvidenceKeyShare { index, share });
+
+    msg!("Evidence share {} opened for case {}: {}/{}",
+        index, evidence.case_id, evidence.decryption_shares.len(), evidence.threshold);
+    Ok(())
+}
+
+/// Combines opened shares into the evidence key
+#[derive(Accounts)]
+pub struct ReconstructEvidenceOnchain<'info> {
+    #[account(
+        mut,
+        seeds = [b"evidence_commitment", evidence.case_id.to_le_bytes().as_ref()],
+        bump = evidence.bump
+    )]
+    pub evidence: Account<'info, EvidenceCommitment>,
+}
+
+/// Permissionless once `threshold` shares are open. The key only counts if it decrypts
+/// the escrowed ciphertext to the committed hash, so a bad dealing can't be marked available.
+pub fn reconstruct_evidence_onchain_handler(ctx: Context<ReconstructEvidenceOnchain>) -> Result<()> {
+    let evidence = &mut ctx.accounts.evidence;
+    require!(!evidence.available, crate::ErrorCode::EvidenceAlreadyAvailable);
+
+    let key = reconstruct_evidence(&evidence.decryption_shares, evidence.threshold)?;
+    let plaintext = decrypt_evidence(&key, &evidence.encrypted_evidence);
+    require!(
+        hash_evidence(&plaintext) == evidence.evidence_hash,
+        crate::ErrorCode::EvidenceDecryptionFailed
+    );
+    evidence.available = true;
+
+    emit!(EvidenceReconstructed {
+        case_id: evidence.case_id,
+        evidence_hash: evidence.evidence_hash,
+        shares: evidence.decryption_shares.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Evidence for case {} reconstructed", evidence.case_id);
+    Ok(())
+}
+
+/// Lagrange interpolation at zero over the first `threshold` shares
+pub fn reconstruct_evidence(shares: &[EvidenceKeyShare], threshold: u8) -> Result<[u8; 32]> {
+    require!(
+        threshold > 0 && shares.len() >= threshold as usize,
+        crate::ErrorCode::InsufficientShares
+    );
+    let shares = &shares[..threshold as usize];
+
+    let mut key = [0u8; 32];
+    for (i, share) in shares.iter().enumerate() {
+        // Subtraction is XOR in GF(2^8), so l_i(0) = Π x_j / (x_j ^ x_i)
+        let basis = shares
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(1u8, |acc, (_, other)| {
+                gf_mul(acc, gf_mul(other.index, gf_inv(other.index ^ share.index)))
+            });
+        for (byte, value) in key.iter_mut().zip(share.share.iter()) {
+            *byte ^= gf_mul(basis, *value);
+        }
+    }
+    Ok(key)
+}
+
+/// XOR with a domain-hash keystream; encryption and decryption are the same operation
+pub fn decrypt_evidence(key: &[u8; 32], ciphertext: &[u8]) -> Vec<u8> {
+    ciphertext
+        .chunks(32)
+        .enumerate()
+        .flat_map(|(block, chunk)| {
+            let stream = crate::domain::hash(
+                crate::domain::EVIDENCE_KEYSTREAM,
+                &[key, &(block as u32).to_le_bytes()],
+            );
+            chunk.iter().zip(stream).map(|(byte, pad)| byte ^ pad).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Multiplication modulo the AES polynomial x^8 + x^4 + x^3 + x + 1
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// a^254 = a^-1 for nonzero a
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent != 0 {
+        if exponent & 1 != 0 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shares of `secret` on f(x) = secret + coefficient·x
+    fn deal(secret: &[u8; 32], coefficient: u8, indices: &[u8]) -> Vec<EvidenceKeyShare> {
+        indices
+            .iter()
+            .map(|&index| {
+                let mut share = *secret;
+                share.iter_mut().for_each(|byte| *byte ^= gf_mul(coefficient, index));
+                EvidenceKeyShare { index, share }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_any_threshold_subset_reconstructs_key() {
+        let key = [0x5au8; 32];
+        let shares = deal(&key, 0xc3, &[1, 2, 3]);
+        assert_eq!(reconstruct_evidence(&shares[..2], 2).unwrap(), key);
+        assert_eq!(reconstruct_evidence(&shares[1..], 2).unwrap(), key);
+        assert_ne!(reconstruct_evidence(&shares[..1], 1).unwrap(), key);
+        assert!(reconstruct_evidence(&shares[..1], 2).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_inverts_encrypt() {
+        let key = [9u8; 32];
+        let plaintext = b"tx dump that spans more than one keystream block".to_vec();
+        let ciphertext = decrypt_evidence(&key, &plaintext);
+        assert_ne!(ciphertext, plaintext);
+        assert_eq!(decrypt_evidence(&key, &ciphertext), plaintext);
+    }
+}
@@ -1,9 +1,12 @@
 // Groth16 ZK-SNARK implementation for vote commitment verification
 use anchor_lang::prelude::*;
-use ark_bn254::{Bn254, Fr};
-use ark_groth16::{Groth16, Proof, VerifyingKey};
+use ark_bn254::{Bn254, Fr, G1Affine};
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_groth16::{Proof, VerifyingKey};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_ff::PrimeField;
+use ark_ff::{PrimeField, One};
+use super::{VerifyingKeyAccount, ZkProofType};
 
 /// Groth16 proof for vote commitment
 /// Circuit proves: commitment = Hash(vote || salt) without revealing vote or salt
@@ -31,13 +34,13 @@ impl VoteCommitmentProof {
         let public_inputs = vec![commitment_fr, case_id_fr];
         
         // Verify Groth16 proof
-        let valid = Groth16::<Bn254>::verify(
-            verifying_key,
-            &public_inputs,
-            &self.proof,
-        ).map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
-        
-        Ok(valid)
+        let prepared_inputs = prepare_public_inputs(verifying_key, &public_inputs)?;
+        verify_with_prepared_inputs(verifying_key, &self.proof, &prepared_inputs)
+    }
+
+    /// Public inputs for the vote commitment circuit, in circuit order
+    pub fn public_inputs_for(commitment: &[u8; 32], case_id: u64) -> Result<Vec<Fr>> {
+        Ok(vec![bytes_to_field_element(commitment)?, Fr::from(case_id)])
     }
     
     /// Deserialize proof from bytes
@@ -73,6 +76,121 @@ impl VoteCommitmentProof {
     }
 }
 
+/// Groth16 proof for juror eligibility
+/// Circuit proves: the voter knows the secret behind one leaf Poseidon(tag, identity, stake)
+/// of the case's juror set, with stake >= min_stake, and voter = Poseidon(tag, secret, case_id)
+#[derive(Clone)]
+pub struct JurorEligibilityProof {
+    pub proof: Proof<Bn254>,
+}
+
+impl JurorEligibilityProof {
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey<Bn254>,
+        juror_root: &[u8; 32],
+        min_stake: u64,
+        case_id: u64,
+        voter: &[u8; 32],
+        commitment: &[u8; 32],
+    ) -> Result<bool> {
+        let public_inputs = Self::public_inputs_for(juror_root, min_stake, case_id, voter, commitment)?;
+        let prepared_inputs = prepare_public_inputs(verifying_key, &public_inputs)?;
+        verify_with_prepared_inputs(verifying_key, &self.proof, &prepared_inputs)
+    }
+
+    /// Public inputs for the eligibility circuit, in circuit order
+    pub fn public_inputs_for(
+        juror_root: &[u8; 32],
+        min_stake: u64,
+        case_id: u64,
+        voter: &[u8; 32],
+        commitment: &[u8; 32],
+    ) -> Result<Vec<Fr>> {
+        Ok(vec![
+            bytes_to_field_element(juror_root)?,
+            Fr::from(min_stake),
+            Fr::from(case_id),
+            bytes_to_field_element(voter)?,
+            bytes_to_field_element(commitment)?,
+        ])
+    }
+
+    pub fn from_bytes(proof_bytes: &[u8]) -> Result<Self> {
+        require!(proof_bytes.len() >= 192, crate::ErrorCode::InvalidZkProof);
+        let proof = Proof::<Bn254>::deserialize_compressed(&proof_bytes[0..192])
+            .map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
+        Ok(JurorEligibilityProof { proof })
+    }
+}
+
+/// Groth16 proof for anonymous reporting (Semaphore-style)
+/// Circuit proves: Poseidon(identity secret) is a leaf under the reporter set root, a note
+/// Poseidon(secret, nullifier secret) is a leaf under the pool root, and the nullifier is
+/// Poseidon(nullifier secret); the signal ties the proof to one submission
+#[derive(Clone)]
+pub struct ReporterMembershipProof {
+    pub proof: Proof<Bn254>,
+}
+
+impl ReporterMembershipProof {
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey<Bn254>,
+        reporter_root: &[u8; 32],
+        pool_root: &[u8; 32],
+        nullifier: &[u8; 32],
+        signal: &[u8; 32],
+    ) -> Result<bool> {
+        let public_inputs = [reporter_root, pool_root, nullifier, signal]
+            .into_iter()
+            .map(bytes_to_field_element)
+            .collect::<Result<Vec<_>>>()?;
+        let prepared_inputs = prepare_public_inputs(verifying_key, &public_inputs)?;
+        verify_with_prepared_inputs(verifying_key, &self.proof, &prepared_inputs)
+    }
+
+    pub fn from_bytes(proof_bytes: &[u8]) -> Result<Self> {
+        require!(proof_bytes.len() >= 192, crate::ErrorCode::InvalidZkProof);
+        let proof = Proof::<Bn254>::deserialize_compressed(&proof_bytes[0..192])
+            .map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
+        Ok(ReporterMembershipProof { proof })
+    }
+}
+
+/// Groth16 proof of personhood for juror registration
+/// Circuit proves: the holder's credential is a leaf under the personhood registry root and
+/// the nullifier is Poseidon(credential secret, juror-registration tag), so one credential
+/// yields one nullifier no matter which key it is presented for; the signal binds the key
+#[derive(Clone)]
+pub struct UniqueIdentityProof {
+    pub proof: Proof<Bn254>,
+}
+
+impl UniqueIdentityProof {
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey<Bn254>,
+        credential_root: &[u8; 32],
+        nullifier: &[u8; 32],
+        signal: &[u8; 32],
+    ) -> Result<bool> {
+        let public_inputs = [credential_root, nullifier, signal]
+            .into_iter()
+            .map(bytes_to_field_element)
+            .collect::<Result<Vec<_>>>()?;
+        let prepared_inputs = prepare_public_inputs(verifying_key, &public_inputs)?;
+        verify_with_prepared_inputs(verifying_key, &self.proof, &prepared_inputs)
+    }
+
+    pub fn from_bytes(proof_bytes: &[u8]) -> Result<Self> {
+        require!(proof_bytes.len() >= 192, crate::ErrorCode::InvalidZkProof);
+        let proof = Proof::<Bn254>::deserialize_compressed(&proof_bytes[0..192])
+            .map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
+        Ok(UniqueIdentityProof { proof })
+    }
+}
+
 /// Verifying key for vote commitment circuit
 pub struct VoteCommitmentVerifyingKey {
     pub vk: VerifyingKey<Bn254>,
@@ -89,19 +207,13 @@ impl VoteCommitmentVerifyingKey {
         Ok(VoteCommitmentVerifyingKey { vk })
     }
     
-    /// Get default embedded verifying key
-    /// In production, this should be stored on-chain or loaded from a PDA
-    pub fn default_embedded() -> Result<Self> {
-        // This is a placeholder - in production you'd generate this from your circuit
-        // For now, we'll use a mock VK
-        msg!("WARNING: Using mock verifying key - replace with real circuit VK");
-        
-        // In practice, you would:
-        // 1. Define your circuit in Circom or arkworks
-        // 2. Run the trusted setup ceremony
-        // 3. Embed the verifying key here or store on-chain
-        
-        Err(error!(crate::ErrorCode::InvalidZkProof))
+    /// Load the key registered for the vote commitment circuit
+    pub fn from_registry(account: &VerifyingKeyAccount) -> Result<Self> {
+        require!(
+            account.proof_type == ZkProofType::VoteCommitment,
+            crate::ErrorCode::InvalidVerifyingKey
+        );
+        Ok(VoteCommitmentVerifyingKey { vk: account.verifying_key()? })
     }
 }
 
@@ -132,14 +244,64 @@ impl EvidenceEncryptionProof {
     ) -> Result<bool> {
         let hash_fr = bytes_to_field_element(evidence_hash)?;
         
-        let valid = Groth16::<Bn254>::verify(
-            verifying_key,
-            &[hash_fr],
-            &self.proof,
-        ).map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
-        
-        Ok(valid)
+        let prepared_inputs = prepare_public_inputs(verifying_key, &[hash_fr])?;
+        verify_with_prepared_inputs(verifying_key, &self.proof, &prepared_inputs)
+    }
+}
+
+/// First half of Groth16 verification: fold public inputs into a single G1 point.
+/// This is the MSM over `gamma_abc_g1` and needs no pairings, so it can run in
+/// its own instruction ahead of the pairing check.
+pub fn prepare_public_inputs(
+    verifying_key: &VerifyingKey<Bn254>,
+    public_inputs: &[Fr],
+) -> Result<G1Affine> {
+    require!(
+        public_inputs.len() + 1 == verifying_key.gamma_abc_g1.len(),
+        crate::ErrorCode::InvalidZkProof
+    );
+
+    let mut g_ic = verifying_key.gamma_abc_g1[0].into_group();
+    for (input, base) in public_inputs.iter().zip(verifying_key.gamma_abc_g1.iter().skip(1)) {
+        g_ic += base.mul_bigint(input.into_bigint());
     }
+
+    Ok(g_ic.into_affine())
+}
+
+/// Second half of Groth16 verification: a single multi-Miller loop and final
+/// exponentiation checking e(A,B) = e(alpha,beta) * e(inputs,gamma) * e(C,delta).
+pub fn verify_with_prepared_inputs(
+    verifying_key: &VerifyingKey<Bn254>,
+    proof: &Proof<Bn254>,
+    prepared_inputs: &G1Affine,
+) -> Result<bool> {
+    let neg_alpha = (-verifying_key.alpha_g1.into_group()).into_affine();
+    let neg_gamma = (-verifying_key.gamma_g2.into_group()).into_affine();
+    let neg_delta = (-verifying_key.delta_g2.into_group()).into_affine();
+
+    let miller = Bn254::multi_miller_loop(
+        [proof.a, *prepared_inputs, proof.c, neg_alpha],
+        [proof.b, neg_gamma, neg_delta, verifying_key.beta_g2],
+    );
+    let result = Bn254::final_exponentiation(miller)
+        .ok_or(error!(crate::ErrorCode::InvalidZkProof))?;
+
+    Ok(result.0.is_one())
+}
+
+/// Serialize a prepared-inputs point for storage between instructions
+pub fn serialize_prepared_inputs(point: &G1Affine) -> Result<[u8; 64]> {
+    let mut out = [0u8; 64];
+    point
+        .serialize_uncompressed(&mut out[..])
+        .map_err(|_| error!(crate::ErrorCode::InvalidZkProof))?;
+    Ok(out)
+}
+
+pub fn deserialize_prepared_inputs(bytes: &[u8; 64]) -> Result<G1Affine> {
+    G1Affine::deserialize_uncompressed(&bytes[..])
+        .map_err(|_| error!(crate::ErrorCode::InvalidZkProof))
 }
 
 #[cfg(test)]
@@ -152,4 +314,11 @@ mod tests {
         let result = bytes_to_field_element(&bytes);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_prepared_inputs_roundtrip() {
+        let point = G1Affine::generator();
+        let bytes = serialize_prepared_inputs(&point).unwrap();
+        assert_eq!(deserialize_prepared_inputs(&bytes).unwrap(), point);
+    }
 }
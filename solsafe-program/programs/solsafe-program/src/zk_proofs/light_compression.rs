@@ -1,128 +1,496 @@
-// Light Protocol ZK Compression Integration
-// Efficient private state management using ZK compression
-use anchor_lang::prelude::*;
-use super::{ZkProof, VoteCommitment};
-
-/// Compressed state tree for votes using Light Protocol
-#[account]
-pub struct CompressedVoteState {
-    pub merkle_root: [u8; 32],
-    pub case_id: u64,
-    pub total_commitments: u64,
-    pub tree_height: u8,
-    pub bump: u8,
-}
-
-impl CompressedVoteState {
-    pub const LEN: usize = 32 + // merkle_root
-        8 + // case_id
-        8 + // total_commitments
-        1 + // tree_height
-        1; // bump
-
-    pub fn initialize(case_id: u64, bump: u8) -> Self {
-        CompressedVoteState {
-            merkle_root: [0u8; 32],
-            case_id,
-            total_commitments: 0,
-            tree_height: 20, // Supports 2^20 votes
-            bump,
-        }
-    }
-
-    /// Add vote commitment to compressed state
-    pub fn add_commitment(&mut self, commitment: &VoteCommitment) -> Result<()> {
-        // Update merkle root with new commitment
-        self.merkle_root = self.compute_new_root(&commitment.commitment)?;
-        self.total_commitments += 1;
-        
-        msg!("Vote commitment added to compressed state. Total: {}", self.total_commitments);
-        Ok(())
-    }
-
-    fn compute_new_root(&self, commitment: &[u8; 32]) -> Result<[u8; 32]> {
-        use solana_program::hash::hashv;
-        let result = hashv(&[&self.merkle_root, commitment]);
-        Ok(result.to_bytes())
-    }
-
-    /// Verify membership proof for a commitment
-    pub fn verify_membership(&self, commitment: &[u8; 32], proof: &[Vec<u8>]) -> Result<bool> {
-        let mut current_hash = *commitment;
-        
-        for sibling in proof {
-            current_hash = self.hash_pair(&current_hash, sibling)?;
-        }
-        
-        Ok(current_hash == self.merkle_root)
-    }
-
-    fn hash_pair(&self, left: &[u8; 32], right: &[u8]) -> Result<[u8; 32]> {
-        use solana_program::hash::hashv;
-        let result = hashv(&[left, right]);
-        Ok(result.to_bytes())
-    }
-}
-
-/// Light Protocol ZK compression configuration
-#[account]
-pub struct LightCompressionConfig {
-    pub authority: Pubkey,
-    pub compression_enabled: bool,
-    pub max_tree_depth: u8,
-    pub state_tree_count: u64,
-    pub bump: u8,
-}
-
-impl LightCompressionConfig {
-    pub const LEN: usize = 32 + // authority
-        1 + // compression_enabled
-        1 + // max_tree_depth
-        8 + // state_tree_count
-        1; // bump
-}
-
-/// Batch vote commitment for efficiency
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct BatchVoteCommitment {
-    pub commitments: Vec<[u8; 32]>,
-    pub nullifiers: Vec<[u8; 32]>,
-    pub batch_proof: Vec<u8>,
-}
-
-impl BatchVoteCommitment {
-    /// Create batch commitment for multiple votes (Arcium MPC integration point)
-    pub fn new(commitments: Vec<[u8; 32]>, nullifiers: Vec<[u8; 32]>) -> Self {
-        // Generate batch proof using Light Protocol compression
-        let batch_proof = Self::generate_batch_proof(&commitments);
-        
-        BatchVoteCommitment {
-            commitments,
-            nullifiers,
-            batch_proof,
-        }
-    }
-
-    fn generate_batch_proof(commitments: &[[u8; 32]]) -> Vec<u8> {
-        // Generate efficient batch proof using ZK compression
-        use solana_program::hash::hashv;
-        let mut proof_data = Vec::new();
-        
-        for commitment in commitments {
-            proof_data.extend_from_slice(commitment);
-        }
-        
-        let proof_hash = hashv(&[&proof_data]);
-        proof_hash.to_bytes().to_vec()
-    }
-
-    pub fn verify(&self) -> Result<bool> {
-        // Verify batch proof is valid
-        require!(
-            self.commitments.len() == self.nullifiers.len(),
-            crate::ErrorCode::InvalidBatchSize
-        );
-        
-        Ok(true)
-    }
-}
+// Light Protocol ZK Compression Integration
+// Vote commitments are appended as compressed accounts to a Light state tree through CPIs
+// into the Light system program, and read back with Light validity proofs.
+// Instruction layouts mirror light-system-program 1.x, encoded here since the SDK isn't a dependency.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use crate::state::GlobalConfig;
+use crate::{CompressedVoteVerified, ErrorCode};
+use super::{VoteCommitment, VoteCommitmentAccount};
+
+pub const LIGHT_SYSTEM_PROGRAM_ID: Pubkey = pubkey!("SySTEM1eSU2p4BGQfQpb84uE9GvaYLt4Z3fa7fSRXF7");
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Seed of the PDA that signs for this program in Light system CPIs
+pub const CPI_AUTHORITY_SEED: &[u8] = b"cpi_authority";
+
+/// Anchor discriminator of the Light system program's `invoke_cpi`
+const INVOKE_CPI_DISCRIMINATOR: [u8; 8] = [49, 212, 191, 129, 39, 194, 43, 196];
+
+/// Accounts a compressing instruction passes after its own, in this order
+const LIGHT_ACCOUNTS_LEN: usize = 10;
+
+/// Per-case handle on the Light state tree holding the case's vote commitments
+#[account]
+pub struct CompressedVoteState {
+    pub merkle_tree: Pubkey,          // Default when compression was off at tally opening
+    pub nullifier_queue: Pubkey,
+    pub case_id: u64,
+    pub total_commitments: u64,
+    pub bump: u8,
+}
+
+impl CompressedVoteState {
+    pub const LEN: usize = 32 + // merkle_tree
+        32 + // nullifier_queue
+        8 + // case_id
+        8 + // total_commitments
+        1; // bump
+
+    pub fn initialize(case_id: u64, config: Option<&LightCompressionConfig>, bump: u8) -> Self {
+        let (merkle_tree, nullifier_queue) = config
+            .filter(|config| config.compression_enabled)
+            .map_or((Pubkey::default(), Pubkey::default()), |config| {
+                (config.state_tree, config.nullifier_queue)
+            });
+        CompressedVoteState {
+            merkle_tree,
+            nullifier_queue,
+            case_id,
+            total_commitments: 0,
+            bump,
+        }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.merkle_tree != Pubkey::default()
+    }
+
+    /// Count a commitment, appending it to the case's state tree when compression is on
+    pub fn add_commitment<'info>(
+        &mut self,
+        commitment: &VoteCommitment,
+        juror: Pubkey,
+        fee_payer: &AccountInfo<'info>,
+        light_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if self.is_compressed() {
+            let leaf = CompressedVoteLeaf {
+                case_id: self.case_id,
+                juror,
+                commitment: commitment.commitment,
+                nullifier: commitment.nullifier,
+            };
+            append_leaf(
+                crate::domain::COMPRESSED_VOTE_LEAF,
+                &leaf,
+                (self.merkle_tree, self.nullifier_queue),
+                fee_payer,
+                light_accounts,
+            )?;
+        }
+        self.total_commitments += 1;
+
+        msg!("Vote commitment added to compressed state. Total: {}", self.total_commitments);
+        Ok(())
+    }
+
+    /// Prove `leaf` is in the state tree. The Light system program checks the validity proof
+    /// against the tree root, nullifies the leaf, and the identical leaf is appended again.
+    pub fn verify_membership<'info>(
+        &self,
+        leaf: &CompressedVoteLeaf,
+        leaf_index: u32,
+        root_index: u16,
+        proof: CompressedProof,
+        fee_payer: &AccountInfo<'info>,
+        light_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(self.is_compressed(), ErrorCode::CompressionDisabled);
+        let data = InstructionDataInvokeCpi {
+            proof: Some(proof),
+            input_compressed_accounts_with_merkle_context: vec![PackedCompressedAccountWithMerkleContext {
+                compressed_account: compressed_account(crate::domain::COMPRESSED_VOTE_LEAF, leaf)?,
+                merkle_context: PackedMerkleContext {
+                    merkle_tree_pubkey_index: 0,
+                    nullifier_queue_pubkey_index: 1,
+                    leaf_index,
+                    queue_index: None,
+                },
+                root_index,
+                read_only: false,
+            }],
+            output_compressed_accounts: vec![output(crate::domain::COMPRESSED_VOTE_LEAF, leaf)?],
+            ..Default::default()
+        };
+        invoke_light(data, (self.merkle_tree, self.nullifier_queue), fee_payer, light_accounts)
+    }
+}
+
+/// `light_accounts`: Light system program, this program's CPI authority, registered
+/// program PDA, noop program, account compression authority, account compression
+/// program, this program, system program, then the state tree and its nullifier queue
+fn invoke_light<'info>(
+    data: InstructionDataInvokeCpi,
+    (merkle_tree, nullifier_queue): (Pubkey, Pubkey),
+    fee_payer: &AccountInfo<'info>,
+    light_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    require!(light_accounts.len() >= LIGHT_ACCOUNTS_LEN, ErrorCode::InvalidLightAccounts);
+    let light_accounts = &light_accounts[..LIGHT_ACCOUNTS_LEN];
+    let (cpi_authority, bump) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED], &crate::ID);
+    require!(
+        light_accounts[0].key() == LIGHT_SYSTEM_PROGRAM_ID
+            && light_accounts[1].key() == cpi_authority
+            && light_accounts[3].key() == NOOP_PROGRAM_ID
+            && light_accounts[5].key() == ACCOUNT_COMPRESSION_PROGRAM_ID
+            && light_accounts[6].key() == crate::ID
+            && light_accounts[7].key() == anchor_lang::system_program::ID
+            && light_accounts[8].key() == merkle_tree
+            && light_accounts[9].key() == nullifier_queue,
+        ErrorCode::InvalidLightAccounts
+    );
+
+    let mut instruction_data = INVOKE_CPI_DISCRIMINATOR.to_vec();
+    // invoke_cpi takes its inputs as a borsh-encoded byte vector
+    data.try_to_vec()?.serialize(&mut instruction_data)?;
+
+    let light_system_program = &light_accounts[0];
+    let accounts = vec![
+        AccountMeta::new(fee_payer.key(), true),
+        AccountMeta::new_readonly(cpi_authority, true),
+        AccountMeta::new_readonly(light_accounts[2].key(), false),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+        AccountMeta::new_readonly(light_accounts[4].key(), false),
+        AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        // No sol pool, decompression recipient, or CPI context
+        AccountMeta::new_readonly(LIGHT_SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new_readonly(LIGHT_SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        AccountMeta::new_readonly(LIGHT_SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new(nullifier_queue, false),
+    ];
+    let mut infos = vec![fee_payer.clone()];
+    infos.extend_from_slice(light_accounts);
+
+    invoke_signed(
+        &Instruction { program_id: light_system_program.key(), accounts, data: instruction_data },
+        &infos,
+        &[&[CPI_AUTHORITY_SEED, &[bump]]],
+    )?;
+    Ok(())
+}
+
+/// Append `leaf` as a new compressed account owned by this program. `tag` is the leaf
+/// kind's domain tag, which also derives its discriminator.
+pub(crate) fn append_leaf<'info, T: AnchorSerialize>(
+    tag: &[u8],
+    leaf: &T,
+    tree: (Pubkey, Pubkey),
+    fee_payer: &AccountInfo<'info>,
+    light_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let data = InstructionDataInvokeCpi {
+        output_compressed_accounts: vec![output(tag, leaf)?],
+        ..Default::default()
+    };
+    invoke_light(data, tree, fee_payer, light_accounts)
+}
+
+/// Light hashes leaves with Poseidon, so the data hash must fit the BN254 field
+pub fn leaf_data_hash(tag: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut hash = crate::domain::hash(tag, &[data]);
+    hash[0] = 0;
+    hash
+}
+
+fn compressed_account<T: AnchorSerialize>(tag: &[u8], leaf: &T) -> Result<CompressedAccount> {
+    let data = leaf.try_to_vec()?;
+    let discriminator = crate::domain::hash(tag, &[]);
+    Ok(CompressedAccount {
+        owner: crate::ID,
+        lamports: 0,
+        address: None,
+        data: Some(CompressedAccountData {
+            discriminator: discriminator[..8].try_into().unwrap(),
+            data_hash: leaf_data_hash(tag, &data),
+            data,
+        }),
+    })
+}
+
+fn output<T: AnchorSerialize>(tag: &[u8], leaf: &T) -> Result<OutputCompressedAccountWithPackedContext> {
+    Ok(OutputCompressedAccountWithPackedContext {
+        compressed_account: compressed_account(tag, leaf)?,
+        merkle_tree_index: 0,
+    })
+}
+
+/// A juror's vote commitment as stored in the state tree
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedVoteLeaf {
+    pub case_id: u64,
+    pub juror: Pubkey,
+    pub commitment: [u8; 32],
+    pub nullifier: [u8; 32],
+}
+
+impl CompressedVoteLeaf {
+    pub fn from_account(account: &VoteCommitmentAccount) -> Self {
+        CompressedVoteLeaf {
+            case_id: account.case_id,
+            juror: account.juror,
+            commitment: account.commitment,
+            nullifier: account.nullifier,
+        }
+    }
+}
+
+/// Groth16 validity proof from the Light prover, compressed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CompressedProof {
+    pub a: [u8; 32],
+    pub b: [u8; 64],
+    pub c: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+struct InstructionDataInvokeCpi {
+    proof: Option<CompressedProof>,
+    new_address_params: Vec<NewAddressParamsPacked>,
+    input_compressed_accounts_with_merkle_context: Vec<PackedCompressedAccountWithMerkleContext>,
+    output_compressed_accounts: Vec<OutputCompressedAccountWithPackedContext>,
+    relay_fee: Option<u64>,
+    compress_or_decompress_lamports: Option<u64>,
+    is_compress: bool,
+    cpi_context: Option<CompressedCpiContext>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct NewAddressParamsPacked {
+    seed: [u8; 32],
+    address_queue_account_index: u8,
+    address_merkle_tree_account_index: u8,
+    address_merkle_tree_root_index: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct CompressedCpiContext {
+    set_context: bool,
+    first_set_context: bool,
+    cpi_context_account_index: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct CompressedAccount {
+    owner: Pubkey,
+    lamports: u64,
+    address: Option<[u8; 32]>,
+    data: Option<CompressedAccountData>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct CompressedAccountData {
+    discriminator: [u8; 8],
+    data: Vec<u8>,
+    data_hash: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct QueueIndex {
+    queue_id: u8,
+    index: u16,
+}
+
+/// Tree and queue are indices into the accounts after the Light system program's own
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct PackedMerkleContext {
+    merkle_tree_pubkey_index: u8,
+    nullifier_queue_pubkey_index: u8,
+    leaf_index: u32,
+    queue_index: Option<QueueIndex>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct PackedCompressedAccountWithMerkleContext {
+    compressed_account: CompressedAccount,
+    merkle_context: PackedMerkleContext,
+    root_index: u16,
+    read_only: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+struct OutputCompressedAccountWithPackedContext {
+    compressed_account: CompressedAccount,
+    merkle_tree_index: u8,
+}
+
+/// Light Protocol ZK compression configuration: the state tree new confidential tallies use
+#[account]
+pub struct LightCompressionConfig {
+    pub compression_enabled: bool,
+    pub state_tree: Pubkey,
+    pub nullifier_queue: Pubkey,
+    pub bump: u8,
+}
+
+impl LightCompressionConfig {
+    pub const LEN: usize = 1 + // compression_enabled
+        32 + // state_tree
+        32 + // nullifier_queue
+        1; // bump
+}
+
+#[derive(Accounts)]
+pub struct ConfigureLightCompression<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + LightCompressionConfig::LEN,
+        seeds = [b"light_config"],
+        bump
+    )]
+    pub light_config: Account<'info, LightCompressionConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cases whose tally is already open keep the tree they started with
+pub fn configure_handler(
+    ctx: Context<ConfigureLightCompression>,
+    state_tree: Pubkey,
+    nullifier_queue: Pubkey,
+    compression_enabled: bool,
+) -> Result<()> {
+    require!(
+        !compression_enabled || (state_tree != Pubkey::default() && nullifier_queue != Pubkey::default()),
+        ErrorCode::InvalidLightAccounts
+    );
+    let light_config = &mut ctx.accounts.light_config;
+    light_config.compression_enabled = compression_enabled;
+    light_config.state_tree = state_tree;
+    light_config.nullifier_queue = nullifier_queue;
+    light_config.bump = ctx.bumps.light_config;
+
+    msg!("Light compression {} on tree {}", compression_enabled, state_tree);
+    Ok(())
+}
+
+/// Proof-carrying read of a juror's compressed commitment; Light accounts follow in
+/// `remaining_accounts`
+#[derive(Accounts)]
+pub struct VerifyCompressedVote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vote_commitment", vote_commitment_account.case_id.to_le_bytes().as_ref(), vote_commitment_account.seed().as_ref()],
+        bump = vote_commitment_account.bump
+    )]
+    pub vote_commitment_account: Account<'info, VoteCommitmentAccount>,
+
+    #[account(
+        seeds = [b"compressed_votes", vote_commitment_account.case_id.to_le_bytes().as_ref()],
+        bump = compressed_state.bump
+    )]
+    pub compressed_state: Account<'info, CompressedVoteState>,
+}
+
+pub fn verify_compressed_vote_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyCompressedVote<'info>>,
+    leaf_index: u32,
+    root_index: u16,
+    proof: CompressedProof,
+) -> Result<()> {
+    let leaf = CompressedVoteLeaf::from_account(&ctx.accounts.vote_commitment_account);
+    ctx.accounts.compressed_state.verify_membership(
+        &leaf,
+        leaf_index,
+        root_index,
+        proof,
+        &ctx.accounts.payer.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(CompressedVoteVerified {
+        case_id: leaf.case_id,
+        juror: leaf.juror,
+        commitment: leaf.commitment,
+        leaf_index,
+    });
+    msg!("Compressed vote of {} verified for case {}", leaf.juror, leaf.case_id);
+    Ok(())
+}
+
+/// Batch vote commitment for efficiency
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchVoteCommitment {
+    pub commitments: Vec<[u8; 32]>,
+    pub nullifiers: Vec<[u8; 32]>,
+    pub batch_proof: Vec<u8>,
+}
+
+impl BatchVoteCommitment {
+    /// Create batch commitment for multiple votes (Arcium MPC integration point)
+    pub fn new(commitments: Vec<[u8; 32]>, nullifiers: Vec<[u8; 32]>) -> Self {
+        // Generate batch proof using Light Protocol compression
+        let batch_proof = Self::generate_batch_proof(&commitments);
+
+        BatchVoteCommitment {
+            commitments,
+            nullifiers,
+            batch_proof,
+        }
+    }
+
+    fn generate_batch_proof(commitments: &[[u8; 32]]) -> Vec<u8> {
+        // Generate efficient batch proof using ZK compression
+        let mut proof_data = Vec::new();
+
+        for commitment in commitments {
+            proof_data.extend_from_slice(commitment);
+        }
+
+        crate::domain::hash(crate::domain::BATCH_PROOF, &[&proof_data]).to_vec()
+    }
+
+    pub fn verify(&self) -> Result<bool> {
+        // Verify batch proof is valid
+        require!(
+            self.commitments.len() == self.nullifiers.len(),
+            crate::ErrorCode::InvalidBatchSize
+        );
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaf_hash_fits_bn254_field() {
+        let leaf = CompressedVoteLeaf {
+            case_id: 7,
+            juror: Pubkey::new_unique(),
+            commitment: [1u8; 32],
+            nullifier: [2u8; 32],
+        };
+        let account = compressed_account(crate::domain::COMPRESSED_VOTE_LEAF, &leaf).unwrap();
+        let data = account.data.unwrap();
+        assert_eq!(data.data_hash[0], 0);
+        assert_eq!(CompressedVoteLeaf::try_from_slice(&data.data).unwrap(), leaf);
+    }
+
+    #[test]
+    fn test_empty_invoke_encoding() {
+        // proof None, four empty vectors/options, is_compress, cpi_context None
+        let data = InstructionDataInvokeCpi::default().try_to_vec().unwrap();
+        assert_eq!(data, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
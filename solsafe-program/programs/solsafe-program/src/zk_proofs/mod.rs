@@ -1,180 +1,365 @@
-// Zero-Knowledge Proof Module for Private Voting and Evidence Review
-pub mod private_vote;
-pub mod evidence_verification;
-pub mod light_compression;
-pub mod arcium_mpc;
-pub mod dust_confidential;
-pub mod groth16_verifier;  // NEW: Groth16 ZK-SNARK verifier
-
-use anchor_lang::prelude::*;
-
-// Re-export main types
-pub use private_vote::*;
-pub use evidence_verification::*;
-pub use light_compression::*;
-pub use arcium_mpc::*;
-pub use dust_confidential::*;
-pub use groth16_verifier::*;
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct ZkProof {
-    pub proof_data: Vec<u8>,
-    pub public_inputs: Vec<u8>,
-    pub proof_type: ZkProofType,
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum ZkProofType {
-    VoteCommitment,
-    EvidenceHash,
-    JurorEligibility,
-    TallyVerification,
-}
-
-impl ZkProof {
-    pub fn verify(&self) -> Result<bool> {
-        // Verify ZK proof based on type
-        match self.proof_type {
-            ZkProofType::VoteCommitment => self.verify_vote_commitment(),
-            ZkProofType::EvidenceHash => self.verify_evidence_hash(),
-            ZkProofType::JurorEligibility => self.verify_juror_eligibility(),
-            ZkProofType::TallyVerification => self.verify_tally(),
-        }
-    }
-
-    fn verify_vote_commitment(&self) -> Result<bool> {
-        // Use Groth16 ZK-SNARK verification for true zero-knowledge
-        require!(self.proof_data.len() >= 192, crate::ErrorCode::InvalidZkProof); // Groth16 proof size
-        require!(self.public_inputs.len() >= 40, crate::ErrorCode::InvalidZkProof); // case_id (8) + commitment (32)
-        
-        // Extract case_id from public inputs
-        let case_id_bytes: [u8; 8] = self.public_inputs[0..8].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        let case_id = u64::from_le_bytes(case_id_bytes);
-        
-        // Extract commitment from public inputs
-        let commitment: [u8; 32] = self.public_inputs[8..40].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        
-        // Verify commitment structure (non-zero, valid hash format)
-        require!(commitment != [0u8; 32], crate::ErrorCode::InvalidZkProof);
-        
-        // Deserialize and verify Groth16 proof
-        let groth16_proof = VoteCommitmentProof::from_bytes(&self.proof_data)?;
-        
-        // For now, use hash-based verification until verifying key is set up
-        // TODO: Replace with actual Groth16 verification once circuit is deployed
-        // let vk = VoteCommitmentVerifyingKey::default_embedded()?;
-        // let valid = groth16_proof.verify(&vk.vk, &commitment, case_id)?;
-        
-        // Fallback to hash-based verification
-        use solana_program::hash::hashv;
-        let computed_nullifier = hashv(&[&case_id.to_le_bytes(), &commitment]);
-        
-        if self.proof_data.len() >= 32 {
-            let provided_nullifier: [u8; 32] = self.proof_data[0..32].try_into()
-                .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-            require!(
-                computed_nullifier.to_bytes() == provided_nullifier,
-                crate::ErrorCode::InvalidZkProof
-            );
-        }
-        
-        msg!("Vote commitment verified (hash-based until Groth16 circuit deployed)");
-        Ok(true)
-    }
-
-    fn verify_evidence_hash(&self) -> Result<bool> {
-        // Verify evidence hash format
-        require!(self.public_inputs.len() >= 32, crate::ErrorCode::InvalidZkProof);
-        
-        let evidence_hash: [u8; 32] = self.public_inputs[0..32].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        
-        // Verify hash is non-zero
-        require!(evidence_hash != [0u8; 32], crate::ErrorCode::InvalidZkProof);
-        
-        msg!("Evidence hash verified");
-        Ok(true)
-    }
-
-    fn verify_juror_eligibility(&self) -> Result<bool> {
-        // Verify juror eligibility proof format
-        require!(self.proof_data.len() >= 32, crate::ErrorCode::InvalidZkProof);
-        require!(self.public_inputs.len() >= 32, crate::ErrorCode::InvalidZkProof);
-        
-        // Extract juror pubkey hash from public inputs
-        let juror_hash: [u8; 32] = self.public_inputs[0..32].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        
-        // Verify non-zero hash
-        require!(juror_hash != [0u8; 32], crate::ErrorCode::InvalidZkProof);
-        
-        msg!("Juror eligibility verified");
-        Ok(true)
-    }
-
-    fn verify_tally(&self) -> Result<bool> {
-        // Verify tally proof format
-        require!(self.public_inputs.len() >= 16, crate::ErrorCode::InvalidZkProof); // approve_count (8) + reject_count (8)
-        
-        let approve_bytes: [u8; 8] = self.public_inputs[0..8].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        let reject_bytes: [u8; 8] = self.public_inputs[8..16].try_into()
-            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
-        
-        let approve_count = u64::from_le_bytes(approve_bytes);
-        let reject_count = u64::from_le_bytes(reject_bytes);
-        
-        // Basic sanity check
-        require!(approve_count + reject_count > 0, crate::ErrorCode::InvalidZkProof);
-        
-        msg!("Tally verified: {} approve, {} reject", approve_count, reject_count);
-        Ok(true)
-    }
-}
-
-// Commitment scheme for private votes
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct VoteCommitment {
-    pub commitment: [u8; 32],  // Hash commitment
-    pub nullifier: [u8; 32],   // Prevents double voting
-    pub case_id: u64,
-    pub timestamp: i64,
-}
-
-impl VoteCommitment {
-    pub fn new(case_id: u64, vote: bool, salt: [u8; 32], timestamp: i64) -> Self {
-        let commitment = Self::compute_commitment(vote, &salt);
-        let nullifier = Self::compute_nullifier(case_id, &commitment);
-        
-        VoteCommitment {
-            commitment,
-            nullifier,
-            case_id,
-            timestamp,
-        }
-    }
-
-    pub fn verify_reveal(
-        &self,
-        revealed_vote: bool,
-        revealed_salt: &[u8; 32],
-    ) -> bool {
-        let recomputed = Self::compute_commitment(revealed_vote, revealed_salt);
-        recomputed == self.commitment
-    }
-
-    fn compute_commitment(vote: bool, salt: &[u8; 32]) -> [u8; 32] {
-        use solana_program::hash::hashv;
-        let vote_byte = if vote { 1u8 } else { 0u8 };
-        let result = hashv(&[&[vote_byte], salt]);
-        result.to_bytes()
-    }
-
-    fn compute_nullifier(case_id: u64, commitment: &[u8; 32]) -> [u8; 32] {
-        use solana_program::hash::hashv;
-        let result = hashv(&[&case_id.to_le_bytes(), commitment]);
-        result.to_bytes()
-    }
-}
+// Zero-Knowledge Proof Module for Private Voting and Evidence Review
+pub mod private_vote;
+pub mod evidence_verification;
+pub mod light_compression;
+pub mod arcium_mpc;
+pub mod dust_confidential;
+pub mod groth16_verifier;  // NEW: Groth16 ZK-SNARK verifier
+pub mod split_verification;
+pub mod verifying_key_registry;
+
+use anchor_lang::prelude::*;
+use ark_bn254::Bn254;
+use ark_groth16::VerifyingKey;
+
+// Re-export main types
+pub use private_vote::*;
+pub use evidence_verification::*;
+pub use light_compression::*;
+pub use arcium_mpc::*;
+pub use dust_confidential::*;
+pub use groth16_verifier::*;
+pub use split_verification::*;
+pub use verifying_key_registry::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ZkProof {
+    pub proof_data: Vec<u8>,
+    pub public_inputs: Vec<u8>,
+    pub proof_type: ZkProofType,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZkProofType {
+    VoteCommitment,
+    EvidenceHash,
+    JurorEligibility,
+    TallyVerification,
+    ReporterMembership,
+    UniqueIdentity,
+}
+
+impl ZkProof {
+    /// `verifying_key` is the registered key for this proof type, if one has been registered
+    /// `scheme` is the case's commitment scheme, which the fallback nullifier check follows
+    pub fn verify(&self, verifying_key: Option<&VerifyingKey<Bn254>>, scheme: CommitmentScheme) -> Result<bool> {
+        // Verify ZK proof based on type
+        match self.proof_type {
+            ZkProofType::VoteCommitment => self.verify_vote_commitment(verifying_key, scheme),
+            ZkProofType::EvidenceHash => self.verify_evidence_hash(),
+            ZkProofType::JurorEligibility => self.verify_juror_eligibility(verifying_key),
+            ZkProofType::TallyVerification => self.verify_tally(),
+            ZkProofType::ReporterMembership => self.verify_reporter_membership(verifying_key),
+            ZkProofType::UniqueIdentity => self.verify_unique_identity(verifying_key),
+        }
+    }
+
+    fn verify_vote_commitment(
+        &self,
+        verifying_key: Option<&VerifyingKey<Bn254>>,
+        scheme: CommitmentScheme,
+    ) -> Result<bool> {
+        // Use Groth16 ZK-SNARK verification for true zero-knowledge
+        require!(self.proof_data.len() >= 192, crate::ErrorCode::InvalidZkProof); // Groth16 proof size
+        require!(self.public_inputs.len() >= 40, crate::ErrorCode::InvalidZkProof); // case_id (8) + commitment (32)
+        
+        // Extract case_id from public inputs
+        let case_id_bytes: [u8; 8] = self.public_inputs[0..8].try_into()
+            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+        let case_id = u64::from_le_bytes(case_id_bytes);
+        
+        // Extract commitment from public inputs
+        let commitment: [u8; 32] = self.public_inputs[8..40].try_into()
+            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+        
+        // Verify commitment structure (non-zero, valid hash format)
+        require!(commitment != [0u8; 32], crate::ErrorCode::InvalidZkProof);
+        
+        // Deserialize and verify Groth16 proof
+        let groth16_proof = VoteCommitmentProof::from_bytes(&self.proof_data)?;
+
+        // Once a circuit key is registered every proof must pass the pairing check
+        if let Some(vk) = verifying_key {
+            require!(
+                groth16_proof.verify(vk, &commitment, case_id)?,
+                crate::ErrorCode::InvalidZkProof
+            );
+            msg!("Vote commitment verified (Groth16)");
+            return Ok(true);
+        }
+
+        // Hash-based fallback until the circuit's key is registered
+        let computed_nullifier = VoteCommitment::compute_nullifier_with(scheme, case_id, &commitment)?;
+        
+        if self.proof_data.len() >= 32 {
+            let provided_nullifier: [u8; 32] = self.proof_data[0..32].try_into()
+                .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+            require!(
+                computed_nullifier == provided_nullifier,
+                crate::ErrorCode::InvalidZkProof
+            );
+        }
+        
+        msg!("Vote commitment verified (hash-based until a verifying key is registered)");
+        Ok(true)
+    }
+
+    fn verify_evidence_hash(&self) -> Result<bool> {
+        // Verify evidence hash format
+        require!(self.public_inputs.len() >= 32, crate::ErrorCode::InvalidZkProof);
+        
+        let evidence_hash: [u8; 32] = self.public_inputs[0..32].try_into()
+            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+        
+        // Verify hash is non-zero
+        require!(evidence_hash != [0u8; 32], crate::ErrorCode::InvalidZkProof);
+        
+        msg!("Evidence hash verified");
+        Ok(true)
+    }
+
+    /// Public inputs are `eligibility_inputs`. There is no fallback: without the circuit's
+    /// registered key an eligibility proof proves nothing.
+    fn verify_juror_eligibility(&self, verifying_key: Option<&VerifyingKey<Bn254>>) -> Result<bool> {
+        let vk = verifying_key.ok_or(crate::ErrorCode::InvalidEligibilityProof)?;
+        require!(self.public_inputs.len() == 112, crate::ErrorCode::InvalidZkProof);
+
+        let inputs = &self.public_inputs;
+        let juror_root: [u8; 32] = inputs[0..32].try_into().unwrap();
+        let min_stake = u64::from_le_bytes(inputs[32..40].try_into().unwrap());
+        let case_id = u64::from_le_bytes(inputs[40..48].try_into().unwrap());
+        let voter: [u8; 32] = inputs[48..80].try_into().unwrap();
+        let commitment: [u8; 32] = inputs[80..112].try_into().unwrap();
+
+        let proof = JurorEligibilityProof::from_bytes(&self.proof_data)?;
+        require!(
+            proof.verify(vk, &juror_root, min_stake, case_id, &voter, &commitment)?,
+            crate::ErrorCode::InvalidEligibilityProof
+        );
+        msg!("Juror eligibility verified (Groth16)");
+        Ok(true)
+    }
+
+    /// Public inputs of a JurorEligibility proof: juror set root, minimum stake, case id
+    /// (both little-endian), voter tag, vote commitment
+    pub fn eligibility_inputs(
+        juror_root: &[u8; 32],
+        min_stake: u64,
+        case_id: u64,
+        voter: &[u8; 32],
+        commitment: &[u8; 32],
+    ) -> Vec<u8> {
+        [&juror_root[..], &min_stake.to_le_bytes(), &case_id.to_le_bytes(), voter, commitment].concat()
+    }
+
+    /// Public inputs are `membership_inputs`; like eligibility, no registered key means no proof
+    fn verify_reporter_membership(&self, verifying_key: Option<&VerifyingKey<Bn254>>) -> Result<bool> {
+        let vk = verifying_key.ok_or(crate::ErrorCode::InvalidMembershipProof)?;
+        require!(self.public_inputs.len() == 128, crate::ErrorCode::InvalidZkProof);
+
+        let input = |i: usize| -> [u8; 32] { self.public_inputs[32 * i..32 * i + 32].try_into().unwrap() };
+        let proof = ReporterMembershipProof::from_bytes(&self.proof_data)?;
+        require!(
+            proof.verify(vk, &input(0), &input(1), &input(2), &input(3))?,
+            crate::ErrorCode::InvalidMembershipProof
+        );
+        msg!("Reporter membership verified (Groth16)");
+        Ok(true)
+    }
+
+    /// Public inputs of a ReporterMembership proof: reporter set root, pool root, note
+    /// nullifier, signal
+    pub fn membership_inputs(
+        reporter_root: &[u8; 32],
+        pool_root: &[u8; 32],
+        nullifier: &[u8; 32],
+        signal: &[u8; 32],
+    ) -> Vec<u8> {
+        [&reporter_root[..], pool_root, nullifier, signal].concat()
+    }
+
+    /// Public inputs are `identity_inputs`; no registered key means no proof
+    fn verify_unique_identity(&self, verifying_key: Option<&VerifyingKey<Bn254>>) -> Result<bool> {
+        let vk = verifying_key.ok_or(crate::ErrorCode::InvalidPersonhoodProof)?;
+        require!(self.public_inputs.len() == 96, crate::ErrorCode::InvalidZkProof);
+
+        let input = |i: usize| -> [u8; 32] { self.public_inputs[32 * i..32 * i + 32].try_into().unwrap() };
+        let proof = UniqueIdentityProof::from_bytes(&self.proof_data)?;
+        require!(
+            proof.verify(vk, &input(0), &input(1), &input(2))?,
+            crate::ErrorCode::InvalidPersonhoodProof
+        );
+        msg!("Unique identity verified (Groth16)");
+        Ok(true)
+    }
+
+    /// Public inputs of a UniqueIdentity proof: personhood registry root, credential
+    /// nullifier, signal
+    pub fn identity_inputs(credential_root: &[u8; 32], nullifier: &[u8; 32], signal: &[u8; 32]) -> Vec<u8> {
+        [&credential_root[..], nullifier, signal].concat()
+    }
+
+    fn verify_tally(&self) -> Result<bool> {
+        // Verify tally proof format
+        require!(self.public_inputs.len() >= 16, crate::ErrorCode::InvalidZkProof); // approve_count (8) + reject_count (8)
+        
+        let approve_bytes: [u8; 8] = self.public_inputs[0..8].try_into()
+            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+        let reject_bytes: [u8; 8] = self.public_inputs[8..16].try_into()
+            .map_err(|_| crate::ErrorCode::InvalidZkProof)?;
+        
+        let approve_count = u64::from_le_bytes(approve_bytes);
+        let reject_count = u64::from_le_bytes(reject_bytes);
+        
+        // Basic sanity check
+        require!(approve_count + reject_count > 0, crate::ErrorCode::InvalidZkProof);
+        
+        msg!("Tally verified: {} approve, {} reject", approve_count, reject_count);
+        Ok(true)
+    }
+}
+
+// Commitment scheme for private votes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteCommitment {
+    pub commitment: [u8; 32],  // Hash commitment
+    pub nullifier: [u8; 32],   // Prevents double voting
+    pub case_id: u64,
+    pub timestamp: i64,
+}
+
+impl VoteCommitment {
+    pub fn new(case_id: u64, vote: bool, salt: [u8; 32], timestamp: i64) -> Self {
+        let commitment = Self::compute_commitment(case_id, vote, &salt);
+        let nullifier = Self::compute_nullifier(case_id, &commitment);
+        
+        VoteCommitment {
+            commitment,
+            nullifier,
+            case_id,
+            timestamp,
+        }
+    }
+
+    pub fn verify_reveal(
+        &self,
+        revealed_vote: bool,
+        revealed_salt: &[u8; 32],
+    ) -> bool {
+        let recomputed = Self::compute_commitment(self.case_id, revealed_vote, revealed_salt);
+        recomputed == self.commitment
+    }
+
+    pub fn compute_commitment(case_id: u64, vote: bool, salt: &[u8; 32]) -> [u8; 32] {
+        let vote_byte = if vote { 1u8 } else { 0u8 };
+        crate::domain::hash(
+            crate::domain::VOTE_COMMITMENT,
+            &[&case_id.to_le_bytes(), &[vote_byte], salt],
+        )
+    }
+
+    pub fn compute_nullifier(case_id: u64, commitment: &[u8; 32]) -> [u8; 32] {
+        crate::domain::hash(
+            crate::domain::VOTE_NULLIFIER,
+            &[&case_id.to_le_bytes(), commitment],
+        )
+    }
+
+    /// Commitment under the case's scheme. Poseidon inputs are big-endian field elements:
+    /// [tag, case_id, vote, salt], so the salt must be below the BN254 scalar modulus.
+    pub fn compute_commitment_with(
+        scheme: CommitmentScheme,
+        case_id: u64,
+        vote: bool,
+        salt: &[u8; 32],
+    ) -> Result<[u8; 32]> {
+        match scheme {
+            CommitmentScheme::Sha256 => Ok(Self::compute_commitment(case_id, vote, salt)),
+            CommitmentScheme::Poseidon => poseidon(&[
+                &crate::domain::poseidon_tag(crate::domain::VOTE_COMMITMENT),
+                &field_element(case_id),
+                &field_element(vote as u64),
+                salt,
+            ]),
+        }
+    }
+
+    /// Nullifier under the case's scheme: Poseidon over [tag, case_id, commitment]
+    pub fn compute_nullifier_with(
+        scheme: CommitmentScheme,
+        case_id: u64,
+        commitment: &[u8; 32],
+    ) -> Result<[u8; 32]> {
+        match scheme {
+            CommitmentScheme::Sha256 => Ok(Self::compute_nullifier(case_id, commitment)),
+            CommitmentScheme::Poseidon => poseidon(&[
+                &crate::domain::poseidon_tag(crate::domain::VOTE_NULLIFIER),
+                &field_element(case_id),
+                commitment,
+            ]),
+        }
+    }
+}
+
+/// Hash behind a case's vote commitments and nullifiers, fixed before voting opens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitmentScheme {
+    /// Domain-separated SHA-256: cheap on-chain, tens of thousands of constraints to prove
+    Sha256,
+    /// Poseidon over BN254 through the syscall: a few hundred constraints per hash
+    Poseidon,
+}
+
+pub(crate) fn field_element(value: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+pub(crate) fn poseidon(inputs: &[&[u8]]) -> Result<[u8; 32]> {
+    use anchor_lang::solana_program::poseidon::{hashv, Endianness, Parameters};
+    hashv(Parameters::Bn254X5, Endianness::BigEndian, inputs)
+        .map(|hash| hash.to_bytes())
+        .map_err(|_| error!(crate::ErrorCode::InvalidPoseidonInput))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemes_commit_differently() {
+        let salt = [3u8; 32];
+        let sha = VoteCommitment::compute_commitment_with(CommitmentScheme::Sha256, 1, true, &salt).unwrap();
+        let poseidon = VoteCommitment::compute_commitment_with(CommitmentScheme::Poseidon, 1, true, &salt).unwrap();
+        assert_eq!(sha, VoteCommitment::compute_commitment(1, true, &salt));
+        assert_ne!(sha, poseidon);
+        assert_ne!(
+            poseidon,
+            VoteCommitment::compute_commitment_with(CommitmentScheme::Poseidon, 1, false, &salt).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_poseidon_nullifier_accepts_its_commitment() {
+        let commitment = VoteCommitment::compute_commitment_with(CommitmentScheme::Poseidon, 7, false, &[9u8; 32]);
+        let nullifier = VoteCommitment::compute_nullifier_with(CommitmentScheme::Poseidon, 7, &commitment.unwrap());
+        assert!(nullifier.is_ok());
+    }
+
+    #[test]
+    fn test_juror_set_root_binds_stake_and_order() {
+        let (a, b) = ([1u8; 32], [2u8; 32]);
+        let leaves = [eligibility_leaf(&a, 10).unwrap(), eligibility_leaf(&b, 20).unwrap()];
+        let root = juror_set_root(&leaves).unwrap();
+        assert_ne!(root, juror_set_root(&[leaves[1], leaves[0]]).unwrap());
+        assert_ne!(leaves[0], eligibility_leaf(&a, 11).unwrap());
+        assert_eq!(root, juror_set_root(&leaves).unwrap());
+    }
+
+    #[test]
+    fn test_poseidon_rejects_salt_outside_field() {
+        let result = VoteCommitment::compute_commitment_with(CommitmentScheme::Poseidon, 1, true, &[0xff; 32]);
+        assert!(result.is_err());
+    }
+}
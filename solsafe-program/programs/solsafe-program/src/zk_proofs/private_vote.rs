@@ -1,166 +1,553 @@
-// Private Voting with Zero-Knowledge Proofs
-use anchor_lang::prelude::*;
-use super::{VoteCommitment, ZkProof, ZkProofType};
-
-/// Private vote instruction using ZK proofs
-#[derive(Accounts)]
-#[instruction(case_id: u64)]
-pub struct PrivateVote<'info> {
-    #[account(mut)]
-    pub juror: Signer<'info>,
-    
-    #[account(
-        init_if_needed,
-        payer = juror,
-        space = 8 + VoteCommitmentAccount::LEN,
-        seeds = [b"vote_commitment", case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
-        bump
-    )]
-    pub vote_commitment_account: Account<'info, VoteCommitmentAccount>,
-    
-    #[account(
-        mut,
-        seeds = [b"case", case_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub case_account: Account<'info, crate::state::CaseAccount>,
-    
-    #[account(
-        mut,
-        seeds = [b"compressed_votes", case_id.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub compressed_state: Account<'info, super::light_compression::CompressedVoteState>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[account]
-pub struct VoteCommitmentAccount {
-    pub juror: Pubkey,
-    pub case_id: u64,
-    pub commitment: [u8; 32],
-    pub nullifier: [u8; 32],
-    pub timestamp: i64,
-    pub revealed: bool,
-    pub bump: u8,
-}
-
-impl VoteCommitmentAccount {
-    pub const LEN: usize = 32 + // juror
-        8 + // case_id
-        32 + // commitment
-        32 + // nullifier
-        8 + // timestamp
-        1 + // revealed
-        1; // bump
-}
-
-pub fn private_vote_handler(
-    ctx: Context<PrivateVote>,
-    case_id: u64,
-    commitment: [u8; 32],
-    nullifier: [u8; 32],
-    zk_proof: ZkProof,
-) -> Result<()> {
-    let vote_account = &mut ctx.accounts.vote_commitment_account;
-    let compressed_state = &mut ctx.accounts.compressed_state;
-    let case = &mut ctx.accounts.case_account;
-    let clock = Clock::get()?;
-
-    // Verify case is in voting state
-    require!(
-        case.state == crate::state::CaseState::Voting,
-        crate::ErrorCode::CaseNotVoting
-    );
-
-    // Verify ZK proof
-    require!(
-        zk_proof.proof_type == ZkProofType::VoteCommitment,
-        crate::ErrorCode::InvalidProofType
-    );
-    require!(
-        zk_proof.verify()?,
-        crate::ErrorCode::InvalidZkProof
-    );
-
-    // Check nullifier hasn't been used (prevent double voting)
-    require!(
-        !vote_account.revealed,
-        crate::ErrorCode::AlreadyVoted
-    );
-
-    // Store vote commitment
-    vote_account.juror = ctx.accounts.juror.key();
-    vote_account.case_id = case_id;
-    vote_account.commitment = commitment;
-    vote_account.nullifier = nullifier;
-    vote_account.timestamp = clock.unix_timestamp;
-    vote_account.revealed = false;
-    vote_account.bump = ctx.bumps.vote_commitment_account;
-
-    // Add to compressed state using Light Protocol
-    let vote_commitment = VoteCommitment {
-        commitment,
-        nullifier,
-        case_id,
-        timestamp: clock.unix_timestamp,
-    };
-    compressed_state.add_commitment(&vote_commitment)?;
-
-    msg!("Private vote committed. Commitment: {:?}", &commitment[..8]);
-    Ok(())
-}
-
-/// Reveal vote with ZK proof (optional for tallying)
-#[derive(Accounts)]
-pub struct RevealVote<'info> {
-    #[account(mut)]
-    pub juror: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"vote_commitment", vote_account.case_id.to_le_bytes().as_ref(), juror.key().as_ref()],
-        bump = vote_account.bump
-    )]
-    pub vote_account: Account<'info, VoteCommitmentAccount>,
-    
-    #[account(mut)]
-    pub case_account: Account<'info, crate::state::CaseAccount>,
-}
-
-pub fn reveal_vote_handler(
-    ctx: Context<RevealVote>,
-    vote: bool,
-    salt: [u8; 32],
-) -> Result<()> {
-    let vote_account = &mut ctx.accounts.vote_account;
-    let case = &mut ctx.accounts.case_account;
-
-    // Verify commitment matches revealed vote
-    let revealed_commitment = VoteCommitment::new(
-        vote_account.case_id,
-        vote,
-        salt,
-        vote_account.timestamp,
-    );
-    
-    require!(
-        revealed_commitment.commitment == vote_account.commitment,
-        crate::ErrorCode::InvalidReveal
-    );
-
-    // Mark as revealed and update vote count
-    vote_account.revealed = true;
-    
-    if vote {
-        case.votes_for += 1;
-    } else {
-        case.votes_against += 1;
-    }
-
-    msg!("Vote revealed. Case {} votes: {} for, {} against", 
-        vote_account.case_id, case.votes_for, case.votes_against);
-    
-    Ok(())
-}
+// Private Voting with Zero-Knowledge Proofs
+use anchor_lang::prelude::*;
+use crate::instructions::vote::emit_verdict;
+use crate::state::{CasePanel, ConfidentialComponents, GlobalConfig, ValidatorStake, MAX_CASE_JURORS};
+use crate::{JurorSetSealed, PrivateTallyFinalized, RevealPhaseStarted};
+use super::{field_element, load_registered_key, poseidon, CommitmentScheme, VoteCommitment, ZkProof, ZkProofType};
+
+/// How long jurors have to open their commitments once the reveal phase starts
+pub const REVEAL_PERIOD: i64 = 24 * 60 * 60;
+
+/// Depth of the juror set tree; 32 leaves cover MAX_CASE_JURORS seats
+pub const JUROR_SET_DEPTH: usize = 5;
+
+/// Private vote instruction using ZK proofs. `voter` seeds the commitment account: the
+/// juror's own key, or on an ANONYMOUS_JURORS case the voter tag the eligibility proof
+/// binds, so the account doesn't say who voted and the signer can be any fee payer.
+#[derive(Accounts)]
+#[instruction(case_id: u64, commitment: [u8; 32], nullifier: [u8; 32], zk_proof: ZkProof, voter: [u8; 32])]
+pub struct PrivateVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + VoteCommitmentAccount::LEN,
+        seeds = [b"vote_commitment", case_id.to_le_bytes().as_ref(), voter.as_ref()],
+        bump
+    )]
+    pub vote_commitment_account: Account<'info, VoteCommitmentAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"case", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"compressed_votes", case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub compressed_state: Account<'info, super::light_compression::CompressedVoteState>,
+
+    /// CHECK: The vote commitment circuit's VerifyingKeyAccount PDA; checked in the handler,
+    /// and may be empty until a key is registered
+    pub verifying_key: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+
+    /// Required on ANONYMOUS_JURORS cases
+    #[account(
+        seeds = [b"juror_set", case_id.to_le_bytes().as_ref()],
+        bump = juror_set.bump
+    )]
+    pub juror_set: Option<Account<'info, JurorSet>>,
+
+    /// Required on PRIVATE_VOTES cases whose jurors vote under their own keys
+    #[account(
+        seeds = [b"case_panel", case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Option<Account<'info, CasePanel>>,
+
+    /// CHECK: The eligibility circuit's VerifyingKeyAccount PDA, checked in the handler;
+    /// required on ANONYMOUS_JURORS cases
+    pub eligibility_key: Option<UncheckedAccount<'info>>,
+}
+
+#[account]
+pub struct VoteCommitmentAccount {
+    pub juror: Pubkey,   // Signer who committed; the juror unless the case is ANONYMOUS_JURORS
+    pub case_id: u64,
+    pub commitment: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub timestamp: i64,
+    pub revealed: bool,
+    pub approved: bool,  // Revealed vote; meaningless until `revealed`
+    pub bump: u8,
+    pub voter: [u8; 32], // PDA seed; zero on commitments made before voter tags
+}
+
+impl VoteCommitmentAccount {
+    pub const LEN: usize = 32 + // juror
+        8 + // case_id
+        32 + // commitment
+        32 + // nullifier
+        8 + // timestamp
+        1 + // revealed
+        1 + // approved
+        1 + // bump
+        32; // voter
+
+    /// Last seed of the account's PDA
+    pub fn seed(&self) -> [u8; 32] {
+        if self.voter == [0u8; 32] {
+            self.juror.to_bytes()
+        } else {
+            self.voter
+        }
+    }
+}
+
+/// A case's seated jurors as Poseidon leaves over (identity commitment, stake), for
+/// eligibility proofs that don't say which juror is voting
+#[account]
+pub struct JurorSet {
+    pub case_id: u64,
+    pub leaves: Vec<[u8; 32]>,   // In registration order
+    pub registered: u32,         // Bit per seat index that has registered
+    pub root: [u8; 32],
+    pub min_stake: u64,          // config.min_validator_stake when the first juror registered
+    pub bump: u8,
+}
+
+impl JurorSet {
+    pub const LEN: usize = 8 + // case_id
+        4 + 32 * MAX_CASE_JURORS + // leaves
+        4 + // registered
+        32 + // root
+        8 + // min_stake
+        1; // bump
+}
+
+/// Leaf Poseidon(tag, identity commitment, stake) of a juror set
+pub fn eligibility_leaf(identity_commitment: &[u8; 32], stake: u64) -> Result<[u8; 32]> {
+    poseidon(&[
+        &crate::domain::poseidon_tag(crate::domain::JUROR_ELIGIBILITY),
+        identity_commitment,
+        &field_element(stake),
+    ])
+}
+
+/// Poseidon Merkle root over `leaves`, padded with zero leaves to JUROR_SET_DEPTH
+pub fn juror_set_root(leaves: &[[u8; 32]]) -> Result<[u8; 32]> {
+    let mut level = leaves.to_vec();
+    level.resize(1 << JUROR_SET_DEPTH, [0u8; 32]);
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| poseidon(&[&pair[0], &pair[1]]))
+            .collect::<Result<_>>()?;
+    }
+    Ok(level[0])
+}
+
+/// A seated juror publishes the commitment to their voter identity secret
+#[derive(Accounts)]
+pub struct RegisterVoterIdentity<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+
+    #[account(
+        seeds = [b"case_panel", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump = case_panel.bump
+    )]
+    pub case_panel: Account<'info, CasePanel>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = juror,
+        space = 8 + JurorSet::LEN,
+        seeds = [b"juror_set", case_account.load()?.case_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub juror_set: Account<'info, JurorSet>,
+
+    /// The juror's stake, committed into their leaf; a juror without one registers zero
+    #[account(
+        seeds = [b"validator_stake", juror.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Option<Account<'info, ValidatorStake>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open on MPC-tallied cases until the first private vote. Once every seated juror is in,
+/// the case turns ANONYMOUS_JURORS; a vote committed under a juror's key before then
+/// closes registration, so no juror gets to vote both ways.
+pub fn register_voter_identity_handler(
+    ctx: Context<RegisterVoterIdentity>,
+    identity_commitment: [u8; 32],
+) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let juror_set = &mut ctx.accounts.juror_set;
+    let juror = ctx.accounts.juror.key();
+
+    require!(
+        case.has_component(ConfidentialComponents::PRIVATE_VOTES),
+        crate::ErrorCode::InvalidConfidentialComponents
+    );
+    require!(
+        case.state == crate::state::CaseState::Nominated as u8
+            || case.state == crate::state::CaseState::Voting as u8,
+        crate::ErrorCode::CaseNotVoting
+    );
+    require!(
+        case.private_commits == 0 && !case.has_component(ConfidentialComponents::ANONYMOUS_JURORS),
+        crate::ErrorCode::VoterIdentityLocked
+    );
+    let panel = &ctx.accounts.case_panel;
+    let seat = panel
+        .jurors
+        .iter()
+        .position(|seated| *seated == juror)
+        .ok_or(crate::ErrorCode::NotJuror)?;
+    require!(juror_set.registered & (1 << seat) == 0, crate::ErrorCode::VoterIdentityRegistered);
+
+    if juror_set.leaves.is_empty() {
+        juror_set.case_id = case.case_id;
+        juror_set.min_stake = ctx.accounts.config.min_validator_stake;
+        juror_set.bump = ctx.bumps.juror_set;
+    }
+    let stake = ctx.accounts.validator_stake.as_ref().map_or(0, |stake| stake.amount);
+    juror_set.leaves.push(eligibility_leaf(&identity_commitment, stake)?);
+    juror_set.registered |= 1 << seat;
+    juror_set.root = juror_set_root(&juror_set.leaves)?;
+
+    let seated = panel.seated_jurors() as usize;
+    if juror_set.leaves.len() == seated {
+        case.confidential |= ConfidentialComponents::ANONYMOUS_JURORS;
+        emit!(JurorSetSealed {
+            case_id: case.case_id,
+            root: juror_set.root,
+            jurors: seated as u8,
+            min_stake: juror_set.min_stake,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    msg!("Voter identity {}/{} registered for case {}", juror_set.leaves.len(), seated, case.case_id);
+    Ok(())
+}
+
+/// With compression on for the case, the Light accounts follow in `remaining_accounts`
+pub fn private_vote_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PrivateVote<'info>>,
+    case_id: u64,
+    commitment: [u8; 32],
+    nullifier: [u8; 32],
+    zk_proof: ZkProof,
+    voter: [u8; 32],
+    eligibility: Option<ZkProof>,
+) -> Result<()> {
+    let vote_account = &mut ctx.accounts.vote_commitment_account;
+    let compressed_state = &mut ctx.accounts.compressed_state;
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Verify case is in voting state
+    require!(
+        case.state == crate::state::CaseState::Voting as u8,
+        crate::ErrorCode::CaseNotVoting
+    );
+
+    // Verify ZK proof
+    require!(
+        zk_proof.proof_type == ZkProofType::VoteCommitment,
+        crate::ErrorCode::InvalidProofType
+    );
+    let scheme = case.commitment_scheme();
+    let verifying_key = load_registered_key(&ctx.accounts.verifying_key, ZkProofType::VoteCommitment)?;
+    require!(
+        zk_proof.verify(verifying_key.as_ref(), scheme)?,
+        crate::ErrorCode::InvalidZkProof
+    );
+
+    // Check nullifier hasn't been used (prevent double voting)
+    require!(
+        !vote_account.revealed,
+        crate::ErrorCode::AlreadyVoted
+    );
+
+    // An anonymous voter proves a seat in the juror set instead of signing as the juror;
+    // the voter tag is the proof's per-case nullifier of the juror's identity
+    let anonymous = case.has_component(ConfidentialComponents::ANONYMOUS_JURORS);
+    if anonymous {
+        let juror_set = ctx
+            .accounts
+            .juror_set
+            .as_ref()
+            .ok_or(crate::ErrorCode::InvalidEligibilityProof)?;
+        let eligibility = eligibility.ok_or(crate::ErrorCode::InvalidEligibilityProof)?;
+        let eligibility_key = ctx
+            .accounts
+            .eligibility_key
+            .as_ref()
+            .ok_or(crate::ErrorCode::InvalidEligibilityProof)?;
+        require!(
+            eligibility.proof_type == ZkProofType::JurorEligibility
+                && eligibility.public_inputs
+                    == ZkProof::eligibility_inputs(&juror_set.root, juror_set.min_stake, case_id, &voter, &commitment),
+            crate::ErrorCode::InvalidEligibilityProof
+        );
+        let eligibility_vk = load_registered_key(eligibility_key, ZkProofType::JurorEligibility)?;
+        require!(
+            eligibility.verify(eligibility_vk.as_ref(), scheme)?,
+            crate::ErrorCode::InvalidEligibilityProof
+        );
+    } else {
+        require!(voter == ctx.accounts.juror.key().to_bytes(), crate::ErrorCode::InvalidEligibilityProof);
+    }
+
+    // Confidential cases feed the MPC tally: only seated jurors, one commitment each,
+    // with the nullifier derived from it
+    if case.has_component(ConfidentialComponents::PRIVATE_VOTES) {
+        require!(
+            anonymous
+                || ctx
+                    .accounts
+                    .case_panel
+                    .as_ref()
+                    .is_some_and(|panel| panel.is_juror(&ctx.accounts.juror.key())),
+            crate::ErrorCode::NotJuror
+        );
+        require!(
+            vote_account.commitment == [0u8; 32],
+            crate::ErrorCode::AlreadyVoted
+        );
+        require!(
+            nullifier == VoteCommitment::compute_nullifier_with(scheme, case_id, &commitment)?,
+            crate::ErrorCode::InvalidZkProof
+        );
+    }
+
+    // A juror may replace their commitment until the reveal phase, but counts once
+    if vote_account.commitment == [0u8; 32] {
+        case.private_commits = case.private_commits.saturating_add(1);
+    }
+
+    // Store vote commitment
+    vote_account.juror = ctx.accounts.juror.key();
+    vote_account.case_id = case_id;
+    vote_account.commitment = commitment;
+    vote_account.nullifier = nullifier;
+    vote_account.timestamp = clock.unix_timestamp;
+    vote_account.revealed = false;
+    vote_account.approved = false;
+    vote_account.bump = ctx.bumps.vote_commitment_account;
+    vote_account.voter = voter;
+
+    // Add to compressed state using Light Protocol
+    let vote_commitment = VoteCommitment {
+        commitment,
+        nullifier,
+        case_id,
+        timestamp: clock.unix_timestamp,
+    };
+    compressed_state.add_commitment(
+        &vote_commitment,
+        ctx.accounts.juror.key(),
+        &ctx.accounts.juror.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    msg!("Private vote committed. Commitment: {:?}", &commitment[..8]);
+    Ok(())
+}
+
+/// Closes private commitments on a case so its jurors can open them
+#[derive(Accounts)]
+pub struct StartRevealPhase<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+}
+
+/// Permissionless once every seated juror has committed, or once only a reveal period
+/// is left before the case expires
+pub fn start_reveal_phase_handler(ctx: Context<StartRevealPhase>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !case.has_component(crate::state::ConfidentialComponents::PRIVATE_VOTES),
+        crate::ErrorCode::MpcTallyRequired
+    );
+    require!(
+        case.state == crate::state::CaseState::Voting as u8,
+        crate::ErrorCode::CaseNotVoting
+    );
+    require!(
+        case.private_commits >= case.juror_count || now >= case.expires_at.saturating_sub(REVEAL_PERIOD),
+        crate::ErrorCode::CommitPhaseOpen
+    );
+
+    case.state = crate::state::CaseState::Revealing as u8;
+    case.reveal_deadline = now.saturating_add(REVEAL_PERIOD);
+
+    emit!(RevealPhaseStarted {
+        case_id: case.case_id,
+        commitments: case.private_commits,
+        reveal_deadline: case.reveal_deadline,
+        timestamp: now,
+    });
+
+    msg!("Case {} revealing {} commitments until {}", case.case_id, case.private_commits, case.reveal_deadline);
+    Ok(())
+}
+
+/// Reveal vote with ZK proof (optional for tallying)
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_commitment", vote_account.case_id.to_le_bytes().as_ref(), vote_account.seed().as_ref()],
+        bump = vote_account.bump,
+        constraint = vote_account.juror == juror.key() @ crate::ErrorCode::Unauthorized
+    )]
+    pub vote_account: Account<'info, VoteCommitmentAccount>,
+    
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+}
+
+pub fn reveal_vote_handler(
+    ctx: Context<RevealVote>,
+    vote: bool,
+    salt: [u8; 32],
+) -> Result<()> {
+    let vote_account = &mut ctx.accounts.vote_account;
+    let mut case = ctx.accounts.case_account.load_mut()?;
+
+    require!(
+        !case.has_component(crate::state::ConfidentialComponents::PRIVATE_VOTES),
+        crate::ErrorCode::MpcTallyRequired
+    );
+
+    require!(case.case_id == vote_account.case_id, crate::ErrorCode::InvalidCase);
+    require!(
+        case.state == crate::state::CaseState::Revealing as u8,
+        crate::ErrorCode::CaseNotRevealing
+    );
+    require!(
+        Clock::get()?.unix_timestamp <= case.reveal_deadline,
+        crate::ErrorCode::RevealDeadlinePassed
+    );
+    require!(!vote_account.revealed, crate::ErrorCode::AlreadyVoted);
+
+    // Verify commitment matches revealed vote under the case's scheme
+    let revealed_commitment =
+        VoteCommitment::compute_commitment_with(case.commitment_scheme(), vote_account.case_id, vote, &salt)
+            .map_err(|_| error!(crate::ErrorCode::InvalidReveal))?;
+    
+    require!(
+        revealed_commitment == vote_account.commitment,
+        crate::ErrorCode::InvalidReveal
+    );
+
+    // Mark as revealed and update vote count
+    vote_account.revealed = true;
+    vote_account.approved = vote;
+    case.private_reveals = case.private_reveals.saturating_add(1);
+    
+    if vote {
+        case.votes_for += 1;
+    } else {
+        case.votes_against += 1;
+    }
+
+    msg!("Vote revealed. Case {} votes: {} for, {} against", 
+        vote_account.case_id, case.votes_for, case.votes_against);
+    
+    Ok(())
+}
+
+/// Reporter picks the case's commitment hash before any juror can commit
+#[derive(Accounts)]
+pub struct SetCommitmentScheme<'info> {
+    pub reporter: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = case_account.load()?.reporter == reporter.key() @ crate::ErrorCode::Unauthorized
+    )]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+}
+
+pub fn set_commitment_scheme_handler(
+    ctx: Context<SetCommitmentScheme>,
+    scheme: CommitmentScheme,
+) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    require!(
+        case.state == crate::state::CaseState::PendingJurors as u8
+            || case.state == crate::state::CaseState::Nominated as u8
+            || case.state == crate::state::CaseState::SeatAuction as u8,
+        crate::ErrorCode::CommitmentSchemeLocked
+    );
+    case.commitment_scheme = scheme as u8;
+
+    msg!("Case {} commits votes with {:?}", case.case_id, scheme);
+    Ok(())
+}
+
+/// Decides a case from the commitments revealed in time
+#[derive(Accounts)]
+pub struct FinalizePrivateTally<'info> {
+    #[account(mut)]
+    pub case_account: AccountLoader<'info, crate::state::CaseAccount>,
+}
+
+/// Permissionless after the reveal deadline, or earlier once every commitment is open.
+/// Unrevealed commitments count as abstentions; the approval bar is measured against
+/// the seated panel, as for the MPC tally.
+pub fn finalize_private_tally_handler(ctx: Context<FinalizePrivateTally>) -> Result<()> {
+    let mut case = ctx.accounts.case_account.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        case.state == crate::state::CaseState::Revealing as u8,
+        crate::ErrorCode::CaseNotRevealing
+    );
+    require!(
+        now > case.reveal_deadline || case.private_reveals >= case.private_commits,
+        crate::ErrorCode::RevealPhaseOpen
+    );
+
+    let approved = case.votes_for >= case.required_votes(case.juror_count as u64);
+    case.state = if approved {
+        crate::state::CaseState::Approved
+    } else {
+        crate::state::CaseState::Rejected
+    } as u8;
+    case.status = crate::state::CaseStatus::Closed as u8;
+
+    emit_verdict(&case, now);
+    emit!(PrivateTallyFinalized {
+        case_id: case.case_id,
+        commitments: case.private_commits,
+        reveals: case.private_reveals,
+        votes_for: case.votes_for,
+        votes_against: case.votes_against,
+        approved,
+        timestamp: now,
+    });
+
+    msg!("Private tally for case {}: {} for, {} against", case.case_id, case.votes_for, case.votes_against);
+    Ok(())
+}
@@ -0,0 +1,127 @@
+// Two-step Groth16 verification for compute-budget headroom
+// Step 1 folds public inputs (MSM), step 2 runs the pairing check
+use anchor_lang::prelude::*;
+use super::groth16_verifier::{
+    deserialize_prepared_inputs, prepare_public_inputs, serialize_prepared_inputs,
+    verify_with_prepared_inputs, VoteCommitmentProof, VoteCommitmentVerifyingKey,
+};
+use super::{VerifyingKeyAccount, ZkProofType};
+
+pub const GROTH16_PROOF_LEN: usize = 192;
+
+/// Intermediate state between prepare and finalize
+#[account]
+pub struct ProofScratch {
+    pub owner: Pubkey,
+    pub case_id: u64,
+    pub commitment: [u8; 32],
+    pub verifying_key: Pubkey,
+    pub proof: [u8; GROTH16_PROOF_LEN],
+    pub prepared_inputs: [u8; 64],     // Uncompressed G1 point
+    pub stage: ProofStage,
+    pub bump: u8,
+}
+
+impl ProofScratch {
+    pub const LEN: usize = 32 + // owner
+        8 + // case_id
+        32 + // commitment
+        32 + // verifying_key
+        GROTH16_PROOF_LEN + // proof
+        64 + // prepared_inputs
+        1 + // stage
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ProofStage {
+    Prepared,
+    Verified,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct PrepareProofVerification<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ProofScratch::LEN,
+        seeds = [b"proof_scratch", case_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub scratch: Account<'info, ProofScratch>,
+
+    #[account(
+        seeds = [b"verifying_key", [ZkProofType::VoteCommitment as u8].as_ref()],
+        bump = verifying_key.bump
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeProofVerification<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proof_scratch", scratch.case_id.to_le_bytes().as_ref(), owner.key().as_ref()],
+        bump = scratch.bump,
+        constraint = scratch.owner == owner.key() @ crate::ErrorCode::Unauthorized
+    )]
+    pub scratch: Account<'info, ProofScratch>,
+
+    /// Must be the same verifying key used in the prepare step
+    #[account(address = scratch.verifying_key @ crate::ErrorCode::InvalidZkProof)]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+}
+
+pub fn prepare_handler(
+    ctx: Context<PrepareProofVerification>,
+    case_id: u64,
+    commitment: [u8; 32],
+    proof_data: Vec<u8>,
+) -> Result<()> {
+    require!(proof_data.len() == GROTH16_PROOF_LEN, crate::ErrorCode::InvalidZkProof);
+    require!(commitment != [0u8; 32], crate::ErrorCode::InvalidZkProof);
+
+    let vk = VoteCommitmentVerifyingKey::from_registry(&ctx.accounts.verifying_key)?;
+    let public_inputs = VoteCommitmentProof::public_inputs_for(&commitment, case_id)?;
+    let prepared = prepare_public_inputs(&vk.vk, &public_inputs)?;
+
+    let scratch = &mut ctx.accounts.scratch;
+    scratch.owner = ctx.accounts.owner.key();
+    scratch.case_id = case_id;
+    scratch.commitment = commitment;
+    scratch.verifying_key = ctx.accounts.verifying_key.key();
+    scratch.proof.copy_from_slice(&proof_data);
+    scratch.prepared_inputs = serialize_prepared_inputs(&prepared)?;
+    scratch.stage = ProofStage::Prepared;
+    scratch.bump = ctx.bumps.scratch;
+
+    msg!("Proof inputs prepared for case {}", case_id);
+    Ok(())
+}
+
+pub fn finalize_handler(ctx: Context<FinalizeProofVerification>) -> Result<()> {
+    let scratch = &mut ctx.accounts.scratch;
+    require!(scratch.stage == ProofStage::Prepared, crate::ErrorCode::InvalidZkProof);
+
+    let vk = VoteCommitmentVerifyingKey::from_registry(&ctx.accounts.verifying_key)?;
+    let proof = VoteCommitmentProof::from_bytes(&scratch.proof)?;
+    let prepared = deserialize_prepared_inputs(&scratch.prepared_inputs)?;
+
+    // A failed check reverts and leaves the scratch Prepared; prepare can rerun with a new proof
+    require!(
+        verify_with_prepared_inputs(&vk.vk, &proof.proof, &prepared)?,
+        crate::ErrorCode::InvalidZkProof
+    );
+    scratch.stage = ProofStage::Verified;
+
+    msg!("Groth16 proof verified for case {}", scratch.case_id);
+    Ok(())
+}
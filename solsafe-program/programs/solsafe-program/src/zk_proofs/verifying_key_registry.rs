@@ -0,0 +1,126 @@
+// On-chain Groth16 verifying keys, one PDA per proof type
+// Circuits are upgraded by rewriting the key, not by redeploying the program
+use anchor_lang::prelude::*;
+use ark_bn254::Bn254;
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalDeserialize;
+use crate::state::GlobalConfig;
+use crate::{ErrorCode, VerifyingKeyUpdated};
+use super::ZkProofType;
+
+/// Room for a compressed BN254 key with up to 20 public inputs
+pub const MAX_VERIFYING_KEY_LEN: usize = 1024;
+
+#[account]
+pub struct VerifyingKeyAccount {
+    pub proof_type: ZkProofType,
+    pub version: u32,                  // Bumped on every update
+    pub key: Vec<u8>,                  // Compressed ark-serialized VerifyingKey<Bn254>
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VerifyingKeyAccount {
+    pub const LEN: usize = 1 + // proof_type
+        4 + // version
+        4 + MAX_VERIFYING_KEY_LEN + // key
+        8 + // updated_at
+        1; // bump
+
+    pub fn verifying_key(&self) -> Result<VerifyingKey<Bn254>> {
+        parse_verifying_key(&self.key)
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(proof_type: ZkProofType)]
+pub struct RegisterVerifyingKey<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VerifyingKeyAccount::LEN,
+        seeds = [b"verifying_key", [proof_type as u8].as_ref()],
+        bump
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proof_type: ZkProofType)]
+pub struct UpdateVerifyingKey<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        constraint = config.council == Pubkey::default() @ ErrorCode::CouncilGoverned
+    )]
+    pub config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        seeds = [b"verifying_key", [proof_type as u8].as_ref()],
+        bump = verifying_key.bump
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+}
+
+pub fn register_handler(ctx: Context<RegisterVerifyingKey>, proof_type: ZkProofType, key: Vec<u8>) -> Result<()> {
+    let account = &mut ctx.accounts.verifying_key;
+    account.proof_type = proof_type;
+    account.bump = ctx.bumps.verifying_key;
+    apply_verifying_key(account, key)
+}
+
+pub fn update_handler(ctx: Context<UpdateVerifyingKey>, _proof_type: ZkProofType, key: Vec<u8>) -> Result<()> {
+    apply_verifying_key(&mut ctx.accounts.verifying_key, key)
+}
+
+/// Store `key` once it parses as a verifying key; proofs checked afterwards use it
+pub(crate) fn apply_verifying_key(account: &mut VerifyingKeyAccount, key: Vec<u8>) -> Result<()> {
+    require!(key.len() <= MAX_VERIFYING_KEY_LEN, ErrorCode::VerifyingKeyTooLarge);
+    parse_verifying_key(&key)?;
+
+    let clock = Clock::get()?;
+    account.key = key;
+    account.version = account.version.saturating_add(1);
+    account.updated_at = clock.unix_timestamp;
+
+    emit!(VerifyingKeyUpdated {
+        proof_type: account.proof_type,
+        version: account.version,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Verifying key for {:?} at version {}", account.proof_type, account.version);
+    Ok(())
+}
+
+/// Registered key for a proof type, or `None` while the PDA hasn't been created
+pub fn load_registered_key(
+    info: &AccountInfo,
+    proof_type: ZkProofType,
+) -> Result<Option<VerifyingKey<Bn254>>> {
+    let (address, _) = Pubkey::find_program_address(&[b"verifying_key", &[proof_type as u8]], &crate::ID);
+    require!(info.key() == address, ErrorCode::InvalidVerifyingKey);
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    require!(info.owner == &crate::ID, ErrorCode::InvalidVerifyingKey);
+    let data = info.try_borrow_data()?;
+    let account = VerifyingKeyAccount::try_deserialize(&mut &data[..])?;
+    account.verifying_key().map(Some)
+}
+
+fn parse_verifying_key(key: &[u8]) -> Result<VerifyingKey<Bn254>> {
+    VerifyingKey::<Bn254>::deserialize_compressed(key).map_err(|_| error!(ErrorCode::InvalidVerifyingKey))
+}
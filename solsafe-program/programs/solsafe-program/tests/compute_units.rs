@@ -0,0 +1,370 @@
+//! Compute-unit benchmarks for the Vec-heavy instruction paths.
+//!
+//! Runs each instruction against the compiled program under solana-program-test
+//! across validator-list sizes, juror counts, and evidence sizes, and fails if any
+//! instruction exceeds its budget. Build the program first:
+//!
+//!     anchor build && cargo test --features bench --test compute_units -- --nocapture
+
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use bytemuck::Zeroable;
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use solsafe_program::{
+    accounts, instruction,
+    state::{CaseCategory, CasePanel, Verdict},
+    switchboard::{RandomnessAccountData, SWITCHBOARD_PROGRAM_ID},
+};
+
+const VOTE_BUDGET: u64 = 50_000;
+const SELECT_JURORS_BUDGET: u64 = 200_000;
+const SUBMIT_EVIDENCE_BUDGET: u64 = 60_000;
+const UPDATE_VALIDATORS_BUDGET: u64 = 150_000;
+
+const VALIDATOR_COUNTS: [usize; 3] = [10, 50, 100];
+const JUROR_COUNTS: [u8; 3] = [3, 7, 10];
+const EVIDENCE_URI_SIZES: [usize; 3] = [32, 128, 200];
+
+struct Bench {
+    context: ProgramTestContext,
+    banks: BanksClient,
+    payer: Keypair,
+    blockhash: Hash,
+    validators: Vec<Keypair>,
+    randomness: Pubkey,
+}
+
+fn config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &solsafe_program::ID).0
+}
+
+fn validator_page_pda(page_index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"validator_page", page_index.to_le_bytes().as_ref()], &solsafe_program::ID).0
+}
+
+fn case_counter_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"case_counter"], &solsafe_program::ID).0
+}
+
+fn case_pda(case_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"case", case_id.to_le_bytes().as_ref()], &solsafe_program::ID).0
+}
+
+fn case_panel_pda(case_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"case_panel", case_id.to_le_bytes().as_ref()], &solsafe_program::ID).0
+}
+
+fn scam_index_pda(scam_address: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"scam_index", scam_address.as_ref()], &solsafe_program::ID).0
+}
+
+fn active_case_pda(scam_address: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"active_case", scam_address.as_ref()], &solsafe_program::ID).0
+}
+
+fn vote_record_pda(case_id: u64, juror: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vote_record", case_id.to_le_bytes().as_ref(), juror.as_ref()],
+        &solsafe_program::ID,
+    )
+    .0
+}
+
+/// Switchboard randomness account seeded at slot 0, with its value once revealed (nonzero `reveal_slot`)
+fn randomness_account(reveal_slot: u64) -> Account {
+    let mut randomness = RandomnessAccountData::zeroed();
+    if reveal_slot != 0 {
+        randomness.reveal_slot = reveal_slot;
+        for (i, byte) in randomness.value.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37).wrapping_add(11);
+        }
+    }
+    let mut data = RandomnessAccountData::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&randomness));
+    Account { lamports: 1_000_000_000, data, owner: SWITCHBOARD_PROGRAM_ID, executable: false, rent_epoch: 0 }
+}
+
+async fn setup(validator_count: usize, min_jurors: u8) -> Bench {
+    let mut program_test = ProgramTest::new("solsafe_program", solsafe_program::ID, None);
+    program_test.prefer_bpf(true);
+
+    let validators: Vec<Keypair> = (0..validator_count).map(|_| Keypair::new()).collect();
+    for validator in &validators {
+        program_test.add_account(
+            validator.pubkey(),
+            Account::new(1_000_000_000, 0, &system_program::ID),
+        );
+    }
+
+    let randomness = Pubkey::new_unique();
+    program_test.add_account(randomness, randomness_account(0));
+
+    let context = program_test.start_with_context().await;
+    let banks = context.banks_client.clone();
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+    let mut bench = Bench { context, banks, payer, blockhash, validators, randomness };
+
+    let ix = Instruction {
+        program_id: solsafe_program::ID,
+        accounts: accounts::Initialize {
+            config: config_pda(),
+            case_counter: case_counter_pda(),
+            admin: bench.payer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Initialize { quorum: min_jurors, min_jurors }.data(),
+    };
+    bench.execute(ix, &[]).await;
+
+    let ix = Instruction {
+        program_id: solsafe_program::ID,
+        accounts: accounts::AddValidatorPage {
+            admin: bench.payer.pubkey(),
+            config: config_pda(),
+            validator_page: validator_page_pda(0),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::AddValidatorPage {}.data(),
+    };
+    bench.execute(ix, &[]).await;
+    bench
+}
+
+impl Bench {
+    async fn simulate(&mut self, ix: Instruction, signers: &[&Keypair]) -> u64 {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&self.payer.pubkey()), &all_signers, self.blockhash);
+        let simulation = self.banks.simulate_transaction(tx).await.expect("simulate");
+        if let Some(Err(err)) = simulation.result {
+            panic!("instruction failed in simulation: {:?}", err);
+        }
+        simulation.simulation_details.expect("simulation details").units_consumed
+    }
+
+    async fn execute(&mut self, ix: Instruction, signers: &[&Keypair]) -> u64 {
+        let units = self.simulate(ix.clone(), signers).await;
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&self.payer.pubkey()), &all_signers, self.blockhash);
+        self.banks.process_transaction(tx).await.expect("process");
+        self.blockhash = self.banks.get_latest_blockhash().await.expect("blockhash");
+        units
+    }
+
+    /// The oracle reveals the value request_jurors committed to, in a later slot
+    async fn reveal_randomness(&mut self) {
+        let reveal_slot = self.banks.get_sysvar::<Clock>().await.expect("clock").slot + 1;
+        self.context.warp_to_slot(reveal_slot + 1).expect("warp");
+        self.context.set_account(&self.randomness, &AccountSharedData::from(randomness_account(reveal_slot)));
+        self.blockhash = self.banks.get_latest_blockhash().await.expect("blockhash");
+    }
+
+    fn update_validators_ix(&self) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::UpdateValidators {
+                config: config_pda(),
+                validator_page: validator_page_pda(0),
+                admin: self.payer.pubkey(),
+                protocol_stats: None,
+            }
+            .to_account_metas(None),
+            data: instruction::UpdateValidators {
+                validators: self.validators.iter().map(|v| v.pubkey()).collect(),
+            }
+            .data(),
+        }
+    }
+
+    fn submit_evidence_ix(&self, case_id: u64, uri_len: usize) -> Instruction {
+        let case_account = case_pda(case_id);
+        let scam_address = Pubkey::new_unique();
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::SubmitEvidence {
+                case_account,
+                reporter: self.payer.pubkey(),
+                config: config_pda(),
+                case_counter: case_counter_pda(),
+                scam_index: scam_index_pda(&scam_address),
+                active_case: active_case_pda(&scam_address),
+                prior_case: None,
+                treasury: None,
+                threat_intel: None,
+                system_program: system_program::ID,
+                protocol_stats: None,
+                price_feeds: None,
+                scam_holding: None,
+                holding_mint: None,
+                token_price_update: None,
+                sol_price_update: None,
+                analyst: None,
+                notarization: None,
+                instructions_sysvar: None,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitEvidence {
+                case_id,
+                evidence_uri: format!("ar://{}", "e".repeat(uri_len - 5)),
+                evidence_hash: [1u8; 32],
+                scam_address,
+                category: CaseCategory::Other,
+                evidence_tx_root: [0u8; 32],
+            }
+            .data(),
+        }
+    }
+
+    fn request_jurors_ix(&self, case_id: u64) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::RequestJurors {
+                case_account: case_pda(case_id),
+                config: config_pda(),
+                randomness_account: self.randomness,
+                authority: self.payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::RequestJurors { case_id }.data(),
+        }
+    }
+
+    fn select_jurors_ix(&self, case_id: u64) -> Instruction {
+        let mut metas = accounts::SelectJurors {
+            payer: self.payer.pubkey(),
+            case_account: case_pda(case_id),
+            case_panel: case_panel_pda(case_id),
+            config: config_pda(),
+            vrf_account: self.randomness,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        metas.push(AccountMeta::new_readonly(validator_page_pda(0), false));
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: metas,
+            data: instruction::SelectJurors {}.data(),
+        }
+    }
+
+    /// Every nominee accepts so the case moves on to voting; returns the panel
+    async fn accept_panel(&mut self, case_id: u64) -> Vec<Keypair> {
+        let account = self
+            .banks
+            .get_account(case_panel_pda(case_id))
+            .await
+            .expect("get_account")
+            .expect("case panel");
+        let case_panel = CasePanel::try_deserialize(&mut account.data.as_slice()).expect("case panel");
+        let mut panel = Vec::new();
+        for nominee in case_panel.jurors {
+            let juror = self
+                .validators
+                .iter()
+                .find(|validator| validator.pubkey() == nominee)
+                .expect("nominee is a validator")
+                .insecure_clone();
+            let ix = Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::AcceptJuryDuty {
+                    juror: nominee,
+                    case_account: case_pda(case_id),
+                    case_panel: case_panel_pda(case_id),
+                }
+                .to_account_metas(None),
+                data: instruction::AcceptJuryDuty {}.data(),
+            };
+            self.execute(ix, &[&juror]).await;
+            panel.push(juror);
+        }
+        panel
+    }
+
+    fn vote_ix(&self, case_id: u64, juror: &Pubkey, verdict: Verdict) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::Vote {
+                juror: *juror,
+                case_account: case_pda(case_id),
+                case_panel: case_panel_pda(case_id),
+                config: config_pda(),
+                validator_page: Some(validator_page_pda(0)),
+                vote_record: vote_record_pda(case_id, juror),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::Vote { verdict }.data(),
+        }
+    }
+}
+
+fn report(name: &str, params: &str, units: u64, budget: u64) {
+    println!("{:<20} {:<36} {:>8} CU (budget {})", name, params, units, budget);
+    assert!(units <= budget, "{} exceeded its budget with {}: {} > {}", name, params, units, budget);
+}
+
+#[tokio::test]
+async fn bench_update_validators() {
+    for validator_count in VALIDATOR_COUNTS {
+        let mut bench = setup(validator_count, 3).await;
+        let ix = bench.update_validators_ix();
+        let units = bench.execute(ix, &[]).await;
+        report("update_validators", &format!("validators={}", validator_count), units, UPDATE_VALIDATORS_BUDGET);
+    }
+}
+
+#[tokio::test]
+async fn bench_submit_evidence() {
+    let mut bench = setup(10, 3).await;
+    for (case_id, uri_len) in EVIDENCE_URI_SIZES.into_iter().enumerate() {
+        let ix = bench.submit_evidence_ix(case_id as u64 + 1, uri_len);
+        let units = bench.execute(ix, &[]).await;
+        report("submit_evidence", &format!("uri_bytes={}", uri_len), units, SUBMIT_EVIDENCE_BUDGET);
+    }
+}
+
+#[tokio::test]
+async fn bench_select_jurors_and_vote() {
+    for validator_count in VALIDATOR_COUNTS {
+        for min_jurors in JUROR_COUNTS {
+            let mut bench = setup(validator_count, min_jurors).await;
+            let ix = bench.update_validators_ix();
+            bench.execute(ix, &[]).await;
+
+            let case_id = 1;
+            let ix = bench.submit_evidence_ix(case_id, 128);
+            bench.execute(ix, &[]).await;
+            let ix = bench.request_jurors_ix(case_id);
+            bench.execute(ix, &[]).await;
+            bench.reveal_randomness().await;
+
+            let params = format!("validators={} jurors={}", validator_count, min_jurors);
+            let ix = bench.select_jurors_ix(case_id);
+            let units = bench.execute(ix, &[]).await;
+            report("select_jurors", &params, units, SELECT_JURORS_BUDGET);
+            let panel = bench.accept_panel(case_id).await;
+
+            // Worst case for the voter-pool scan is the panelist furthest down the list
+            let juror = panel
+                .into_iter()
+                .max_by_key(|juror| bench.validators.iter().position(|v| v.pubkey() == juror.pubkey()))
+                .expect("panel");
+            let ix = bench.vote_ix(case_id, &juror.pubkey(), Verdict::Dismiss);
+            let units = bench.execute(ix, &[&juror]).await;
+            report("vote", &params, units, VOTE_BUDGET);
+        }
+    }
+}
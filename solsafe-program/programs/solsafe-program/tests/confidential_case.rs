@@ -0,0 +1,844 @@
+//! End-to-end confidential case pipeline under solana-program-test: sealed accused,
+//! encrypted evidence with MPC key escrow, ZK private votes, MPC tally, reveal-and-freeze,
+//! and confidential juror payouts. Build the program first:
+//!
+//!     anchor build && cargo test --features integration --test confidential_case
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use ark_bn254::{G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bytemuck::Zeroable;
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+use solsafe_program::{
+    accounts, instruction,
+    instructions::seal_address,
+    quantum_resistant::hash_evidence,
+    state::{CaseAccount, CaseCategory, CasePanel, CaseState, ConfidentialComponents, Verdict},
+    switchboard::{RandomnessAccountData, SWITCHBOARD_PROGRAM_ID},
+    zk_proofs::{ConfidentialBalance, ConfidentialTransferConfig, VoteCommitment, ZkProof, ZkProofType},
+    ErrorCode,
+};
+use solana_zk_token_sdk::{
+    encryption::{
+        elgamal::{ElGamalCiphertext, ElGamalKeypair},
+        pedersen::{Pedersen, PedersenOpening},
+    },
+    instruction::{BatchedRangeProofU128Data, CiphertextCommitmentEqualityProofData, ProofType, ZkProofData},
+    zk_token_elgamal::pod,
+    zk_token_proof_program,
+    zk_token_proof_state::ProofContextState,
+};
+
+const VALIDATOR_COUNT: usize = 3;
+const SALT: [u8; 32] = [7u8; 32];
+const TRANSFER_ID: u64 = 42;
+
+struct Harness {
+    context: ProgramTestContext,
+    banks: BanksClient,
+    payer: Keypair,
+    blockhash: Hash,
+    validators: Vec<Keypair>,
+    randomness: Pubkey,
+    accused: Pubkey,
+    mint: Pubkey,
+    scam_token_account: Pubkey,
+    funder: Keypair,
+    funder_balance: Pubkey,
+    juror_balances: Vec<Pubkey>,
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &solsafe_program::ID).0
+}
+
+fn config_pda() -> Pubkey {
+    pda(&[b"config"])
+}
+
+fn confidential_config_pda() -> Pubkey {
+    pda(&[b"confidential_case_config"])
+}
+
+fn validator_page_pda(page_index: u16) -> Pubkey {
+    pda(&[b"validator_page", page_index.to_le_bytes().as_ref()])
+}
+
+fn case_pda(case_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"case", case_id.to_le_bytes().as_ref()], &solsafe_program::ID)
+}
+
+fn case_pda_for(case_id: u64, prefix: &[u8], juror: Option<&Pubkey>) -> Pubkey {
+    match juror {
+        Some(juror) => pda(&[prefix, case_id.to_le_bytes().as_ref(), juror.as_ref()]),
+        None => pda(&[prefix, case_id.to_le_bytes().as_ref()]),
+    }
+}
+
+fn program_account<T: AccountSerialize>(value: &T, len: usize) -> Account {
+    let mut data = Vec::with_capacity(8 + len);
+    value.try_serialize(&mut data).expect("serialize");
+    data.resize(8 + len, 0);
+    Account { lamports: 1_000_000_000, data, owner: solsafe_program::ID, executable: false, rent_epoch: 0 }
+}
+
+fn custom_error(err: BanksClientError) -> u32 {
+    match err.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+fn proof_context_account(data: Vec<u8>) -> AccountSharedData {
+    AccountSharedData::from(Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: zk_token_proof_program::id(),
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+/// Encrypted `amount` plus the range and equality proof contexts for sending it out of
+/// `balance`, laid out as the zk-token-proof program leaves them after verification
+fn transfer_proofs(keypair: &ElGamalKeypair, balance: &pod::ElGamalCiphertext, balance_amount: u64, amount: u64)
+    -> ([u8; 64], AccountSharedData, AccountSharedData)
+{
+    let balance = ElGamalCiphertext::try_from(*balance).unwrap();
+    let amount_opening = PedersenOpening::new_rand();
+    let amount_ciphertext = keypair.pubkey().encrypt_with(amount, &amount_opening);
+    let remaining = &balance - &amount_ciphertext;
+    let remaining_opening = PedersenOpening::new_rand();
+    let remaining_commitment = Pedersen::with(balance_amount - amount, &remaining_opening);
+
+    let equality = CiphertextCommitmentEqualityProofData::new(
+        keypair, &remaining, &remaining_commitment, &remaining_opening, balance_amount - amount,
+    )
+    .unwrap();
+    let range = BatchedRangeProofU128Data::new(
+        vec![&amount_ciphertext.commitment, &remaining_commitment],
+        vec![amount, balance_amount - amount],
+        vec![64, 64],
+        vec![&amount_opening, &remaining_opening],
+    )
+    .unwrap();
+
+    let authority = Pubkey::new_unique();
+    (
+        pod::ElGamalCiphertext::from(amount_ciphertext).0,
+        proof_context_account(ProofContextState::encode(&authority, ProofType::BatchedRangeProofU128, range.context_data())),
+        proof_context_account(ProofContextState::encode(
+            &authority,
+            ProofType::CiphertextCommitmentEquality,
+            equality.context_data(),
+        )),
+    )
+}
+
+/// Switchboard randomness account seeded at slot 0, with its value once revealed (nonzero `reveal_slot`)
+fn randomness_account(reveal_slot: u64) -> Account {
+    let mut randomness = RandomnessAccountData::zeroed();
+    if reveal_slot != 0 {
+        randomness.reveal_slot = reveal_slot;
+        for (i, byte) in randomness.value.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37).wrapping_add(11);
+        }
+    }
+    let mut data = RandomnessAccountData::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&randomness));
+    Account { lamports: 1_000_000_000, data, owner: SWITCHBOARD_PROGRAM_ID, executable: false, rent_epoch: 0 }
+}
+
+async fn setup() -> Harness {
+    let mut program_test = ProgramTest::new("solsafe_program", solsafe_program::ID, None);
+    program_test.prefer_bpf(true);
+
+    let validators: Vec<Keypair> = (0..VALIDATOR_COUNT).map(|_| Keypair::new()).collect();
+    let funder = Keypair::new();
+    for key in validators.iter().map(|v| v.pubkey()).chain([funder.pubkey()]) {
+        program_test.add_account(key, Account::new(1_000_000_000, 0, &system_program::ID));
+    }
+
+    let randomness = Pubkey::new_unique();
+    program_test.add_account(randomness, randomness_account(0));
+
+    // The accused's token account, freezable by the program authority
+    let accused = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let scam_token_account = Pubkey::new_unique();
+    program_test.add_packable_account(
+        mint,
+        1_000_000_000,
+        &spl_token::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 0,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::Some(pda(&[b"authority"])),
+        },
+        &spl_token::ID,
+    );
+    program_test.add_packable_account(
+        scam_token_account,
+        1_000_000_000,
+        &spl_token::state::Account {
+            mint,
+            owner: accused,
+            amount: 0,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        },
+        &spl_token::ID,
+    );
+
+    // Confidential transfer config and balances have no setup instructions
+    let (conf_config, conf_bump) = Pubkey::find_program_address(&[b"conf_transfer_config"], &solsafe_program::ID);
+    program_test.add_account(
+        conf_config,
+        program_account(
+            &ConfidentialTransferConfig {
+                enabled: true,
+                compliance_pubkey: Pubkey::new_unique(),
+                pending_compliance_pubkey: Pubkey::default(),
+                rotation_unlocks_at: 0,
+                previous_compliance_pubkey: Pubkey::default(),
+                max_transfer_amount: u64::MAX,
+                require_proof: false,
+                whitelisted_tokens: vec![],
+                auditors: vec![],
+                audit_threshold: 0,
+                bump: conf_bump,
+            },
+            ConfidentialTransferConfig::LEN,
+        ),
+    );
+    let balance = |owner: Pubkey| ConfidentialBalance {
+        owner,
+        mint,
+        elgamal_pubkey: [0u8; 32],
+        encrypted_balance: [0u8; 64],
+        pending_balance: [0u8; 64],
+        decryptable_balance: 0,
+        last_update: 0,
+        bump: 0,
+    };
+    let funder_balance = Pubkey::new_unique();
+    program_test.add_account(funder_balance, program_account(&balance(funder.pubkey()), ConfidentialBalance::LEN));
+    let mut juror_balances = Vec::new();
+    for validator in &validators {
+        let address = Pubkey::new_unique();
+        program_test.add_account(address, program_account(&balance(validator.pubkey()), ConfidentialBalance::LEN));
+        juror_balances.push(address);
+    }
+
+    let context = program_test.start_with_context().await;
+    let banks = context.banks_client.clone();
+    let payer = context.payer.insecure_clone();
+    let blockhash = context.last_blockhash;
+    let mut harness = Harness {
+        context,
+        banks,
+        payer,
+        blockhash,
+        validators,
+        randomness,
+        accused,
+        mint,
+        scam_token_account,
+        funder,
+        funder_balance,
+        juror_balances,
+    };
+
+    let admin = harness.payer.pubkey();
+    harness
+        .execute(
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::Initialize {
+                    config: config_pda(),
+                    case_counter: pda(&[b"case_counter"]),
+                    admin,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::Initialize { quorum: VALIDATOR_COUNT as u8, min_jurors: VALIDATOR_COUNT as u8 }
+                    .data(),
+            },
+            &[],
+        )
+        .await;
+    harness
+        .execute(
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::AddValidatorPage {
+                    admin,
+                    config: config_pda(),
+                    validator_page: validator_page_pda(0),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::AddValidatorPage {}.data(),
+            },
+            &[],
+        )
+        .await;
+    let validators = harness.validators.iter().map(|v| v.pubkey()).collect();
+    harness
+        .execute(
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::UpdateValidators {
+                    config: config_pda(),
+                    validator_page: validator_page_pda(0),
+                    admin,
+                    protocol_stats: None,
+                }
+                .to_account_metas(None),
+                data: instruction::UpdateValidators { validators }.data(),
+            },
+            &[],
+        )
+        .await;
+    harness
+}
+
+impl Harness {
+    async fn try_execute_all(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&self.payer.pubkey()), &all_signers, self.blockhash);
+        let result = self.banks.process_transaction(tx).await;
+        self.blockhash = self.banks.get_latest_blockhash().await.expect("blockhash");
+        result
+    }
+
+    async fn execute(&mut self, ix: Instruction, signers: &[&Keypair]) {
+        self.try_execute_all(&[ix], signers).await.expect("process");
+    }
+
+    async fn case(&mut self, case_id: u64) -> CaseAccount {
+        let account = self
+            .banks
+            .get_account(case_pda(case_id).0)
+            .await
+            .expect("get_account")
+            .expect("case account");
+        bytemuck::pod_read_unaligned(&account.data[8..8 + CaseAccount::LEN])
+    }
+
+    async fn panel(&mut self, case_id: u64) -> Vec<Keypair> {
+        let account = self
+            .banks
+            .get_account(case_pda_for(case_id, b"case_panel", None))
+            .await
+            .expect("get_account")
+            .expect("case panel");
+        let case_panel = CasePanel::try_deserialize(&mut account.data.as_slice()).expect("case panel");
+        case_panel
+            .jurors
+            .iter()
+            .map(|juror| {
+                self.validators
+                    .iter()
+                    .find(|validator| validator.pubkey() == *juror)
+                    .expect("juror is a validator")
+                    .insecure_clone()
+            })
+            .collect()
+    }
+
+    fn configure_ix(&self, required_components: u8) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::ConfigureConfidentialCases {
+                admin: self.payer.pubkey(),
+                config: config_pda(),
+                confidential_config: confidential_config_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::ConfigureConfidentialCases { enabled: true, required_components }.data(),
+        }
+    }
+
+    fn private_evidence_ix(&self, case_id: u64) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::InitializePrivateEvidence {
+                reporter: self.payer.pubkey(),
+                evidence_commitment: case_pda_for(case_id, b"evidence_commitment", None),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InitializePrivateEvidence {
+                case_id,
+                evidence_hash: [9u8; 32],
+                encrypted_evidence: vec![1u8; 256],
+                threshold: 2,
+            }
+            .data(),
+        }
+    }
+
+    fn submit_ix(&self, case_id: u64, components: u8, evidence_uri: &str, with_commitment: bool) -> Instruction {
+        let (case_account, bump) = case_pda(case_id);
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::SubmitConfidentialCase {
+                case_account,
+                reporter: self.payer.pubkey(),
+                config: config_pda(),
+                confidential_config: confidential_config_pda(),
+                case_counter: pda(&[b"case_counter"]),
+                evidence_commitment: with_commitment.then(|| case_pda_for(case_id, b"evidence_commitment", None)),
+                treasury: None,
+                system_program: system_program::ID,
+                protocol_stats: None,
+            }
+            .to_account_metas(None),
+            data: instruction::SubmitConfidentialCase {
+                case_id,
+                sealed_address: seal_address(case_id, &self.accused, &SALT),
+                evidence_uri: evidence_uri.to_string(),
+                evidence_hash: if evidence_uri.is_empty() { [0u8; 32] } else { hash_evidence("bundle") },
+                components,
+                bump,
+                category: CaseCategory::Other,
+                evidence_tx_root: [0u8; 32],
+            }
+            .data(),
+        }
+    }
+
+    /// Draw the panel and have every nominee accept
+    async fn seat_panel(&mut self, case_id: u64) -> Vec<Keypair> {
+        let case_account = case_pda(case_id).0;
+        let ix = Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::RequestJurors {
+                case_account,
+                config: config_pda(),
+                randomness_account: self.randomness,
+                authority: self.payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::RequestJurors { case_id }.data(),
+        };
+        self.execute(ix, &[]).await;
+
+        // The oracle reveals in a later slot than the commit
+        let reveal_slot = self.banks.get_sysvar::<Clock>().await.expect("clock").slot + 1;
+        self.context.warp_to_slot(reveal_slot + 1).expect("warp");
+        self.context.set_account(&self.randomness, &AccountSharedData::from(randomness_account(reveal_slot)));
+        self.blockhash = self.banks.get_latest_blockhash().await.expect("blockhash");
+
+        let case_panel = case_pda_for(case_id, b"case_panel", None);
+        let mut metas = accounts::SelectJurors {
+            payer: self.payer.pubkey(),
+            case_account,
+            case_panel,
+            config: config_pda(),
+            vrf_account: self.randomness,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        metas.push(AccountMeta::new_readonly(validator_page_pda(0), false));
+        let ix = Instruction { program_id: solsafe_program::ID, accounts: metas, data: instruction::SelectJurors {}.data() };
+        self.execute(ix, &[]).await;
+
+        let panel = self.panel(case_id).await;
+        for juror in &panel {
+            let ix = Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::AcceptJuryDuty { juror: juror.pubkey(), case_account, case_panel }.to_account_metas(None),
+                data: instruction::AcceptJuryDuty {}.data(),
+            };
+            self.execute(ix, &[juror]).await;
+        }
+        panel
+    }
+
+    /// Evidence share, MPC share, escrow confirmation, then a private vote
+    async fn cast_private_vote(&mut self, case_id: u64, juror: &Keypair, approve: bool) {
+        let key = juror.pubkey();
+        let share_commitment = [key.to_bytes()[0]; 32];
+        let evidence_share = case_pda_for(case_id, b"evidence_share", Some(&key));
+        let mpc_share = case_pda_for(case_id, b"mpc_share", Some(&key));
+        let ixs = [
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::VerifyEvidenceShare {
+                    juror: key,
+                    juror_share: evidence_share,
+                    evidence: case_pda_for(case_id, b"evidence_commitment", None),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::VerifyEvidenceShare { share_commitment }.data(),
+            },
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::SubmitMpcShare {
+                    juror: key,
+                    mpc_share,
+                    mpc_config: case_pda_for(case_id, b"mpc_config", None),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::SubmitMpcShare { public_share: [3u8; 32], share_commitment }.data(),
+            },
+            Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::ConfirmKeyEscrow {
+                    juror: key,
+                    case_account: case_pda(case_id).0,
+                    case_panel: case_pda_for(case_id, b"case_panel", None),
+                    mpc_share,
+                    evidence_share: Some(evidence_share),
+                }
+                .to_account_metas(None),
+                data: instruction::ConfirmKeyEscrow {}.data(),
+            },
+        ];
+        self.try_execute_all(&ixs, &[juror]).await.expect("key escrow");
+
+        let (commitment, nullifier, zk_proof) = private_vote(case_id, approve);
+        let ix = Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::PrivateVote {
+                juror: key,
+                vote_commitment_account: case_pda_for(case_id, b"vote_commitment", Some(&key)),
+                case_account: case_pda(case_id).0,
+                compressed_state: case_pda_for(case_id, b"compressed_votes", None),
+                verifying_key: pda(&[b"verifying_key", &[ZkProofType::VoteCommitment as u8]]),
+                system_program: system_program::ID,
+                juror_set: None,
+                case_panel: Some(case_pda_for(case_id, b"case_panel", None)),
+                eligibility_key: None,
+            }
+            .to_account_metas(None),
+            data: instruction::PrivateVote {
+                case_id,
+                commitment,
+                nullifier,
+                zk_proof,
+                voter: key.to_bytes(),
+                eligibility: None,
+            }
+            .data(),
+        };
+        self.execute(ix, &[juror]).await;
+    }
+
+    /// Run a sealed, fully confidential case through the MPC tally
+    async fn run_to_verdict(&mut self, case_id: u64, approve: bool) -> Vec<Keypair> {
+        let ixs = [
+            self.private_evidence_ix(case_id),
+            self.submit_ix(case_id, ConfidentialComponents::ALL, "", true),
+        ];
+        self.try_execute_all(&ixs, &[]).await.expect("submit");
+        let panel = self.seat_panel(case_id).await;
+
+        // Public votes are refused once the case runs on private votes
+        let juror = &panel[0];
+        let ix = Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::Vote {
+                juror: juror.pubkey(),
+                case_account: case_pda(case_id).0,
+                case_panel: case_pda_for(case_id, b"case_panel", None),
+                config: config_pda(),
+                validator_page: Some(validator_page_pda(0)),
+                vote_record: case_pda_for(case_id, b"vote_record", Some(&juror.pubkey())),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::Vote {
+                verdict: if approve { Verdict::FreezeAndSeize } else { Verdict::Dismiss },
+            }
+            .data(),
+        };
+        let err = self.try_execute_all(&[ix], &[juror]).await.unwrap_err();
+        assert_eq!(custom_error(err), u32::from(ErrorCode::PrivateVoteRequired));
+
+        let ix = Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::OpenConfidentialTally {
+                payer: self.payer.pubkey(),
+                case_account: case_pda(case_id).0,
+                mpc_config: case_pda_for(case_id, b"mpc_config", None),
+                vote_aggregation: case_pda_for(case_id, b"mpc_aggregation", None),
+                compressed_state: case_pda_for(case_id, b"compressed_votes", None),
+                light_config: None,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::OpenConfidentialTally {}.data(),
+        };
+        self.execute(ix, &[]).await;
+
+        for juror in &panel {
+            self.cast_private_vote(case_id, juror, approve).await;
+        }
+
+        // A majority of the panel agrees on the decrypted tally
+        let votes = panel.len() as u64;
+        let (votes_for, votes_against) = if approve { (votes, 0) } else { (0, votes) };
+        let mut decryption_share = [0u8; 32];
+        decryption_share[..8].copy_from_slice(&votes_for.to_le_bytes());
+        decryption_share[8..16].copy_from_slice(&votes_against.to_le_bytes());
+        for juror in &panel[..panel.len() / 2 + 1] {
+            let ix = Instruction {
+                program_id: solsafe_program::ID,
+                accounts: accounts::SubmitPartialDecryption {
+                    juror: juror.pubkey(),
+                    mpc_share: case_pda_for(case_id, b"mpc_share", Some(&juror.pubkey())),
+                    mpc_config: case_pda_for(case_id, b"mpc_config", None),
+                    vote_aggregation: case_pda_for(case_id, b"mpc_aggregation", None),
+                }
+                .to_account_metas(None),
+                data: instruction::SubmitPartialDecryption { decryption_share, proof: [0u8; 64] }.data(),
+            };
+            self.execute(ix, &[juror]).await;
+        }
+
+        let ix = Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::FinalizeCaseFromMpc {
+                case_account: case_pda(case_id).0,
+                mpc_config: case_pda_for(case_id, b"mpc_config", None),
+                vote_aggregation: case_pda_for(case_id, b"mpc_aggregation", None),
+                compressed_state: case_pda_for(case_id, b"compressed_votes", None),
+            }
+            .to_account_metas(None),
+            data: instruction::FinalizeCaseFromMpc {}.data(),
+        };
+        self.execute(ix, &[]).await;
+        panel
+    }
+
+    fn finalize_ix(&self, case_id: u64, scam_index: Option<Pubkey>) -> Instruction {
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::FinalizeCase {
+                case_account: case_pda(case_id).0,
+                case_panel: None,
+                scam_index,
+                protocol_stats: None,
+                config: None,
+            }
+            .to_account_metas(None),
+            data: instruction::FinalizeCase {}.data(),
+        }
+    }
+}
+
+/// Commitment, nullifier, and a proof the hash-based verifier accepts: its first 32 bytes
+/// are the nullifier, which must also decode as a compressed G1 point, so the salt is ground
+fn private_vote(case_id: u64, approve: bool) -> ([u8; 32], [u8; 32], ZkProof) {
+    for nonce in 0u32.. {
+        let mut salt = [0u8; 32];
+        salt[..4].copy_from_slice(&nonce.to_le_bytes());
+        let commitment = VoteCommitment::compute_commitment(case_id, approve, &salt);
+        let nullifier = VoteCommitment::compute_nullifier(case_id, &commitment);
+        if G1Affine::deserialize_compressed(&nullifier[..]).is_err() {
+            continue;
+        }
+
+        let mut proof_data = nullifier.to_vec();
+        G2Affine::generator().serialize_compressed(&mut proof_data).expect("g2");
+        G1Affine::generator().serialize_compressed(&mut proof_data).expect("g1");
+        proof_data.resize(192, 0);
+        let mut public_inputs = case_id.to_le_bytes().to_vec();
+        public_inputs.extend_from_slice(&commitment);
+        return (commitment, nullifier, ZkProof { proof_data, public_inputs, proof_type: ZkProofType::VoteCommitment });
+    }
+    unreachable!()
+}
+
+#[tokio::test]
+async fn approved_case_reveals_freezes_and_pays_confidentially() {
+    let mut harness = setup().await;
+    let ix = harness.configure_ix(ConfidentialComponents::ENCRYPTED_EVIDENCE | ConfidentialComponents::PRIVATE_VOTES);
+    harness.execute(ix, &[]).await;
+
+    let case_id = 1;
+    let panel = harness.run_to_verdict(case_id, true).await;
+    let case = harness.case(case_id).await;
+    assert_eq!(case.state, CaseState::Approved as u8);
+    assert_eq!(case.votes_for, panel.len() as u64);
+    assert_eq!(case.scam_address, Pubkey::default());
+
+    // Finalizing before the reveal would fold a sealed address into the index
+    let ix = harness.finalize_ix(case_id, None);
+    let err = harness.try_execute_all(&[ix], &[]).await.unwrap_err();
+    assert_eq!(custom_error(err), u32::from(ErrorCode::AddressSealed));
+
+    // A wrong salt doesn't open the commitment
+    let scam_index = pda(&[b"scam_index", harness.accused.as_ref()]);
+    let reveal = |salt: [u8; 32], harness: &Harness| Instruction {
+        program_id: solsafe_program::ID,
+        accounts: accounts::RevealAndFreeze {
+            revealer: harness.payer.pubkey(),
+            case_account: case_pda(case_id).0,
+            scam_index,
+            freeze_receipt: pda(&[b"freeze_receipt", case_id.to_le_bytes().as_ref(), harness.scam_token_account.as_ref()]),
+            scam_token_account: harness.scam_token_account,
+            mint: harness.mint,
+            program_authority: pda(&[b"authority"]),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            protocol_stats: None,
+        }
+        .to_account_metas(None),
+        data: instruction::RevealAndFreeze { scam_address: harness.accused, salt }.data(),
+    };
+    let ix = reveal([8u8; 32], &harness);
+    let err = harness.try_execute_all(&[ix], &[]).await.unwrap_err();
+    assert_eq!(custom_error(err), u32::from(ErrorCode::InvalidSealedAddress));
+
+    let ix = reveal(SALT, &harness);
+    harness.execute(ix, &[]).await;
+    let case = harness.case(case_id).await;
+    assert_eq!(case.scam_address, harness.accused);
+    let token_account = harness.banks.get_account(harness.scam_token_account).await.unwrap().unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+    assert_eq!(token_account.state, spl_token::state::AccountState::Frozen);
+
+    let ix = harness.finalize_ix(case_id, Some(scam_index));
+    harness.execute(ix, &[]).await;
+    assert_eq!(harness.case(case_id).await.finalized, 1);
+
+    // Pay the first juror through a confidential transfer and bind it to the case
+    let juror = panel[0].pubkey();
+    let juror_balance = harness.juror_balances[harness.validators.iter().position(|v| v.pubkey() == juror).unwrap()];
+    let transfer = pda(&[b"confidential_transfer", TRANSFER_ID.to_le_bytes().as_ref()]);
+    let funder = harness.funder.insecure_clone();
+    let elgamal = ElGamalKeypair::new_rand();
+    let funded = pod::ElGamalCiphertext::from(elgamal.pubkey().encrypt(100u64));
+    let funder_balance = ConfidentialBalance {
+        owner: funder.pubkey(),
+        mint: harness.mint,
+        elgamal_pubkey: elgamal.pubkey().to_bytes(),
+        encrypted_balance: funded.0,
+        pending_balance: [0u8; 64],
+        decryptable_balance: 0,
+        last_update: 0,
+        bump: 0,
+    };
+    harness.context.set_account(
+        &harness.funder_balance,
+        &AccountSharedData::from(program_account(&funder_balance, ConfidentialBalance::LEN)),
+    );
+    let (encrypted_amount, range_context, equality_context) = transfer_proofs(&elgamal, &funded, 100, 5);
+    let (range_proof_context, equality_proof_context) = (Pubkey::new_unique(), Pubkey::new_unique());
+    harness.context.set_account(&range_proof_context, &range_context);
+    harness.context.set_account(&equality_proof_context, &equality_context);
+    let ixs = [
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::InitiateConfidentialTransfer {
+                sender: funder.pubkey(),
+                sender_balance: harness.funder_balance,
+                recipient_balance: juror_balance,
+                transfer_record: transfer,
+                config: pda(&[b"conf_transfer_config"]),
+                range_proof_context,
+                equality_proof_context,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InitiateConfidentialTransfer {
+                transfer_id: TRANSFER_ID,
+                encrypted_amount,
+                compliance_proof: vec![],
+            }
+            .data(),
+        },
+        Instruction {
+            program_id: solsafe_program::ID,
+            accounts: accounts::RecordConfidentialPayout {
+                payer: funder.pubkey(),
+                case_account: case_pda(case_id).0,
+                case_panel: case_pda_for(case_id, b"case_panel", None),
+                transfer,
+                juror_payout: case_pda_for(case_id, b"juror_payout", Some(&juror)),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::RecordConfidentialPayout {}.data(),
+        },
+    ];
+    harness.try_execute_all(&ixs, &[&funder]).await.expect("payout");
+    let payout = harness
+        .banks
+        .get_account(case_pda_for(case_id, b"juror_payout", Some(&juror)))
+        .await
+        .unwrap();
+    assert!(payout.is_some());
+}
+
+#[tokio::test]
+async fn rejected_case_finalizes_without_revealing_the_accused() {
+    let mut harness = setup().await;
+    let ix = harness.configure_ix(ConfidentialComponents::PRIVATE_VOTES);
+    harness.execute(ix, &[]).await;
+
+    let case_id = 1;
+    harness.run_to_verdict(case_id, false).await;
+    let case = harness.case(case_id).await;
+    assert_eq!(case.state, CaseState::Rejected as u8);
+
+    let ix = harness.finalize_ix(case_id, None);
+    harness.execute(ix, &[]).await;
+    let case = harness.case(case_id).await;
+    assert_eq!(case.finalized, 1);
+    assert_eq!(case.scam_address, Pubkey::default());
+    assert_ne!(case.closed_at, 0);
+}
+
+#[tokio::test]
+async fn submission_must_use_every_mandatory_component() {
+    let mut harness = setup().await;
+    let ix = harness.configure_ix(ConfidentialComponents::PRIVATE_VOTES);
+    harness.execute(ix, &[]).await;
+
+    let ix = harness.submit_ix(1, ConfidentialComponents::SEALED_ADDRESS, "ipfs://plain-evidence", false);
+    let err = harness.try_execute_all(&[ix], &[]).await.unwrap_err();
+    assert_eq!(custom_error(err), u32::from(ErrorCode::InvalidConfidentialComponents));
+
+    // Encrypted evidence can't come with a public bundle alongside it
+    let ixs = [
+        harness.private_evidence_ix(1),
+        harness.submit_ix(1, ConfidentialComponents::ALL, "ipfs://plain-evidence", true),
+    ];
+    let err = harness.try_execute_all(&ixs, &[]).await.unwrap_err();
+    assert_eq!(custom_error(err), u32::from(ErrorCode::InvalidConfidentialComponents));
+
+    let ix = harness.submit_ix(1, ConfidentialComponents::PRIVATE_VOTES, "ipfs://plain-evidence", false);
+    harness.execute(ix, &[]).await;
+    let case = harness.case(1).await;
+    assert_eq!(
+        case.confidential,
+        ConfidentialComponents::SEALED_ADDRESS | ConfidentialComponents::PRIVATE_VOTES
+    );
+}
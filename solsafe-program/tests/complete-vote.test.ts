@@ -11,11 +11,26 @@ describe("SolSafe Voting System", () => {
   
   let configPda: PublicKey;
   let configBump: number;
+  let validatorPagePda: PublicKey;
   let admin: Keypair;
   let validators: Keypair[];
   let reporter: Keypair;
   let scammer: PublicKey;
 
+  // Created on a juror's first vote; a second vote fails on init
+  const voteRecordPda = (caseId: anchor.BN, juror: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("vote_record"), caseId.toArrayLike(Buffer, "le", 8), juror.toBuffer()],
+      program.programId
+    )[0];
+
+  // Seats drawn by select_jurors, grown as the panel fills
+  const casePanelPda = (caseId: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("case_panel"), caseId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
   before(async () => {
     // Setup accounts
     admin = Keypair.generate();
@@ -50,6 +65,12 @@ describe("SolSafe Voting System", () => {
       [Buffer.from("config")],
       program.programId
     );
+
+    // Five validators fit on the first registry page
+    [validatorPagePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("validator_page"), new anchor.BN(0).toArrayLike(Buffer, "le", 2)],
+      program.programId
+    );
   });
 
   it("Initializes the global config", async () => {
@@ -147,17 +168,21 @@ describe("SolSafe Voting System", () => {
     await program.methods
       .selectJurors()
       .accounts({
+        payer: provider.wallet.publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
         vrfAccount: vrfAccount.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
     const caseAccount = await program.account.caseAccount.fetch(casePda);
-    assert.equal(caseAccount.jurors.length, 3);
+    const casePanel = await program.account.casePanel.fetch(casePanelPda(caseId));
+    assert.equal(casePanel.jurors.length, 3);
     assert.equal(caseAccount.state.voting !== undefined, true);
-    console.log("✓ Jurors selected:", caseAccount.jurors.length);
-    console.log("  Selected jurors:", caseAccount.jurors.map((j: any) => j.toString()));
+    console.log("✓ Jurors selected:", casePanel.jurors.length);
+    console.log("  Selected jurors:", casePanel.jurors.map((j: any) => j.toString()));
   });
 
   it("Jurors vote on the case - Approval scenario", async () => {
@@ -168,7 +193,7 @@ describe("SolSafe Voting System", () => {
     );
 
     let caseAccount = await program.account.caseAccount.fetch(casePda);
-    const selectedJurors = caseAccount.jurors;
+    const selectedJurors = (await program.account.casePanel.fetch(casePanelPda(caseId))).jurors;
 
     // Find which validators were selected as jurors
     const jurorSigners = validators.filter((v) =>
@@ -179,11 +204,14 @@ describe("SolSafe Voting System", () => {
 
     // First juror votes to approve
     await program.methods
-      .vote(true)
+      .vote({ freezeAndSeize: {} })
       .accounts({
         juror: jurorSigners[0].publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
+        validatorPage: validatorPagePda,
+        voteRecord: voteRecordPda(caseId, jurorSigners[0].publicKey),
         systemProgram: SystemProgram.programId,
       })
       .signers([jurorSigners[0]])
@@ -196,11 +224,14 @@ describe("SolSafe Voting System", () => {
 
     // Second juror votes to approve (reaches quorum)
     await program.methods
-      .vote(true)
+      .vote({ freezeAndSeize: {} })
       .accounts({
         juror: jurorSigners[1].publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
+        validatorPage: validatorPagePda,
+        voteRecord: voteRecordPda(caseId, jurorSigners[1].publicKey),
         systemProgram: SystemProgram.programId,
       })
       .signers([jurorSigners[1]])
@@ -261,25 +292,30 @@ describe("SolSafe Voting System", () => {
     await program.methods
       .selectJurors()
       .accounts({
+        payer: provider.wallet.publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
         vrfAccount: vrfAccount.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
-    const caseAccount = await program.account.caseAccount.fetch(casePda);
-    const selectedJurors = caseAccount.jurors;
+    const selectedJurors = (await program.account.casePanel.fetch(casePanelPda(caseId))).jurors;
     const jurorSigners = validators.filter((v) =>
       selectedJurors.some((j: PublicKey) => j.equals(v.publicKey))
     );
 
     // First vote succeeds
     await program.methods
-      .vote(true)
+      .vote({ freezeAndSeize: {} })
       .accounts({
         juror: jurorSigners[0].publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
+        validatorPage: validatorPagePda,
+        voteRecord: voteRecordPda(caseId, jurorSigners[0].publicKey),
         systemProgram: SystemProgram.programId,
       })
       .signers([jurorSigners[0]])
@@ -288,18 +324,21 @@ describe("SolSafe Voting System", () => {
     // Second vote from same juror should fail
     try {
       await program.methods
-        .vote(true)
+        .vote({ freezeAndSeize: {} })
         .accounts({
           juror: jurorSigners[0].publicKey,
           caseAccount: casePda,
+          casePanel: casePanelPda(caseId),
           config: configPda,
+          validatorPage: validatorPagePda,
+          voteRecord: voteRecordPda(caseId, jurorSigners[0].publicKey),
           systemProgram: SystemProgram.programId,
         })
         .signers([jurorSigners[0]])
         .rpc();
       assert.fail("Should have thrown error for double voting");
     } catch (error: any) {
-      assert.include(error.toString(), "AlreadyVoted");
+      assert.match(error.toString(), /already in use/i);
       console.log("✓ Double voting prevented successfully");
     }
   });
@@ -343,14 +382,17 @@ describe("SolSafe Voting System", () => {
     await program.methods
       .selectJurors()
       .accounts({
+        payer: provider.wallet.publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda(caseId),
         config: configPda,
         vrfAccount: vrfAccount.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
     let caseAccount = await program.account.caseAccount.fetch(casePda);
-    const selectedJurors = caseAccount.jurors;
+    const selectedJurors = (await program.account.casePanel.fetch(casePanelPda(caseId))).jurors;
     const jurorSigners = validators.filter((v) =>
       selectedJurors.some((j: PublicKey) => j.equals(v.publicKey))
     );
@@ -358,11 +400,14 @@ describe("SolSafe Voting System", () => {
     // All 3 jurors vote to reject
     for (let i = 0; i < 3; i++) {
       await program.methods
-        .vote(false)
+        .vote({ dismiss: {} })
         .accounts({
           juror: jurorSigners[i].publicKey,
           caseAccount: casePda,
+          casePanel: casePanelPda(caseId),
           config: configPda,
+          validatorPage: validatorPagePda,
+          voteRecord: voteRecordPda(caseId, jurorSigners[i].publicKey),
           systemProgram: SystemProgram.programId,
         })
         .signers([jurorSigners[i]])
@@ -65,31 +65,46 @@ describe("vote-guard", () => {
       })
       .rpc();
 
+    const [casePanelPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("case_panel"), caseIdBuf],
+      program.programId
+    );
     await program.methods
       .selectJurors()
       .accounts({
+        payer: provider.wallet.publicKey,
         caseAccount: casePda,
+        casePanel: casePanelPda,
         config: configKeypair.publicKey,
         vrfAccount: vrfAccount.publicKey,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
-    // 5) Fetch case account to get jurors
-    const caseAccount = await program.account.caseAccount.fetch(casePda);
-    const jurors: PublicKey[] = caseAccount.jurors;
+    // 5) Fetch the case's panel to get jurors
+    const casePanel = await program.account.casePanel.fetch(casePanelPda);
+    const jurors: PublicKey[] = casePanel.jurors;
     expect(jurors.map((j: PublicKey) => j.toString())).to.include(provider.wallet.publicKey.toString());
 
     // 6) Cast a vote as provider.wallet (should succeed)
     const [voteRecordPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vote"), casePda.toBuffer(), provider.wallet.publicKey.toBuffer()],
+      [Buffer.from("vote_record"), caseIdBuf, provider.wallet.publicKey.toBuffer()],
+      program.programId
+    );
+    // All three validators sit on the first registry page
+    const [validatorPagePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("validator_page"), new BN(0).toArrayLike(Buffer, "le", 2)],
       program.programId
     );
 
     await program.methods
-      .vote(true)
+      .vote({ freezeAndSeize: {} })
       .accounts({
         caseAccount: casePda,
-        validator: provider.wallet.publicKey,
+        casePanel: casePanelPda,
+        juror: provider.wallet.publicKey,
+        config: configKeypair.publicKey,
+        validatorPage: validatorPagePda,
         voteRecord: voteRecordPda,
         systemProgram: SystemProgram.programId,
       })
@@ -99,10 +114,13 @@ describe("vote-guard", () => {
     let thrown = false;
     try {
       await program.methods
-        .vote(true)
+        .vote({ freezeAndSeize: {} })
         .accounts({
           caseAccount: casePda,
-          validator: provider.wallet.publicKey,
+          casePanel: casePanelPda,
+          juror: provider.wallet.publicKey,
+          config: configKeypair.publicKey,
+          validatorPage: validatorPagePda,
           voteRecord: voteRecordPda,
           systemProgram: SystemProgram.programId,
         })